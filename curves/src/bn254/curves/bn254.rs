@@ -0,0 +1,4 @@
+pub use ark_bn254::g1::Parameters as BN254Parameters;
+
+pub type BN254 = ark_bn254::G1Affine;
+pub type ProjectiveBN254 = ark_bn254::G1Projective;
@@ -0,0 +1,5 @@
+pub mod bn254;
+pub mod grumpkin;
+
+#[cfg(test)]
+mod tests;
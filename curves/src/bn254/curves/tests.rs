@@ -0,0 +1,41 @@
+use crate::bn254::{Fq, Fr, Grumpkin, BN254};
+use ark_algebra_test_templates::{curves::*, groups::*};
+use ark_ec::AffineCurve;
+use ark_ff::{FpParameters, PrimeField, Zero};
+use ark_std::test_rng;
+use rand::Rng;
+
+use super::grumpkin;
+
+#[test]
+fn test_grumpkin_projective_curve() {
+    curve_tests::<grumpkin::ProjectiveGrumpkin>();
+
+    sw_tests::<grumpkin::GrumpkinParameters>();
+}
+
+#[test]
+fn test_grumpkin_projective_group() {
+    let mut rng = test_rng();
+    let a: grumpkin::ProjectiveGrumpkin = rng.gen();
+    let b: grumpkin::ProjectiveGrumpkin = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_grumpkin_generator() {
+    let generator = Grumpkin::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bn254_grumpkin_cycle() {
+    // the order of each curve is the size of the base field of the other one
+    assert!(BN254::prime_subgroup_generator()
+        .mul(<Fr as PrimeField>::Params::MODULUS)
+        .is_zero());
+    assert!(Grumpkin::prime_subgroup_generator()
+        .mul(<Fq as PrimeField>::Params::MODULUS)
+        .is_zero());
+}
@@ -0,0 +1,13 @@
+//! The BN254 curve and Grumpkin, its cycle partner.
+//!
+//! Grumpkin is defined over the scalar field of BN254 and its scalar field is
+//! the base field of BN254, so that the two curves form a cycle. The fields
+//! and BN254 itself are the ones from `ark_bn254`.
+
+pub mod curves;
+
+pub use ark_bn254::{Fq, Fr};
+pub use curves::{
+    bn254::{BN254Parameters, ProjectiveBN254, BN254},
+    grumpkin::{Grumpkin, GrumpkinParameters, ProjectiveGrumpkin},
+};
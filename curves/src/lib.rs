@@ -1 +1,3 @@
+pub mod bn254;
 pub mod pasta;
+pub mod serialization;
@@ -0,0 +1,240 @@
+//! Canonical encodings of the points of the curves of this crate.
+//!
+//! Field elements are encoded in little-endian, on the number of bytes of
+//! their representation (32 bytes for the pasta and BN254 fields), and
+//! decoding rejects non-canonical encodings (values larger than the modulus).
+//!
+//! - The uncompressed encoding of a point is `x || y`. The point at infinity
+//!   is encoded as `(0, 0)`, which is not on the curves of this crate.
+//! - The compressed encoding of a point is `x || flags`, where `flags` is a
+//!   byte whose bit 0 is the parity of `y` and bit 1 is set for the point at
+//!   infinity (`x` is then zero). For points other than the point at infinity,
+//!   this is the format of the compressed public keys of Mina.
+//!
+//! Decoding checks that points are on the curve and in the prime-order
+//! subgroup.
+
+use ark_ec::{short_weierstrass_jacobian::GroupAffine, SWModelParameters};
+use ark_ff::{BigInteger, FromBytes, PrimeField, ToBytes, Zero};
+use thiserror::Error;
+
+/// Flag of the compressed encoding for an odd y-coordinate.
+const FLAG_ODD: u8 = 0x01;
+
+/// Flag of the compressed encoding for the point at infinity.
+const FLAG_INFINITY: u8 = 0x02;
+
+/// Serialization errors
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerializationError {
+    /// Invalid number of bytes
+    #[error("invalid length: expected {expected} bytes, found {found}")]
+    InvalidLength { expected: usize, found: usize },
+    /// Field element larger than the modulus
+    #[error("non-canonical field element")]
+    NonCanonicalField,
+    /// Invalid flag byte of a compressed point
+    #[error("invalid flags")]
+    InvalidFlags,
+    /// No point of the curve has this x-coordinate
+    #[error("invalid x-coordinate")]
+    InvalidXCoordinate,
+    /// Point not on curve
+    #[error("point not on curve")]
+    NotOnCurve,
+    /// Point not in the prime-order subgroup
+    #[error("point not in the prime-order subgroup")]
+    NotInSubgroup,
+}
+
+/// Serialization Result
+pub type Result<T> = std::result::Result<T, SerializationError>;
+
+/// The size of an encoded field element, in bytes.
+pub fn field_size<F: PrimeField>() -> usize {
+    F::BigInt::NUM_LIMBS * 8
+}
+
+/// The size of a compressed point of the curve `P`, in bytes.
+pub fn compressed_size<P: SWModelParameters>() -> usize
+where
+    P::BaseField: PrimeField,
+{
+    field_size::<P::BaseField>() + 1
+}
+
+/// The size of an uncompressed point of the curve `P`, in bytes.
+pub fn uncompressed_size<P: SWModelParameters>() -> usize
+where
+    P::BaseField: PrimeField,
+{
+    2 * field_size::<P::BaseField>()
+}
+
+/// Encodes a field element in little-endian.
+pub fn field_to_bytes<F: PrimeField>(x: &F) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(field_size::<F>());
+    x.into_repr()
+        .write(&mut bytes)
+        .expect("writing to a vector never fails");
+    bytes
+}
+
+/// Decodes a field element encoded in little-endian.
+///
+/// # Errors
+///
+/// Will give error if `bytes` has the wrong length, or encodes a value larger
+/// than the modulus.
+pub fn field_from_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F> {
+    check_length(bytes, field_size::<F>())?;
+    let repr = F::BigInt::read(bytes).map_err(|_| SerializationError::NonCanonicalField)?;
+    F::from_repr(repr).ok_or(SerializationError::NonCanonicalField)
+}
+
+/// Returns the parity of the y-coordinate, as used by the compressed encoding.
+pub fn y_parity<F: PrimeField>(y: &F) -> bool {
+    y.into_repr().is_odd()
+}
+
+/// Returns the point of the curve `P` with x-coordinate `x` and a
+/// y-coordinate of parity `is_odd`, checking that it is in the prime-order
+/// subgroup. This is the inverse of `(p.x, y_parity(&p.y))`.
+///
+/// # Errors
+///
+/// Will give error if no point of the curve has the x-coordinate `x`, or if
+/// the point is not in the prime-order subgroup.
+pub fn from_x_and_parity<P: SWModelParameters>(
+    x: P::BaseField,
+    is_odd: bool,
+) -> Result<GroupAffine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    let mut point = GroupAffine::<P>::get_point_from_x(x, false)
+        .ok_or(SerializationError::InvalidXCoordinate)?;
+    if y_parity(&point.y) != is_odd {
+        point.y = -point.y;
+    }
+    check_subgroup(point)
+}
+
+/// Encodes a point in compressed form.
+pub fn to_compressed_bytes<P: SWModelParameters>(point: &GroupAffine<P>) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let (x, flags) = if point.infinity {
+        (P::BaseField::zero(), FLAG_INFINITY)
+    } else if y_parity(&point.y) {
+        (point.x, FLAG_ODD)
+    } else {
+        (point.x, 0)
+    };
+    let mut bytes = field_to_bytes(&x);
+    bytes.push(flags);
+    bytes
+}
+
+/// Decodes a point in compressed form.
+///
+/// # Errors
+///
+/// Will give error if `bytes` is not the encoding of a point of the
+/// prime-order subgroup.
+pub fn from_compressed_bytes<P: SWModelParameters>(bytes: &[u8]) -> Result<GroupAffine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    check_length(bytes, compressed_size::<P>())?;
+    let (x_bytes, flags) = bytes.split_at(field_size::<P::BaseField>());
+    let x: P::BaseField = field_from_bytes(x_bytes)?;
+    match flags[0] {
+        FLAG_INFINITY if x.is_zero() => Ok(GroupAffine::zero()),
+        0 => from_x_and_parity(x, false),
+        FLAG_ODD => from_x_and_parity(x, true),
+        _ => Err(SerializationError::InvalidFlags),
+    }
+}
+
+/// Decodes many points in compressed form, concatenated in `bytes`.
+///
+/// # Errors
+///
+/// Will give error if `bytes` is not a multiple of the size of a compressed
+/// point, or if any point fails to decode.
+pub fn batch_from_compressed_bytes<P: SWModelParameters>(
+    bytes: &[u8],
+) -> Result<Vec<GroupAffine<P>>>
+where
+    P::BaseField: PrimeField,
+{
+    let size = compressed_size::<P>();
+    if bytes.len() % size != 0 {
+        return Err(SerializationError::InvalidLength {
+            expected: (bytes.len() / size + 1) * size,
+            found: bytes.len(),
+        });
+    }
+    bytes.chunks(size).map(from_compressed_bytes).collect()
+}
+
+/// Encodes a point in uncompressed form.
+pub fn to_uncompressed_bytes<P: SWModelParameters>(point: &GroupAffine<P>) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let (x, y) = if point.infinity {
+        (P::BaseField::zero(), P::BaseField::zero())
+    } else {
+        (point.x, point.y)
+    };
+    [field_to_bytes(&x), field_to_bytes(&y)].concat()
+}
+
+/// Decodes a point in uncompressed form.
+///
+/// # Errors
+///
+/// Will give error if `bytes` is not the encoding of a point of the
+/// prime-order subgroup.
+pub fn from_uncompressed_bytes<P: SWModelParameters>(bytes: &[u8]) -> Result<GroupAffine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    check_length(bytes, uncompressed_size::<P>())?;
+    let (x_bytes, y_bytes) = bytes.split_at(field_size::<P::BaseField>());
+    let x: P::BaseField = field_from_bytes(x_bytes)?;
+    let y: P::BaseField = field_from_bytes(y_bytes)?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(GroupAffine::zero());
+    }
+    let point = GroupAffine::<P>::new(x, y, false);
+    if !point.is_on_curve() {
+        return Err(SerializationError::NotOnCurve);
+    }
+    check_subgroup(point)
+}
+
+fn check_length(bytes: &[u8], expected: usize) -> Result<()> {
+    if bytes.len() == expected {
+        Ok(())
+    } else {
+        Err(SerializationError::InvalidLength {
+            expected,
+            found: bytes.len(),
+        })
+    }
+}
+
+fn check_subgroup<P: SWModelParameters>(point: GroupAffine<P>) -> Result<GroupAffine<P>> {
+    if point.is_in_correct_subgroup_assuming_on_curve() {
+        Ok(point)
+    } else {
+        Err(SerializationError::NotInSubgroup)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,152 @@
+use super::*;
+use crate::{
+    bn254::{BN254Parameters, GrumpkinParameters},
+    pasta::{Fp, Pallas, PallasParameters, VestaParameters},
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{FpParameters, PrimeField, UniformRand};
+use ark_std::test_rng;
+
+fn test_roundtrip<P: SWModelParameters>()
+where
+    P::BaseField: PrimeField,
+{
+    let mut rng = test_rng();
+    let mut points: Vec<GroupAffine<P>> = (0..10)
+        .map(|_| {
+            GroupAffine::<P>::prime_subgroup_generator()
+                .mul(P::ScalarField::rand(&mut rng))
+                .into_affine()
+        })
+        .collect();
+    points.push(GroupAffine::zero());
+
+    for point in &points {
+        let compressed = to_compressed_bytes(point);
+        assert_eq!(compressed.len(), compressed_size::<P>());
+        assert_eq!(from_compressed_bytes::<P>(&compressed), Ok(*point));
+
+        let uncompressed = to_uncompressed_bytes(point);
+        assert_eq!(uncompressed.len(), uncompressed_size::<P>());
+        assert_eq!(from_uncompressed_bytes::<P>(&uncompressed), Ok(*point));
+    }
+
+    let batch: Vec<u8> = points.iter().flat_map(to_compressed_bytes).collect();
+    assert_eq!(batch_from_compressed_bytes::<P>(&batch), Ok(points));
+    assert!(matches!(
+        batch_from_compressed_bytes::<P>(&batch[1..]),
+        Err(SerializationError::InvalidLength { .. })
+    ));
+}
+
+#[test]
+fn test_roundtrip_pasta() {
+    test_roundtrip::<PallasParameters>();
+    test_roundtrip::<VestaParameters>();
+}
+
+#[test]
+fn test_roundtrip_bn254() {
+    test_roundtrip::<BN254Parameters>();
+    test_roundtrip::<GrumpkinParameters>();
+}
+
+#[test]
+fn test_mina_compressed_public_key() {
+    // A public key and its compressed form, as encoded by mina-signer
+    let public = hex::decode("44100485d466a4c9f481d43be9a6d4a9a5e97adac19777b14b6b7a81edee39093179f4f12897797cfe78bf2fd6321f36b00bd0592defbf39a199b4168735883c").unwrap();
+    let compressed =
+        hex::decode("44100485d466a4c9f481d43be9a6d4a9a5e97adac19777b14b6b7a81edee390901").unwrap();
+
+    let point = from_uncompressed_bytes::<PallasParameters>(&public).unwrap();
+    assert_eq!(to_compressed_bytes(&point), compressed);
+    assert_eq!(
+        from_compressed_bytes::<PallasParameters>(&compressed),
+        Ok(point)
+    );
+    assert_eq!(
+        from_x_and_parity::<PallasParameters>(point.x, true),
+        Ok(point)
+    );
+    assert_eq!(
+        from_x_and_parity::<PallasParameters>(point.x, false),
+        Ok(-point)
+    );
+}
+
+#[test]
+fn test_invalid_encodings() {
+    let generator = Pallas::prime_subgroup_generator();
+
+    // wrong lengths
+    let compressed = to_compressed_bytes(&generator);
+    assert_eq!(
+        from_compressed_bytes::<PallasParameters>(&compressed[1..]),
+        Err(SerializationError::InvalidLength {
+            expected: 33,
+            found: 32
+        })
+    );
+    let uncompressed = to_uncompressed_bytes(&generator);
+    assert_eq!(
+        from_uncompressed_bytes::<PallasParameters>(&[uncompressed.clone(), vec![0]].concat()),
+        Err(SerializationError::InvalidLength {
+            expected: 64,
+            found: 65
+        })
+    );
+
+    // non-canonical x-coordinate: x + p
+    let mut x_plus_p = generator.x.into_repr();
+    x_plus_p.add_nocarry(&<Fp as PrimeField>::Params::MODULUS);
+    let mut bytes = vec![];
+    x_plus_p.write(&mut bytes).unwrap();
+    bytes.push(compressed[32]);
+    assert_eq!(
+        from_compressed_bytes::<PallasParameters>(&bytes),
+        Err(SerializationError::NonCanonicalField)
+    );
+
+    // invalid flags
+    let mut bytes = compressed.clone();
+    bytes[32] = 0x04;
+    assert_eq!(
+        from_compressed_bytes::<PallasParameters>(&bytes),
+        Err(SerializationError::InvalidFlags)
+    );
+    let mut bytes = compressed;
+    bytes[32] = FLAG_INFINITY;
+    assert_eq!(
+        from_compressed_bytes::<PallasParameters>(&bytes),
+        Err(SerializationError::InvalidFlags)
+    );
+
+    // invalid x-coordinate: x^3 + 5 is not a square for x = 2
+    let mut bytes = field_to_bytes(&Fp::from(2u64));
+    bytes.push(0);
+    assert_eq!(
+        from_compressed_bytes::<PallasParameters>(&bytes),
+        Err(SerializationError::InvalidXCoordinate)
+    );
+
+    // not on curve
+    let mut bytes = uncompressed;
+    bytes[32] ^= 1;
+    assert_eq!(
+        from_uncompressed_bytes::<PallasParameters>(&bytes),
+        Err(SerializationError::NotOnCurve)
+    );
+}
+
+#[test]
+fn test_field_encoding() {
+    let x = Fp::from(0x0102u64);
+    let bytes = field_to_bytes(&x);
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(&bytes[..3], &[0x02, 0x01, 0x00]);
+    assert_eq!(field_from_bytes(&bytes), Ok(x));
+
+    // the encodings of the other curves have the same size
+    assert_eq!(compressed_size::<VestaParameters>(), 33);
+    assert_eq!(uncompressed_size::<BN254Parameters>(), 64);
+}
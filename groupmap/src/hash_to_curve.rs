@@ -0,0 +1,179 @@
+//! Hashing to elliptic curves, with the API of RFC 9380.
+//!
+//! A message is first hashed to field elements with `expand_message_xmd`
+//! (SHA-256), and the field elements are mapped to the curve with the
+//! Shallue–van de Woestijne map of [BWParameters]:
+//!
+//! - [HashToCurveSuite::hash_to_curve] maps two field elements and adds the
+//!   two points. Its output is indistinguishable from a random point
+//!   (the `_RO_` suites of the RFC).
+//! - [HashToCurveSuite::encode_to_curve] maps a single field element, and is
+//!   cheaper, but its output is not uniformly distributed (the `_NU_` suites).
+//!
+//! In both cases, the cofactor of the curve is cleared at the end.
+//!
+//! As the map to the curve is the one of [BWParameters] and not the
+//! `map_to_curve_svdw` of the RFC, the suites are not the `SVDW` suites of the
+//! RFC, and their outputs differ. The suite identifiers used for the test
+//! vectors are `PALLAS_XMD:SHA-256_BW_RO_`, `VESTA_XMD:SHA-256_BW_RO_` and
+//! `BN254G1_XMD:SHA-256_BW_RO_` (and `_NU_` for the encodings).
+//!
+//! RFC 9380: Faz-Hernandez et al., "Hashing to Elliptic Curves". <https://www.rfc-editor.org/rfc/rfc9380>
+
+use crate::{BWParameters, GroupMap};
+use ark_ec::{short_weierstrass_jacobian::GroupAffine, AffineCurve, SWModelParameters};
+use ark_ff::{BigInteger, PrimeField};
+use sha2::{Digest, Sha256};
+
+/// The output size of SHA-256, in bytes.
+const B_IN_BYTES: usize = 32;
+
+/// The input block size of SHA-256, in bytes.
+const S_IN_BYTES: usize = 64;
+
+/// The target security level, in bits.
+const SECURITY_LEVEL: usize = 128;
+
+/// The prefix used to hash domain separation tags longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands `msg` into `len_in_bytes` pseudo-random bytes with SHA-256,
+/// following `expand_message_xmd` (RFC 9380, section 5.3.1).
+///
+/// Domain separation tags longer than 255 bytes are hashed first, as in
+/// section 5.3.3 of the RFC.
+///
+/// # Panics
+///
+/// Will panic if `len_in_bytes` is larger than `255 * 32` or than `65535`.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = (len_in_bytes + B_IN_BYTES - 1) / B_IN_BYTES;
+    assert!(ell <= 255 && len_in_bytes <= 65535);
+
+    let dst_prime = {
+        let mut dst_prime = if dst.len() > 255 {
+            Sha256::new()
+                .chain_update(OVERSIZE_DST_PREFIX)
+                .chain_update(dst)
+                .finalize()
+                .to_vec()
+        } else {
+            dst.to_vec()
+        };
+        dst_prime.push(dst_prime.len() as u8);
+        dst_prime
+    };
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = Sha256::new()
+        .chain_update([0u8; S_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hashes `msg` to `count` elements of the prime field `F`
+/// (RFC 9380, section 5.2).
+pub fn hash_to_field<F: PrimeField>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    // L = ceil((ceil(log2(p)) + k) / 8)
+    let len_per_elem = (F::size_in_bits() + SECURITY_LEVEL + 7) / 8;
+    let uniform_bytes = expand_message_xmd(msg, dst, count * len_per_elem);
+    uniform_bytes
+        .chunks(len_per_elem)
+        .map(F::from_be_bytes_mod_order)
+        .collect()
+}
+
+/// Returns the "sign" of a field element, its parity (RFC 9380, section 4.1).
+pub fn sgn0<F: PrimeField>(x: F) -> bool {
+    x.into_repr().is_odd()
+}
+
+/// A hash to the curve `G`, with a fixed domain separation tag.
+#[derive(Clone)]
+pub struct HashToCurveSuite<G: SWModelParameters> {
+    map: BWParameters<G>,
+    dst: Vec<u8>,
+}
+
+impl<G: SWModelParameters> HashToCurveSuite<G>
+where
+    G::BaseField: PrimeField,
+{
+    /// Creates a suite with the domain separation tag `dst`.
+    pub fn new(dst: &[u8]) -> Self {
+        HashToCurveSuite {
+            map: BWParameters::setup(),
+            dst: dst.to_vec(),
+        }
+    }
+
+    /// Returns the domain separation tag of the suite.
+    pub fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    /// Hashes `msg` to `count` elements of the base field.
+    pub fn hash_to_field(&self, msg: &[u8], count: usize) -> Vec<G::BaseField> {
+        hash_to_field(msg, &self.dst, count)
+    }
+
+    /// Maps a field element to the curve. Unlike [GroupMap::to_group], the
+    /// sign of the y-coordinate is chosen deterministically, to be the sign
+    /// of `u`. The cofactor is not cleared.
+    pub fn map_to_curve(&self, u: G::BaseField) -> GroupAffine<G> {
+        let (x, y) = self.map.to_group(u);
+        let y = if sgn0(u) == sgn0(y) { y } else { -y };
+        GroupAffine::new(x, y, false)
+    }
+
+    /// Hashes `msg` to a point of the prime-order subgroup, uniformly.
+    pub fn hash_to_curve(&self, msg: &[u8]) -> GroupAffine<G> {
+        let u = self.hash_to_field(msg, 2);
+        let q0 = self.map_to_curve(u[0]);
+        let q1 = self.map_to_curve(u[1]);
+        (q0 + q1).mul_by_cofactor()
+    }
+
+    /// Encodes `msg` as a point of the prime-order subgroup. It is cheaper
+    /// than [HashToCurveSuite::hash_to_curve], but the output is not uniform.
+    pub fn encode_to_curve(&self, msg: &[u8]) -> GroupAffine<G> {
+        let u = self.hash_to_field(msg, 1);
+        self.map_to_curve(u[0]).mul_by_cofactor()
+    }
+}
+
+/// Hashes `msg` to a point of the curve `G`, with the domain separation tag
+/// `dst`. To hash many messages, create a [HashToCurveSuite] once instead.
+pub fn hash_to_curve<G: SWModelParameters>(msg: &[u8], dst: &[u8]) -> GroupAffine<G>
+where
+    G::BaseField: PrimeField,
+{
+    HashToCurveSuite::new(dst).hash_to_curve(msg)
+}
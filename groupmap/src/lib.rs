@@ -22,6 +22,10 @@
 use ark_ec::models::SWModelParameters;
 use ark_ff::{Field, One, SquareRootField, Zero};
 
+pub mod hash_to_curve;
+
+pub use hash_to_curve::{hash_to_curve, HashToCurveSuite};
+
 pub trait GroupMap<F> {
     fn setup() -> Self;
     fn to_group(&self, u: F) -> (F, F);
@@ -107,7 +111,8 @@ fn potential_xs<G: SWModelParameters>(
 }
 
 /// returns the y-coordinate if x is a valid point on the curve, otherwise None
-/// TODO: what about sign?
+/// the sign of the y-coordinate is whichever the square root returns,
+/// see [HashToCurveSuite::map_to_curve] for a deterministic choice
 pub fn get_y<G: SWModelParameters>(x: G::BaseField) -> Option<G::BaseField> {
     let fx = curve_eqn::<G>(x);
     fx.sqrt()
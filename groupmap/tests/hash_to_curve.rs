@@ -0,0 +1,128 @@
+use ark_ec::{short_weierstrass_jacobian::GroupAffine, SWModelParameters};
+use ark_ff::PrimeField;
+use groupmap::hash_to_curve::{expand_message_xmd, sgn0, HashToCurveSuite};
+use mina_curves::{
+    bn254::BN254Parameters,
+    pasta::{PallasParameters, VestaParameters},
+};
+use serde::Deserialize;
+use std::{fs::File, path::PathBuf};
+
+//
+// Helpers for test vectors
+//
+
+#[derive(Debug, Deserialize)]
+struct TestVectors {
+    suites: Vec<Suite>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Suite {
+    curve: String,
+    dst: String,
+    random_oracle: bool,
+    vectors: Vec<TestVector>,
+}
+
+/// The field elements and coordinates are in big-endian hexadecimal.
+#[derive(Debug, Deserialize)]
+struct TestVector {
+    msg: String,
+    u: Vec<String>,
+    x: String,
+    y: String,
+}
+
+fn from_hex<F: PrimeField>(hexstring: &str) -> F {
+    let bytes = hex::decode(hexstring).expect("invalid hexstring");
+    F::from_be_bytes_mod_order(&bytes)
+}
+
+fn test_suite<G: SWModelParameters>(suite: &Suite)
+where
+    G::BaseField: PrimeField,
+{
+    let h2c = HashToCurveSuite::<G>::new(suite.dst.as_bytes());
+    for vector in &suite.vectors {
+        let msg = vector.msg.as_bytes();
+        let u: Vec<G::BaseField> = vector.u.iter().map(|u| from_hex(u)).collect();
+        assert_eq!(h2c.hash_to_field(msg, u.len()), u, "{:?}", vector.msg);
+
+        let point = if suite.random_oracle {
+            h2c.hash_to_curve(msg)
+        } else {
+            h2c.encode_to_curve(msg)
+        };
+        let expected = GroupAffine::<G>::new(from_hex(&vector.x), from_hex(&vector.y), false);
+        assert_eq!(point, expected, "{:?}", vector.msg);
+    }
+}
+
+//
+// Tests
+//
+
+#[test]
+fn test_hash_to_curve_vectors() {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/test_vectors/hash_to_curve.json");
+    let file = File::open(&path).expect("couldn't open test vector file");
+    let test_vectors: TestVectors =
+        serde_json::from_reader(file).expect("couldn't deserialize test vector file");
+
+    for suite in &test_vectors.suites {
+        match suite.curve.as_str() {
+            "pallas" => test_suite::<PallasParameters>(suite),
+            "vesta" => test_suite::<VestaParameters>(suite),
+            "bn254" => test_suite::<BN254Parameters>(suite),
+            curve => panic!("unknown curve {curve}"),
+        }
+    }
+}
+
+#[test]
+fn test_expand_message_xmd() {
+    // Test vectors of RFC 9380, appendix K.1
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    let vectors: [(&[u8], usize, &str); 4] = [
+        (
+            b"",
+            0x20,
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+        ),
+        (
+            b"abc",
+            0x20,
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+        ),
+        (
+            b"",
+            0x80,
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+        ),
+        (
+            b"abc",
+            0x80,
+            "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+        ),
+    ];
+    for (msg, len_in_bytes, expected) in vectors {
+        assert_eq!(
+            hex::encode(expand_message_xmd(msg, dst, len_in_bytes)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_map_to_curve_sign() {
+    let h2c = HashToCurveSuite::<VestaParameters>::new(b"test_map_to_curve_sign");
+    for u in h2c.hash_to_field(b"", 100) {
+        let g = h2c.map_to_curve(u);
+        assert!(g.is_on_curve());
+        assert_eq!(sgn0(u), sgn0(g.y));
+        // x only depends on u^2, so the sign of u is the sign of y
+        assert_eq!(h2c.map_to_curve(-u), -g);
+    }
+}
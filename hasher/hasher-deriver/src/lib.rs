@@ -0,0 +1,285 @@
+//! **This crate is not meant to be imported directly by users**.
+//! You should import [mina-hasher](https://crates.io/crates/mina-hasher) instead.
+//!
+//! mina-hasher-deriver adds a derive macro for the `Hashable` trait of mina-hasher.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Fields, GenericArgument, Lit, MetaNameValue, PathArguments, Type};
+
+/// Encoding of a field of the structure into the random oracle input
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    /// Append the field with `append_hashable` (the default)
+    Hashable,
+    Field,
+    Scalar,
+    U32,
+    U64,
+    Bool,
+    Bytes,
+}
+
+impl Encoding {
+    fn from_ident(ident: &str) -> Option<Self> {
+        match ident {
+            "field" => Some(Self::Field),
+            "scalar" => Some(Self::Scalar),
+            "u32" => Some(Self::U32),
+            "u64" => Some(Self::U64),
+            "bool" => Some(Self::Bool),
+            "bytes" => Some(Self::Bytes),
+            _ => None,
+        }
+    }
+
+    // `roi.append_xxx(value)`, where `value` is a reference to the encoded value
+    fn append(self, value: TokenStream2) -> TokenStream2 {
+        match self {
+            Self::Hashable => quote! { roi.append_hashable(#value) },
+            Self::Field => quote! { roi.append_field(*#value) },
+            Self::Scalar => quote! { roi.append_scalar(*#value) },
+            Self::U32 => quote! { roi.append_u32(*#value) },
+            Self::U64 => quote! { roi.append_u64(*#value) },
+            Self::Bool => quote! { roi.append_bool(*#value) },
+            Self::Bytes => quote! { roi.append_bytes(#value) },
+        }
+    }
+}
+
+/// Whether `ty` is an array or a vector, whose elements are encoded one by one
+fn is_sequence(ty: &Type) -> bool {
+    match ty {
+        Type::Array(_) => true,
+        Type::Path(p) => p.path.segments.last().map_or(false, |segment| {
+            segment.ident == "Vec"
+                && matches!(
+                    &segment.arguments,
+                    PathArguments::AngleBracketed(args)
+                        if matches!(args.args.first(), Some(GenericArgument::Type(_)))
+                )
+        }),
+        _ => false,
+    }
+}
+
+/// The [Hashable] derive macro.
+/// It generates implementations of \[`mina_hasher::Hashable`\],
+/// appending the fields of your structure to the random oracle input in declaration order.
+///
+/// For example:
+///
+/// ```ignore
+/// #[derive(Clone, mina_hasher::Hashable)]
+/// #[hashable(domain = "Example")]
+/// struct Example {
+///     #[hashable(u32)]
+///     x: u32,
+///     #[hashable(u64)]
+///     y: u64,
+/// }
+/// ```
+///
+/// By default, a field is appended with `ROInput::append_hashable`, so it must implement
+/// `Hashable` as well. Other encodings can be specified for each field, mapping to the
+/// corresponding `ROInput::append_*` method: `field`, `scalar`, `u32`, `u64`, `bool` and `bytes`.
+/// The elements of arrays and vectors are appended one by one, except with the `bytes` encoding:
+///
+/// ```text
+/// #[hashable(bool)]
+/// tag: [bool; 3],
+/// #[hashable(bytes)]
+/// memo: [u8; 34],
+/// ```
+///
+/// You can skip a field:
+///
+/// ```text
+/// #[hashable(skip)]
+/// field_to_skip: NotHashable,
+/// ```
+///
+/// The domain string is either a constant (`#[hashable(domain = "...")]`),
+/// or the output of a function of the domain parameter, which is `()` by default:
+///
+/// ```text
+/// #[derive(Clone, mina_hasher::Hashable)]
+/// #[hashable(domain_param = "u32", domain_fn = "my_domain_string")]
+/// struct MyType {
+/// ```
+///
+/// where `my_domain_string` takes the domain parameter, and returns a `String` or an `Option<String>`.
+/// Without domain, the domain string is `None`: the type can only be hashed as part of another one.
+///
+#[proc_macro_derive(Hashable, attributes(hashable))]
+pub fn derive_hashable(item: TokenStream) -> TokenStream {
+    // parse struct
+    let item_struct: syn::ItemStruct =
+        syn::parse(item).expect("only structs are supported with `Hashable`");
+
+    // parse the attributes of the structure
+    #[derive(Default)]
+    struct HelperAttributes {
+        domain: Option<String>,
+        domain_param: Option<String>,
+        domain_fn: Option<String>,
+    }
+    let mut helper_attributes = HelperAttributes::default();
+
+    let malformed_hashable_helper =
+        "hashable helper malformed. It should look like `#[hashable(key = value)]`";
+    for attr in &item_struct.attrs {
+        if let Ok(syn::Meta::List(meta)) = attr.parse_meta() {
+            // we only care about `#[hashable(...)]`
+            if !meta.path.is_ident("hashable") {
+                continue;
+            }
+
+            for meta_inner in meta.nested {
+                match meta_inner {
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(MetaNameValue {
+                        path,
+                        eq_token: _,
+                        lit,
+                    })) => {
+                        let value = match lit {
+                            Lit::Str(lit) => lit.value(),
+                            _ => panic!("{malformed_hashable_helper}"),
+                        };
+                        if path.is_ident("domain") {
+                            helper_attributes.domain = Some(value);
+                        } else if path.is_ident("domain_param") {
+                            helper_attributes.domain_param = Some(value);
+                        } else if path.is_ident("domain_fn") {
+                            helper_attributes.domain_fn = Some(value);
+                        } else {
+                            panic!("{malformed_hashable_helper}");
+                        }
+                    }
+                    x => panic!("{x:?} {malformed_hashable_helper}"),
+                };
+            }
+        }
+    }
+
+    // parse the attributes of the fields, in declaration order
+    let malformed_field_helper =
+        "hashable field helper malformed. It should look like `#[hashable(skip)]`, or `#[hashable(encoding)]` with an encoding among `field`, `scalar`, `u32`, `u64`, `bool` and `bytes`";
+    let fields: Vec<_> = match &item_struct.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => vec![],
+    };
+    let mut appends = vec![];
+    for (idx, field) in fields.iter().enumerate() {
+        let mut encoding = None;
+        let mut skip = false;
+        for attr in &field.attrs {
+            if let Ok(syn::Meta::List(meta)) = attr.parse_meta() {
+                if !meta.path.is_ident("hashable") {
+                    continue;
+                }
+
+                for meta_inner in meta.nested {
+                    let ident = match meta_inner {
+                        syn::NestedMeta::Meta(syn::Meta::Path(path)) => path
+                            .get_ident()
+                            .map(|ident| ident.to_string())
+                            .unwrap_or_else(|| panic!("{malformed_field_helper}")),
+                        _ => panic!("{malformed_field_helper}"),
+                    };
+                    if ident == "skip" {
+                        skip = true;
+                    } else if let Some(field_encoding) = Encoding::from_ident(&ident) {
+                        if encoding.replace(field_encoding).is_some() {
+                            panic!("a field can only have one encoding");
+                        }
+                    } else {
+                        panic!("{malformed_field_helper}");
+                    }
+                }
+            }
+        }
+
+        if skip {
+            if encoding.is_some() {
+                panic!("a skipped field cannot have an encoding");
+            }
+            continue;
+        }
+        let encoding = encoding.unwrap_or(Encoding::Hashable);
+
+        // `self.name` or `self.idx` for tuple structs
+        let member = match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let idx = syn::Index::from(idx);
+                quote! { #idx }
+            }
+        };
+
+        appends.push(if encoding != Encoding::Bytes && is_sequence(&field.ty) {
+            let append = encoding.append(quote! { x });
+            quote! {
+                for x in self.#member.iter() {
+                    roi = #append;
+                }
+            }
+        } else {
+            let append = encoding.append(quote! { &self.#member });
+            quote! {
+                roi = #append;
+            }
+        });
+    }
+
+    // the domain parameter and the domain string
+    let domain_param: syn::Type =
+        syn::parse_str(helper_attributes.domain_param.as_deref().unwrap_or("()"))
+            .expect("could not parse your domain parameter");
+    let domain_string = match (&helper_attributes.domain, &helper_attributes.domain_fn) {
+        (Some(domain), None) => quote! {
+            fn domain_string(_: Self::D) -> Option<String> {
+                Some(#domain.to_string())
+            }
+        },
+        (None, Some(domain_fn)) => {
+            let domain_fn: syn::Path =
+                syn::parse_str(domain_fn).expect("could not parse your domain function");
+            quote! {
+                fn domain_string(domain_param: Self::D) -> Option<String> {
+                    #domain_fn(domain_param).into()
+                }
+            }
+        }
+        (None, None) => quote! {
+            fn domain_string(_: Self::D) -> Option<String> {
+                None
+            }
+        },
+        (Some(_), Some(_)) => panic!("`domain` and `domain_fn` cannot be specified together"),
+    };
+
+    // the deriver is used by both external users and mina-hasher itself,
+    // which declares `extern crate self as mina_hasher` for this path to resolve
+    let name = &item_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = item_struct.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics ::mina_hasher::Hashable for #name #ty_generics #where_clause {
+            type D = #domain_param;
+
+            #[allow(unused_mut)]
+            fn to_roinput(&self) -> ::mina_hasher::ROInput {
+                let mut roi = ::mina_hasher::ROInput::new();
+                #( #appends )*
+                roi
+            }
+
+            #domain_string
+        }
+    };
+    gen.into()
+}
@@ -1,11 +1,17 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+// For the paths generated by the `Hashable` derive macro to resolve in this crate
+extern crate self as mina_hasher;
+
 pub mod poseidon;
 pub mod roinput;
+pub mod stream;
 pub use mina_curves::pasta::Fp;
+pub use mina_hasher_deriver::Hashable;
 pub use poseidon::{PoseidonHasherKimchi, PoseidonHasherLegacy};
 pub use roinput::ROInput;
+pub use stream::{StreamHasherKimchi, StreamHasherLegacy};
 
 #[cfg(test)]
 mod tests;
@@ -179,3 +185,13 @@ where
 {
     poseidon::new_kimchi::<H>(domain_param)
 }
+
+/// Create a legacy stream hasher context, for arbitrary-length inputs
+pub fn create_stream_legacy(domain_string: &str) -> StreamHasherLegacy {
+    stream::new_legacy(domain_string)
+}
+
+/// Create an experimental kimchi stream hasher context, for arbitrary-length inputs
+pub fn create_stream_kimchi(domain_string: &str) -> StreamHasherKimchi {
+    stream::new_kimchi(domain_string)
+}
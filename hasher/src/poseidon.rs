@@ -5,6 +5,7 @@
 use std::marker::PhantomData;
 
 use crate::DomainParameter;
+use ark_ff::Zero;
 use mina_curves::pasta::Fp;
 use mina_poseidon::{
     constants::{PlonkSpongeConstantsKimchi, PlonkSpongeConstantsLegacy, SpongeConstants},
@@ -23,7 +24,7 @@ use super::{domain_prefix_to_field, Hashable, Hasher};
 pub struct Poseidon<SC: SpongeConstants, H: Hashable> {
     sponge: ArithmeticSponge<Fp, SC>,
     sponge_state: SpongeState,
-    state: Vec<Fp>,
+    state: [Fp; 3],
     phantom: PhantomData<H>,
 }
 
@@ -32,7 +33,7 @@ impl<SC: SpongeConstants, H: Hashable> Poseidon<SC, H> {
         let mut poseidon = Poseidon::<SC, H> {
             sponge: ArithmeticSponge::<Fp, SC>::new(sponge_params),
             sponge_state: SpongeState::Absorbed(0),
-            state: vec![],
+            state: [Fp::zero(); 3],
             phantom: PhantomData,
         };
 
@@ -65,7 +66,7 @@ where
     fn reset(&mut self) -> &mut dyn Hasher<H> {
         // Efficient reset
         self.sponge.sponge_state = self.sponge_state.clone();
-        self.sponge.state = self.state.clone();
+        self.sponge.state = self.state;
 
         self
     }
@@ -83,7 +84,7 @@ where
 
         // Save initial state for efficient reset
         self.sponge_state = self.sponge.sponge_state.clone();
-        self.state = self.sponge.state.clone();
+        self.state = self.sponge.state;
 
         self
     }
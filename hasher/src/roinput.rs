@@ -3,14 +3,38 @@
 //! Definition of random oracle input structure and
 //! methods for serializing into bytes and field elements
 
+use std::fmt;
+
 use bitvec::{prelude::*, view::AsBits};
 
 use ark_ff::PrimeField;
 use mina_curves::pasta::{Fp, Fq};
+use num_bigint::BigUint;
 use o1_utils::FieldHelpers;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::Hashable;
 
+/// Random oracle input errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ROInputError {
+    /// Invalid length of the serialized random oracle input
+    #[error("invalid length: expected {expected} bytes, got {got}")]
+    InvalidLength {
+        /// Expected number of bytes
+        expected: usize,
+        /// Actual number of bytes
+        got: usize,
+    },
+    /// Invalid base field element
+    #[error("invalid field element at index {0}")]
+    InvalidField(usize),
+    /// The bits after the last bit of the input are not zero
+    #[error("non-zero padding bits")]
+    NonZeroPadding,
+}
+
 /// Random oracle input structure
 ///
 /// The random oracle input encapsulates the serialization format and methods using during hashing.
@@ -54,10 +78,186 @@ use super::Hashable;
 /// Additionally, during signing all members of the random oracle input get serialized
 /// together in two different ways: both as *bytes* and as a vector of *field elements*.
 /// The random oracle input automates and encapsulates this complexity.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+///
+/// **Serialization:** With serde, a random oracle input is represented as the `HashInputLegacy`
+/// of o1js: its field elements (as decimal strings) and its bits (as booleans). Unlike the
+/// `packed` pairs of field elements and bit sizes of the `HashInput` of o1js, which are packed
+/// greedily without being split across field elements, the bits of a random oracle input are the
+/// legacy bit string, packed in chunks of 254 bits by [`ROInput::to_fields`].
+///
+/// **Tracing:** With the `item_tracing` feature, a random oracle input also keeps its appended
+/// items, for `ROInput::layout` and `ROInput::debug_view`.  Without it, appending does not
+/// allocate anything but the fields and bits.  The items are not serialized, and two inputs with
+/// the same fields and bits are equal.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "ROInputRepr", try_from = "ROInputRepr")]
 pub struct ROInput {
     fields: Vec<Fp>,
     bits: BitVec<u8>,
+    #[cfg(feature = "item_tracing")]
+    items: Vec<ROInputItem>,
+}
+
+impl PartialEq for ROInput {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields && self.bits == other.bits
+    }
+}
+
+impl Eq for ROInput {}
+
+/// An item appended to a random oracle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ROInputItem {
+    /// A base field element
+    Field(Fp),
+    /// A scalar field element
+    Scalar(Fq),
+    /// A single bit
+    Bool(bool),
+    /// Bytes
+    Bytes(Vec<u8>),
+    /// A 32-bit unsigned integer
+    U32(u32),
+    /// A 64-bit unsigned integer
+    U64(u64),
+    /// Bits whose origin is unknown (for a deserialized input)
+    Bits(Vec<bool>),
+}
+
+#[cfg(feature = "item_tracing")]
+impl ROInputItem {
+    /// Number of bits of the item, if it is packed with the other bits
+    fn packed_len(&self) -> Option<usize> {
+        match self {
+            ROInputItem::Field(_) => None,
+            ROInputItem::Scalar(_) => Some(Fq::size_in_bits()),
+            ROInputItem::Bool(_) => Some(1),
+            ROInputItem::Bytes(bytes) => Some(8 * bytes.len()),
+            ROInputItem::U32(_) => Some(32),
+            ROInputItem::U64(_) => Some(64),
+            ROInputItem::Bits(bits) => Some(bits.len()),
+        }
+    }
+}
+
+impl fmt::Display for ROInputItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ROInputItem::Field(x) => write!(f, "field {}", x.to_hex()),
+            ROInputItem::Scalar(x) => write!(f, "scalar {}", x.to_hex()),
+            ROInputItem::Bool(b) => write!(f, "bool {b}"),
+            ROInputItem::Bytes(bytes) => write!(f, "bytes {}", hex::encode(bytes)),
+            ROInputItem::U32(x) => write!(f, "u32 {x}"),
+            ROInputItem::U64(x) => write!(f, "u64 {x}"),
+            ROInputItem::Bits(bits) => {
+                write!(f, "bits ")?;
+                bits.iter().try_for_each(|b| write!(f, "{}", u8::from(*b)))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "item_tracing")]
+/// Where an appended item landed in the field elements of [`ROInput::to_fields`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemLayout {
+    /// The appended item
+    pub item: ROInputItem,
+    /// Index of the field element containing the first bit of the item
+    pub field: usize,
+    /// Offset of the first bit of the item in this field element
+    pub bit: usize,
+    /// Number of bits of the item
+    ///
+    /// Packed bits that do not fit in the field element continue in the next one.
+    pub len: usize,
+}
+
+#[cfg(feature = "item_tracing")]
+impl fmt::Display for ItemLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let end = self.bit + self.len;
+        write!(f, "field {}, bits {}..{}", self.field, self.bit, end)?;
+        // the packed bits of a field element are all but the last one
+        let packed_bits = Fp::size_in_bits() - 1;
+        if matches!(self.item, ROInputItem::Field(_)) || end <= packed_bits {
+            write!(f, ": {}", self.item)
+        } else {
+            let last = self.field + (end - 1) / packed_bits;
+            write!(f, " (continues to field {last}): {}", self.item)
+        }
+    }
+}
+
+/// The serde representation of [`ROInput`], the `HashInputLegacy` of o1js
+#[derive(Serialize, Deserialize)]
+struct ROInputRepr {
+    fields: Vec<String>,
+    bits: Vec<bool>,
+}
+
+impl From<ROInput> for ROInputRepr {
+    fn from(roi: ROInput) -> Self {
+        ROInputRepr {
+            fields: roi
+                .fields
+                .iter()
+                .map(|f| f.to_biguint().to_string())
+                .collect(),
+            bits: roi.bits.iter().by_vals().collect(),
+        }
+    }
+}
+
+impl TryFrom<ROInputRepr> for ROInput {
+    type Error = ROInputError;
+
+    fn try_from(repr: ROInputRepr) -> Result<Self, Self::Error> {
+        let fields = repr
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let big: BigUint = f.parse().map_err(|_| ROInputError::InvalidField(i))?;
+                // N.B. `from_biguint` reduces the values larger than the modulus
+                match Fp::from_biguint(&big) {
+                    Ok(x) if x.to_biguint() == big => Ok(x),
+                    _ => Err(ROInputError::InvalidField(i)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let bits = repr.bits.into_iter().collect();
+
+        Ok(fields
+            .into_iter()
+            .fold(ROInput::new(), ROInput::append_field)
+            .append_unknown_bits(bits))
+    }
+}
+
+/// The first `num_bits` bits of `bits`, which must be padded with less than a byte of zeros
+fn unpack_bits(bits: &BitSlice<u8>, num_bits: usize) -> Result<BitVec<u8>, ROInputError> {
+    let expected = (num_bits + 7) / 8;
+    if bits.len() != 8 * expected {
+        return Err(ROInputError::InvalidLength {
+            expected,
+            got: bits.len() / 8,
+        });
+    }
+    if bits[num_bits..].any() {
+        return Err(ROInputError::NonZeroPadding);
+    }
+
+    Ok(copy_bits(&bits[..num_bits]))
+}
+
+/// Copy of a bit slice that is not necessarily byte-aligned (see [`ROInput::to_fields`])
+fn copy_bits(bits: &BitSlice<u8>) -> BitVec<u8> {
+    let mut bv = BitVec::<u8>::new();
+    bv.resize(bits.len(), false);
+    bv.clone_from_bitslice(bits);
+    bv
 }
 
 impl ROInput {
@@ -66,9 +266,18 @@ impl ROInput {
         ROInput {
             fields: vec![],
             bits: BitVec::new(),
+            #[cfg(feature = "item_tracing")]
+            items: vec![],
         }
     }
 
+    /// Keep the appended item, with the `item_tracing` feature
+    #[inline]
+    fn trace(&mut self, _item: impl FnOnce() -> ROInputItem) {
+        #[cfg(feature = "item_tracing")]
+        self.items.push(_item());
+    }
+
     /// Append a `Hashable` input
     pub fn append_hashable(self, input: &impl Hashable) -> Self {
         self.append_roinput(input.to_roinput())
@@ -78,12 +287,15 @@ impl ROInput {
     pub fn append_roinput(mut self, mut roi: ROInput) -> Self {
         self.fields.append(&mut roi.fields);
         self.bits.extend(roi.bits);
+        #[cfg(feature = "item_tracing")]
+        self.items.append(&mut roi.items);
         self
     }
 
     /// Append a base field element
     pub fn append_field(mut self, f: Fp) -> Self {
         self.fields.push(f);
+        self.trace(|| ROInputItem::Field(f));
         self
     }
 
@@ -93,29 +305,125 @@ impl ROInput {
         let bytes = s.to_bytes();
         let bits = &bytes.as_bits::<Lsb0>()[..Fq::size_in_bits()];
         self.bits.extend(bits);
+        self.trace(|| ROInputItem::Scalar(s));
         self
     }
 
     /// Append a single bit
     pub fn append_bool(mut self, b: bool) -> Self {
         self.bits.push(b);
+        self.trace(|| ROInputItem::Bool(b));
         self
     }
 
     /// Append bytes
     pub fn append_bytes(mut self, bytes: &[u8]) -> Self {
         self.bits.extend_from_bitslice(bytes.as_bits::<Lsb0>());
+        self.trace(|| ROInputItem::Bytes(bytes.to_vec()));
         self
     }
 
     /// Append a 32-bit unsigned integer
-    pub fn append_u32(self, x: u32) -> Self {
-        self.append_bytes(&x.to_le_bytes())
+    pub fn append_u32(mut self, x: u32) -> Self {
+        self.bits
+            .extend_from_bitslice(x.to_le_bytes().as_bits::<Lsb0>());
+        self.trace(|| ROInputItem::U32(x));
+        self
     }
 
     /// Append a 64-bit unsigned integer
-    pub fn append_u64(self, x: u64) -> Self {
-        self.append_bytes(&x.to_le_bytes())
+    pub fn append_u64(mut self, x: u64) -> Self {
+        self.bits
+            .extend_from_bitslice(x.to_le_bytes().as_bits::<Lsb0>());
+        self.trace(|| ROInputItem::U64(x));
+        self
+    }
+
+    /// Append bits whose origin is unknown
+    fn append_unknown_bits(mut self, bits: BitVec<u8>) -> Self {
+        if !bits.is_empty() {
+            self.trace(|| ROInputItem::Bits(bits.iter().map(|b| *b).collect()));
+            self.bits.extend(bits);
+        }
+        self
+    }
+
+    /// Deserialize a random oracle input from the bytes of [`ROInput::to_bytes`]
+    ///
+    /// The bytes do not delimit the field elements from the other bits, so the number of field
+    /// elements `num_fields` and the number of other bits `num_bits` must be given.
+    pub fn from_bytes(
+        bytes: &[u8],
+        num_fields: usize,
+        num_bits: usize,
+    ) -> Result<Self, ROInputError> {
+        let field_bits = num_fields * Fp::size_in_bits();
+        let bits =
+            unpack_bits(bytes.as_bits::<Lsb0>(), field_bits + num_bits).map_err(|e| match e {
+                ROInputError::InvalidLength { expected, .. } => ROInputError::InvalidLength {
+                    expected,
+                    got: bytes.len(),
+                },
+                e => e,
+            })?;
+
+        let roi = bits[..field_bits]
+            .chunks(Fp::size_in_bits())
+            .enumerate()
+            .try_fold(ROInput::new(), |roi, (i, chunk)| {
+                let mut bv = copy_bits(chunk);
+                bv.resize(Fp::size_in_bytes() * 8, false);
+                let f =
+                    Fp::from_bytes(&bv.into_vec()).map_err(|_| ROInputError::InvalidField(i))?;
+                Ok(roi.append_field(f))
+            })?;
+
+        Ok(roi.append_unknown_bits(copy_bits(&bits[field_bits..])))
+    }
+
+    /// The appended items, with the field element and the bit offset where they landed
+    /// in [`ROInput::to_fields`] (with the `item_tracing` feature)
+    ///
+    /// The base field elements come first, in the order they were appended,
+    /// followed by the other items, packed in the remaining field elements.
+    #[cfg(feature = "item_tracing")]
+    pub fn layout(&self) -> Vec<ItemLayout> {
+        let packed_bits = Fp::size_in_bits() - 1;
+        let mut layout = vec![];
+        let mut offset = 0;
+
+        for item in self.items.iter().filter(|item| item.packed_len().is_none()) {
+            layout.push(ItemLayout {
+                item: item.clone(),
+                field: layout.len(),
+                bit: 0,
+                len: Fp::size_in_bits(),
+            });
+        }
+
+        let num_fields = layout.len();
+        for item in &self.items {
+            if let Some(len) = item.packed_len() {
+                layout.push(ItemLayout {
+                    item: item.clone(),
+                    field: num_fields + offset / packed_bits,
+                    bit: offset % packed_bits,
+                    len,
+                });
+                offset += len;
+            }
+        }
+
+        layout
+    }
+
+    /// A human-readable view of [`ROInput::layout`], one item per line
+    #[cfg(feature = "item_tracing")]
+    pub fn debug_view(&self) -> String {
+        self.layout()
+            .iter()
+            .map(|item| format!("{item}\n"))
+            .collect()
     }
 
     /// Serialize random oracle input to bytes
@@ -906,4 +1214,115 @@ mod tests {
         };
         assert_ne!(b1.to_roinput(), b2.to_roinput());
     }
+
+    fn mixed_roinput() -> ROInput {
+        ROInput::new()
+            .append_u64(1000000)
+            .append_field(Fp::from(7u64))
+            .append_bool(true)
+            .append_scalar(Fq::from(42u64))
+            .append_bytes(&[0xde, 0xad])
+            .append_field(Fp::from(11u64))
+            .append_u32(834803)
+    }
+
+    #[test]
+    fn from_bytes_roundtrip() {
+        let roi = mixed_roinput();
+        let parsed = ROInput::from_bytes(&roi.to_bytes(), 2, 64 + 1 + 255 + 16 + 32)
+            .expect("failed to parse bytes");
+        assert_eq!(parsed, roi);
+        assert_eq!(parsed.to_fields(), roi.to_fields());
+        assert_eq!(parsed.to_bytes(), roi.to_bytes());
+
+        assert_eq!(ROInput::from_bytes(&[], 0, 0), Ok(ROInput::new()));
+    }
+
+    #[test]
+    fn from_bytes_errors() {
+        let bytes = mixed_roinput().to_bytes();
+        assert_eq!(
+            ROInput::from_bytes(&bytes, 2, 64),
+            Err(ROInputError::InvalidLength {
+                expected: 72,
+                got: bytes.len()
+            })
+        );
+        assert_eq!(
+            ROInput::from_bytes(&[0x02], 0, 1),
+            Err(ROInputError::NonZeroPadding)
+        );
+        assert_eq!(
+            ROInput::from_bytes(&[0xff; 32], 1, 1),
+            Err(ROInputError::InvalidField(0))
+        );
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let roi = ROInput::new()
+            .append_field(Fp::from(1u64))
+            .append_bool(true)
+            .append_bool(false)
+            .append_u32(1729);
+        let json = serde_json::to_value(&roi).expect("failed to serialize");
+
+        // The `HashInputLegacy` of o1js, with the bits of 1729 least significant first
+        let (t, f) = (true, false);
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "fields": ["1"],
+                "bits": [
+                    t, f, t, f, f, f, f, f, t, t, f, t, t, f, f, f, f, f, f, f, f, f, f, f, f, f,
+                    f, f, f, f, f, f, f, f,
+                ],
+            })
+        );
+
+        let parsed: ROInput = serde_json::from_value(json).expect("failed to deserialize");
+        assert_eq!(parsed, roi);
+        assert_eq!(parsed.to_fields(), roi.to_fields());
+
+        // field elements must be canonical
+        let modulus =
+            "28948022309329048855892746252171976963363056481941560715954676764349967630337";
+        let json = serde_json::json!({ "fields": [modulus], "bits": [] });
+        assert!(serde_json::from_value::<ROInput>(json).is_err());
+        let json = serde_json::json!({ "fields": ["0x01"], "bits": [] });
+        assert!(serde_json::from_value::<ROInput>(json).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "item_tracing")]
+    fn layout() {
+        let layout = mixed_roinput().layout();
+        let offsets: Vec<_> = layout.iter().map(|l| (l.field, l.bit, l.len)).collect();
+        assert_eq!(
+            offsets,
+            [
+                (0, 0, 255),
+                (1, 0, 255),
+                (2, 0, 64),
+                (2, 64, 1),
+                (2, 65, 255),
+                (3, 66, 16),
+                (3, 82, 32),
+            ]
+        );
+        assert_eq!(layout[0].item, ROInputItem::Field(Fp::from(7u64)));
+        assert_eq!(layout[2].item, ROInputItem::U64(1000000));
+
+        assert_eq!(
+            mixed_roinput().debug_view().lines().nth(4),
+            Some(
+                "field 2, bits 65..320 (continues to field 3): scalar \
+                 2a00000000000000000000000000000000000000000000000000000000000000"
+            )
+        );
+
+        // the bits of a parsed input are a single item
+        let parsed = ROInput::from_bytes(&[0x05], 0, 3).expect("failed to parse bytes");
+        assert_eq!(parsed.debug_view(), "field 0, bits 0..3: bits 101\n");
+    }
 }
@@ -0,0 +1,166 @@
+//! Mina Poseidon stream hasher
+//!
+//! Unlike the [`Hasher`](crate::Hasher), which hashes fixed-size [`Hashable`](crate::Hashable)
+//! structures, the [`StreamHasher`] hashes arbitrary-length sequences of bytes and field
+//! elements, fed incrementally, e.g. from a large file.
+//!
+//! The input is split into segments of consecutive bytes or consecutive field elements.
+//! Bytes are packed into field elements of [`BYTES_PER_FIELD`] bytes (little-endian), the
+//! last one of a segment being padded with zeros. As the length of the input is not known
+//! in advance, each segment is followed by its length and kind, so the absorbed sequence
+//! of field elements can be parsed backwards and distinct inputs are hashed differently.
+//!
+use crate::domain_prefix_to_field;
+use ark_ff::PrimeField;
+use mina_curves::pasta::Fp;
+use mina_poseidon::{
+    constants::{PlonkSpongeConstantsKimchi, PlonkSpongeConstantsLegacy, SpongeConstants},
+    pasta,
+    poseidon::{ArithmeticSponge, ArithmeticSpongeParams, Sponge},
+};
+use o1_utils::FieldHelpers;
+
+/// Number of bytes packed into each field element, so that any packing is smaller than the modulus
+pub const BYTES_PER_FIELD: usize = 31;
+
+/// Kind of a segment of the input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Segment {
+    Bytes,
+    Fields,
+}
+
+/// Poseidon stream hasher context
+#[derive(Clone)]
+pub struct StreamHasher<SC: SpongeConstants> {
+    sponge: ArithmeticSponge<Fp, SC>,
+    // Bytes of the current segment that are not yet absorbed
+    pending: Vec<u8>,
+    // Kind and length (in bytes or field elements) of the current segment
+    segment: Option<(Segment, u64)>,
+}
+
+/// Poseidon stream hasher type with legacy plonk sponge constants
+pub type StreamHasherLegacy = StreamHasher<PlonkSpongeConstantsLegacy>;
+
+/// Poseidon stream hasher type with experimental kimchi plonk sponge constants
+pub type StreamHasherKimchi = StreamHasher<PlonkSpongeConstantsKimchi>;
+
+/// Create a legacy stream hasher context
+pub(crate) fn new_legacy(domain_string: &str) -> StreamHasherLegacy {
+    StreamHasher::new(domain_string, pasta::fp_legacy::static_params())
+}
+
+/// Create an experimental kimchi stream hasher context
+pub(crate) fn new_kimchi(domain_string: &str) -> StreamHasherKimchi {
+    StreamHasher::new(domain_string, pasta::fp_kimchi::static_params())
+}
+
+impl<SC: SpongeConstants> StreamHasher<SC> {
+    fn new(domain_string: &str, sponge_params: &'static ArithmeticSpongeParams<Fp>) -> Self {
+        // N.B. As the Hasher, set the sponge's initial state by hashing the domain bytes
+        let mut sponge = ArithmeticSponge::<Fp, SC>::new(sponge_params);
+        sponge.absorb(&[domain_prefix_to_field::<Fp>(domain_string.to_string())]);
+        sponge.squeeze();
+
+        StreamHasher {
+            sponge,
+            pending: vec![],
+            segment: None,
+        }
+    }
+
+    /// Consume the bytes `input`
+    pub fn update(&mut self, input: &[u8]) -> &mut Self {
+        if input.is_empty() {
+            return self;
+        }
+        self.start_segment(Segment::Bytes, input.len() as u64);
+
+        self.pending.extend_from_slice(input);
+        let full = self.pending.len() - self.pending.len() % BYTES_PER_FIELD;
+        let fields: Vec<Fp> = self.pending[..full]
+            .chunks(BYTES_PER_FIELD)
+            .map(pack_bytes)
+            .collect();
+        self.sponge.absorb(&fields);
+        self.pending.drain(..full);
+
+        self
+    }
+
+    /// Consume the field element `input`
+    pub fn update_field(&mut self, input: Fp) -> &mut Self {
+        self.update_fields(&[input])
+    }
+
+    /// Consume the field elements `input`
+    pub fn update_fields(&mut self, input: &[Fp]) -> &mut Self {
+        if input.is_empty() {
+            return self;
+        }
+        self.start_segment(Segment::Fields, input.len() as u64);
+        self.sponge.absorb(input);
+
+        self
+    }
+
+    /// Consume the context and obtain the hash of everything consumed
+    pub fn finalize(mut self) -> Fp {
+        self.end_segment();
+        self.sponge.squeeze()
+    }
+
+    // Extends the current segment if it has the same kind, or starts a new one
+    fn start_segment(&mut self, kind: Segment, len: u64) {
+        match &mut self.segment {
+            Some((current, current_len)) if *current == kind => *current_len += len,
+            _ => {
+                self.end_segment();
+                self.segment = Some((kind, len));
+            }
+        }
+    }
+
+    // Absorbs the remaining bytes and the trailer `2 * length + kind` of the current segment
+    fn end_segment(&mut self) {
+        if let Some((kind, len)) = self.segment.take() {
+            if !self.pending.is_empty() {
+                self.sponge.absorb(&[pack_bytes(&self.pending)]);
+                self.pending.clear();
+            }
+            let kind = match kind {
+                Segment::Bytes => 0,
+                Segment::Fields => 1,
+            };
+            self.sponge.absorb(&[Fp::from(2 * len + kind)]);
+        }
+    }
+}
+
+impl<SC: SpongeConstants + Clone> StreamHasher<SC> {
+    /// Obtain the hash of everything consumed so far, without consuming the context
+    pub fn digest(&self) -> Fp {
+        self.clone().finalize()
+    }
+}
+
+/// Pack at most [`BYTES_PER_FIELD`] bytes into a field element (little-endian)
+fn pack_bytes(bytes: &[u8]) -> Fp {
+    assert!(bytes.len() <= BYTES_PER_FIELD);
+    let mut bytes = bytes.to_vec();
+    bytes.resize(Fp::size_in_bytes(), 0);
+    Fp::from_bytes(&bytes).expect("packed bytes are smaller than the modulus")
+}
+
+/// Adapter for `std::hash::Hasher` call sites, e.g. to compute checksums of
+/// `std::hash::Hash` values. The output is the 64 low bits of the digest.
+impl<SC: SpongeConstants + Clone> std::hash::Hasher for StreamHasher<SC> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.digest().into_repr().as_ref()[0]
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{create_kimchi, create_legacy, Fp, Hashable, Hasher, ROInput};
+use mina_curves::pasta::Fq;
+
+// Hashed as part of `Derived`
+#[derive(Clone, Hashable)]
+struct Point {
+    #[hashable(field)]
+    x: Fp,
+    #[hashable(bool)]
+    is_odd: bool,
+}
+
+#[derive(Clone, Hashable)]
+#[hashable(domain = "Derived")]
+struct Derived {
+    #[hashable(u64)]
+    fee: u64,
+    point: Point,
+    #[hashable(u32)]
+    nonce: u32,
+    #[hashable(skip)]
+    #[allow(dead_code)]
+    comment: String,
+    #[hashable(bytes)]
+    memo: [u8; 4],
+    #[hashable(bool)]
+    tag: [bool; 3],
+    #[hashable(scalar)]
+    scalar: Fq,
+    #[hashable(field)]
+    fields: Vec<Fp>,
+    others: [Point; 2],
+}
+
+// `Derived`, with a hand-written `Hashable` implementation
+#[derive(Clone)]
+struct HandWritten(Derived);
+
+impl Hashable for HandWritten {
+    type D = ();
+
+    fn to_roinput(&self) -> ROInput {
+        let d = &self.0;
+        let mut roi = ROInput::new()
+            .append_u64(d.fee)
+            .append_field(d.point.x)
+            .append_bool(d.point.is_odd)
+            .append_u32(d.nonce)
+            .append_bytes(&d.memo);
+        for bit in d.tag {
+            roi = roi.append_bool(bit);
+        }
+        roi = roi.append_scalar(d.scalar);
+        for f in &d.fields {
+            roi = roi.append_field(*f);
+        }
+        for point in &d.others {
+            roi = roi.append_field(point.x).append_bool(point.is_odd);
+        }
+        roi
+    }
+
+    fn domain_string(_: Self::D) -> Option<String> {
+        "Derived".to_string().into()
+    }
+}
+
+fn derived() -> Derived {
+    let point = |x: u64, is_odd| Point {
+        x: Fp::from(x),
+        is_odd,
+    };
+    Derived {
+        fee: 1_000_000,
+        point: point(7, true),
+        nonce: 42,
+        comment: "not hashed".to_string(),
+        memo: [1, 2, 3, 4],
+        tag: [true, false, true],
+        scalar: Fq::from(99u64),
+        fields: vec![Fp::from(1u64), Fp::from(2u64)],
+        others: [point(3, false), point(5, true)],
+    }
+}
+
+#[test]
+fn derive_matches_hand_written() {
+    let derived = derived();
+    let hand_written = HandWritten(derived.clone());
+    assert_eq!(derived.to_roinput(), hand_written.to_roinput());
+    assert_eq!(Derived::domain_string(()), HandWritten::domain_string(()));
+
+    assert_eq!(
+        create_legacy::<Derived>(()).hash(&derived),
+        create_legacy::<HandWritten>(()).hash(&hand_written)
+    );
+    assert_eq!(
+        create_kimchi::<Derived>(()).hash(&derived),
+        create_kimchi::<HandWritten>(()).hash(&hand_written)
+    );
+
+    // Skipped fields are not hashed
+    let other = Derived {
+        comment: "still not hashed".to_string(),
+        ..derived.clone()
+    };
+    assert_eq!(other.to_roinput(), derived.to_roinput());
+    assert_eq!(Point::domain_string(()), None);
+}
+
+#[test]
+fn derive_domain_fn() {
+    fn domain_string(height: u64) -> String {
+        format!("Node{height:03}")
+    }
+
+    #[derive(Clone, Hashable)]
+    #[hashable(domain_param = "u64", domain_fn = "domain_string")]
+    struct Node(#[hashable(field)] Fp, #[hashable(field)] Fp);
+
+    assert_eq!(Node::domain_string(7), Some("Node007".to_string()));
+    assert_eq!(
+        Node(Fp::from(1u64), Fp::from(2u64)).to_roinput(),
+        ROInput::new()
+            .append_field(Fp::from(1u64))
+            .append_field(Fp::from(2u64))
+    );
+
+    let node = Node(Fp::from(1u64), Fp::from(2u64));
+    assert_ne!(
+        create_kimchi::<Node>(1).hash(&node),
+        create_kimchi::<Node>(2).hash(&node)
+    );
+}
@@ -1,6 +1,8 @@
 use crate::{create_legacy, Hashable, Hasher, ROInput};
 
+mod derive;
 mod hasher;
+mod stream;
 
 #[test]
 fn interfaces() {
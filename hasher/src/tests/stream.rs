@@ -0,0 +1,114 @@
+use crate::{
+    create_legacy, create_stream_kimchi, create_stream_legacy, Fp, Hashable, Hasher, ROInput,
+};
+use o1_utils::FieldHelpers;
+use std::hash::{Hash, Hasher as _};
+
+#[test]
+fn stream_chunking() {
+    let input: Vec<u8> = (0..100).collect();
+    let expected = create_stream_kimchi("Stream").update(&input).digest();
+
+    for split in [0, 1, 30, 31, 32, 62, 99, 100] {
+        let mut hasher = create_stream_kimchi("Stream");
+        hasher.update(&input[..split]).update(&input[split..]);
+        assert_eq!(hasher.finalize(), expected);
+    }
+
+    let mut hasher = create_stream_kimchi("Stream");
+    input.iter().for_each(|byte| {
+        hasher.update(&[*byte]);
+    });
+    assert_eq!(hasher.finalize(), expected);
+}
+
+#[test]
+fn stream_domain_separation() {
+    let hash = |f: &dyn Fn(&mut crate::StreamHasherKimchi)| {
+        let mut hasher = create_stream_kimchi("Stream");
+        f(&mut hasher);
+        hasher.finalize()
+    };
+
+    let digests = [
+        hash(&|_| {}),
+        hash(&|h| {
+            h.update(&[0]);
+        }),
+        hash(&|h| {
+            h.update(&[0, 0]);
+        }),
+        hash(&|h| {
+            h.update(b"a");
+        }),
+        hash(&|h| {
+            h.update(b"a\0");
+        }),
+        hash(&|h| {
+            h.update_field(Fp::from(97u64));
+        }),
+        hash(&|h| {
+            h.update_field(Fp::from(97u64)).update(b"a");
+        }),
+        hash(&|h| {
+            h.update(b"a").update_field(Fp::from(97u64));
+        }),
+        hash(&|h| {
+            h.update_fields(&[Fp::from(97u64), Fp::from(2u64)]);
+        }),
+    ];
+    for (i, x) in digests.iter().enumerate() {
+        for y in &digests[i + 1..] {
+            assert_ne!(x, y);
+        }
+    }
+
+    // the domain string and the sponge constants separate the hashes too
+    assert_ne!(
+        create_stream_kimchi("Stream").update(b"a").digest(),
+        create_stream_kimchi("Stream 2").update(b"a").digest()
+    );
+    assert_ne!(
+        create_stream_kimchi("Stream").update(b"a").digest(),
+        create_stream_legacy("Stream").update(b"a").digest()
+    );
+}
+
+#[test]
+fn stream_absorbed_fields() {
+    // "abc" is packed into one field element, followed by the trailer 2 * 3 + 0
+    #[derive(Clone)]
+    struct Absorbed;
+
+    impl Hashable for Absorbed {
+        type D = ();
+
+        fn to_roinput(&self) -> ROInput {
+            ROInput::new()
+                .append_field(Fp::from(0x636261u64))
+                .append_field(Fp::from(6u64))
+        }
+
+        fn domain_string(_: Self::D) -> Option<String> {
+            "Stream".to_string().into()
+        }
+    }
+
+    assert_eq!(
+        create_stream_legacy("Stream").update(b"abc").digest(),
+        create_legacy::<Absorbed>(()).hash(&Absorbed)
+    );
+}
+
+#[test]
+fn stream_std_hasher() {
+    let mut hasher = create_stream_kimchi("Stream");
+    "hello".hash(&mut hasher);
+    let digest = hasher.digest();
+    assert_eq!(hasher.finish().to_le_bytes(), digest.to_bytes()[..8]);
+
+    // finish does not consume nor reset the context
+    assert_eq!(hasher.finish(), hasher.finish());
+    hasher.write(b"world");
+    assert_ne!(hasher.digest(), digest);
+}
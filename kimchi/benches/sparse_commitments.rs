@@ -0,0 +1,112 @@
+use ark_ff::{One, Zero};
+use ark_poly::{EvaluationDomain, Evaluations};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+use kimchi::circuits::{
+    gate::CircuitGate,
+    polynomials::{foreign_field_add::witness::FFOps, generic::GenericGateSpec},
+    wires::Wire,
+};
+use kimchi::prover_index::testing::new_index_for_test_with_lookups;
+use mina_curves::pasta::{Fp, Vesta};
+use num_bigint::BigUint;
+use poly_commitment::{PolyComm, SRS as _};
+
+/// A circuit of `2^size_log2` rows containing only a handful of foreign field
+/// addition gates, so that their selector is almost everywhere zero.
+fn circuit_with_few_ffadd_gates(size_log2: u32) -> Vec<CircuitGate<Fp>> {
+    let foreign_field_modulus = BigUint::from(2u64).pow(255) - BigUint::from(19u64);
+    let opcodes = [FFOps::Add, FFOps::Sub, FFOps::Add];
+    let (next_row, mut gates) =
+        CircuitGate::<Fp>::create_chain_ffadd(0, &opcodes, &foreign_field_modulus);
+
+    // there's some overhead that we need to remove (e.g. zk rows)
+    let num_gates = (1 << size_log2) - 10;
+    for row in next_row..num_gates {
+        gates.push(CircuitGate::create_generic_gadget(
+            Wire::for_row(row),
+            GenericGateSpec::Const(1u32.into()),
+            None,
+        ));
+    }
+    gates
+}
+
+pub fn bench_verifier_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Sparse selectors");
+    group.sample_size(10).sampling_mode(SamplingMode::Flat);
+
+    for size in [10, 14] {
+        let index = new_index_for_test_with_lookups::<Vesta>(
+            circuit_with_few_ffadd_gates(size),
+            0,
+            0,
+            vec![],
+            None,
+            true,
+            None,
+        );
+        let domain = index.cs.domain.d1;
+        let selector = index
+            .column_evaluations
+            .foreign_field_add_selector8
+            .as_ref()
+            .unwrap();
+        let selector = Evaluations::from_vec_and_domain(
+            selector
+                .evals
+                .iter()
+                .step_by(selector.evals.len() / domain.size())
+                .copied()
+                .collect(),
+            domain,
+        );
+        let non_zero: Vec<_> = selector
+            .evals
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !e.is_zero())
+            .map(|(i, e)| (i, *e))
+            .collect();
+        assert!(non_zero.iter().all(|(_, e)| e.is_one()));
+        let basis: Vec<_> = index
+            .srs
+            .get_lagrange_basis(domain.size())
+            .unwrap()
+            .iter()
+            .collect();
+
+        group.bench_function(format!("dense selector commitment (2^{size} rows)"), |b| {
+            b.iter(|| black_box(PolyComm::multi_scalar_mul(&basis, &selector.evals)))
+        });
+        group.bench_function(format!("selector commitment (2^{size} rows)"), |b| {
+            b.iter(|| {
+                black_box(
+                    index
+                        .srs
+                        .commit_evaluations_non_hiding(domain, black_box(&selector)),
+                )
+            })
+        });
+        group.bench_function(
+            format!(
+                "sparse selector commitment (2^{size} rows, {} non-zero)",
+                non_zero.len()
+            ),
+            |b| {
+                b.iter(|| {
+                    black_box(
+                        index
+                            .srs
+                            .commit_sparse_evaluations_non_hiding(domain, black_box(&non_zero)),
+                    )
+                })
+            },
+        );
+        group.bench_function(format!("verifier index creation (2^{size} rows)"), |b| {
+            b.iter(|| black_box(index.verifier_index()))
+        });
+    }
+}
+
+criterion_group!(benches, bench_verifier_index);
+criterion_main!(benches);
@@ -136,6 +136,9 @@ impl<F: Copy> ColumnEvaluations<F> for ProofEvaluations<PointEvaluations<F>> {
             Index(GateType::Rot64) => self
                 .rot_selector
                 .ok_or(ExprError::MissingIndexEvaluation(col)),
+            Index(GateType::PoseidonPartial) => self
+                .poseidon_partial_selector
+                .ok_or(ExprError::MissingIndexEvaluation(col)),
             Permutation(i) => Ok(self.s[i]),
             Coefficient(i) => Ok(self.coefficients[i]),
             LookupKindIndex(LookupPattern::Xor) => self
@@ -55,6 +55,8 @@ pub struct FeatureFlags {
     pub xor: bool,
     /// ROT gate
     pub rot: bool,
+    /// Poseidon partial rounds gate
+    pub poseidon_partial: bool,
     /// Lookup features
     pub lookup_features: LookupFeatures,
 }
@@ -140,6 +142,10 @@ pub struct ColumnEvaluations<F: PrimeField> {
     /// Rot gate selector over domain d8
     #[serde_as(as = "Option<o1_utils::serialization::SerdeAs>")]
     pub rot_selector8: Option<E<F, D<F>>>,
+
+    /// Poseidon partial rounds gate selector over domain d8
+    #[serde_as(as = "Option<o1_utils::serialization::SerdeAs>")]
+    pub poseidon_partial_selector8: Option<E<F, D<F>>>,
 }
 
 #[serde_as]
@@ -594,6 +600,20 @@ impl<F: PrimeField + SquareRootField> ConstraintSystem<F> {
             }
         };
 
+        let poseidon_partial_selector8 = {
+            if !self.feature_flags.poseidon_partial {
+                None
+            } else {
+                Some(selector_polynomial(
+                    GateType::PoseidonPartial,
+                    &self.gates,
+                    &self.domain,
+                    &self.domain.d8,
+                    self.disable_gates_checks,
+                ))
+            }
+        };
+
         // TODO: This doesn't need to be degree 8 but that would require some changes in expr
         let coefficients8 = array::from_fn(|i| {
             evaluated_column_coefficients.coefficients[i]
@@ -615,6 +635,7 @@ impl<F: PrimeField + SquareRootField> ConstraintSystem<F> {
             foreign_field_mul_selector8,
             xor_selector8,
             rot_selector8,
+            poseidon_partial_selector8,
         }
     }
 }
@@ -636,6 +657,7 @@ impl FeatureFlags {
             foreign_field_mul: false,
             xor: false,
             rot: false,
+            poseidon_partial: false,
         };
 
         for gate in gates {
@@ -646,6 +668,7 @@ impl FeatureFlags {
                 GateType::ForeignFieldMul => feature_flags.foreign_field_mul = true,
                 GateType::Xor16 => feature_flags.xor = true,
                 GateType::Rot64 => feature_flags.rot = true,
+                GateType::PoseidonPartial => feature_flags.poseidon_partial = true,
                 _ => (),
             }
         }
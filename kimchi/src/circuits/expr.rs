@@ -642,6 +642,7 @@ pub enum FeatureFlag {
     ForeignFieldMul,
     Xor,
     Rot,
+    PoseidonPartial,
     LookupTables,
     RuntimeLookupTables,
     LookupPattern(LookupPattern),
@@ -824,6 +825,7 @@ where
                         ForeignFieldMul => features.foreign_field_mul,
                         Xor => features.xor,
                         Rot => features.rot,
+                        PoseidonPartial => features.poseidon_partial,
                         LookupTables => {
                             features.lookup_features.patterns != LookupPatterns::default()
                         }
@@ -111,6 +111,8 @@ pub enum GateType {
     Rot64,
     KeccakRound,
     KeccakSponge,
+    /// Poseidon permutation gate for partial rounds
+    PoseidonPartial,
 }
 
 /// Gate error
@@ -229,6 +231,7 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
             Rot64 => self
                 .verify_witness::<G>(row, witness, &index.cs, public)
                 .map_err(|e| e.to_string()),
+            PoseidonPartial => self.verify_poseidon_partial::<G>(row, witness),
             KeccakRound => self
                 .verify_witness::<G>(row, witness, &index.cs, public)
                 .map_err(|e| e.to_string()),
@@ -342,6 +345,9 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
             GateType::KeccakSponge => {
                 keccak::circuitgates::KeccakSponge::constraint_checks(&env, &mut cache)
             }
+            GateType::PoseidonPartial => {
+                poseidon::PoseidonPartial::constraint_checks(&env, &mut cache)
+            }
         };
 
         // Check for failed constraints
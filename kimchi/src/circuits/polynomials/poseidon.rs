@@ -24,6 +24,12 @@
 //~ This makes that state accessible to the permutation.
 //~ ```
 //~
+//~ The poseidon partial gate uses the same layout to encode 5 partial rounds,
+//~ in which the S-box is only applied to the first element of the state.
+//~ It is used, together with the poseidon gate for the full rounds,
+//~ to implement the variant of the permutation with partial rounds
+//~ (5 full rounds, 45 partial rounds and 5 full rounds, on 11 rows).
+//~
 
 use crate::{
     circuits::{
@@ -37,7 +43,7 @@ use crate::{
 };
 use ark_ff::{Field, PrimeField, SquareRootField};
 use mina_poseidon::{
-    constants::{PlonkSpongeConstantsKimchi, SpongeConstants},
+    constants::{PlonkSpongeConstantsKimchi, PlonkSpongeConstantsKimchiPartial, SpongeConstants},
     poseidon::{sbox, ArithmeticSponge, ArithmeticSpongeParams, Sponge},
 };
 use std::{marker::PhantomData, ops::Range};
@@ -59,6 +65,16 @@ pub const ROUNDS_PER_HASH: usize = PlonkSpongeConstantsKimchi::PERM_ROUNDS_FULL;
 /// Number of PLONK rows required to implement Poseidon
 pub const POS_ROWS_PER_HASH: usize = ROUNDS_PER_HASH / ROUNDS_PER_ROW;
 
+/// Number of PLONK rows using the Poseidon gate at the beginning (and at the end)
+/// of the Poseidon permutation with partial rounds
+pub const FULL_ROWS_PER_HALF: usize =
+    PlonkSpongeConstantsKimchiPartial::PERM_HALF_ROUNDS_FULL / ROUNDS_PER_ROW;
+
+/// Number of PLONK rows using the PoseidonPartial gate in the Poseidon permutation
+/// with partial rounds
+pub const PARTIAL_ROWS_PER_HASH: usize =
+    PlonkSpongeConstantsKimchiPartial::PERM_ROUNDS_PARTIAL / ROUNDS_PER_ROW;
+
 /// The order in a row in which we store states before and after permutations
 pub const STATE_ORDER: [usize; ROUNDS_PER_ROW] = [
     0, // the first state is stored first
@@ -132,6 +148,40 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
         (gates, last_row)
     }
 
+    pub fn create_poseidon_partial(
+        wires: GateWires,
+        // Coefficients are passed in in the logical order
+        coeffs: [[F; SPONGE_WIDTH]; ROUNDS_PER_ROW],
+    ) -> Self {
+        let coeffs = coeffs.iter().flatten().copied().collect();
+        CircuitGate::new(GateType::PoseidonPartial, wires, coeffs)
+    }
+
+    /// `create_poseidon_partial_gadget(row, first_and_last_row, round_constants)` creates an entire set of constraint
+    /// for a Poseidon hash using the permutation with partial rounds ([PlonkSpongeConstantsKimchiPartial]).
+    /// The full rounds are encoded with Poseidon gates and the partial rounds with PoseidonPartial gates,
+    /// so that the gadget uses the same number of rows as [CircuitGate::create_poseidon_gadget].
+    ///
+    /// The round constants must come from parameters sharing the MDS matrix of the curve's sponge parameters.
+    /// The function returns a set of gates, as well as the next pointer to the circuit (next empty absolute row)
+    pub fn create_poseidon_partial_gadget(
+        // the absolute row in the circuit
+        row: usize,
+        // first and last row of the poseidon circuit (because they are used in the permutation)
+        first_and_last_row: [GateWires; 2],
+        round_constants: &[Vec<F>],
+    ) -> (Vec<Self>, usize) {
+        let (mut gates, last_row) =
+            Self::create_poseidon_gadget(row, first_and_last_row, round_constants);
+
+        // the partial rounds are in the middle of the permutation
+        for gate in &mut gates[FULL_ROWS_PER_HALF..FULL_ROWS_PER_HALF + PARTIAL_ROWS_PER_HASH] {
+            gate.typ = GateType::PoseidonPartial;
+        }
+
+        (gates, last_row)
+    }
+
     /// Checks if a witness verifies a poseidon gate
     ///
     /// # Errors
@@ -148,6 +198,33 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
             GateType::Poseidon,
             "incorrect gate type (should be poseidon)"
         );
+        self.verify_poseidon_rounds::<G>(row, witness)
+    }
+
+    /// Checks if a witness verifies a poseidon partial gate
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `self.typ` is not `PoseidonPartial` gate, or `state` does not match after `permutation`.
+    pub fn verify_poseidon_partial<G: KimchiCurve<ScalarField = F>>(
+        &self,
+        row: usize,
+        witness: &[Vec<F>; COLUMNS],
+    ) -> Result<(), String> {
+        ensure_eq!(
+            self.typ,
+            GateType::PoseidonPartial,
+            "incorrect gate type (should be poseidon partial)"
+        );
+        self.verify_poseidon_rounds::<G>(row, witness)
+    }
+
+    fn verify_poseidon_rounds<G: KimchiCurve<ScalarField = F>>(
+        &self,
+        row: usize,
+        witness: &[Vec<F>; COLUMNS],
+    ) -> Result<(), String> {
+        let full_rounds = self.typ == GateType::Poseidon;
 
         // fetch each state in the right order
         let mut states = vec![];
@@ -172,8 +249,12 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
                 // i-th(new_state) = i-th(rc) + mds(sbox(state))
                 let state = &states[round];
                 let mut new_state = rc[round][i];
-                for (&s, mds) in state.iter().zip(mds_row.iter()) {
-                    let sboxed = sbox::<F, PlonkSpongeConstantsKimchi>(s);
+                for (j, (&s, mds)) in state.iter().zip(mds_row.iter()).enumerate() {
+                    let sboxed = if full_rounds || j == 0 {
+                        sbox::<F, PlonkSpongeConstantsKimchi>(s)
+                    } else {
+                        s
+                    };
                     new_state += sboxed * mds;
                 }
 
@@ -181,7 +262,8 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
                     new_state,
                     states[round + 1][i],
                     format!(
-                        "poseidon: permutation of state[{}] -> state[{}][{}] is incorrect",
+                        "{:?}: permutation of state[{}] -> state[{}][{}] is incorrect",
+                        self.typ,
                         round,
                         round + 1,
                         i
@@ -205,7 +287,7 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
     pub fn rc(&self) -> [[F; SPONGE_WIDTH]; ROUNDS_PER_ROW] {
         std::array::from_fn(|round| {
             std::array::from_fn(|col| {
-                if self.typ == GateType::Poseidon {
+                if matches!(self.typ, GateType::Poseidon | GateType::PoseidonPartial) {
                     self.coeffs[SPONGE_WIDTH * round + col]
                 } else {
                     F::zero()
@@ -218,12 +300,40 @@ impl<F: PrimeField + SquareRootField> CircuitGate<F> {
 /// `generate_witness(row, params, witness_cols, input)` uses a sponge initialized with
 /// `params` to generate a witness for starting at row `row` in `witness_cols`,
 /// and with input `input`.
+pub fn generate_witness<F: Field>(
+    row: usize,
+    params: &'static ArithmeticSpongeParams<F>,
+    witness_cols: &mut [Vec<F>; COLUMNS],
+    input: [F; SPONGE_WIDTH],
+) {
+    generate_rounds_witness::<F, PlonkSpongeConstantsKimchi>(row, params, witness_cols, input)
+}
+
+/// `generate_partial_witness(row, params, witness_cols, input)` generates the witness
+/// of [CircuitGate::create_poseidon_partial_gadget], for the permutation with partial rounds
+/// ([PlonkSpongeConstantsKimchiPartial]) using `params`, starting at row `row` in `witness_cols`,
+/// and with input `input`.
+pub fn generate_partial_witness<F: Field>(
+    row: usize,
+    params: &'static ArithmeticSpongeParams<F>,
+    witness_cols: &mut [Vec<F>; COLUMNS],
+    input: [F; SPONGE_WIDTH],
+) {
+    generate_rounds_witness::<F, PlonkSpongeConstantsKimchiPartial>(
+        row,
+        params,
+        witness_cols,
+        input,
+    )
+}
+
+/// Generates the witness of the rounds of the permutation described by `SC`.
 ///
 /// # Panics
 ///
 /// Will panic if the `circuit` has `INITIAL_ARK`.
 #[allow(clippy::assertions_on_constants)]
-pub fn generate_witness<F: Field>(
+fn generate_rounds_witness<F: Field, SC: SpongeConstants>(
     row: usize,
     params: &'static ArithmeticSpongeParams<F>,
     witness_cols: &mut [Vec<F>; COLUMNS],
@@ -235,8 +345,12 @@ pub fn generate_witness<F: Field>(
     witness_cols[2][row] = input[2];
 
     // set the sponge state
-    let mut sponge = ArithmeticSponge::<F, PlonkSpongeConstantsKimchi>::new(params);
-    sponge.state = input.into();
+    let mut sponge = ArithmeticSponge::<F, SC>::new(params);
+    sponge.state = input;
+
+    // the rounds in the middle of the permutation are partial rounds, if any
+    let partial_rounds =
+        SC::PERM_HALF_ROUNDS_FULL..SC::PERM_HALF_ROUNDS_FULL + SC::PERM_ROUNDS_PARTIAL;
 
     // for the poseidon rows
     for row_idx in 0..POS_ROWS_PER_HASH {
@@ -254,10 +368,14 @@ pub fn generate_witness<F: Field>(
 
             // apply the sponge and record the result in the witness
             assert!(
-                !PlonkSpongeConstantsKimchi::PERM_INITIAL_ARK,
+                !SC::PERM_INITIAL_ARK,
                 "this won't work if the circuit has an INITIAL_ARK"
             );
-            sponge.full_round(abs_round);
+            if partial_rounds.contains(&abs_round) {
+                sponge.partial_round(abs_round);
+            } else {
+                sponge.full_round(abs_round);
+            }
 
             // apply the sponge and record the result in the witness
             let cols_to_update = round_to_cols((round + 1) % ROUNDS_PER_ROW);
@@ -340,87 +458,130 @@ where
     const CONSTRAINTS: u32 = 15;
 
     fn constraint_checks<T: ExprOps<F>>(env: &ArgumentEnv<F, T>, cache: &mut Cache) -> Vec<T> {
-        let mut res = vec![];
+        rounds_constraints(env, cache, true)
+    }
+}
 
-        let mut idx = 0;
+/// Implementation of the PoseidonPartial gate,
+/// which constrains 5 partial rounds of the permutation in the same way as [Poseidon].
+/// Only the first element of each state goes through the S-box:
+///
+/// ```ignore
+/// curr[round_range(1)][0] =
+///      mds[0][0] * sbox(curr[round_range(0)][0])
+///    + mds[0][1] * curr[round_range(0)][1]
+///    + mds[0][2] * curr[round_range(0)][2]
+///    + rcm[round_range(1)][0]
+/// ...
+/// ```
+#[derive(Default)]
+pub struct PoseidonPartial<F>(PhantomData<F>);
 
-        //~ We define $M_{r, c}$ as the MDS matrix at row $r$ and column $c$.
-        let mds: Vec<Vec<_>> = (0..SPONGE_WIDTH)
-            .map(|row| (0..SPONGE_WIDTH).map(|col| env.mds(row, col)).collect())
-            .collect();
+impl<F> Argument<F> for PoseidonPartial<F>
+where
+    F: PrimeField,
+{
+    const ARGUMENT_TYPE: ArgumentType = ArgumentType::Gate(GateType::PoseidonPartial);
+    const CONSTRAINTS: u32 = 15;
 
-        for e in &ROUND_EQUATIONS {
-            let &RoundEquation {
-                source,
-                target: (target_row, target_round),
-            } = e;
-            //~
-            //~ We define the S-box operation as $w^S$ for $S$ the `SPONGE_BOX` constant.
-            let sboxed: Vec<_> = round_to_cols(source)
-                .map(|i| {
+    fn constraint_checks<T: ExprOps<F>>(env: &ArgumentEnv<F, T>, cache: &mut Cache) -> Vec<T> {
+        rounds_constraints(env, cache, false)
+    }
+}
+
+/// The constraints of 5 rounds of the permutation,
+/// which are full rounds if `full_rounds` is set, and partial rounds otherwise.
+fn rounds_constraints<F: PrimeField, T: ExprOps<F>>(
+    env: &ArgumentEnv<F, T>,
+    cache: &mut Cache,
+    full_rounds: bool,
+) -> Vec<T> {
+    let mut res = vec![];
+
+    let mut idx = 0;
+
+    //~ We define $M_{r, c}$ as the MDS matrix at row $r$ and column $c$.
+    let mds: Vec<Vec<_>> = (0..SPONGE_WIDTH)
+        .map(|row| (0..SPONGE_WIDTH).map(|col| env.mds(row, col)).collect())
+        .collect();
+
+    for e in &ROUND_EQUATIONS {
+        let &RoundEquation {
+            source,
+            target: (target_row, target_round),
+        } = e;
+        //~
+        //~ We define the S-box operation as $w^S$ for $S$ the `SPONGE_BOX` constant.
+        //~ In the partial rounds, only the first element of the state goes through the S-box.
+        let sboxed: Vec<_> = round_to_cols(source)
+            .enumerate()
+            .map(|(j, i)| {
+                if full_rounds || j == 0 {
                     cache.cache(
                         env.witness_curr(i)
                             .pow(u64::from(PlonkSpongeConstantsKimchi::PERM_SBOX)),
                     )
-                })
-                .collect();
-
-            for (j, col) in round_to_cols(target_round).enumerate() {
-                //~
-                //~ We store the 15 round constants $r_i$ required for the 5 rounds (3 per round) in the coefficient table:
-                //~
-                //~ |  0 |  1 |  2 |  3 |  4 |  5 |  6 |  7 |  8 |  9 | 10 | 11 | 12 | 13 | 14 |
-                //~ |:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|
-                //~ | r0 | r1 | r2 | r3 | r4 | r5 | r6 | r7 | r8 | r9 | r10 | r11 | r12 | r13 | r14 |
-                let rc = env.coeff(idx);
-
-                idx += 1;
-
-                //~
-                //~ The initial state, stored in the first three registers, are not constrained.
-                //~ The following 4 states (of 3 field elements), including 1 in the next row,
-                //~ are constrained to represent the 5 rounds of permutation.
-                //~ Each of the associated 15 registers is associated to a constraint, calculated as:
-                //~
-                //~ first round:
-                //~
-                //~ * $w_6 - \left(r_0 + (M_{0, 0} w_0^S + M_{0, 1} w_1^S + M_{0, 2} w_2^S)\right)$
-                //~ * $w_7 - \left(r_1 + (M_{1, 0} w_0^S + M_{1, 1} w_1^S + M_{1, 2} w_2^S)\right)$
-                //~ * $w_8 - \left(r_2 + (M_{2, 0} w_0^S + M_{2, 1} w_1^S + M_{2, 2} w_2^S)\right)$
-                //~
-                //~ second round:
-                //~
-                //~ * $w_9 - \left(r_3 + (M_{0, 0} w_6^S + M_{0, 1} w_7^S + M_{0, 2} w_8^S)\right)$
-                //~ * $w_{10} - \left(r_4 + (M_{1, 0} w_6^S + M_{1, 1} w_7^S + M_{1, 2} w_8^S)\right)$
-                //~ * $w_{11} - \left(r_5 + (M_{2, 0} w_6^S + M_{2, 1} w_7^S + M_{2, 2} w_8^S)\right)$
-                //~
-                //~ third round:
-                //~
-                //~ * $w_{12} - \left(r_6 + (M_{0, 0} w_9^S + M_{0, 1} w_{10}^S + M_{0, 2} w_{11}^S)\right)$
-                //~ * $w_{13} - \left(r_7 + (M_{1, 0} w_9^S + M_{1, 1} w_{10}^S + M_{1, 2} w_{11}^S)\right)$
-                //~ * $w_{14} - \left(r_8 + (M_{2, 0} w_9^S + M_{2, 1} w_{10}^S + M_{2, 2} w_{11}^S)\right)$
-                //~
-                //~ fourth round:
-                //~
-                //~ * $w_3 - \left(r_9 + (M_{0, 0} w_{12}^S + M_{0, 1} w_{13}^S + M_{0, 2} w_{14}^S)\right)$
-                //~ * $w_4 - \left(r_{10} + (M_{1, 0} w_{12}^S + M_{1, 1} w_{13}^S + M_{1, 2} w_{14}^S)\right)$
-                //~ * $w_5 - \left(r_{11} + (M_{2, 0} w_{12}^S + M_{2, 1} w_{13}^S + M_{2, 2} w_{14}^S)\right)$
-                //~
-                //~ fifth round:
-                //~
-                //~ * $w_{0, next} - \left(r_{12} + (M_{0, 0} w_3^S + M_{0, 1} w_4^S + M_{0, 2} w_5^S)\right)$
-                //~ * $w_{1, next} - \left(r_{13} + (M_{1, 0} w_3^S + M_{1, 1} w_4^S + M_{1, 2} w_5^S)\right)$
-                //~ * $w_{2, next} - \left(r_{14} + (M_{2, 0} w_3^S + M_{2, 1} w_4^S + M_{2, 2} w_5^S)\right)$
-                //~
-                //~ where $w_{i, next}$ is the polynomial $w_i(\omega x)$ which points to the next row.
-                let constraint = env.witness(target_row, col)
-                    - sboxed
-                        .iter()
-                        .zip(mds[j].iter())
-                        .fold(rc, |acc, (x, c)| acc + c.clone() * x.clone());
-                res.push(constraint);
-            }
+                } else {
+                    env.witness_curr(i)
+                }
+            })
+            .collect();
+
+        for (j, col) in round_to_cols(target_round).enumerate() {
+            //~
+            //~ We store the 15 round constants $r_i$ required for the 5 rounds (3 per round) in the coefficient table:
+            //~
+            //~ |  0 |  1 |  2 |  3 |  4 |  5 |  6 |  7 |  8 |  9 | 10 | 11 | 12 | 13 | 14 |
+            //~ |:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|:--:|
+            //~ | r0 | r1 | r2 | r3 | r4 | r5 | r6 | r7 | r8 | r9 | r10 | r11 | r12 | r13 | r14 |
+            let rc = env.coeff(idx);
+
+            idx += 1;
+
+            //~
+            //~ The initial state, stored in the first three registers, are not constrained.
+            //~ The following 4 states (of 3 field elements), including 1 in the next row,
+            //~ are constrained to represent the 5 rounds of permutation.
+            //~ Each of the associated 15 registers is associated to a constraint, calculated as:
+            //~
+            //~ first round:
+            //~
+            //~ * $w_6 - \left(r_0 + (M_{0, 0} w_0^S + M_{0, 1} w_1^S + M_{0, 2} w_2^S)\right)$
+            //~ * $w_7 - \left(r_1 + (M_{1, 0} w_0^S + M_{1, 1} w_1^S + M_{1, 2} w_2^S)\right)$
+            //~ * $w_8 - \left(r_2 + (M_{2, 0} w_0^S + M_{2, 1} w_1^S + M_{2, 2} w_2^S)\right)$
+            //~
+            //~ second round:
+            //~
+            //~ * $w_9 - \left(r_3 + (M_{0, 0} w_6^S + M_{0, 1} w_7^S + M_{0, 2} w_8^S)\right)$
+            //~ * $w_{10} - \left(r_4 + (M_{1, 0} w_6^S + M_{1, 1} w_7^S + M_{1, 2} w_8^S)\right)$
+            //~ * $w_{11} - \left(r_5 + (M_{2, 0} w_6^S + M_{2, 1} w_7^S + M_{2, 2} w_8^S)\right)$
+            //~
+            //~ third round:
+            //~
+            //~ * $w_{12} - \left(r_6 + (M_{0, 0} w_9^S + M_{0, 1} w_{10}^S + M_{0, 2} w_{11}^S)\right)$
+            //~ * $w_{13} - \left(r_7 + (M_{1, 0} w_9^S + M_{1, 1} w_{10}^S + M_{1, 2} w_{11}^S)\right)$
+            //~ * $w_{14} - \left(r_8 + (M_{2, 0} w_9^S + M_{2, 1} w_{10}^S + M_{2, 2} w_{11}^S)\right)$
+            //~
+            //~ fourth round:
+            //~
+            //~ * $w_3 - \left(r_9 + (M_{0, 0} w_{12}^S + M_{0, 1} w_{13}^S + M_{0, 2} w_{14}^S)\right)$
+            //~ * $w_4 - \left(r_{10} + (M_{1, 0} w_{12}^S + M_{1, 1} w_{13}^S + M_{1, 2} w_{14}^S)\right)$
+            //~ * $w_5 - \left(r_{11} + (M_{2, 0} w_{12}^S + M_{2, 1} w_{13}^S + M_{2, 2} w_{14}^S)\right)$
+            //~
+            //~ fifth round:
+            //~
+            //~ * $w_{0, next} - \left(r_{12} + (M_{0, 0} w_3^S + M_{0, 1} w_4^S + M_{0, 2} w_5^S)\right)$
+            //~ * $w_{1, next} - \left(r_{13} + (M_{1, 0} w_3^S + M_{1, 1} w_4^S + M_{1, 2} w_5^S)\right)$
+            //~ * $w_{2, next} - \left(r_{14} + (M_{2, 0} w_3^S + M_{2, 1} w_4^S + M_{2, 2} w_5^S)\right)$
+            //~
+            //~ where $w_{i, next}$ is the polynomial $w_i(\omega x)$ which points to the next row.
+            let constraint = env.witness(target_row, col)
+                - sboxed
+                    .iter()
+                    .zip(mds[j].iter())
+                    .fold(rc, |acc, (x, c)| acc + c.clone() * x.clone());
+            res.push(constraint);
         }
-        res
     }
+    res
 }
@@ -167,38 +167,82 @@ impl KimchiCurve for GroupAffine<LegacyPallasParameters> {
 }
 
 #[cfg(feature = "bn254")]
-use mina_poseidon::dummy_values::kimchi_dummy;
+use mina_curves::bn254::{BN254Parameters, GrumpkinParameters};
 
 #[cfg(feature = "bn254")]
-impl KimchiCurve for GroupAffine<ark_bn254::g1::Parameters> {
+fn bn254_endos() -> &'static (
+    <BN254Parameters as ModelParameters>::BaseField,
+    <BN254Parameters as ModelParameters>::ScalarField,
+) {
+    static BN254_ENDOS: Lazy<(
+        <BN254Parameters as ModelParameters>::BaseField,
+        <BN254Parameters as ModelParameters>::ScalarField,
+    )> = Lazy::new(endos::<GroupAffine<BN254Parameters>>);
+    &BN254_ENDOS
+}
+
+#[cfg(feature = "bn254")]
+fn grumpkin_endos() -> &'static (
+    <GrumpkinParameters as ModelParameters>::BaseField,
+    <GrumpkinParameters as ModelParameters>::ScalarField,
+) {
+    static GRUMPKIN_ENDOS: Lazy<(
+        <GrumpkinParameters as ModelParameters>::BaseField,
+        <GrumpkinParameters as ModelParameters>::ScalarField,
+    )> = Lazy::new(endos::<GroupAffine<GrumpkinParameters>>);
+    &GRUMPKIN_ENDOS
+}
+
+#[cfg(feature = "bn254")]
+impl KimchiCurve for GroupAffine<BN254Parameters> {
     const NAME: &'static str = "bn254";
 
     fn sponge_params() -> &'static ArithmeticSpongeParams<Self::ScalarField> {
-        // TODO: Generate some params
-        static PARAMS: Lazy<ArithmeticSpongeParams<ark_bn254::Fr>> = Lazy::new(kimchi_dummy);
-        &PARAMS
+        mina_poseidon::bn254::fr_kimchi::static_params()
+    }
+
+    fn other_curve_sponge_params() -> &'static ArithmeticSpongeParams<Self::BaseField> {
+        mina_poseidon::bn254::fq_kimchi::static_params()
+    }
+
+    fn endos() -> &'static (Self::BaseField, Self::ScalarField) {
+        bn254_endos()
+    }
+
+    fn other_curve_endo() -> &'static Self::ScalarField {
+        &grumpkin_endos().0
+    }
+
+    fn other_curve_prime_subgroup_generator() -> (Self::ScalarField, Self::ScalarField) {
+        GroupAffine::<GrumpkinParameters>::prime_subgroup_generator()
+            .to_coordinates()
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "bn254")]
+impl KimchiCurve for GroupAffine<GrumpkinParameters> {
+    const NAME: &'static str = "grumpkin";
+
+    fn sponge_params() -> &'static ArithmeticSpongeParams<Self::ScalarField> {
+        mina_poseidon::bn254::fq_kimchi::static_params()
     }
 
     fn other_curve_sponge_params() -> &'static ArithmeticSpongeParams<Self::BaseField> {
-        // TODO: Generate some params
-        static PARAMS: Lazy<ArithmeticSpongeParams<ark_bn254::Fq>> = Lazy::new(kimchi_dummy);
-        &PARAMS
+        mina_poseidon::bn254::fr_kimchi::static_params()
     }
 
     fn endos() -> &'static (Self::BaseField, Self::ScalarField) {
-        static ENDOS: Lazy<(ark_bn254::Fq, ark_bn254::Fr)> =
-            Lazy::new(endos::<ark_bn254::G1Affine>);
-        &ENDOS
+        grumpkin_endos()
     }
 
     fn other_curve_endo() -> &'static Self::ScalarField {
-        // TODO: Dummy value, this is definitely not right
-        static ENDO: Lazy<ark_bn254::Fr> = Lazy::new(|| 13u64.into());
-        &ENDO
+        &bn254_endos().0
     }
 
     fn other_curve_prime_subgroup_generator() -> (Self::ScalarField, Self::ScalarField) {
-        // TODO: Dummy value, this is definitely not right
-        (44u64.into(), 88u64.into())
+        GroupAffine::<BN254Parameters>::prime_subgroup_generator()
+            .to_coordinates()
+            .unwrap()
     }
 }
@@ -1,12 +1,13 @@
 use crate::circuits::gate::CurrOrNext;
 use ark_ec::AffineCurve;
-use ark_ff::Zero;
+use ark_ff::{PrimeField, Zero};
 use ark_poly::{EvaluationDomain, Evaluations, Radix2EvaluationDomain};
 use error_term::{compute_error, ExtendedEnv, Side};
 use expressions::{folding_expression, FoldingColumnTrait, IntegratedFoldingExpr};
 pub use expressions::{ExpExtension, FoldingCompatibleExpr};
 pub use instance_witness::{Instance, RelaxedInstance, RelaxedWitness, Witness};
 use instance_witness::{RelaxableInstance, RelaxablePair};
+use mina_poseidon::{transcript::Transcript, FqSponge};
 use poly_commitment::{commitment::CommitmentCurve, PolyComm, SRS};
 use quadraticization::ExtendedWitnessGenerator;
 use std::{fmt::Debug, hash::Hash};
@@ -19,6 +20,20 @@ mod quadraticization;
 mod test;
 
 type ScalarField<C> = <<C as FoldingConfig>::Curve as AffineCurve>::ScalarField;
+type BaseField<C> = <<C as FoldingConfig>::Curve as AffineCurve>::BaseField;
+
+/// The domain separator of the folding transcripts.
+pub const FOLDING_DOMAIN_SEPARATOR: &[u8] = b"kimchi-folding";
+
+/// The transcript used to create the folding challenges. It is expected to be
+/// created with [FOLDING_DOMAIN_SEPARATOR], or forked from the transcript of
+/// the protocol using folding.
+pub type FoldingTranscript<CF> = Transcript<
+    BaseField<CF>,
+    <CF as FoldingConfig>::Curve,
+    ScalarField<CF>,
+    <CF as FoldingConfig>::Sponge,
+>;
 
 pub trait FoldingConfig: Clone + Debug + Eq + Hash + 'static {
     type Column: FoldingColumnTrait + Debug + Eq + Hash;
@@ -32,9 +47,8 @@ pub trait FoldingConfig: Clone + Debug + Eq + Hash + 'static {
 
     type Srs: SRS<Self::Curve>;
 
-    /// FIXME: use Sponge from kimchi
-    /// The sponge used to create challenges
-    type Sponge: Sponge<Self::Curve>;
+    /// The sponge used by the transcript to create challenges
+    type Sponge: FqSponge<BaseField<Self>, Self::Curve, ScalarField<Self>>;
 
     /// For Plonk, it will be the commitments to the polynomials and the challenges
     type Instance: Instance<Self::Curve>;
@@ -215,12 +229,6 @@ pub trait FoldingEnv<F, I, W, Col, Chal> {
     fn alpha(&self, i: usize, side: Side) -> F;
 }
 
-/// TODO: Use Sponge trait from kimchi
-pub trait Sponge<G: CommitmentCurve> {
-    /// Compute a challenge from two commitments
-    fn challenge(absorbe: &[PolyComm<G>; 2]) -> G::ScalarField;
-}
-
 type Evals<F> = Evaluations<F, Radix2EvaluationDomain<F>>;
 
 pub struct FoldingScheme<CF: FoldingConfig> {
@@ -259,11 +267,27 @@ impl<CF: FoldingConfig> FoldingScheme<CF> {
         (scheme, final_expression)
     }
 
+    /// Absorbs the commitments to the error terms in the transcript, and
+    /// squeezes the folding challenge.
+    fn challenge(
+        transcript: &mut FoldingTranscript<CF>,
+        error_commitments: &[PolyComm<CF::Curve>; 2],
+    ) -> ScalarField<CF>
+    where
+        BaseField<CF>: PrimeField,
+    {
+        for comm in error_commitments {
+            transcript.absorb_points(b"error_comm", &comm.elems);
+        }
+        transcript.challenge(b"folding_challenge")
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn fold_instance_witness_pair<I, W, A, B>(
         &self,
         a: A,
         b: B,
+        transcript: &mut FoldingTranscript<CF>,
     ) -> (
         RelaxedInstance<CF::Curve, CF::Instance>,
         RelaxedWitness<CF::Curve, CF::Witness>,
@@ -272,6 +296,7 @@ impl<CF: FoldingConfig> FoldingScheme<CF> {
     where
         A: RelaxablePair<CF::Curve, CF::Instance, CF::Witness>,
         B: RelaxablePair<CF::Curve, CF::Instance, CF::Witness>,
+        BaseField<CF>: PrimeField,
     {
         let a = a.relax(&self.zero_vec, self.zero_commitment.clone());
         let b = b.relax(&self.zero_vec, self.zero_commitment.clone());
@@ -290,7 +315,7 @@ impl<CF: FoldingConfig> FoldingScheme<CF> {
             .map(|e| self.srs.commit_evaluations_non_hiding(self.domain, e));
 
         let error = error_evals.map(|e| e.evals);
-        let challenge = <CF::Sponge>::challenge(&error_commitments);
+        let challenge = Self::challenge(transcript, &error_commitments);
         let ([ins1, ins2], [wit1, wit2]) = env.unwrap();
         let instance =
             RelaxedInstance::combine_and_sub_error(ins1, ins2, challenge, &error_commitments);
@@ -303,14 +328,16 @@ impl<CF: FoldingConfig> FoldingScheme<CF> {
         a: A,
         b: B,
         error_commitments: [PolyComm<CF::Curve>; 2],
+        transcript: &mut FoldingTranscript<CF>,
     ) -> RelaxedInstance<CF::Curve, CF::Instance>
     where
         A: RelaxableInstance<CF::Curve, CF::Instance>,
         B: RelaxableInstance<CF::Curve, CF::Instance>,
+        BaseField<CF>: PrimeField,
     {
         let a: RelaxedInstance<CF::Curve, CF::Instance> = a.relax(self.zero_commitment.clone());
         let b: RelaxedInstance<CF::Curve, CF::Instance> = b.relax(self.zero_commitment.clone());
-        let challenge = <CF::Sponge>::challenge(&error_commitments);
+        let challenge = Self::challenge(transcript, &error_commitments);
         RelaxedInstance::combine_and_sub_error(a, b, challenge, &error_commitments)
     }
 }
@@ -323,21 +350,15 @@ mod example {
             expr::{ConstantExpr, Expr},
             gate::CurrOrNext,
         },
-        curve::KimchiCurve,
         folding::{
             error_term::Side, expressions::FoldingColumnTrait, FoldingConfig, FoldingEnv, Instance,
-            Sponge, Witness,
+            Witness,
         },
     };
     use ark_bn254;
     use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_ff::Zero;
-    use mina_poseidon::{
-        constants::PlonkSpongeConstantsKimchi,
-        sponge::{DefaultFqSponge, ScalarChallenge},
-        FqSponge,
-    };
-    use poly_commitment::PolyComm;
+    use mina_poseidon::{constants::PlonkSpongeConstantsKimchi, sponge::DefaultFqSponge};
 
     /// Field = BN254 prime field
     /// Statement: I know w such that C(x, y, w) = 0
@@ -369,20 +390,6 @@ mod example {
         }
     }
 
-    // TODO: get rid of trait Sponge in folding, and use the one from kimchi
-    impl Sponge<Curve> for BaseSponge {
-        fn challenge(absorbe: &[PolyComm<Curve>; 2]) -> Fp {
-            // This function does not have a &self because it is meant to absorb and squeeze only once
-            let mut s = BaseSponge::new(Curve::other_curve_sponge_params());
-            s.absorb_g(&absorbe[0].elems);
-            s.absorb_g(&absorbe[1].elems);
-            // Squeeze sponge
-            let chal = ScalarChallenge(s.challenge());
-            let (_, endo_r) = Curve::endos();
-            chal.to_field(endo_r)
-        }
-    }
-
     /// The instance is the commitments to the polynomials and the challenges
     #[derive(Clone, Debug, PartialEq, Eq)]
     struct TestInstance {
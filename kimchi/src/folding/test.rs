@@ -1,7 +1,7 @@
 use crate::folding::{
     error_term::Side,
     expressions::{extract_terms, FoldingColumnTrait},
-    FoldingConfig, FoldingEnv, Instance, Sponge, Witness,
+    FoldingConfig, FoldingEnv, Instance, Witness,
 };
 
 mod mock {
@@ -9,7 +9,8 @@ mod mock {
     use crate::circuits::gate::CurrOrNext::{self, Curr};
     use ark_ec::AffineCurve;
     use itertools::Itertools;
-    use mina_curves::pasta::Pallas;
+    use mina_curves::pasta::{Pallas, PallasParameters};
+    use mina_poseidon::{constants::PlonkSpongeConstantsKimchi, sponge::DefaultFqSponge};
     use num_traits::Zero;
     use poly_commitment::commitment::CommitmentCurve;
     use std::marker::PhantomData;
@@ -22,12 +23,6 @@ mod mock {
         }
     }
 
-    impl<G: CommitmentCurve> Sponge<G> for Mock {
-        fn challenge(_absorbe: &[poly_commitment::PolyComm<G>; 2]) -> <G>::ScalarField {
-            panic!("just for test")
-        }
-    }
-
     impl<G: CommitmentCurve> Instance<G> for Mock {
         fn combine(_a: Self, _b: Self, _challenge: G::ScalarField) -> Self {
             Mock
@@ -80,7 +75,7 @@ mod mock {
 
         type Srs = poly_commitment::srs::SRS<Pallas>;
 
-        type Sponge = Mock;
+        type Sponge = DefaultFqSponge<PallasParameters, PlonkSpongeConstantsKimchi>;
 
         type Instance = Mock;
 
@@ -0,0 +1,162 @@
+//! Sponges based on Keccak-256, to verify kimchi proofs on the EVM.
+//!
+//! Both sponges keep a 32-byte state, initially zero. Absorbing appends the
+//! encoding of the values to a buffer, and squeezing replaces the state by
+//! `keccak256(state || buffer)` and empties the buffer. This is what a
+//! Solidity verifier computes with `keccak256(abi.encodePacked(state, ...))`.
+//!
+//! Field elements are encoded in big-endian, on 32 bytes for the BN254 and
+//! pasta fields, as `uint256`s. Points are encoded as their two coordinates,
+//! and the point at infinity as `(0, 0)`, as for the EVM precompiles.
+//! A squeezed state is reduced modulo the order of the field, except for the
+//! scalar challenges (see [ScalarChallenge]), which are its low 128 bits.
+
+use crate::plonk_sponge::FrSponge;
+use ark_ec::{short_weierstrass_jacobian::GroupAffine, SWModelParameters};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use mina_poseidon::{poseidon::ArithmeticSpongeParams, sponge::ScalarChallenge, FqSponge};
+use std::marker::PhantomData;
+use tiny_keccak::{Hasher, Keccak};
+
+/// The number of bytes of a challenge.
+const CHALLENGE_LENGTH_IN_BYTES: usize = 16;
+
+/// The state shared by [KeccakFqSponge] and [KeccakFrSponge].
+#[derive(Clone, Debug, Default)]
+struct KeccakState {
+    state: [u8; 32],
+    pending: Vec<u8>,
+}
+
+impl KeccakState {
+    fn absorb_field<F: PrimeField>(&mut self, x: &F) {
+        self.pending.extend(x.into_repr().to_bytes_be());
+    }
+
+    fn squeeze(&mut self) -> [u8; 32] {
+        let mut hasher = Keccak::v256();
+        hasher.update(&self.state);
+        hasher.update(&self.pending);
+        hasher.finalize(&mut self.state);
+        self.pending.clear();
+        self.state
+    }
+
+    fn squeeze_field<F: PrimeField>(&mut self) -> F {
+        F::from_be_bytes_mod_order(&self.squeeze())
+    }
+
+    fn squeeze_challenge<F: PrimeField>(&mut self) -> F {
+        F::from_be_bytes_mod_order(&self.squeeze()[32 - CHALLENGE_LENGTH_IN_BYTES..])
+    }
+}
+
+/// A Keccak-256 [FqSponge], absorbing points of the curve `P`.
+#[derive(Debug)]
+pub struct KeccakFqSponge<P> {
+    state: KeccakState,
+    _curve: PhantomData<P>,
+}
+
+// Implemented by hand, as the curve parameters are not `Clone`.
+impl<P> Clone for KeccakFqSponge<P> {
+    fn clone(&self) -> Self {
+        KeccakFqSponge {
+            state: self.state.clone(),
+            _curve: PhantomData,
+        }
+    }
+}
+
+impl<P: SWModelParameters> FqSponge<P::BaseField, GroupAffine<P>, P::ScalarField>
+    for KeccakFqSponge<P>
+where
+    P::BaseField: PrimeField,
+{
+    /// Creates a new sponge. The parameters are not used.
+    fn new(_params: &'static ArithmeticSpongeParams<P::BaseField>) -> Self {
+        KeccakFqSponge {
+            state: KeccakState::default(),
+            _curve: PhantomData,
+        }
+    }
+
+    fn absorb_g(&mut self, g: &[GroupAffine<P>]) {
+        for g in g {
+            if g.infinity {
+                let zero = P::BaseField::zero();
+                self.state.absorb_field(&zero);
+                self.state.absorb_field(&zero);
+            } else {
+                self.state.absorb_field(&g.x);
+                self.state.absorb_field(&g.y);
+            }
+        }
+    }
+
+    fn absorb_fq(&mut self, x: &[P::BaseField]) {
+        x.iter().for_each(|x| self.state.absorb_field(x));
+    }
+
+    fn absorb_fr(&mut self, x: &[P::ScalarField]) {
+        x.iter().for_each(|x| self.state.absorb_field(x));
+    }
+
+    fn challenge_fq(&mut self) -> P::BaseField {
+        self.state.squeeze_field()
+    }
+
+    fn challenge(&mut self) -> P::ScalarField {
+        self.state.squeeze_challenge()
+    }
+
+    fn digest_fq(mut self) -> P::BaseField {
+        self.state.squeeze_field()
+    }
+
+    fn digest(mut self) -> P::ScalarField {
+        self.state.squeeze_field()
+    }
+}
+
+/// A Keccak-256 [FrSponge].
+#[derive(Debug)]
+pub struct KeccakFrSponge<F> {
+    state: KeccakState,
+    _field: PhantomData<F>,
+}
+
+impl<F> Clone for KeccakFrSponge<F> {
+    fn clone(&self) -> Self {
+        KeccakFrSponge {
+            state: self.state.clone(),
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField> FrSponge<F> for KeccakFrSponge<F> {
+    /// Creates a new sponge. The parameters are not used.
+    fn new(_params: &'static ArithmeticSpongeParams<F>) -> Self {
+        KeccakFrSponge {
+            state: KeccakState::default(),
+            _field: PhantomData,
+        }
+    }
+
+    fn absorb(&mut self, x: &F) {
+        self.state.absorb_field(x);
+    }
+
+    fn absorb_multiple(&mut self, x: &[F]) {
+        x.iter().for_each(|x| self.state.absorb_field(x));
+    }
+
+    fn challenge(&mut self) -> ScalarChallenge<F> {
+        ScalarChallenge(self.state.squeeze_challenge())
+    }
+
+    fn digest(mut self) -> F {
+        self.state.squeeze_field()
+    }
+}
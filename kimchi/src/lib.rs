@@ -16,6 +16,7 @@ pub mod circuits;
 pub mod curve;
 pub mod error;
 pub mod folding;
+pub mod keccak_sponge;
 pub mod lagrange_basis_evaluations;
 pub mod linearization;
 pub mod oracles;
@@ -15,7 +15,7 @@ use crate::circuits::polynomials::{
     foreign_field_add::circuitgates::ForeignFieldAdd,
     foreign_field_mul::circuitgates::ForeignFieldMul,
     generic, permutation,
-    poseidon::Poseidon,
+    poseidon::{Poseidon, PoseidonPartial},
     range_check::circuitgates::{RangeCheck0, RangeCheck1},
     rot,
     varbasemul::VarbaseMul,
@@ -154,6 +154,22 @@ pub fn constraints_expr<F: PrimeField + SquareRootField>(
         }
     }
 
+    {
+        let mut poseidon_partial_expr =
+            || PoseidonPartial::combined_constraints(&powers_of_alpha, &mut cache);
+        if let Some(feature_flags) = feature_flags {
+            if feature_flags.poseidon_partial {
+                expr += poseidon_partial_expr();
+            }
+        } else {
+            expr += Expr::IfFeature(
+                FeatureFlag::PoseidonPartial,
+                Box::new(poseidon_partial_expr()),
+                Box::new(Expr::zero()),
+            );
+        }
+    }
+
     if generic {
         expr += generic::Generic::combined_constraints(&powers_of_alpha, &mut cache);
     }
@@ -253,6 +269,7 @@ pub fn linearization_columns<F: FftField + SquareRootField>(
                 foreign_field_mul: true,
                 xor: true,
                 rot: true,
+                poseidon_partial: true,
                 lookup_features: LookupFeatures {
                     patterns: LookupPatterns {
                         xor: true,
@@ -318,6 +335,7 @@ pub fn linearization_columns<F: FftField + SquareRootField>(
     h.insert(Index(GateType::ForeignFieldMul));
     h.insert(Index(GateType::Xor16));
     h.insert(Index(GateType::Rot64));
+    h.insert(Index(GateType::PoseidonPartial));
 
     // lookup selectors
     h.insert(LookupRuntimeSelector);
@@ -28,42 +28,10 @@ pub trait FrSponge<Fr: Field> {
     /// Consumes the sponge and returns the current digest, by squeezing.
     fn digest(self) -> Fr;
 
-    /// Absorbs the given evaluations into the sponge.
+    /// Absorbs the given evaluations into the sponge. All the evaluations of
+    /// the same polynomial are absorbed at the same time.
     // TODO: IMO this function should be inlined in prover/verifier
-    fn absorb_evaluations(&mut self, e: &ProofEvaluations<PointEvaluations<Vec<Fr>>>);
-}
-
-impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
-    fn new(params: &'static ArithmeticSpongeParams<Fr>) -> DefaultFrSponge<Fr, SC> {
-        DefaultFrSponge {
-            sponge: ArithmeticSponge::new(params),
-            last_squeezed: vec![],
-        }
-    }
-
-    fn absorb(&mut self, x: &Fr) {
-        self.last_squeezed = vec![];
-        self.sponge.absorb(&[*x]);
-    }
-
-    fn absorb_multiple(&mut self, x: &[Fr]) {
-        self.last_squeezed = vec![];
-        self.sponge.absorb(x);
-    }
-
-    fn challenge(&mut self) -> ScalarChallenge<Fr> {
-        // TODO: why involve sponge_5_wires here?
-        ScalarChallenge(self.squeeze(mina_poseidon::sponge::CHALLENGE_LENGTH_IN_LIMBS))
-    }
-
-    fn digest(mut self) -> Fr {
-        self.sponge.squeeze()
-    }
-
-    // We absorb all evaluations of the same polynomial at the same time
     fn absorb_evaluations(&mut self, e: &ProofEvaluations<PointEvaluations<Vec<Fr>>>) {
-        self.last_squeezed = vec![];
-
         let ProofEvaluations {
             public: _, // Must be absorbed first manually for now, to handle Mina annoyances
             w,
@@ -82,6 +50,7 @@ impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
             foreign_field_mul_selector,
             xor_selector,
             rot_selector,
+            poseidon_partial_selector,
             lookup_aggregation,
             lookup_table,
             lookup_sorted,
@@ -126,6 +95,9 @@ impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
         if let Some(rot_selector) = rot_selector.as_ref() {
             points.push(rot_selector)
         }
+        if let Some(poseidon_partial_selector) = poseidon_partial_selector.as_ref() {
+            points.push(poseidon_partial_selector)
+        }
         if let Some(lookup_aggregation) = lookup_aggregation.as_ref() {
             points.push(lookup_aggregation)
         }
@@ -158,8 +130,36 @@ impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
         }
 
         points.into_iter().for_each(|p| {
-            self.sponge.absorb(&p.zeta);
-            self.sponge.absorb(&p.zeta_omega);
+            self.absorb_multiple(&p.zeta);
+            self.absorb_multiple(&p.zeta_omega);
         })
     }
 }
+
+impl<Fr: PrimeField> FrSponge<Fr> for DefaultFrSponge<Fr, SC> {
+    fn new(params: &'static ArithmeticSpongeParams<Fr>) -> DefaultFrSponge<Fr, SC> {
+        DefaultFrSponge {
+            sponge: ArithmeticSponge::new(params),
+            last_squeezed: vec![],
+        }
+    }
+
+    fn absorb(&mut self, x: &Fr) {
+        self.last_squeezed = vec![];
+        self.sponge.absorb(&[*x]);
+    }
+
+    fn absorb_multiple(&mut self, x: &[Fr]) {
+        self.last_squeezed = vec![];
+        self.sponge.absorb(x);
+    }
+
+    fn challenge(&mut self) -> ScalarChallenge<Fr> {
+        // TODO: why involve sponge_5_wires here?
+        ScalarChallenge(self.squeeze(mina_poseidon::sponge::CHALLENGE_LENGTH_IN_LIMBS))
+    }
+
+    fn digest(mut self) -> Fr {
+        self.sponge.squeeze()
+    }
+}
@@ -55,6 +55,7 @@ mod tests {
             g,
             h,
             lagrange_bases,
+            precomputed_table: None,
         };
         let srs_bytes = rmp_serde::to_vec(&srs).unwrap();
         let output = hex::encode(srs_bytes.clone());
@@ -102,6 +102,12 @@ pub struct ProofEvaluations<Evals> {
     pub range_check_lookup_selector: Option<Evals>,
     /// evaluation of the ForeignFieldMul range check pattern selector polynomial
     pub foreign_field_mul_lookup_selector: Option<Evals>,
+
+    // Optional gates added after the lookup evaluations, at the end so that the
+    // serialization of the other evaluations is unchanged, and missing in older proofs
+    /// evaluation of the PoseidonPartial selector polynomial
+    #[serde(default)]
+    pub poseidon_partial_selector: Option<Evals>,
 }
 
 /// Commitments linked to the lookup feature
@@ -222,6 +228,7 @@ impl<Eval> ProofEvaluations<Eval> {
             lookup_gate_lookup_selector,
             range_check_lookup_selector,
             foreign_field_mul_lookup_selector,
+            poseidon_partial_selector,
         } = self;
         ProofEvaluations {
             public: public.map(f),
@@ -250,6 +257,7 @@ impl<Eval> ProofEvaluations<Eval> {
             lookup_gate_lookup_selector: lookup_gate_lookup_selector.map(f),
             range_check_lookup_selector: range_check_lookup_selector.map(f),
             foreign_field_mul_lookup_selector: foreign_field_mul_lookup_selector.map(f),
+            poseidon_partial_selector: poseidon_partial_selector.map(f),
         }
     }
 
@@ -281,6 +289,7 @@ impl<Eval> ProofEvaluations<Eval> {
             lookup_gate_lookup_selector,
             range_check_lookup_selector,
             foreign_field_mul_lookup_selector,
+            poseidon_partial_selector,
         } = self;
         ProofEvaluations {
             public: public.as_ref().map(f),
@@ -341,6 +350,7 @@ impl<Eval> ProofEvaluations<Eval> {
             lookup_gate_lookup_selector: lookup_gate_lookup_selector.as_ref().map(f),
             range_check_lookup_selector: range_check_lookup_selector.as_ref().map(f),
             foreign_field_mul_lookup_selector: foreign_field_mul_lookup_selector.as_ref().map(f),
+            poseidon_partial_selector: poseidon_partial_selector.as_ref().map(f),
         }
     }
 }
@@ -428,6 +438,7 @@ impl<F: Zero + Copy> ProofEvaluations<PointEvaluations<F>> {
             lookup_gate_lookup_selector: None,
             range_check_lookup_selector: None,
             foreign_field_mul_lookup_selector: None,
+            poseidon_partial_selector: None,
         }
     }
 }
@@ -473,6 +484,7 @@ impl<F> ProofEvaluations<F> {
             Column::Index(GateType::ForeignFieldMul) => self.foreign_field_mul_selector.as_ref(),
             Column::Index(GateType::Xor16) => self.xor_selector.as_ref(),
             Column::Index(GateType::Rot64) => self.rot_selector.as_ref(),
+            Column::Index(GateType::PoseidonPartial) => self.poseidon_partial_selector.as_ref(),
             Column::Index(_) => None,
             Column::Coefficient(i) => Some(&self.coefficients[i]),
             Column::Permutation(i) => Some(&self.s[i]),
@@ -604,6 +616,7 @@ pub mod caml {
         pub lookup_gate_lookup_selector: Option<PointEvaluations<Vec<CamlF>>>,
         pub range_check_lookup_selector: Option<PointEvaluations<Vec<CamlF>>>,
         pub foreign_field_mul_lookup_selector: Option<PointEvaluations<Vec<CamlF>>>,
+        pub poseidon_partial_selector: Option<PointEvaluations<Vec<CamlF>>>,
     }
 
     //
@@ -816,6 +829,9 @@ pub mod caml {
                 foreign_field_mul_lookup_selector: pe
                     .foreign_field_mul_lookup_selector
                     .map(|x| x.map(&|x| x.into_iter().map(Into::into).collect())),
+                poseidon_partial_selector: pe
+                    .poseidon_partial_selector
+                    .map(|x| x.map(&|x| x.into_iter().map(Into::into).collect())),
             };
 
             (first, second)
@@ -985,6 +1001,9 @@ pub mod caml {
                 foreign_field_mul_lookup_selector: cpe
                     .foreign_field_mul_lookup_selector
                     .map(|x| x.map(&|x| x.iter().map(|x| x.clone().into()).collect())),
+                poseidon_partial_selector: cpe
+                    .poseidon_partial_selector
+                    .map(|x| x.map(&|x| x.into_iter().map(Into::into).collect())),
             }
         }
     }
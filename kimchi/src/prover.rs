@@ -14,7 +14,7 @@ use crate::{
             foreign_field_add::circuitgates::ForeignFieldAdd,
             foreign_field_mul::{self, circuitgates::ForeignFieldMul},
             generic, permutation,
-            poseidon::Poseidon,
+            poseidon::{Poseidon, PoseidonPartial},
             range_check::circuitgates::{RangeCheck0, RangeCheck1},
             rot::Rot64,
             varbasemul::VarbaseMul,
@@ -39,12 +39,10 @@ use ark_poly::{
     Radix2EvaluationDomain as D, UVPolynomial,
 };
 use itertools::Itertools;
-use mina_poseidon::{sponge::ScalarChallenge, FqSponge};
+use mina_poseidon::{sponge::ScalarChallenge, transcript::Transcript, FqSponge};
 use o1_utils::ExtendedDensePolynomial as _;
 use poly_commitment::{
-    commitment::{
-        absorb_commitment, b_poly_coefficients, BlindedCommitment, CommitmentCurve, PolyComm,
-    },
+    commitment::{b_poly_coefficients, BlindedCommitment, CommitmentCurve, PolyComm},
     evaluation_proof::DensePolynomialOrEvaluations,
     OpenProof, SRS as _,
 };
@@ -238,15 +236,18 @@ where
 
         //~ 1. Setup the Fq-Sponge.
         internal_tracing::checkpoint!(internal_traces; set_up_fq_sponge);
-        let mut fq_sponge = EFqSponge::new(G::other_curve_sponge_params());
+        // The transcript is in the compatible mode, so that it absorbs and squeezes exactly as
+        // the Fq-Sponge (labels are ignored).
+        let mut transcript =
+            Transcript::<_, G, _, EFqSponge>::compatible(G::other_curve_sponge_params());
 
         //~ 1. Absorb the digest of the VerifierIndex.
         let verifier_index_digest = index.verifier_index_digest::<EFqSponge>();
-        fq_sponge.absorb_fq(&[verifier_index_digest]);
+        transcript.absorb_base(b"verifier_index_digest", &[verifier_index_digest]);
 
         //~ 1. Absorb the commitments of the previous challenges with the Fq-sponge.
         for RecursionChallenge { comm, .. } in &prev_challenges {
-            absorb_commitment(&mut fq_sponge, comm)
+            transcript.absorb_points(b"prev_challenge_comm", &comm.elems)
         }
 
         //~ 1. Compute the negated public input polynomial as
@@ -277,7 +278,7 @@ where
         //~    Note: unlike the original PLONK protocol,
         //~    the prover also provides evaluations of the public polynomial to help the verifier circuit.
         //~    This is why we need to absorb the commitment to the public polynomial at this point.
-        absorb_commitment(&mut fq_sponge, &public_comm);
+        transcript.absorb_points(b"public_comm", &public_comm.elems);
 
         //~ 1. Commit to the witness columns by creating `COLUMNS` hidding commitments.
         //~
@@ -321,7 +322,7 @@ where
         //~ 1. Absorb the witness commitments with the Fq-Sponge.
         w_comm
             .iter()
-            .for_each(|c| absorb_commitment(&mut fq_sponge, &c.commitment));
+            .for_each(|c| transcript.absorb_points(b"w_comm", &c.commitment.elems));
 
         //~ 1. Compute the witness polynomials by interpolating each `COLUMNS` of the witness.
         //~    As mentioned above, we commit using the evaluations form rather than the coefficients
@@ -396,7 +397,8 @@ where
                         .commit(&runtime_table_contribution, num_chunks, rng);
 
                 // absorb the commitment
-                absorb_commitment(&mut fq_sponge, &runtime_table_comm.commitment);
+                transcript
+                    .absorb_points(b"runtime_table_comm", &runtime_table_comm.commitment.elems);
 
                 // pre-compute the updated second column of the lookup table
                 let mut second_column_d8 = runtime_table_contribution_d8.clone();
@@ -418,7 +420,7 @@ where
             //~~   then squeeze the Fq-Sponge to obtain the joint combiner challenge $j'$,
             //~~   otherwise set the joint combiner challenge $j'$ to $0$.
             let joint_combiner = if lcs.configuration.lookup_info.features.joint_lookup_used {
-                fq_sponge.challenge()
+                transcript.challenge(b"joint_combiner")
             } else {
                 G::ScalarField::zero()
             };
@@ -537,7 +539,7 @@ where
             //~~ * Absorb each commitments to the sorted polynomials.
             sorted_comms
                 .iter()
-                .for_each(|c| absorb_commitment(&mut fq_sponge, &c.commitment));
+                .for_each(|c| transcript.absorb_points(b"lookup_sorted_comm", &c.commitment.elems));
 
             // precompute different forms of the sorted polynomials for later
             // TODO: We can avoid storing these coefficients.
@@ -557,10 +559,10 @@ where
         }
 
         //~ 1. Sample $\beta$ with the Fq-Sponge.
-        let beta = fq_sponge.challenge();
+        let beta = transcript.challenge(b"beta");
 
         //~ 1. Sample $\gamma$ with the Fq-Sponge.
-        let gamma = fq_sponge.challenge();
+        let gamma = transcript.challenge(b"gamma");
 
         //~ 1. If using lookup:
         if let Some(lcs) = &index.cs.lookup_constraint_system {
@@ -589,7 +591,7 @@ where
                 .commit_evaluations(index.cs.domain.d1, &aggreg, rng);
 
             //~~ * Absorb the commitment to the aggregation polynomial with the Fq-Sponge.
-            absorb_commitment(&mut fq_sponge, &aggreg_comm.commitment);
+            transcript.absorb_points(b"lookup_aggreg_comm", &aggreg_comm.commitment.elems);
 
             // precompute different forms of the aggregation polynomial for later
             let aggreg_coeffs = aggreg.interpolate();
@@ -610,10 +612,10 @@ where
         let z_comm = index.srs.commit(&z_poly, num_chunks, rng);
 
         //~ 1. Absorb the permutation aggregation polynomial $z$ with the Fq-Sponge.
-        absorb_commitment(&mut fq_sponge, &z_comm.commitment);
+        transcript.absorb_points(b"z_comm", &z_comm.commitment.elems);
 
         //~ 1. Sample $\alpha'$ with the Fq-Sponge.
-        let alpha_chal = ScalarChallenge(fq_sponge.challenge());
+        let alpha_chal = ScalarChallenge(transcript.challenge(b"alpha"));
 
         //~ 1. Derive $\alpha$ from $\alpha'$ using the endomorphism (TODO: details)
         let alpha: G::ScalarField = alpha_chal.to_field(endo_r);
@@ -701,6 +703,10 @@ where
                 index_evals.insert(GateType::Rot64, selector);
             }
 
+            if let Some(selector) = index.column_evaluations.poseidon_partial_selector8.as_ref() {
+                index_evals.insert(GateType::PoseidonPartial, selector);
+            }
+
             let mds = &G::sponge_params().mds;
             Environment {
                 constants: Constants {
@@ -776,6 +782,10 @@ where
                     .is_some();
                 let xor_enabled = index.column_evaluations.xor_selector8.is_some();
                 let rot_enabled = index.column_evaluations.rot_selector8.is_some();
+                let poseidon_partial_enabled = index
+                    .column_evaluations
+                    .poseidon_partial_selector8
+                    .is_some();
 
                 for gate in [
                     (
@@ -800,6 +810,8 @@ where
                     (&Xor16::default(), xor_enabled),
                     // Rot gate
                     (&Rot64::default(), rot_enabled),
+                    // Poseidon partial rounds gate
+                    (&PoseidonPartial::default(), poseidon_partial_enabled),
                 ]
                 .into_iter()
                 .filter_map(|(gate, is_enabled)| if is_enabled { Some(gate) } else { None })
@@ -871,10 +883,10 @@ where
         let t_comm = { index.srs.commit(&quotient_poly, 7 * num_chunks, rng) };
 
         //~ 1. Absorb the the commitment of the quotient polynomial with the Fq-Sponge.
-        absorb_commitment(&mut fq_sponge, &t_comm.commitment);
+        transcript.absorb_points(b"t_comm", &t_comm.commitment.elems);
 
         //~ 1. Sample $\zeta'$ with the Fq-Sponge.
-        let zeta_chal = ScalarChallenge(fq_sponge.challenge());
+        let zeta_chal = ScalarChallenge(transcript.challenge(b"zeta"));
 
         //~ 1. Derive $\zeta$ from $\zeta'$ using the endomorphism (TODO: specify)
         let zeta = zeta_chal.to_field(endo_r);
@@ -1055,6 +1067,11 @@ where
                 .rot_selector8
                 .as_ref()
                 .map(chunked_evals_for_selector),
+            poseidon_partial_selector: index
+                .column_evaluations
+                .poseidon_partial_selector8
+                .as_ref()
+                .map(chunked_evals_for_selector),
 
             runtime_lookup_table_selector: index.cs.lookup_constraint_system.as_ref().and_then(
                 |lcs| {
@@ -1164,11 +1181,11 @@ where
         let ft_eval1 = ft.evaluate(&zeta_omega);
 
         //~ 1. Setup the Fr-Sponge
-        let fq_sponge_before_evaluations = fq_sponge.clone();
+        let fq_sponge_before_evaluations = transcript.sponge().clone();
         let mut fr_sponge = EFrSponge::new(G::sponge_params());
 
         //~ 1. Squeeze the Fq-sponge and absorb the result with the Fr-Sponge.
-        fr_sponge.absorb(&fq_sponge.digest());
+        fr_sponge.absorb(&transcript.digest());
 
         //~ 1. Absorb the previous recursion challenges.
         let prev_challenge_digest = {
@@ -1339,6 +1356,14 @@ where
         if let Some(rot_selector8) = index.column_evaluations.rot_selector8.as_ref() {
             polynomials.push((evaluations_form(rot_selector8), non_hiding(num_chunks)));
         }
+        if let Some(poseidon_partial_selector8) =
+            index.column_evaluations.poseidon_partial_selector8.as_ref()
+        {
+            polynomials.push((
+                evaluations_form(poseidon_partial_selector8),
+                non_hiding(num_chunks),
+            ));
+        }
 
         //~~ * optionally, the runtime table
         //~ 1. if using lookup:
@@ -966,7 +966,7 @@ impl<Field: PrimeField> SnarkyConstraintSystem<Field> {
                 if s == Field::one() {
                     x
                 } else {
-                    let sx = self.create_internal(Some(s), vec![(s, x)]);
+                    let sx = self.create_internal(None, vec![(s, x)]);
                     // s * x - sx = 0
                     self.add_generic_constraint(
                         labels,
@@ -988,7 +988,7 @@ impl<Field: PrimeField> SnarkyConstraintSystem<Field> {
             (s, ConstantOrVar::Constant) => match self.cached_constants.get(&s) {
                 Some(x) => *x,
                 None => {
-                    let x = self.create_internal(None, vec![]);
+                    let x = self.create_internal(Some(s), vec![]);
                     self.add_generic_constraint(
                         labels,
                         loc,
@@ -0,0 +1,365 @@
+//! Gadgets for the Pallas curve, whose points have their coordinates in the circuit field [Fp].
+//!
+//! Points are added with the `CompleteAdd` gate,
+//! and multiplied by scalars with the `VarBaseMul` gate.
+
+use crate::snarky::{
+    boolean::Boolean,
+    constraint_system::{EcAddCompleteInput, KimchiConstraint, ScaleRound},
+    cvar::FieldVar,
+    errors::SnarkyResult,
+    runner::{Constraint, RunState},
+    snarky_type::SnarkyType,
+};
+use ark_ec::models::SWModelParameters;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use mina_curves::pasta::{Fp, Fq, Pallas, PallasParameters};
+use num_bigint::BigUint;
+use o1_utils::FieldHelpers;
+use std::{borrow::Cow, iter::once};
+
+/// The number of bits processed by a scalar multiplication.
+pub const SCALAR_BITS: usize = 255;
+
+/// The number of bits processed by a single `VarBaseMul` gate.
+const BITS_PER_ROUND: usize = 5;
+
+/// An affine point of the Pallas curve, which is never the point at infinity.
+#[derive(Debug, Clone)]
+pub struct PointVar {
+    pub x: FieldVar<Fp>,
+    pub y: FieldVar<Fp>,
+}
+
+impl SnarkyType<Fp> for PointVar {
+    type Auxiliary = ();
+
+    type OutOfCircuit = Pallas;
+
+    const SIZE_IN_FIELD_ELEMENTS: usize = 2;
+
+    fn to_cvars(&self) -> (Vec<FieldVar<Fp>>, Self::Auxiliary) {
+        (vec![self.x.clone(), self.y.clone()], ())
+    }
+
+    fn from_cvars_unsafe(cvars: Vec<FieldVar<Fp>>, _aux: Self::Auxiliary) -> Self {
+        assert_eq!(cvars.len(), Self::SIZE_IN_FIELD_ELEMENTS);
+        Self {
+            x: cvars[0].clone(),
+            y: cvars[1].clone(),
+        }
+    }
+
+    /// Checks that the point is on the curve: `y^2 = x^3 + b`.
+    fn check(&self, cs: &mut RunState<Fp>, loc: Cow<'static, str>) -> SnarkyResult<()> {
+        let x_squared = self.x.mul(&self.x, Some("x^2".into()), loc.clone(), cs)?;
+        let x_cubed = x_squared.mul(&self.x, Some("x^3".into()), loc.clone(), cs)?;
+        let y_squared = self.y.mul(&self.y, Some("y^2".into()), loc.clone(), cs)?;
+        let rhs = x_cubed + FieldVar::constant(PallasParameters::COEFF_B);
+        y_squared.assert_equals(cs, loc, &rhs)
+    }
+
+    fn constraint_system_auxiliary() -> Self::Auxiliary {}
+
+    fn value_to_field_elements(value: &Self::OutOfCircuit) -> (Vec<Fp>, Self::Auxiliary) {
+        (vec![value.x, value.y], ())
+    }
+
+    fn value_of_field_elements(fields: Vec<Fp>, _aux: Self::Auxiliary) -> Self::OutOfCircuit {
+        assert_eq!(fields.len(), Self::SIZE_IN_FIELD_ELEMENTS);
+        Pallas::new(fields[0], fields[1], false)
+    }
+}
+
+impl PointVar {
+    /// Creates a constant point.
+    pub fn constant(point: &Pallas) -> Self {
+        Self {
+            x: FieldVar::constant(point.x),
+            y: FieldVar::constant(point.y),
+        }
+    }
+
+    /// Returns the opposite of the point.
+    pub fn negate(&self) -> Self {
+        Self {
+            x: self.x.clone(),
+            y: -&self.y,
+        }
+    }
+
+    /// Seals both coordinates of the point (see [FieldVar::seal]).
+    pub fn seal(&self, sys: &mut RunState<Fp>, loc: Cow<'static, str>) -> SnarkyResult<Self> {
+        Ok(Self {
+            x: self.x.seal(sys, loc.clone())?,
+            y: self.y.seal(sys, loc)?,
+        })
+    }
+
+    /// Adds two points with the `CompleteAdd` gate.
+    /// Returns the sum, and a boolean that is true iff the sum is the point at infinity
+    /// (in which case the returned point is meaningless).
+    pub fn add_complete(
+        &self,
+        sys: &mut RunState<Fp>,
+        loc: Cow<'static, str>,
+        other: &PointVar,
+    ) -> SnarkyResult<(PointVar, Boolean<Fp>)> {
+        let p1 = self.clone();
+        let p2 = other.clone();
+        let values: [FieldVar<Fp>; 7] = sys.compute_unsafe(loc.clone(), move |env| {
+            let (x1, y1) = (env.read_var(&p1.x), env.read_var(&p1.y));
+            let (x2, y2) = (env.read_var(&p2.x), env.read_var(&p2.y));
+
+            let same_x = x1 == x2;
+            let inf = same_x && y1 != y2;
+            let x21_inv = (x2 - x1).inverse().unwrap_or_else(Fp::zero);
+            let inf_z = if inf {
+                (y2 - y1).inverse().unwrap()
+            } else {
+                Fp::zero()
+            };
+            let slope = if same_x {
+                let x1_squared = x1.square();
+                (x1_squared.double() + x1_squared) / y1.double()
+            } else {
+                (y2 - y1) * x21_inv
+            };
+            let x3 = slope.square() - x1 - x2;
+            let y3 = slope * (x1 - x3) - y1;
+
+            [
+                x3,
+                y3,
+                Fp::from(u64::from(inf)),
+                Fp::from(u64::from(same_x)),
+                slope,
+                inf_z,
+                x21_inv,
+            ]
+        })?;
+        let [x3, y3, inf, same_x, slope, inf_z, x21_inv] = values;
+
+        // the gate constrains `inf` to be a boolean
+        let constraint = KimchiConstraint::EcAddComplete(EcAddCompleteInput {
+            p1: (self.x.clone(), self.y.clone()),
+            p2: (other.x.clone(), other.y.clone()),
+            p3: (x3.clone(), y3.clone()),
+            inf: inf.clone(),
+            same_x,
+            slope,
+            inf_z,
+            x21_inv,
+        });
+        sys.add_constraint(
+            Constraint::KimchiConstraint(constraint),
+            Some("CompleteAdd".into()),
+            loc,
+        )?;
+
+        Ok((PointVar { x: x3, y: y3 }, Boolean::create_unsafe(inf)))
+    }
+
+    /// Multiplies the point by a scalar with the `VarBaseMul` gate.
+    ///
+    /// The gate computes `[2 x + 2^n + 1] T` for the `n`-bit number `x`,
+    /// so `[2 h + 1] T` is obtained with `n` = [SCALAR_BITS] and `x = h - 2^254 mod q`,
+    /// where `q` is the order of the curve. The two most significant bits of `x` are zero,
+    /// so that it is not reduced modulo the circuit field
+    /// (this is the case except with negligible probability).
+    /// Finally, the point is subtracted from the result if the scalar is even.
+    ///
+    /// The accumulator of the gate reaches the point at infinity for `h = 0`, so `h = 1`
+    /// is used instead, and the product is then selected among `T` and the point at infinity.
+    ///
+    /// Returns the product, and a boolean that is true iff the product is the point at infinity,
+    /// that is iff the scalar is zero (the point is in the prime-order subgroup).
+    /// The point at infinity has no coordinates, so the point itself is returned in its place.
+    pub fn scale(
+        &self,
+        sys: &mut RunState<Fp>,
+        loc: Cow<'static, str>,
+        scalar: &ScalarVar,
+    ) -> SnarkyResult<(PointVar, Boolean<Fp>)> {
+        // reduce the base once, as it is used by each round
+        let base = self.seal(sys, loc.clone())?;
+
+        // acc = [2] T
+        let (mut acc, _) = base.add_complete(sys, loc.clone(), &base)?;
+
+        // the scalars 0 and 1 are handled after the gate
+        let h_is_zero = scalar.h.equal(sys, loc.clone(), &FieldVar::zero())?;
+        let h = sys.if_(
+            loc.clone(),
+            h_is_zero.clone(),
+            FieldVar::constant(Fp::one()),
+            scalar.h.clone(),
+        )?;
+
+        // the bits of x, most significant first
+        let x = h + FieldVar::constant(scalar_shift());
+        let x_clone = x.clone();
+        let low_bits: [FieldVar<Fp>; SCALAR_BITS - 2] =
+            sys.compute_unsafe(loc.clone(), move |env| {
+                let bits = env.read_var(&x_clone).into_repr().to_bits_le();
+                std::array::from_fn(|i| Fp::from(u64::from(bits[SCALAR_BITS - 3 - i])))
+            })?;
+        let bits: Vec<_> = [FieldVar::zero(), FieldVar::zero()]
+            .into_iter()
+            .chain(low_bits)
+            .collect();
+
+        // the gate constrains the bits to be booleans
+        let mut n = FieldVar::zero();
+        let mut rounds = Vec::with_capacity(SCALAR_BITS / BITS_PER_ROUND);
+        for chunk in bits.chunks(BITS_PER_ROUND) {
+            let (base_clone, acc_clone, n_clone) = (base.clone(), acc.clone(), n.clone());
+            let chunk_clone = chunk.to_vec();
+            let values: [FieldVar<Fp>; 3 * BITS_PER_ROUND + 1] =
+                sys.compute_unsafe(loc.clone(), move |env| {
+                    let (xt, yt) = (env.read_var(&base_clone.x), env.read_var(&base_clone.y));
+                    let mut acc = (env.read_var(&acc_clone.x), env.read_var(&acc_clone.y));
+                    let mut n = env.read_var(&n_clone);
+
+                    // accumulators, then slopes, then n'
+                    let mut values = [Fp::zero(); 3 * BITS_PER_ROUND + 1];
+                    for (i, bit) in chunk_clone.iter().enumerate() {
+                        let b = env.read_var(bit);
+                        n = n.double() + b;
+
+                        // acc = (acc + (b ? T : -T)) + acc
+                        let s1 = (acc.1 - yt * (b.double() - Fp::one())) / (acc.0 - xt);
+                        let s1_squared = s1.square();
+                        let s2 = acc.1.double() / (acc.0.double() + xt - s1_squared) - s1;
+                        let x = xt + s2.square() - s1_squared;
+                        let y = (acc.0 - x) * s2 - acc.1;
+                        acc = (x, y);
+
+                        values[2 * i] = x;
+                        values[2 * i + 1] = y;
+                        values[2 * BITS_PER_ROUND + i] = s1;
+                    }
+                    values[3 * BITS_PER_ROUND] = n;
+                    values
+                })?;
+
+            let accs = once((acc.x.clone(), acc.y.clone()))
+                .chain(
+                    (0..BITS_PER_ROUND).map(|i| (values[2 * i].clone(), values[2 * i + 1].clone())),
+                )
+                .collect();
+            let n_next = values[3 * BITS_PER_ROUND].clone();
+            rounds.push(ScaleRound {
+                accs,
+                bits: chunk.to_vec(),
+                ss: values[2 * BITS_PER_ROUND..3 * BITS_PER_ROUND].to_vec(),
+                base: (base.x.clone(), base.y.clone()),
+                n_prev: n,
+                n_next: n_next.clone(),
+            });
+
+            acc = PointVar {
+                x: values[2 * BITS_PER_ROUND - 2].clone(),
+                y: values[2 * BITS_PER_ROUND - 1].clone(),
+            };
+            n = n_next;
+        }
+        sys.add_constraint(
+            Constraint::KimchiConstraint(KimchiConstraint::EcScale(rounds)),
+            Some("VarBaseMul".into()),
+            loc.clone(),
+        )?;
+        n.assert_equals(sys, loc.clone(), &x)?;
+
+        // acc = [2 h + 1] T, subtract T if the scalar is even,
+        // in which case the difference cannot be the point at infinity (h != 0)
+        let (acc_minus_base, _) = acc.add_complete(sys, loc.clone(), &base.negate())?;
+        let x = sys.if_(loc.clone(), scalar.o.clone(), acc.x, acc_minus_base.x)?;
+        let y = sys.if_(loc.clone(), scalar.o.clone(), acc.y, acc_minus_base.y)?;
+
+        // for h = 0, the product is T or the point at infinity
+        let x = sys.if_(loc.clone(), h_is_zero.clone(), base.x, x)?;
+        let y = sys.if_(loc.clone(), h_is_zero.clone(), base.y, y)?;
+        let is_zero = h_is_zero.and(&scalar.o.not(), sys, loc);
+        Ok((PointVar { x, y }, is_zero))
+    }
+}
+
+/// `-2^254 mod q = q - 2^254`, where `q` is the order of the curve,
+/// which shifts `h` to the number processed by the `VarBaseMul` gate.
+fn scalar_shift() -> Fp {
+    let shift = Fq::modulus_biguint() - (BigUint::one() << (SCALAR_BITS - 1));
+    Fp::from_biguint(&shift).expect("the shift is smaller than the modulus")
+}
+
+/// An element `s = 2 h + o` of the scalar field [Fq] of the curve,
+/// which is larger than the circuit field: it is represented by `h < q / 2`
+/// (which is smaller than the modulus of the circuit field) and the boolean `o`.
+///
+/// The range of `h` is constrained when scaling a point (see [PointVar::scale]).
+#[derive(Debug, Clone)]
+pub struct ScalarVar {
+    pub h: FieldVar<Fp>,
+    pub o: Boolean<Fp>,
+}
+
+impl SnarkyType<Fp> for ScalarVar {
+    type Auxiliary = ();
+
+    type OutOfCircuit = Fq;
+
+    const SIZE_IN_FIELD_ELEMENTS: usize = 2;
+
+    fn to_cvars(&self) -> (Vec<FieldVar<Fp>>, Self::Auxiliary) {
+        (vec![self.h.clone(), self.o.to_field_var()], ())
+    }
+
+    fn from_cvars_unsafe(cvars: Vec<FieldVar<Fp>>, _aux: Self::Auxiliary) -> Self {
+        assert_eq!(cvars.len(), Self::SIZE_IN_FIELD_ELEMENTS);
+        Self {
+            h: cvars[0].clone(),
+            o: Boolean::create_unsafe(cvars[1].clone()),
+        }
+    }
+
+    fn check(&self, cs: &mut RunState<Fp>, loc: Cow<'static, str>) -> SnarkyResult<()> {
+        self.o.check(cs, loc)
+    }
+
+    fn constraint_system_auxiliary() -> Self::Auxiliary {}
+
+    fn value_to_field_elements(value: &Self::OutOfCircuit) -> (Vec<Fp>, Self::Auxiliary) {
+        let mut h = value.into_repr();
+        let o = h.is_odd();
+        h.div2();
+        let h = Fp::from_repr(h).expect("h is smaller than the modulus");
+        (vec![h, Fp::from(u64::from(o))], ())
+    }
+
+    fn value_of_field_elements(fields: Vec<Fp>, _aux: Self::Auxiliary) -> Self::OutOfCircuit {
+        assert_eq!(fields.len(), Self::SIZE_IN_FIELD_ELEMENTS);
+        let h = Fq::from_repr(fields[0].into_repr()).expect("h is smaller than the modulus");
+        h.double() + Fq::from(u64::from(!fields[1].is_zero()))
+    }
+}
+
+impl ScalarVar {
+    /// Converts an element of the circuit field into a scalar with the same integer value.
+    pub fn from_field(
+        sys: &mut RunState<Fp>,
+        loc: Cow<'static, str>,
+        x: &FieldVar<Fp>,
+    ) -> SnarkyResult<Self> {
+        let x_clone = x.clone();
+        let scalar: ScalarVar = sys.compute(loc.clone(), move |env| {
+            let x = env.read_var(&x_clone);
+            Fq::from_repr(x.into_repr()).expect("the circuit field is smaller")
+        })?;
+
+        // x = 2 h + o
+        let recomposed = &scalar.h + &scalar.h + scalar.o.to_field_var();
+        recomposed.assert_equals(sys, loc, x)?;
+
+        Ok(scalar)
+    }
+}
@@ -13,10 +13,12 @@ pub mod boolean;
 pub mod constants;
 pub mod constraint_system;
 pub mod cvar;
+pub mod ec;
 pub mod errors;
 pub mod folding;
 pub mod poseidon;
 pub mod runner;
+pub mod signature;
 pub mod snarky_type;
 pub mod union_find;
 
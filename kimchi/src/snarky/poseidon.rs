@@ -24,9 +24,23 @@ pub fn poseidon<F: PrimeField>(
     preimage: (FieldVar<F>, FieldVar<F>),
 ) -> (FieldVar<F>, FieldVar<F>) {
     let initial_state = [preimage.0, preimage.1, FieldVar::zero()];
-    let (constraint, hash) = {
+    let [a, b, _] = permutation(runner, loc, initial_state);
+    (a, b)
+}
+
+/// Applies the Poseidon permutation to the full state of the sponge (rate and capacity).
+pub fn permutation<F: PrimeField>(
+    runner: &mut RunState<F>,
+    loc: Cow<'static, str>,
+    initial_state: [FieldVar<F>; SPONGE_WIDTH],
+) -> [FieldVar<F>; SPONGE_WIDTH] {
+    let (constraint, state) = {
         let params = runner.poseidon_params();
         let mut iter = successors((initial_state, 0_usize).into(), |(prev, i)| {
+            // the successor of the last state is computed eagerly, there is no such round
+            if *i == ROUNDS_PER_HASH {
+                return None;
+            }
             //this case may justify moving to Cow
             let state = round(runner, loc.clone(), prev, *i, &params);
             Some((state, i + 1))
@@ -46,22 +60,18 @@ pub fn poseidon<F: PrimeField>(
             })
             .collect_vec();
         let last = iter.next().unwrap();
-        let hash = {
-            let [a, b, _] = last.clone();
-            (a, b)
-        };
         let constraint = Constraint::KimchiConstraint(KimchiConstraint::Poseidon2(PoseidonInput {
             states: states.into_iter().map(|s| s.to_vec()).collect(),
             last: last.to_vec(),
         }));
-        (constraint, hash)
+        (constraint, last)
     };
 
     runner
         .add_constraint(constraint, Some("Poseidon".into()), loc)
         .expect("compiler bug");
 
-    hash
+    state
 }
 
 fn round<F: PrimeField>(
@@ -73,9 +83,9 @@ fn round<F: PrimeField>(
 ) -> [FieldVar<F>; SPONGE_WIDTH] {
     runner
         .compute(loc, |env| {
-            let mut state = elements.clone().map(|var| env.read_var(&var)).to_vec();
-            full_round::<F, PlonkSpongeConstantsKimchi>(params, &mut state, round);
-            state.try_into().unwrap()
+            let mut state = elements.clone().map(|var| env.read_var(&var));
+            full_round::<F, PlonkSpongeConstantsKimchi, SPONGE_WIDTH>(params, &mut state, round);
+            state
         })
         .expect("compiler bug")
 }
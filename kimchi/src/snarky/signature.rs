@@ -0,0 +1,211 @@
+//! In-circuit verification of Mina Schnorr signatures.
+//!
+//! A signature `(rx, s)` of a message by the public key `P` is valid iff `R = [s] G - [e] P`
+//! is not the point at infinity, has the x-coordinate `rx`, and has an even y-coordinate,
+//! where `G` is the generator of Pallas and `e` is the message hash:
+//! the Poseidon hash (with the kimchi parameters) of the message fields followed by `P` and `rx`,
+//! from the initial state of the signature domain string.
+//! These are the signatures of `mina_signer::create_kimchi`, for messages made of field elements.
+
+use crate::{
+    circuits::polynomials::poseidon::SPONGE_WIDTH,
+    snarky::{
+        boolean::Boolean,
+        cvar::FieldVar,
+        ec::{PointVar, ScalarVar},
+        errors::SnarkyResult,
+        poseidon::permutation,
+        runner::RunState,
+        snarky_type::SnarkyType,
+    },
+};
+use ark_ec::AffineCurve;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use mina_curves::pasta::{Fp, Fq, Pallas};
+use mina_poseidon::{constants::PlonkSpongeConstantsKimchi, permutation::poseidon_block_cipher};
+use o1_utils::FieldHelpers;
+use std::borrow::Cow;
+
+/// The maximum length of a domain string, in bytes.
+const MAX_DOMAIN_STRING_LEN: usize = 20;
+
+/// The number of field elements absorbed by each permutation of the sponge.
+const RATE: usize = 2;
+
+/// The number of bits of half of a y-coordinate, when checking its parity.
+const HALF_BITS: usize = 253;
+
+/// A Mina signature `(rx, s)`.
+#[derive(Debug, Clone)]
+pub struct SignatureVar {
+    pub rx: FieldVar<Fp>,
+    pub s: ScalarVar,
+}
+
+impl SnarkyType<Fp> for SignatureVar {
+    type Auxiliary = ();
+
+    type OutOfCircuit = (Fp, Fq);
+
+    const SIZE_IN_FIELD_ELEMENTS: usize = 1 + ScalarVar::SIZE_IN_FIELD_ELEMENTS;
+
+    fn to_cvars(&self) -> (Vec<FieldVar<Fp>>, Self::Auxiliary) {
+        let mut cvars = vec![self.rx.clone()];
+        cvars.extend(self.s.to_cvars().0);
+        (cvars, ())
+    }
+
+    fn from_cvars_unsafe(cvars: Vec<FieldVar<Fp>>, _aux: Self::Auxiliary) -> Self {
+        assert_eq!(cvars.len(), Self::SIZE_IN_FIELD_ELEMENTS);
+        Self {
+            rx: cvars[0].clone(),
+            s: ScalarVar::from_cvars_unsafe(cvars[1..].to_vec(), ()),
+        }
+    }
+
+    fn check(&self, cs: &mut RunState<Fp>, loc: Cow<'static, str>) -> SnarkyResult<()> {
+        self.s.check(cs, loc)
+    }
+
+    fn constraint_system_auxiliary() -> Self::Auxiliary {}
+
+    fn value_to_field_elements(value: &Self::OutOfCircuit) -> (Vec<Fp>, Self::Auxiliary) {
+        let mut fields = vec![value.0];
+        fields.extend(ScalarVar::value_to_field_elements(&value.1).0);
+        (fields, ())
+    }
+
+    fn value_of_field_elements(fields: Vec<Fp>, _aux: Self::Auxiliary) -> Self::OutOfCircuit {
+        assert_eq!(fields.len(), Self::SIZE_IN_FIELD_ELEMENTS);
+        let s = ScalarVar::value_of_field_elements(fields[1..].to_vec(), ());
+        (fields[0], s)
+    }
+}
+
+/// Returns a boolean that is true iff `signature` is a valid signature of `message` by `public`,
+/// for the domain string `domain` (for example, `CodaSignature` for the testnet).
+///
+/// The public key is not checked to be on the curve: this is done by [PointVar::check],
+/// when it is computed by the prover.
+///
+/// A signature whose `s` (or whose hash `e`) is zero is rejected, while the native verifier would
+/// accept it if `R = -[e] P` (or `R = [s] G`) matched `rx`: finding such a signature means finding
+/// a preimage of the hash, so the two verifiers agree except with negligible probability.
+///
+/// # Panics
+///
+/// Will panic if `domain` is longer than 20 bytes.
+pub fn verify(
+    sys: &mut RunState<Fp>,
+    loc: Cow<'static, str>,
+    domain: &str,
+    public: &PointVar,
+    signature: &SignatureVar,
+    message: &[FieldVar<Fp>],
+) -> SnarkyResult<Boolean<Fp>> {
+    // e = H(message || public || rx)
+    let mut input = message.to_vec();
+    input.extend([public.x.clone(), public.y.clone(), signature.rx.clone()]);
+    let e = message_hash(sys, loc.clone(), domain, &input);
+    let e = ScalarVar::from_field(sys, loc.clone(), &e)?;
+
+    // R = [s] G - [e] P
+    let generator = PointVar::constant(&Pallas::prime_subgroup_generator());
+    let (s_g, s_zero) = generator.scale(sys, loc.clone(), &signature.s)?;
+    let (e_p, e_zero) = public.scale(sys, loc.clone(), &e)?;
+    let (r, inf) = s_g.add_complete(sys, loc.clone(), &e_p.negate())?;
+
+    let same_x = r.x.equal(sys, loc.clone(), &signature.rx)?;
+    let even_y = is_even(sys, loc.clone(), &r.y)?;
+    Boolean::all(
+        &[s_zero.not(), e_zero.not(), inf.not(), same_x, even_y],
+        sys,
+        loc,
+    )
+}
+
+/// The Poseidon hash of `inputs` (with the kimchi parameters),
+/// from the initial state of the domain string `domain`, as `mina_hasher::create_kimchi` computes it.
+///
+/// # Panics
+///
+/// Will panic if `domain` is longer than 20 bytes.
+pub fn message_hash(
+    sys: &mut RunState<Fp>,
+    loc: Cow<'static, str>,
+    domain: &str,
+    inputs: &[FieldVar<Fp>],
+) -> FieldVar<Fp> {
+    // the initial state absorbs the domain string, outside of the circuit
+    let params = sys.poseidon_params();
+    let mut initial_state = [domain_prefix_to_field(domain), Fp::zero(), Fp::zero()];
+    poseidon_block_cipher::<Fp, PlonkSpongeConstantsKimchi, SPONGE_WIDTH>(
+        &params,
+        &mut initial_state,
+    );
+
+    let mut state = initial_state.map(FieldVar::constant);
+    for chunk in inputs.chunks(RATE) {
+        for (s, input) in state.iter_mut().zip(chunk) {
+            *s = &*s + input;
+        }
+        state = permutation(sys, loc.clone(), state);
+    }
+
+    let [hash, _, _] = state;
+    hash
+}
+
+/// The domain string, padded with `*` and interpreted as a field element.
+fn domain_prefix_to_field(domain: &str) -> Fp {
+    assert!(domain.len() <= MAX_DOMAIN_STRING_LEN);
+    let mut bytes = format!("{domain:*<MAX_DOMAIN_STRING_LEN$}").into_bytes();
+    bytes.resize(Fp::size_in_bytes(), 0);
+    Fp::from_bytes(&bytes).expect("invalid domain bytes")
+}
+
+/// Returns a boolean that is true iff `x` is even (as an integer smaller than the modulus).
+///
+/// `x = 2 k + b` where `k` has [HALF_BITS] bits, so that the sum is not reduced modulo the field
+/// (this is the case except with negligible probability).
+fn is_even(
+    sys: &mut RunState<Fp>,
+    loc: Cow<'static, str>,
+    x: &FieldVar<Fp>,
+) -> SnarkyResult<Boolean<Fp>> {
+    let x_clone = x.clone();
+    let (k_bits, b): ([Boolean<Fp>; HALF_BITS], Boolean<Fp>) =
+        sys.compute(loc.clone(), move |env| {
+            let bits = env.read_var(&x_clone).into_repr().to_bits_le();
+            (std::array::from_fn(|i| bits[i + 1]), bits[0])
+        })?;
+
+    let two = Fp::from(2u64);
+    let k = k_bits
+        .iter()
+        .rev()
+        .fold(FieldVar::zero(), |k, bit| k.scale(two) + bit.to_field_var());
+    let recomposed = k.scale(two) + b.to_field_var();
+    recomposed.assert_equals(sys, loc, x)?;
+
+    Ok(b.not())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domain_prefix() {
+        let prefix = domain_prefix_to_field("CodaSignature");
+        assert_eq!(&prefix.to_bytes()[..20], b"CodaSignature*******");
+    }
+
+    #[test]
+    fn scalar_representation() {
+        for s in [Fq::zero(), Fq::from(7u64), -Fq::from(1u64)] {
+            let (fields, _) = ScalarVar::value_to_field_elements(&s);
+            assert_eq!(ScalarVar::value_of_field_elements(fields, ()), s);
+        }
+    }
+}
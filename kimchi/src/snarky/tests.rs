@@ -3,17 +3,29 @@ use crate::{
     snarky::{
         api::SnarkyCircuit,
         boolean::Boolean,
+        ec::{PointVar, ScalarVar},
         errors::{SnarkyError, SnarkyRuntimeError},
+        poseidon::poseidon,
+        signature::{self, SignatureVar},
+        snarky_type::SnarkyType,
     },
     snarky::{cvar::FieldVar, runner::RunState},
 };
-use ark_ff::One;
-use mina_curves::pasta::{Fp, Vesta, VestaParameters};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{One, UniformRand, Zero};
+use mina_curves::pasta::{Fp, Fq, Pallas, Vesta, VestaParameters};
 use mina_poseidon::{
     constants::PlonkSpongeConstantsKimchi,
+    pasta::fp_kimchi,
+    permutation::poseidon_block_cipher,
     sponge::{DefaultFqSponge, DefaultFrSponge},
 };
+use mina_signer::{
+    transaction::{signature_domain_string, TransactionCommitment},
+    Keypair, NetworkId, ScalarField, Signature, Signer,
+};
 use poly_commitment::evaluation_proof::OpeningProof;
+use rand::{rngs::StdRng, SeedableRng};
 
 use super::prelude::*;
 
@@ -140,3 +152,212 @@ fn test_simple_circuit() {
         }
     }
 }
+
+/// Hashes two private values, or a constant and a scaled private value
+struct HashCircuit {
+    constant: Option<Fp>,
+}
+
+impl SnarkyCircuit for HashCircuit {
+    type Curve = Vesta;
+    type Proof = OpeningProof<Self::Curve>;
+
+    type PrivateInput = (Fp, Fp);
+    type PublicInput = ();
+    type PublicOutput = (FieldVar<Fp>, FieldVar<Fp>);
+
+    fn circuit(
+        &self,
+        sys: &mut RunState<Fp>,
+        _public: Self::PublicInput,
+        private: Option<&Self::PrivateInput>,
+    ) -> SnarkyResult<Self::PublicOutput> {
+        let x: FieldVar<Fp> = sys.compute(loc!(), |_| private.unwrap().0)?;
+        let y: FieldVar<Fp> = sys.compute(loc!(), |_| private.unwrap().1)?;
+
+        let preimage = match self.constant {
+            // both are reduced to new internal variables
+            Some(c) => (FieldVar::constant(c), x.scale(Fp::from(3))),
+            None => (x, y),
+        };
+        Ok(poseidon(sys, loc!(), preimage))
+    }
+}
+
+fn native_hash(a: Fp, b: Fp) -> (Fp, Fp) {
+    let mut state = [a, b, Fp::from(0)];
+    poseidon_block_cipher::<Fp, PlonkSpongeConstantsKimchi, 3>(
+        fp_kimchi::static_params(),
+        &mut state,
+    );
+    (state[0], state[1])
+}
+
+fn prove_hash(circuit: HashCircuit, private_input: (Fp, Fp)) -> (Fp, Fp) {
+    let (mut prover_index, verifier_index) = circuit.compile_to_indexes().unwrap();
+
+    let debug = true;
+    let (proof, public_output) = prover_index
+        .prove::<BaseSponge, ScalarSponge>((), private_input, debug)
+        .unwrap();
+    verifier_index.verify::<BaseSponge, ScalarSponge>(proof, (), *public_output);
+
+    *public_output
+}
+
+#[test]
+fn test_poseidon() {
+    let (x, y) = (Fp::from(2), Fp::from(5));
+    let hash = prove_hash(HashCircuit { constant: None }, (x, y));
+    assert_eq!(hash, native_hash(x, y));
+}
+
+#[test]
+fn test_constant_and_scaled_variable_values() {
+    let x = Fp::from(2);
+    let circuit = HashCircuit {
+        constant: Some(Fp::from(7)),
+    };
+    let hash = prove_hash(circuit, (x, Fp::from(0)));
+    assert_eq!(hash, native_hash(Fp::from(7), x * Fp::from(3)));
+}
+
+#[test]
+fn test_tuple_from_cvars() {
+    let cvars = vec![
+        FieldVar::constant(Fp::from(1)),
+        FieldVar::constant(Fp::from(2)),
+    ];
+    let (a, b) =
+        <(FieldVar<Fp>, FieldVar<Fp>) as SnarkyType<Fp>>::from_cvars_unsafe(cvars, ((), ()));
+    assert!(matches!(a, FieldVar::Constant(c) if c == Fp::from(1)));
+    assert!(matches!(b, FieldVar::Constant(c) if c == Fp::from(2)));
+}
+
+struct ScaleCircuit {}
+
+impl SnarkyCircuit for ScaleCircuit {
+    type Curve = Vesta;
+    type Proof = OpeningProof<Self::Curve>;
+
+    type PrivateInput = Fq;
+    type PublicInput = PointVar;
+    type PublicOutput = (PointVar, Boolean<Fp>);
+
+    fn circuit(
+        &self,
+        sys: &mut RunState<Fp>,
+        public: Self::PublicInput,
+        private: Option<&Self::PrivateInput>,
+    ) -> SnarkyResult<Self::PublicOutput> {
+        let scalar: ScalarVar = sys.compute(loc!(), |_| *private.unwrap())?;
+        public.scale(sys, loc!(), &scalar)
+    }
+}
+
+#[test]
+fn test_scale() {
+    let rng = &mut StdRng::from_seed([0u8; 32]);
+    let (mut prover_index, verifier_index) = ScaleCircuit {}.compile_to_indexes().unwrap();
+
+    let point = Pallas::prime_subgroup_generator()
+        .mul(Fq::rand(rng))
+        .into_affine();
+    // 0 and 1 are handled after the gate
+    for scalar in [0, 1, 2, 3]
+        .map(Fq::from)
+        .into_iter()
+        .chain([Fq::rand(rng)])
+    {
+        let debug = true;
+        let (proof, public_output) = prover_index
+            .prove::<BaseSponge, ScalarSponge>(point, scalar, debug)
+            .unwrap();
+        // the point itself stands for the point at infinity
+        let expected = if scalar.is_zero() {
+            (point, true)
+        } else {
+            (point.mul(scalar).into_affine(), false)
+        };
+        assert_eq!(*public_output, expected);
+
+        verifier_index.verify::<BaseSponge, ScalarSponge>(proof, point, *public_output);
+    }
+}
+
+struct SignatureCircuit {
+    network_id: NetworkId,
+}
+
+impl SnarkyCircuit for SignatureCircuit {
+    type Curve = Vesta;
+    type Proof = OpeningProof<Self::Curve>;
+
+    type PrivateInput = Signature;
+    type PublicInput = (PointVar, FieldVar<Fp>);
+    type PublicOutput = Boolean<Fp>;
+
+    fn circuit(
+        &self,
+        sys: &mut RunState<Fp>,
+        public: Self::PublicInput,
+        private: Option<&Self::PrivateInput>,
+    ) -> SnarkyResult<Self::PublicOutput> {
+        let (public_key, commitment) = public;
+        public_key.check(sys, loc!())?;
+
+        let signature: SignatureVar = sys.compute(loc!(), |_| {
+            let signature = private.unwrap();
+            (signature.rx, signature.s)
+        })?;
+
+        let domain = signature_domain_string(self.network_id.clone());
+        signature::verify(sys, loc!(), &domain, &public_key, &signature, &[commitment])
+    }
+}
+
+#[test]
+fn test_signature_verification() {
+    let rng = &mut StdRng::from_seed([0u8; 32]);
+    let network_id = NetworkId::TESTNET;
+
+    let circuit = SignatureCircuit {
+        network_id: network_id.clone(),
+    };
+    let (mut prover_index, verifier_index) = circuit.compile_to_indexes().unwrap();
+
+    // sign natively
+    let keypair = Keypair::rand(rng).unwrap();
+    let commitment = TransactionCommitment(Fp::from(42));
+    let signature = mina_signer::create_kimchi(network_id.clone()).sign(&keypair, &commitment);
+    let public_input = (*keypair.public.point(), commitment.0);
+
+    // verify in-circuit
+    let cases = [
+        // valid signature
+        (public_input, signature.clone(), true),
+        // different message
+        ((public_input.0, Fp::from(43)), signature.clone(), false),
+        // different signature
+        (
+            public_input,
+            Signature::new(signature.rx, signature.s + signature.s),
+            false,
+        ),
+        // zero s, for which [s] G is the point at infinity
+        (
+            public_input,
+            Signature::new(signature.rx, ScalarField::zero()),
+            false,
+        ),
+    ];
+    for (public_input, signature, expected) in cases {
+        let debug = true;
+        let (proof, public_output) = prover_index
+            .prove::<BaseSponge, ScalarSponge>(public_input, signature, debug)
+            .unwrap();
+        assert_eq!(*public_output, expected);
+
+        verifier_index.verify::<BaseSponge, ScalarSponge>(proof, public_input, *public_output);
+    }
+}
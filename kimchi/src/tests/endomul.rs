@@ -4,9 +4,11 @@ use crate::circuits::{
     wires::*,
 };
 use crate::tests::framework::TestFramework;
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{BigInteger, BitIteratorLE, Field, One, PrimeField, UniformRand, Zero};
-use mina_curves::pasta::{Fp as F, Pallas as Other, Vesta, VestaParameters};
+use ark_ec::{
+    short_weierstrass_jacobian::GroupAffine, AffineCurve, ProjectiveCurve, SWModelParameters,
+};
+use ark_ff::{BigInteger, BitIteratorLE, PrimeField};
+use mina_curves::pasta::{Fp, PallasParameters, Vesta, VestaParameters};
 use mina_poseidon::{
     constants::PlonkSpongeConstantsKimchi,
     sponge::{DefaultFqSponge, DefaultFrSponge, ScalarChallenge},
@@ -17,10 +19,16 @@ use std::array;
 
 type SpongeParams = PlonkSpongeConstantsKimchi;
 type BaseSponge = DefaultFqSponge<VestaParameters, SpongeParams>;
-type ScalarSponge = DefaultFrSponge<F, SpongeParams>;
-
-#[test]
-fn endomul_test() {
+type ScalarSponge = DefaultFrSponge<Fp, SpongeParams>;
+
+/// Creates EndoMul gates for scalar multiplications on `GroupAffine<OtherP>`.
+/// Returns the circuit and its witness.
+fn endomul_circuit<F, OtherP>() -> (Vec<CircuitGate<F>>, [Vec<F>; COLUMNS])
+where
+    F: PrimeField,
+    OtherP: SWModelParameters<BaseField = F> + Clone,
+    OtherP::ScalarField: PrimeField<BigInt = F::BigInt>,
+{
     let bits_per_chunk = 4;
     let num_bits = 128;
     let chunks = num_bits / bits_per_chunk;
@@ -47,7 +55,7 @@ fn endomul_test() {
         gates.push(CircuitGate::new(GateType::Zero, Wire::for_row(row), vec![]));
     }
 
-    let (endo_q, endo_r) = endos::<Other>();
+    let (endo_q, endo_r) = endos::<GroupAffine<OtherP>>();
 
     let mut witness: [Vec<F>; COLUMNS] =
         array::from_fn(|_| vec![F::zero(); rows_per_scalar * num_scalars]);
@@ -59,20 +67,19 @@ fn endomul_test() {
         let bits_lsb: Vec<_> = BitIteratorLE::new(F::rand(rng).into_repr())
             .take(num_bits)
             .collect();
-        let x = <Other as AffineCurve>::ScalarField::from_repr(
-            <F as PrimeField>::BigInt::from_bits_le(&bits_lsb[..]),
-        )
-        .unwrap();
+        let x =
+            OtherP::ScalarField::from_repr(<F as PrimeField>::BigInt::from_bits_le(&bits_lsb[..]))
+                .unwrap();
 
         let x_scalar = ScalarChallenge(x).to_field(&endo_r);
 
-        let base = Other::prime_subgroup_generator();
-        // let g = Other::prime_subgroup_generator().into_projective();
+        let base = GroupAffine::<OtherP>::prime_subgroup_generator();
+        // let g = GroupAffine::<OtherP>::prime_subgroup_generator().into_projective();
         let acc0 = {
-            let t = Other::new(endo_q * base.x, base.y, false);
+            let t = GroupAffine::<OtherP>::new(endo_q * base.x, base.y, false);
             // Ensuring we use affine coordinates
-            let p: Other = t + base;
-            let acc: Other = p + p;
+            let p: GroupAffine<OtherP> = t + base;
+            let acc: GroupAffine<OtherP> = p + p;
             (acc.x, acc.y)
         };
 
@@ -88,20 +95,20 @@ fn endomul_test() {
         );
 
         let expected = {
-            let t = Other::prime_subgroup_generator();
-            let mut acc = Other::new(acc0.0, acc0.1, false);
+            let t = GroupAffine::<OtherP>::prime_subgroup_generator();
+            let mut acc = GroupAffine::<OtherP>::new(acc0.0, acc0.1, false);
             for i in (0..(num_bits / 2)).rev() {
                 let b2i = F::from(bits_lsb[2 * i] as u64);
                 let b2i1 = F::from(bits_lsb[2 * i + 1] as u64);
                 let xq = (F::one() + ((endo_q - F::one()) * b2i1)) * t.x;
                 let yq = (b2i.double() - F::one()) * t.y;
-                acc = acc + (acc + Other::new(xq, yq, false));
+                acc = acc + (acc + GroupAffine::<OtherP>::new(xq, yq, false));
             }
             acc
         };
         assert_eq!(
             expected,
-            Other::prime_subgroup_generator()
+            GroupAffine::<OtherP>::prime_subgroup_generator()
                 .into_projective()
                 .mul(x_scalar.into_repr())
                 .into_affine()
@@ -111,6 +118,13 @@ fn endomul_test() {
         assert_eq!(x.into_repr(), res.n.into_repr());
     }
 
+    (gates, witness)
+}
+
+#[test]
+fn endomul_test_pasta() {
+    let (gates, witness) = endomul_circuit::<Fp, PallasParameters>();
+
     TestFramework::<Vesta>::default()
         .gates(gates)
         .witness(witness)
@@ -118,3 +132,26 @@ fn endomul_test() {
         .prove_and_verify::<BaseSponge, ScalarSponge>()
         .unwrap();
 }
+
+#[cfg(feature = "bn254")]
+#[test]
+fn endomul_test_bn254() {
+    use mina_curves::bn254::{BN254Parameters, Fr, GrumpkinParameters, BN254};
+    use poly_commitment::srs::SRS;
+
+    let (gates, witness) = endomul_circuit::<Fr, GrumpkinParameters>();
+
+    TestFramework::<BN254>::default()
+        .gates(gates)
+        .witness(witness)
+        .setup_with_custom_srs(|d1, size| {
+            let mut srs = SRS::<BN254>::create(size);
+            srs.add_lagrange_basis(d1);
+            srs
+        })
+        .prove_and_verify::<
+            DefaultFqSponge<BN254Parameters, SpongeParams>,
+            DefaultFrSponge<Fr, SpongeParams>,
+        >()
+        .unwrap();
+}
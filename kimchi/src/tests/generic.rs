@@ -1,12 +1,24 @@
 use super::framework::TestFramework;
 use crate::circuits::polynomials::generic::testing::{create_circuit, fill_in_witness};
 use crate::circuits::wires::COLUMNS;
+#[cfg(feature = "bn254")]
+use crate::{plonk_sponge::FrSponge, verifier_index::VerifierIndex};
+#[cfg(feature = "bn254")]
+use ark_ff::UniformRand;
 use ark_ff::Zero;
 use mina_curves::pasta::{Fp, Vesta, VestaParameters};
+#[cfg(feature = "bn254")]
+use mina_poseidon::sponge::FqSponge;
 use mina_poseidon::{
     constants::PlonkSpongeConstantsKimchi,
     sponge::{DefaultFqSponge, DefaultFrSponge},
 };
+#[cfg(feature = "bn254")]
+use poly_commitment::{
+    pairing_proof::{HidingPairingProof, PairingProof, PairingSRS},
+    shplonk::ShplonkProof,
+    OpenProof,
+};
 use std::array;
 
 type SpongeParams = PlonkSpongeConstantsKimchi;
@@ -88,38 +100,92 @@ fn test_generic_gate_pub_empty() {
 }
 
 #[cfg(feature = "bn254")]
-#[test]
-fn test_generic_gate_pairing() {
-    type Fp = ark_bn254::Fr;
-    type SpongeParams = PlonkSpongeConstantsKimchi;
-    type BaseSponge = DefaultFqSponge<ark_bn254::g1::Parameters, SpongeParams>;
-    type ScalarSponge = DefaultFrSponge<Fp, SpongeParams>;
+type BN254 = ark_ec::bn::Bn<ark_bn254::Parameters>;
 
-    use ark_ff::UniformRand;
+/// Create and verify a proof of the generic gate over BN254, with the opening proof `OpeningProof`
+/// on the SRS `create_srs(size)` and the sponges `EFqSponge` and `EFrSponge`
+#[cfg(feature = "bn254")]
+fn test_generic_gate_pairing_with<OpeningProof, EFqSponge, EFrSponge>(
+    create_srs: impl Fn(usize) -> PairingSRS<BN254>,
+) where
+    OpeningProof: OpenProof<ark_bn254::G1Affine, SRS = PairingSRS<BN254>> + Default,
+    VerifierIndex<ark_bn254::G1Affine, OpeningProof>: Clone,
+    EFqSponge: Clone + FqSponge<ark_bn254::Fq, ark_bn254::G1Affine, ark_bn254::Fr>,
+    EFrSponge: FrSponge<ark_bn254::Fr>,
+{
+    type Fp = ark_bn254::Fr;
 
     let public = vec![Fp::from(3u8); 5];
     let gates = create_circuit(0, public.len());
 
-    let rng = &mut rand::rngs::OsRng;
-    let x = Fp::rand(rng);
-
     // create witness
     let mut witness: [Vec<Fp>; COLUMNS] = array::from_fn(|_| vec![Fp::zero(); gates.len()]);
     fill_in_witness(0, &mut witness, &public);
 
     // create and verify proof based on the witness
-    <TestFramework<
-        _,
-        poly_commitment::pairing_proof::PairingProof<ark_ec::bn::Bn<ark_bn254::Parameters>>,
-    > as Default>::default()
-    .gates(gates)
-    .witness(witness)
-    .public_inputs(public)
-    .setup_with_custom_srs(|d1, usize| {
-        let mut srs = poly_commitment::pairing_proof::PairingSRS::create(x, usize);
-        srs.full_srs.add_lagrange_basis(d1);
-        srs
-    })
-    .prove_and_verify::<BaseSponge, ScalarSponge>()
-    .unwrap();
+    TestFramework::<_, OpeningProof>::default()
+        .gates(gates)
+        .witness(witness)
+        .public_inputs(public)
+        .setup_with_custom_srs(|d1, usize| {
+            let mut srs = create_srs(usize);
+            srs.full_srs.add_lagrange_basis(d1);
+            srs
+        })
+        .prove_and_verify::<EFqSponge, EFrSponge>()
+        .unwrap();
+}
+
+#[cfg(feature = "bn254")]
+#[test]
+fn test_generic_gate_pairing() {
+    type SpongeParams = PlonkSpongeConstantsKimchi;
+    type BaseSponge = DefaultFqSponge<ark_bn254::g1::Parameters, SpongeParams>;
+    type ScalarSponge = DefaultFrSponge<ark_bn254::Fr, SpongeParams>;
+
+    let x = ark_bn254::Fr::rand(&mut rand::rngs::OsRng);
+    test_generic_gate_pairing_with::<PairingProof<BN254>, BaseSponge, ScalarSponge>(|size| {
+        PairingSRS::create(x, size)
+    });
+}
+
+#[cfg(feature = "bn254")]
+#[test]
+fn test_generic_gate_pairing_keccak() {
+    type BaseSponge = crate::keccak_sponge::KeccakFqSponge<ark_bn254::g1::Parameters>;
+    type ScalarSponge = crate::keccak_sponge::KeccakFrSponge<ark_bn254::Fr>;
+
+    // with the Keccak transcript
+    let x = ark_bn254::Fr::rand(&mut rand::rngs::OsRng);
+    test_generic_gate_pairing_with::<PairingProof<BN254>, BaseSponge, ScalarSponge>(|size| {
+        PairingSRS::create(x, size)
+    });
+}
+
+#[cfg(feature = "bn254")]
+#[test]
+fn test_generic_gate_pairing_shplonk() {
+    type SpongeParams = PlonkSpongeConstantsKimchi;
+    type BaseSponge = DefaultFqSponge<ark_bn254::g1::Parameters, SpongeParams>;
+    type ScalarSponge = DefaultFrSponge<ark_bn254::Fr, SpongeParams>;
+
+    let x = ark_bn254::Fr::rand(&mut rand::rngs::OsRng);
+    test_generic_gate_pairing_with::<ShplonkProof<BN254>, BaseSponge, ScalarSponge>(|size| {
+        PairingSRS::create(x, size)
+    });
+}
+
+#[cfg(feature = "bn254")]
+#[test]
+fn test_generic_gate_pairing_hiding() {
+    type SpongeParams = PlonkSpongeConstantsKimchi;
+    type BaseSponge = DefaultFqSponge<ark_bn254::g1::Parameters, SpongeParams>;
+    type ScalarSponge = DefaultFrSponge<ark_bn254::Fr, SpongeParams>;
+
+    let rng = &mut rand::rngs::OsRng;
+    let x = ark_bn254::Fr::rand(rng);
+    let gamma = ark_bn254::Fr::rand(rng);
+    test_generic_gate_pairing_with::<HidingPairingProof<BN254>, BaseSponge, ScalarSponge>(|size| {
+        PairingSRS::create_hiding(x, gamma, size)
+    });
 }
@@ -0,0 +1,161 @@
+use crate::{
+    curve::KimchiCurve,
+    keccak_sponge::{KeccakFqSponge, KeccakFrSponge},
+    plonk_sponge::FrSponge,
+};
+use ark_bn254::{g1::Parameters as Bn254Parameters, Fq, Fr, G1Affine};
+use ark_ff::{PrimeField, Zero};
+use mina_curves::pasta::{Fq as PastaFq, Pallas, PallasParameters};
+use mina_poseidon::FqSponge;
+use serde::Deserialize;
+use std::{fs::File, path::PathBuf};
+
+type BaseSponge = KeccakFqSponge<Bn254Parameters>;
+type ScalarSponge = KeccakFrSponge<Fr>;
+
+//
+// Helpers for test vectors
+//
+
+#[derive(Debug, Deserialize)]
+struct TestVectors {
+    test_vectors: Vec<TestVector>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TestVector {
+    name: String,
+    ops: Vec<Op>,
+}
+
+/// An operation on the sponge. The values are `uint256`s, in hexadecimal.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Op {
+    AbsorbG { x: String, y: String },
+    AbsorbFq { value: String },
+    AbsorbFr { value: String },
+    Challenge { expected: String },
+    ChallengeFq { expected: String },
+    Digest { expected: String },
+    DigestFq { expected: String },
+}
+
+fn from_hex<F: PrimeField>(hexstring: &str) -> F {
+    let bytes = hex::decode(hexstring.trim_start_matches("0x")).expect("invalid hexstring");
+    F::from_be_bytes_mod_order(&bytes)
+}
+
+fn test_vectors() -> Vec<TestVector> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("src/tests/test_vectors/keccak_sponge.json");
+    let file = File::open(&path).expect("couldn't open test vector file");
+    let test_vectors: TestVectors =
+        serde_json::from_reader(file).expect("couldn't deserialize test vector file");
+    test_vectors.test_vectors
+}
+
+//
+// Tests
+//
+
+#[test]
+fn test_keccak_fq_sponge_vectors() {
+    for test_vector in test_vectors() {
+        let mut sponge = BaseSponge::new(G1Affine::other_curve_sponge_params());
+        for op in test_vector.ops {
+            match op {
+                Op::AbsorbG { x, y } => {
+                    let (x, y) = (from_hex::<Fq>(&x), from_hex::<Fq>(&y));
+                    let point = if x.is_zero() && y.is_zero() {
+                        G1Affine::zero()
+                    } else {
+                        G1Affine::new(x, y, false)
+                    };
+                    sponge.absorb_g(&[point])
+                }
+                Op::AbsorbFq { value } => sponge.absorb_fq(&[from_hex(&value)]),
+                Op::AbsorbFr { value } => sponge.absorb_fr(&[from_hex(&value)]),
+                Op::Challenge { expected } => {
+                    assert_eq!(
+                        sponge.challenge(),
+                        from_hex(&expected),
+                        "{}",
+                        test_vector.name
+                    )
+                }
+                Op::ChallengeFq { expected } => {
+                    assert_eq!(
+                        sponge.challenge_fq(),
+                        from_hex(&expected),
+                        "{}",
+                        test_vector.name
+                    )
+                }
+                Op::Digest { expected } => {
+                    assert_eq!(sponge.digest(), from_hex(&expected), "{}", test_vector.name);
+                    break;
+                }
+                Op::DigestFq { expected } => {
+                    assert_eq!(
+                        sponge.digest_fq(),
+                        from_hex(&expected),
+                        "{}",
+                        test_vector.name
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_keccak_fr_sponge_vectors() {
+    // The Fr-Sponge encodes and squeezes scalars as the Fq-Sponge, so it
+    // shares the test vectors which only use scalars.
+    for test_vector in test_vectors() {
+        let only_scalars = test_vector.ops.iter().all(|op| {
+            matches!(
+                op,
+                Op::AbsorbFr { .. } | Op::Challenge { .. } | Op::Digest { .. }
+            )
+        });
+        if !only_scalars {
+            continue;
+        }
+        let mut sponge = ScalarSponge::new(G1Affine::sponge_params());
+        for op in test_vector.ops {
+            match op {
+                Op::AbsorbFr { value } => sponge.absorb(&from_hex(&value)),
+                Op::Challenge { expected } => {
+                    assert_eq!(
+                        sponge.challenge().0,
+                        from_hex(&expected),
+                        "{}",
+                        test_vector.name
+                    )
+                }
+                Op::Digest { expected } => {
+                    assert_eq!(sponge.digest(), from_hex(&expected), "{}", test_vector.name);
+                    break;
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_keccak_sponge_pasta() {
+    // The sponges are not specific to BN254: the pasta fields are also
+    // encoded on 32 bytes.
+    let mut fq_sponge =
+        KeccakFqSponge::<PallasParameters>::new(Pallas::other_curve_sponge_params());
+    let mut fr_sponge = KeccakFrSponge::<PastaFq>::new(Pallas::sponge_params());
+    let x = PastaFq::from(42u64);
+    fq_sponge.absorb_g(&[Pallas::zero()]);
+    fq_sponge.absorb_fr(&[x]);
+    fr_sponge.absorb_multiple(&[PastaFq::zero(), PastaFq::zero(), x]);
+    assert_eq!(fq_sponge.challenge(), fr_sponge.challenge().0);
+}
@@ -8,6 +8,8 @@ mod foreign_field_mul;
 mod framework;
 mod generic;
 mod keccak;
+#[cfg(feature = "bn254")]
+mod keccak_sponge;
 mod lookup;
 mod not;
 mod poseidon;
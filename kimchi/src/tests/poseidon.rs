@@ -11,7 +11,9 @@ use crate::{
 use ark_ff::Zero;
 use mina_curves::pasta::{Fp, Vesta, VestaParameters};
 use mina_poseidon::{
-    constants::{PlonkSpongeConstantsKimchi, SpongeConstants},
+    constants::{PlonkSpongeConstantsKimchi, PlonkSpongeConstantsKimchiPartial, SpongeConstants},
+    pasta::fp_kimchi_partial,
+    permutation::poseidon_block_cipher,
     sponge::{DefaultFqSponge, DefaultFrSponge},
 };
 use o1_utils::math;
@@ -88,3 +90,58 @@ fn test_poseidon() {
         .prove_and_verify::<BaseSponge, ScalarSponge>()
         .unwrap();
 }
+
+#[test]
+fn test_poseidon_partial() {
+    let params = fp_kimchi_partial::static_params();
+    // the full rounds are constrained by the poseidon gate, with the MDS matrix of the curve
+    assert_eq!(params.mds, Vesta::sponge_params().mds);
+
+    let first_wire = Wire::for_row(0);
+    let last_wire = Wire::for_row(POS_ROWS_PER_HASH);
+    let (gates, last_row) = CircuitGate::<Fp>::create_poseidon_partial_gadget(
+        0,
+        [first_wire, last_wire],
+        &params.round_constants,
+    );
+    assert_eq!(last_row, POS_ROWS_PER_HASH);
+    assert_eq!(gates.len(), POS_ROWS_PER_HASH + 1);
+
+    let mut witness: [Vec<Fp>; COLUMNS] =
+        array::from_fn(|_| vec![Fp::zero(); POS_ROWS_PER_HASH + 1]);
+    let input = [Fp::from(1u32), Fp::from(2u32), Fp::from(3u32)];
+    polynomials::poseidon::generate_partial_witness(0, params, &mut witness, input);
+
+    // the output of the gadget is the output of the permutation
+    let mut output = input;
+    poseidon_block_cipher::<_, PlonkSpongeConstantsKimchiPartial, 3>(params, &mut output);
+    assert_eq!(output, array::from_fn(|i| witness[i][POS_ROWS_PER_HASH]));
+
+    TestFramework::<Vesta>::default()
+        .gates(gates)
+        .witness(witness)
+        .setup()
+        .prove_and_verify::<BaseSponge, ScalarSponge>()
+        .unwrap();
+}
+
+#[test]
+fn test_poseidon_partial_invalid_witness() {
+    let params = fp_kimchi_partial::static_params();
+    let (gates, _) = CircuitGate::<Fp>::create_poseidon_partial_gadget(
+        0,
+        [Wire::for_row(0), Wire::for_row(POS_ROWS_PER_HASH)],
+        &params.round_constants,
+    );
+
+    // a witness computed with full rounds doesn't satisfy the partial rounds
+    let mut witness: [Vec<Fp>; COLUMNS] =
+        array::from_fn(|_| vec![Fp::zero(); POS_ROWS_PER_HASH + 1]);
+    let input = [Fp::from(1u32), Fp::from(2u32), Fp::from(3u32)];
+    polynomials::poseidon::generate_witness(0, params, &mut witness, input);
+
+    assert_eq!(gates[0].verify_poseidon::<Vesta>(0, &witness), Ok(()));
+    assert!(gates[1]
+        .verify_poseidon_partial::<Vesta>(1, &witness)
+        .is_err());
+}
@@ -4,10 +4,12 @@ use crate::circuits::{
     wires::*,
 };
 use crate::tests::framework::TestFramework;
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{BigInteger, BitIteratorLE, Field, One, PrimeField, UniformRand, Zero};
+use ark_ec::{
+    short_weierstrass_jacobian::GroupAffine, AffineCurve, ProjectiveCurve, SWModelParameters,
+};
+use ark_ff::{BigInteger, BitIteratorLE, Field, One, PrimeField};
 use colored::Colorize;
-use mina_curves::pasta::{Fp as F, Pallas as Other, Vesta, VestaParameters};
+use mina_curves::pasta::{Fp, PallasParameters, Vesta, VestaParameters};
 use mina_poseidon::{
     constants::PlonkSpongeConstantsKimchi,
     sponge::{DefaultFqSponge, DefaultFrSponge},
@@ -18,17 +20,22 @@ use std::time::Instant;
 
 type SpongeParams = PlonkSpongeConstantsKimchi;
 type BaseSponge = DefaultFqSponge<VestaParameters, SpongeParams>;
-type ScalarSponge = DefaultFrSponge<F, SpongeParams>;
+type ScalarSponge = DefaultFrSponge<Fp, SpongeParams>;
 
-#[test]
-fn varbase_mul_test() {
-    let num_bits = F::size_in_bits();
+/// Creates VarBaseMul gates for scalar multiplications on `GroupAffine<OtherP>`.
+/// Returns the circuit and its witness.
+fn varbase_mul_circuit<F, OtherP>() -> (Vec<CircuitGate<F>>, [Vec<F>; COLUMNS])
+where
+    F: PrimeField,
+    OtherP: SWModelParameters<BaseField = F> + Clone,
+    OtherP::ScalarField: PrimeField<BigInt = F::BigInt>,
+{
+    // each gate processes 5 bits, so we only use a multiple of 5 bits of the scalars
+    let num_bits = F::size_in_bits() / 5 * 5;
     let chunks = num_bits / 5;
 
     let num_scalars = 10;
-    let rows_per_scalar = 2 * (255 / 5);
-
-    assert_eq!(num_bits % 5, 0);
+    let rows_per_scalar = 2 * chunks;
 
     let mut gates = vec![];
 
@@ -55,13 +62,12 @@ fn varbase_mul_test() {
     for i in 0..num_scalars {
         let x = F::rand(rng);
         let bits_lsb: Vec<_> = BitIteratorLE::new(x.into_repr()).take(num_bits).collect();
-        let x_ = <Other as AffineCurve>::ScalarField::from_repr(
-            <F as PrimeField>::BigInt::from_bits_le(&bits_lsb[..]),
-        )
-        .unwrap();
+        let x_ =
+            OtherP::ScalarField::from_repr(<F as PrimeField>::BigInt::from_bits_le(&bits_lsb[..]))
+                .unwrap();
 
-        let base = Other::prime_subgroup_generator();
-        let g = Other::prime_subgroup_generator().into_projective();
+        let base = GroupAffine::<OtherP>::prime_subgroup_generator();
+        let g = GroupAffine::<OtherP>::prime_subgroup_generator().into_projective();
         let acc = (g + g).into_affine();
         let acc = (acc.x, acc.y);
 
@@ -75,9 +81,9 @@ fn varbase_mul_test() {
             acc,
         );
 
-        let shift = <Other as AffineCurve>::ScalarField::from(2).pow([(bits_msb.len()) as u64]);
+        let shift = OtherP::ScalarField::from(2u64).pow([(bits_msb.len()) as u64]);
         let expected = g
-            .mul((<Other as AffineCurve>::ScalarField::one() + shift + x_.double()).into_repr())
+            .mul((OtherP::ScalarField::one() + shift + x_.double()).into_repr())
             .into_affine();
 
         assert_eq!(x_.into_repr(), res.n.into_repr());
@@ -89,6 +95,13 @@ fn varbase_mul_test() {
         start.elapsed()
     );
 
+    (gates, witness)
+}
+
+#[test]
+fn varbase_mul_test_pasta() {
+    let (gates, witness) = varbase_mul_circuit::<Fp, PallasParameters>();
+
     TestFramework::<Vesta>::default()
         .gates(gates)
         .witness(witness)
@@ -96,3 +109,26 @@ fn varbase_mul_test() {
         .prove_and_verify::<BaseSponge, ScalarSponge>()
         .unwrap();
 }
+
+#[cfg(feature = "bn254")]
+#[test]
+fn varbase_mul_test_bn254() {
+    use mina_curves::bn254::{BN254Parameters, Fr, GrumpkinParameters, BN254};
+    use poly_commitment::srs::SRS;
+
+    let (gates, witness) = varbase_mul_circuit::<Fr, GrumpkinParameters>();
+
+    TestFramework::<BN254>::default()
+        .gates(gates)
+        .witness(witness)
+        .setup_with_custom_srs(|d1, size| {
+            let mut srs = SRS::<BN254>::create(size);
+            srs.add_lagrange_basis(d1);
+            srs
+        })
+        .prove_and_verify::<
+            DefaultFqSponge<BN254Parameters, SpongeParams>,
+            DefaultFrSponge<Fr, SpongeParams>,
+        >()
+        .unwrap();
+}
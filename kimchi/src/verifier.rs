@@ -22,12 +22,10 @@ use crate::{
 use ark_ec::AffineCurve;
 use ark_ff::{Field, One, PrimeField, Zero};
 use ark_poly::{univariate::DensePolynomial, EvaluationDomain, Polynomial};
-use mina_poseidon::{sponge::ScalarChallenge, FqSponge};
+use mina_poseidon::{sponge::ScalarChallenge, transcript::Transcript, FqSponge};
 use o1_utils::ExtendedDensePolynomial;
 use poly_commitment::{
-    commitment::{
-        absorb_commitment, combined_inner_product, BatchEvaluationProof, Evaluation, PolyComm,
-    },
+    commitment::{combined_inner_product, BatchEvaluationProof, Evaluation, PolyComm},
     OpenProof, SRS as _,
 };
 use rand::thread_rng;
@@ -86,6 +84,7 @@ impl<'a, G: KimchiCurve, OpeningProof: OpenProof<G>> Context<'a, G, OpeningProof
                     ForeignFieldMul => Some(self.verifier_index.foreign_field_mul_comm.as_ref()?),
                     Xor16 => Some(self.verifier_index.xor_comm.as_ref()?),
                     Rot64 => Some(self.verifier_index.rot_comm.as_ref()?),
+                    PoseidonPartial => Some(self.verifier_index.poseidon_partial_comm.as_ref()?),
                     KeccakRound => todo!(),
                     KeccakSponge => todo!(),
                 }
@@ -138,25 +137,28 @@ where
         //~ 1. Setup the Fq-Sponge. This sponge mostly absorbs group
         // elements (points as tuples over the base field), but it
         // squeezes out elements of the group's scalar field.
-        let mut fq_sponge = EFqSponge::new(G::other_curve_sponge_params());
+        // The transcript is in the compatible mode, so that it absorbs and squeezes exactly as
+        // the Fq-Sponge (labels are ignored).
+        let mut transcript =
+            Transcript::<_, G, _, EFqSponge>::compatible(G::other_curve_sponge_params());
 
         //~ 1. Absorb the digest of the VerifierIndex.
         let verifier_index_digest = index.digest::<EFqSponge>();
-        fq_sponge.absorb_fq(&[verifier_index_digest]);
+        transcript.absorb_base(b"verifier_index_digest", &[verifier_index_digest]);
 
         //~ 1. Absorb the commitments of the previous challenges with the Fq-sponge.
         for RecursionChallenge { comm, .. } in &self.prev_challenges {
-            absorb_commitment(&mut fq_sponge, comm);
+            transcript.absorb_points(b"prev_challenge_comm", &comm.elems);
         }
 
         //~ 1. Absorb the commitment of the public input polynomial with the Fq-Sponge.
-        absorb_commitment(&mut fq_sponge, public_comm);
+        transcript.absorb_points(b"public_comm", &public_comm.elems);
 
         //~ 1. Absorb the commitments to the registers / witness columns with the Fq-Sponge.
         self.commitments
             .w_comm
             .iter()
-            .for_each(|c| absorb_commitment(&mut fq_sponge, c));
+            .for_each(|c| transcript.absorb_points(b"w_comm", &c.elems));
 
         //~ 1. If lookup is used:
         if let Some(l) = &index.lookup_index {
@@ -172,7 +174,7 @@ where
                     .runtime
                     .as_ref()
                     .ok_or(VerifyError::IncorrectRuntimeProof)?;
-                absorb_commitment(&mut fq_sponge, runtime_commit);
+                transcript.absorb_points(b"runtime_table_comm", &runtime_commit.elems);
             }
         }
 
@@ -181,7 +183,7 @@ where
             //~~   then squeeze the Fq-Sponge to obtain the joint combiner challenge $j'$,
             //~~   otherwise set the joint combiner challenge $j'$ to $0$.
             let joint_combiner = if l.joint_lookup_used {
-                fq_sponge.challenge()
+                transcript.challenge(b"joint_combiner")
             } else {
                 G::ScalarField::zero()
             };
@@ -206,16 +208,16 @@ where
 
             //~~ * absorb the commitments to the sorted polynomials.
             for com in &lookup_commits.sorted {
-                absorb_commitment(&mut fq_sponge, com);
+                transcript.absorb_points(b"lookup_sorted_comm", &com.elems);
             }
         }
 
         // --- PlonK - Round 2
         //~ 1. Sample the first permutation challenge $\beta$ with the Fq-Sponge.
-        let beta = fq_sponge.challenge();
+        let beta = transcript.challenge(b"beta");
 
         //~ 1. Sample the second permutation challenge $\gamma$ with the Fq-Sponge.
-        let gamma = fq_sponge.challenge();
+        let gamma = transcript.challenge(b"gamma");
 
         //~ 1. If using lookup, absorb the commitment to the aggregation lookup polynomial.
         if index.lookup_index.is_some() {
@@ -225,15 +227,15 @@ where
                 .lookup
                 .as_ref()
                 .ok_or(VerifyError::LookupCommitmentMissing)?;
-            absorb_commitment(&mut fq_sponge, &lookup_commits.aggreg);
+            transcript.absorb_points(b"lookup_aggreg_comm", &lookup_commits.aggreg.elems);
         }
 
         //~ 1. Absorb the commitment to the permutation trace with the Fq-Sponge.
-        absorb_commitment(&mut fq_sponge, &self.commitments.z_comm);
+        transcript.absorb_points(b"z_comm", &self.commitments.z_comm.elems);
 
         // --- PlonK - Round 3
         //~ 1. Sample the quotient challenge $\alpha'$ with the Fq-Sponge.
-        let alpha_chal = ScalarChallenge(fq_sponge.challenge());
+        let alpha_chal = ScalarChallenge(transcript.challenge(b"alpha"));
 
         //~ 1. Derive $\alpha$ from $\alpha'$ using the endomorphism (TODO: details).
         let alpha = alpha_chal.to_field(endo_r);
@@ -248,11 +250,11 @@ where
         }
 
         //~ 1. Absorb the commitment to the quotient polynomial $t$ into the argument.
-        absorb_commitment(&mut fq_sponge, &self.commitments.t_comm);
+        transcript.absorb_points(b"t_comm", &self.commitments.t_comm.elems);
 
         // --- PlonK - Round 4
         //~ 1. Sample $\zeta'$ with the Fq-Sponge.
-        let zeta_chal = ScalarChallenge(fq_sponge.challenge());
+        let zeta_chal = ScalarChallenge(transcript.challenge(b"zeta"));
 
         //~ 1. Derive $\zeta$ from $\zeta'$ using the endomorphism (TODO: specify).
         let zeta = zeta_chal.to_field(endo_r);
@@ -262,7 +264,7 @@ where
         // the previous recursion round), and squeezes scalar elements
         // of the field. The squeeze result is the same as with the
         // `fq_sponge`.
-        let digest = fq_sponge.clone().digest();
+        let digest = transcript.clone().digest();
         let mut fr_sponge = EFrSponge::new(G::sponge_params());
 
         //~ 1. Squeeze the Fq-sponge and absorb the result with the Fr-Sponge.
@@ -527,6 +529,12 @@ where
                         .as_ref()
                         .map(|_| Column::Index(GateType::Rot64)),
                 )
+                .chain(
+                    index
+                        .poseidon_partial_comm
+                        .as_ref()
+                        .map(|_| Column::Index(GateType::PoseidonPartial)),
+                )
                 .chain(
                     index
                         .lookup_index
@@ -599,7 +607,7 @@ where
         };
 
         Ok(OraclesResult {
-            fq_sponge,
+            fq_sponge: transcript.into_sponge(),
             digest,
             oracles,
             all_alphas,
@@ -642,6 +650,7 @@ where
         foreign_field_mul_selector,
         xor_selector,
         rot_selector,
+        poseidon_partial_selector,
         lookup_aggregation,
         lookup_table,
         lookup_sorted,
@@ -728,6 +737,9 @@ where
     if let Some(rot_selector) = rot_selector {
         check_eval_len(rot_selector, "rot selector")?
     }
+    if let Some(poseidon_partial_selector) = poseidon_partial_selector {
+        check_eval_len(poseidon_partial_selector, "poseidon partial selector")?
+    }
 
     // Lookup selectors
 
@@ -1004,6 +1016,12 @@ where
             .as_ref()
             .map(|_| Column::Index(GateType::Rot64)),
     )
+    .chain(
+        verifier_index
+            .poseidon_partial_comm
+            .as_ref()
+            .map(|_| Column::Index(GateType::PoseidonPartial)),
+    )
     //~~ * lookup commitments
     //~
     .chain(
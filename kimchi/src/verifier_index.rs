@@ -13,8 +13,10 @@ use crate::{
     curve::KimchiCurve,
     prover_index::ProverIndex,
 };
-use ark_ff::{One, PrimeField};
-use ark_poly::{univariate::DensePolynomial, Radix2EvaluationDomain as D};
+use ark_ff::{One, PrimeField, Zero};
+use ark_poly::{
+    univariate::DensePolynomial, EvaluationDomain, Evaluations, Radix2EvaluationDomain as D,
+};
 use mina_poseidon::FqSponge;
 use once_cell::sync::OnceCell;
 use poly_commitment::{
@@ -144,6 +146,13 @@ pub struct VerifierIndex<G: KimchiCurve, OpeningProof: OpenProof<G>> {
     #[serde(bound = "PolyComm<G>: Serialize + DeserializeOwned")]
     pub lookup_index: Option<LookupVerifierIndex<G>>,
 
+    // Commitments of the gates added after the lookup index, at the end so that the
+    // serialization of the other fields is unchanged, and missing in older verifier indexes
+    /// Poseidon partial rounds commitments
+    #[serde(default)]
+    #[serde(bound = "Option<PolyComm<G>>: Serialize + DeserializeOwned")]
+    pub poseidon_partial_comm: Option<PolyComm<G>>,
+
     #[serde(skip)]
     pub linearization: Linearization<Vec<PolishToken<G::ScalarField, Column>>, Column>,
     /// The mapping between powers of alpha and constraints
@@ -179,6 +188,21 @@ where
 
         let domain = self.cs.domain.d1;
 
+        // Selectors are mostly zero, so we only commit to their non-zero evaluations
+        let commit_selector = |evals: &Evaluations<G::ScalarField, D<G::ScalarField>>| {
+            let step = evals.domain().size() / domain.size();
+            let non_zero: Vec<_> = evals
+                .evals
+                .iter()
+                .step_by(step)
+                .copied()
+                .enumerate()
+                .filter(|(_, e)| !e.is_zero())
+                .collect();
+            self.srs
+                .commit_sparse_evaluations_non_hiding(domain, &non_zero)
+        };
+
         let lookup_index = {
             self.cs
                 .lookup_constraint_system
@@ -186,10 +210,7 @@ where
                 .map(|cs| LookupVerifierIndex {
                     joint_lookup_used: cs.configuration.lookup_info.features.joint_lookup_used,
                     lookup_info: cs.configuration.lookup_info,
-                    lookup_selectors: cs
-                        .lookup_selectors
-                        .as_ref()
-                        .map(|e| self.srs.commit_evaluations_non_hiding(domain, e)),
+                    lookup_selectors: cs.lookup_selectors.as_ref().map(commit_selector),
                     lookup_table: cs
                         .lookup_table8
                         .iter()
@@ -198,10 +219,7 @@ where
                     table_ids: cs.table_ids8.as_ref().map(|table_ids8| {
                         mask_fixed(self.srs.commit_evaluations_non_hiding(domain, table_ids8))
                     }),
-                    runtime_tables_selector: cs
-                        .runtime_selector
-                        .as_ref()
-                        .map(|e| self.srs.commit_evaluations_non_hiding(domain, e)),
+                    runtime_tables_selector: cs.runtime_selector.as_ref().map(commit_selector),
                 })
         };
 
@@ -227,33 +245,17 @@ where
                     &self.column_evaluations.coefficients8[i],
                 )
             }),
-            generic_comm: mask_fixed(
-                self.srs.commit_evaluations_non_hiding(
-                    domain,
-                    &self.column_evaluations.generic_selector4,
-                ),
-            ),
+            generic_comm: mask_fixed(commit_selector(&self.column_evaluations.generic_selector4)),
 
-            psm_comm: mask_fixed(self.srs.commit_evaluations_non_hiding(
-                domain,
-                &self.column_evaluations.poseidon_selector8,
-            )),
+            psm_comm: mask_fixed(commit_selector(&self.column_evaluations.poseidon_selector8)),
 
-            complete_add_comm: mask_fixed(self.srs.commit_evaluations_non_hiding(
-                domain,
+            complete_add_comm: mask_fixed(commit_selector(
                 &self.column_evaluations.complete_add_selector4,
             )),
-            mul_comm: mask_fixed(
-                self.srs
-                    .commit_evaluations_non_hiding(domain, &self.column_evaluations.mul_selector8),
-            ),
-            emul_comm: mask_fixed(
-                self.srs
-                    .commit_evaluations_non_hiding(domain, &self.column_evaluations.emul_selector8),
-            ),
-
-            endomul_scalar_comm: mask_fixed(self.srs.commit_evaluations_non_hiding(
-                domain,
+            mul_comm: mask_fixed(commit_selector(&self.column_evaluations.mul_selector8)),
+            emul_comm: mask_fixed(commit_selector(&self.column_evaluations.emul_selector8)),
+
+            endomul_scalar_comm: mask_fixed(commit_selector(
                 &self.column_evaluations.endomul_scalar_selector8,
             )),
 
@@ -261,35 +263,40 @@ where
                 .column_evaluations
                 .range_check0_selector8
                 .as_ref()
-                .map(|eval8| self.srs.commit_evaluations_non_hiding(domain, eval8)),
+                .map(commit_selector),
 
             range_check1_comm: self
                 .column_evaluations
                 .range_check1_selector8
                 .as_ref()
-                .map(|eval8| self.srs.commit_evaluations_non_hiding(domain, eval8)),
+                .map(commit_selector),
 
             foreign_field_add_comm: self
                 .column_evaluations
                 .foreign_field_add_selector8
                 .as_ref()
-                .map(|eval8| self.srs.commit_evaluations_non_hiding(domain, eval8)),
+                .map(commit_selector),
 
             foreign_field_mul_comm: self
                 .column_evaluations
                 .foreign_field_mul_selector8
                 .as_ref()
-                .map(|eval8| self.srs.commit_evaluations_non_hiding(domain, eval8)),
+                .map(commit_selector),
             xor_comm: self
                 .column_evaluations
                 .xor_selector8
                 .as_ref()
-                .map(|eval8| self.srs.commit_evaluations_non_hiding(domain, eval8)),
+                .map(commit_selector),
             rot_comm: self
                 .column_evaluations
                 .rot_selector8
                 .as_ref()
-                .map(|eval8| self.srs.commit_evaluations_non_hiding(domain, eval8)),
+                .map(commit_selector),
+            poseidon_partial_comm: self
+                .column_evaluations
+                .poseidon_partial_selector8
+                .as_ref()
+                .map(commit_selector),
 
             shift: self.cs.shift,
             permutation_vanishing_polynomial_m: {
@@ -424,6 +431,7 @@ impl<G: KimchiCurve, OpeningProof: OpenProof<G>> VerifierIndex<G, OpeningProof>
             foreign_field_mul_comm,
             xor_comm,
             rot_comm,
+            poseidon_partial_comm,
 
             // Lookup index; optional
             lookup_index,
@@ -478,6 +486,10 @@ impl<G: KimchiCurve, OpeningProof: OpenProof<G>> VerifierIndex<G, OpeningProof>
             fq_sponge.absorb_g(&rot_comm.elems);
         }
 
+        if let Some(poseidon_partial_comm) = poseidon_partial_comm {
+            fq_sponge.absorb_g(&poseidon_partial_comm.elems);
+        }
+
         // Lookup index; optional
 
         if let Some(LookupVerifierIndex {
@@ -25,6 +25,9 @@ pub mod witness;
 pub mod ffa;
 pub mod serialization;
 
+/// Domain separator of the Fiat-Shamir transcript of the MSM project.
+pub const DOMAIN_SEPARATOR: &[u8] = b"kimchi-msm";
+
 /// Domain size for the MSM project, equal to the BN254 SRS size.
 pub const DOMAIN_SIZE: usize = 1 << 15;
 
@@ -106,13 +106,13 @@ impl<'lt, G> IntoIterator for &'lt LookupProof<G> {
 
 pub mod prover {
     use crate::mvlookup::{LookupTableID, MVLookup, MVLookupWitness};
-    use ark_ff::Zero;
+    use ark_ff::{PrimeField, Zero};
     use ark_poly::Evaluations;
     use ark_poly::{univariate::DensePolynomial, Radix2EvaluationDomain as D};
     use kimchi::circuits::domains::EvaluationDomains;
     use kimchi::curve::KimchiCurve;
-    use mina_poseidon::FqSponge;
-    use poly_commitment::commitment::{absorb_commitment, PolyComm};
+    use mina_poseidon::{transcript::Transcript, FqSponge};
+    use poly_commitment::commitment::PolyComm;
     use poly_commitment::{OpenProof, SRS as _};
     use rayon::iter::IntoParallelIterator;
     use rayon::iter::ParallelIterator;
@@ -150,11 +150,12 @@ pub mod prover {
         >(
             lookups: Vec<MVLookupWitness<G::ScalarField, ID>>,
             domain: EvaluationDomains<G::ScalarField>,
-            fq_sponge: &mut Sponge,
+            transcript: &mut Transcript<G::BaseField, G, G::ScalarField, Sponge>,
             srs: &OpeningProof::SRS,
         ) -> Self
         where
             OpeningProof::SRS: Sync,
+            G::BaseField: PrimeField,
         {
             // Polynomial m(X)
             let lookup_counters_evals_d1 = (&lookups)
@@ -173,14 +174,24 @@ pub mod prover {
                     .map(|evals| evals.interpolate_by_ref())
                     .collect();
 
+            // The multiplicities are mostly zero, so we only commit to the non-zero ones
             let lookup_counters_comm_d1: Vec<PolyComm<G>> = (&lookup_counters_evals_d1)
                 .into_par_iter()
-                .map(|poly| srs.commit_evaluations_non_hiding(domain.d1, poly))
+                .map(|evals| {
+                    let non_zero: Vec<_> = evals
+                        .evals
+                        .iter()
+                        .copied()
+                        .enumerate()
+                        .filter(|(_, m)| !m.is_zero())
+                        .collect();
+                    srs.commit_sparse_evaluations_non_hiding(domain.d1, &non_zero)
+                })
                 .collect();
 
             lookup_counters_comm_d1
                 .iter()
-                .for_each(|comm| absorb_commitment(fq_sponge, comm));
+                .for_each(|comm| transcript.absorb_points(b"mvlookup_m", &comm.elems));
             // -- end of m(X)
 
             // -- start computing the row sums h(X)
@@ -188,10 +199,10 @@ pub mod prover {
             // Coin a combiner to perform vector lookup.
             // The row sums h are defined as
             // h(\omega^i) = \sum_{j = 0}^{m} (1/\beta + f_{j}(\omega^i)) - (1 / (\beta + t(\omega^i)))
-            let vector_lookup_combiner = fq_sponge.challenge();
+            let vector_lookup_combiner = transcript.challenge(b"vector_lookup_combiner");
 
             // Coin an evaluation point for the rational functions
-            let beta = fq_sponge.challenge();
+            let beta = transcript.challenge(b"beta");
 
             let lookup_terms_evals: Vec<Vec<G::ScalarField>> = lookups
                 .into_iter()
@@ -294,7 +305,7 @@ pub mod prover {
 
             lookup_terms_comms_d1
                 .iter()
-                .for_each(|comm| absorb_commitment(fq_sponge, comm));
+                .for_each(|comm| transcript.absorb_points(b"mvlookup_h", &comm.elems));
             // -- end computing the row sums h
 
             // -- start computing the running sum in lookup_aggregation
@@ -328,7 +339,7 @@ pub mod prover {
             let lookup_aggregation_comm_d1 =
                 srs.commit_evaluations_non_hiding(domain.d1, &lookup_aggregation_evals_d1);
 
-            absorb_commitment(fq_sponge, &lookup_aggregation_comm_d1);
+            transcript.absorb_points(b"mvlookup_sum", &lookup_aggregation_comm_d1.elems);
             Self {
                 lookup_counters_evals_d1,
                 lookup_counters_poly_d1,
@@ -4,8 +4,9 @@ use crate::{
     mvlookup::{prover::Env, LookupProof, LookupTableID},
     proof::{Proof, ProofCommitments, ProofEvaluations, ProofInputs},
     witness::Witness,
+    DOMAIN_SEPARATOR,
 };
-use ark_ff::{Field, One, Zero};
+use ark_ff::{Field, One, PrimeField, Zero};
 use ark_poly::Evaluations;
 use ark_poly::{univariate::DensePolynomial, Polynomial, Radix2EvaluationDomain as R2D};
 use kimchi::circuits::domains::EvaluationDomains;
@@ -14,12 +15,11 @@ use kimchi::plonk_sponge::FrSponge;
 use kimchi::proof::PointEvaluations;
 use kimchi::{curve::KimchiCurve, groupmap::GroupMap};
 use mina_poseidon::sponge::ScalarChallenge;
+use mina_poseidon::transcript::Transcript;
 use mina_poseidon::FqSponge;
 use o1_utils::ExtendedDensePolynomial;
 use poly_commitment::{
-    commitment::{absorb_commitment, PolyComm},
-    evaluation_proof::DensePolynomialOrEvaluations,
-    OpenProof, SRS,
+    commitment::PolyComm, evaluation_proof::DensePolynomialOrEvaluations, OpenProof, SRS,
 };
 use rand::{CryptoRng, RngCore};
 use rayon::iter::IntoParallelIterator;
@@ -59,6 +59,7 @@ pub fn prove<
 where
     OpeningProof::SRS: Sync,
     RNG: RngCore + CryptoRng,
+    G::BaseField: PrimeField,
 {
     ////////////////////////////////////////////////////////////////////////////
     // Setting up the protocol
@@ -98,19 +99,20 @@ where
             .collect::<Witness<N, PolyComm<G>>>()
     };
 
-    let mut fq_sponge = EFqSponge::new(G::other_curve_sponge_params());
+    let mut transcript =
+        Transcript::<_, G, _, EFqSponge>::new(G::other_curve_sponge_params(), DOMAIN_SEPARATOR);
 
     // Do not use parallelism
     (&witness_comms)
         .into_iter()
-        .for_each(|comm| absorb_commitment(&mut fq_sponge, comm));
+        .for_each(|comm| transcript.absorb_points(b"witness_comm", &comm.elems));
 
     // -- Start MVLookup
     let lookup_env = if !inputs.mvlookups.is_empty() {
         Some(Env::create::<OpeningProof, EFqSponge, ID>(
             inputs.mvlookups,
             domain,
-            &mut fq_sponge,
+            &mut transcript,
             srs,
         ))
     } else {
@@ -143,7 +145,7 @@ where
     let (_, endo_r) = G::endos();
 
     //~ 1. Sample $\alpha'$ with the Fq-Sponge.
-    let alpha_chal = ScalarChallenge(fq_sponge.challenge());
+    let alpha_chal = ScalarChallenge(transcript.challenge(b"alpha"));
 
     //~ 1. Derive $\alpha$ from $\alpha'$ using the endomorphism (TODO: details)
     let alpha: G::ScalarField = alpha_chal.to_field(endo_r);
@@ -245,10 +247,10 @@ where
     ////////////////////////////////////////////////////////////////////////////
 
     //~ 1. Absorb the the commitment of the quotient polynomial with the Fq-Sponge.
-    absorb_commitment(&mut fq_sponge, &t_comm);
+    transcript.absorb_points(b"t_comm", &t_comm.elems);
 
     //~ 1. Sample $\zeta'$ with the Fq-Sponge.
-    let zeta_chal = ScalarChallenge(fq_sponge.challenge());
+    let zeta_chal = ScalarChallenge(transcript.challenge(b"zeta"));
 
     //~ 1. Derive $\zeta$ from $\zeta'$ using the endomorphism (TODO: specify)
     let zeta = zeta_chal.to_field(endo_r);
@@ -311,9 +313,9 @@ where
     ////////////////////////////////////////////////////////////////////////////
 
     // Fiat Shamir - absorbing evaluations
-    let fq_sponge_before_evaluations = fq_sponge.clone();
+    let fq_sponge_before_evaluations = transcript.sponge().clone();
     let mut fr_sponge = EFrSponge::new(G::sponge_params());
-    fr_sponge.absorb(&fq_sponge.digest());
+    fr_sponge.absorb(&transcript.digest());
 
     for PointEvaluations { zeta, zeta_omega } in (&witness_evals).into_iter() {
         fr_sponge.absorb(zeta);
@@ -1,4 +1,4 @@
-use ark_ff::{Field, One, Zero};
+use ark_ff::{Field, One, PrimeField, Zero};
 
 use kimchi::circuits::domains::EvaluationDomains;
 use kimchi::circuits::expr::{Challenges, Constants, Expr, PolishToken};
@@ -6,15 +6,17 @@ use kimchi::plonk_sponge::FrSponge;
 use kimchi::proof::PointEvaluations;
 use kimchi::{curve::KimchiCurve, groupmap::GroupMap};
 use mina_poseidon::sponge::ScalarChallenge;
+use mina_poseidon::transcript::Transcript;
 use mina_poseidon::FqSponge;
 use poly_commitment::{
-    commitment::{absorb_commitment, combined_inner_product, BatchEvaluationProof, Evaluation},
+    commitment::{combined_inner_product, BatchEvaluationProof, Evaluation},
     OpenProof,
 };
 use rand::thread_rng;
 
 use crate::expr::MSMExpr;
 use crate::proof::Proof;
+use crate::DOMAIN_SEPARATOR;
 
 pub fn verify<
     G: KimchiCurve,
@@ -27,7 +29,10 @@ pub fn verify<
     srs: &OpeningProof::SRS,
     constraint_exprs: &Vec<MSMExpr<G::ScalarField>>,
     proof: &Proof<N, G, OpeningProof>,
-) -> bool {
+) -> bool
+where
+    G::BaseField: PrimeField,
+{
     let Proof {
         proof_comms,
         proof_evals,
@@ -35,28 +40,38 @@ pub fn verify<
     } = proof;
 
     // -- Absorbing the commitments
-    let mut fq_sponge = EFqSponge::new(G::other_curve_sponge_params());
+    let mut transcript =
+        Transcript::<_, G, _, EFqSponge>::new(G::other_curve_sponge_params(), DOMAIN_SEPARATOR);
     (&proof_comms.witness_comms)
         .into_iter()
-        .for_each(|comm| absorb_commitment(&mut fq_sponge, comm));
+        .for_each(|comm| transcript.absorb_points(b"witness_comm", &comm.elems));
 
+    // Replay the MVLookup transcript, see mvlookup::prover::Env::create
     if let Some(mvlookup_comms) = &proof_comms.mvlookup_comms {
         mvlookup_comms
-            .into_iter()
-            .for_each(|comm| absorb_commitment(&mut fq_sponge, comm));
+            .m
+            .iter()
+            .for_each(|comm| transcript.absorb_points(b"mvlookup_m", &comm.elems));
+        let _vector_lookup_combiner = transcript.challenge(b"vector_lookup_combiner");
+        let _beta = transcript.challenge(b"beta");
+        mvlookup_comms
+            .h
+            .iter()
+            .for_each(|comm| transcript.absorb_points(b"mvlookup_h", &comm.elems));
+        transcript.absorb_points(b"mvlookup_sum", &mvlookup_comms.sum.elems);
     }
 
     //~ 1. Sample $\alpha'$ with the Fq-Sponge.
-    let alpha_chal = ScalarChallenge(fq_sponge.challenge());
+    let alpha_chal = ScalarChallenge(transcript.challenge(b"alpha"));
     let (_, endo_r) = G::endos();
     let alpha: G::ScalarField = alpha_chal.to_field(endo_r);
 
-    absorb_commitment(&mut fq_sponge, &proof_comms.t_comm);
+    transcript.absorb_points(b"t_comm", &proof_comms.t_comm.elems);
 
     // -- Finish absorbing the commitments
 
     // -- Preparing for opening proof verification
-    let zeta_chal = ScalarChallenge(fq_sponge.challenge());
+    let zeta_chal = ScalarChallenge(transcript.challenge(b"zeta"));
     let (_, endo_r) = G::endos();
     let zeta: G::ScalarField = zeta_chal.to_field(endo_r);
     let omega = domain.d1.group_gen;
@@ -88,9 +103,9 @@ pub fn verify<
     }
 
     // -- Absorb all coms_and_evaluations
-    let fq_sponge_before_coms_and_evaluations = fq_sponge.clone();
+    let fq_sponge_before_coms_and_evaluations = transcript.sponge().clone();
     let mut fr_sponge = EFrSponge::new(G::sponge_params());
-    fr_sponge.absorb(&fq_sponge.digest());
+    fr_sponge.absorb(&transcript.digest());
 
     for PointEvaluations { zeta, zeta_omega } in (&proof_evals.witness_evals).into_iter() {
         fr_sponge.absorb(zeta);
@@ -6,12 +6,13 @@
 //!     producing the batched opening proof
 //! 3. Verify batch of batched opening proofs
 
+use crate::msm::{ArkworksMsm, MsmBackend};
 use crate::srs::endos;
 use crate::SRS as SRSTrait;
 use crate::{error::CommitmentError, srs::SRS};
 use ark_ec::{
-    models::short_weierstrass_jacobian::GroupAffine as SWJAffine, msm::VariableBaseMSM,
-    AffineCurve, ProjectiveCurve, SWModelParameters,
+    models::short_weierstrass_jacobian::GroupAffine as SWJAffine, AffineCurve, ProjectiveCurve,
+    SWModelParameters,
 };
 use ark_ff::{
     BigInteger, Field, FpParameters, One, PrimeField, SquareRootField, UniformRand, Zero,
@@ -22,6 +23,9 @@ use ark_poly::{
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use core::ops::{Add, Sub};
 use groupmap::{BWParameters, GroupMap};
+use mina_curves::serialization::{
+    batch_from_compressed_bytes, to_compressed_bytes, SerializationError,
+};
 use mina_poseidon::{sponge::ScalarChallenge, FqSponge};
 use o1_utils::math;
 use o1_utils::ExtendedDensePolynomial as _;
@@ -100,6 +104,23 @@ impl<A: Copy + CanonicalDeserialize + CanonicalSerialize> PolyComm<A> {
     }
 }
 
+impl<P: SWModelParameters> PolyComm<SWJAffine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    /// Encodes the chunks of the commitment in compressed form,
+    /// see [mina_curves::serialization].
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        self.elems.iter().flat_map(to_compressed_bytes).collect()
+    }
+
+    /// Decodes a commitment encoded by [PolyComm::to_compressed_bytes],
+    /// checking that the chunks are points of the prime-order subgroup.
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        batch_from_compressed_bytes(bytes).map(PolyComm::new)
+    }
+}
+
 /// Inside the circuit, we have a specialized scalar multiplication which computes
 /// either
 ///
@@ -203,6 +224,15 @@ impl<C: AffineCurve> PolyComm<C> {
     ///
     /// Panics if `com` and `elm` are not of the same size.
     pub fn multi_scalar_mul(com: &[&PolyComm<C>], elm: &[C::ScalarField]) -> Self {
+        Self::multi_scalar_mul_with_backend(com, elm, &ArkworksMsm)
+    }
+
+    /// Same as [PolyComm::multi_scalar_mul], using the given [MsmBackend].
+    pub fn multi_scalar_mul_with_backend(
+        com: &[&PolyComm<C>],
+        elm: &[C::ScalarField],
+        backend: &impl MsmBackend<C>,
+    ) -> Self {
         assert_eq!(com.len(), elm.len());
 
         if com.is_empty() || elm.is_empty() {
@@ -222,7 +252,7 @@ impl<C: AffineCurve> PolyComm<C> {
                 .filter_map(|(com, scalar)| com.elems.get(chunk).map(|c| (c, scalar)))
                 .unzip();
 
-            let chunk_msm = VariableBaseMSM::multi_scalar_mul::<C>(&points, &scalars);
+            let chunk_msm = backend.multi_scalar_mul(&points, &scalars);
             elems.push(chunk_msm.into_affine());
         }
 
@@ -602,6 +632,71 @@ where
         &self,
         plnm: &DensePolynomial<G::ScalarField>,
         num_chunks: usize,
+    ) -> PolyComm<G> {
+        match &self.precomputed_table {
+            Some(table) => self.commit_non_hiding_with_backend(plnm, num_chunks, table.as_ref()),
+            None => self.commit_non_hiding_with_backend(plnm, num_chunks, &ArkworksMsm),
+        }
+    }
+
+    fn commit_evaluations_non_hiding(
+        &self,
+        domain: D<G::ScalarField>,
+        plnm: &Evaluations<G::ScalarField, D<G::ScalarField>>,
+    ) -> PolyComm<G> {
+        self.commit_evaluations_non_hiding_with_backend(domain, plnm, &ArkworksMsm)
+    }
+
+    fn commit_evaluations(
+        &self,
+        domain: D<G::ScalarField>,
+        plnm: &Evaluations<G::ScalarField, D<G::ScalarField>>,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> BlindedCommitment<G> {
+        self.mask(self.commit_evaluations_non_hiding(domain, plnm), rng)
+    }
+
+    fn create(depth: usize) -> Self {
+        SRS::create(depth)
+    }
+
+    fn add_lagrange_basis(&mut self, domain: D<<G>::ScalarField>) {
+        self.add_lagrange_basis(domain)
+    }
+
+    fn size(&self) -> usize {
+        self.g.len()
+    }
+}
+
+/// Commits to the evaluations given as `(index, value)` pairs in the given
+/// Lagrange `basis`, the other evaluations being zero.
+/// The values of repeated indices are added.
+///
+/// ## Panics
+///
+/// Panics if an index is out of the basis.
+pub(crate) fn commit_sparse_evaluations<G: CommitmentCurve>(
+    basis: &[PolyComm<G>],
+    evals: impl Iterator<Item = (usize, G::ScalarField)>,
+    backend: &impl MsmBackend<G>,
+) -> PolyComm<G> {
+    let (comms, scalars): (Vec<_>, Vec<_>) = evals.map(|(i, e)| (&basis[i], e)).unzip();
+    if comms.is_empty() {
+        // same number of chunks as the dense commitment to zero
+        let num_chunks = basis.first().map_or(1, |b| b.len());
+        return PolyComm::new(vec![G::zero(); num_chunks]);
+    }
+    PolyComm::<G>::multi_scalar_mul_with_backend(&comms, &scalars, backend)
+}
+
+impl<G: CommitmentCurve> SRS<G> {
+    /// Same as [SRSTrait::commit_non_hiding], using the given [MsmBackend].
+    pub fn commit_non_hiding_with_backend(
+        &self,
+        plnm: &DensePolynomial<G::ScalarField>,
+        num_chunks: usize,
+        backend: &impl MsmBackend<G>,
     ) -> PolyComm<G> {
         let is_zero = plnm.is_zero();
 
@@ -613,7 +708,7 @@ where
             elems.push(G::zero());
         } else {
             coeffs.chunks(self.g.len()).for_each(|coeffs_chunk| {
-                let chunk = VariableBaseMSM::multi_scalar_mul(&self.g, coeffs_chunk);
+                let chunk = backend.multi_scalar_mul(&self.g, coeffs_chunk);
                 elems.push(chunk.into_affine());
             });
         }
@@ -625,17 +720,23 @@ where
         PolyComm::<G> { elems }
     }
 
-    fn commit_evaluations_non_hiding(
+    /// Same as [SRSTrait::commit_evaluations_non_hiding], using the given [MsmBackend].
+    pub fn commit_evaluations_non_hiding_with_backend(
         &self,
         domain: D<G::ScalarField>,
         plnm: &Evaluations<G::ScalarField, D<G::ScalarField>>,
+        backend: &impl MsmBackend<G>,
     ) -> PolyComm<G> {
         let basis = self
             .lagrange_bases
             .get(&domain.size())
             .unwrap_or_else(|| panic!("lagrange bases for size {} not found", domain.size()));
         let commit_evaluations = |evals: &Vec<G::ScalarField>, basis: &Vec<PolyComm<G>>| {
-            PolyComm::<G>::multi_scalar_mul(&basis.iter().collect::<Vec<_>>()[..], &evals[..])
+            PolyComm::<G>::multi_scalar_mul_with_backend(
+                &basis.iter().collect::<Vec<_>>()[..],
+                &evals[..],
+                backend,
+            )
         };
         match domain.size.cmp(&plnm.domain().size) {
             std::cmp::Ordering::Less => {
@@ -650,29 +751,6 @@ where
         }
     }
 
-    fn commit_evaluations(
-        &self,
-        domain: D<G::ScalarField>,
-        plnm: &Evaluations<G::ScalarField, D<G::ScalarField>>,
-        rng: &mut (impl RngCore + CryptoRng),
-    ) -> BlindedCommitment<G> {
-        self.mask(self.commit_evaluations_non_hiding(domain, plnm), rng)
-    }
-
-    fn create(depth: usize) -> Self {
-        SRS::create(depth)
-    }
-
-    fn add_lagrange_basis(&mut self, domain: D<<G>::ScalarField>) {
-        self.add_lagrange_basis(domain)
-    }
-
-    fn size(&self) -> usize {
-        self.g.len()
-    }
-}
-
-impl<G: CommitmentCurve> SRS<G> {
     /// This function verifies batch of batched polynomial commitment opening proofs
     ///     batch: batch of batched polynomial commitment opening proofs
     ///          vector of evaluation points
@@ -689,6 +767,26 @@ impl<G: CommitmentCurve> SRS<G> {
         batch: &mut [BatchEvaluationProof<G, EFqSponge, OpeningProof<G>>],
         rng: &mut RNG,
     ) -> bool
+    where
+        EFqSponge: FqSponge<G::BaseField, G, G::ScalarField>,
+        RNG: RngCore + CryptoRng,
+        G::BaseField: PrimeField,
+    {
+        match &self.precomputed_table {
+            Some(table) => self.verify_with_backend(group_map, batch, rng, table.as_ref()),
+            None => self.verify_with_backend(group_map, batch, rng, &ArkworksMsm),
+        }
+    }
+
+    /// Same as [SRS::verify], using the given [MsmBackend] for the final
+    /// multi-scalar multiplication.
+    pub fn verify_with_backend<EFqSponge, RNG>(
+        &self,
+        group_map: &G::Map,
+        batch: &mut [BatchEvaluationProof<G, EFqSponge, OpeningProof<G>>],
+        rng: &mut RNG,
+        backend: &impl MsmBackend<G>,
+    ) -> bool
     where
         EFqSponge: FqSponge<G::BaseField, G, G::ScalarField>,
         RNG: RngCore + CryptoRng,
@@ -724,9 +822,12 @@ impl<G: CommitmentCurve> SRS<G> {
 
         // TODO: This will need adjusting
         let padding = padded_length - nonzero_length;
-        let mut points = vec![self.h];
-        points.extend(self.g.clone());
+        // The SRS bases come first, so that backends with precomputations for
+        // `self.g` can make use of them.
+        let mut points = self.g.clone();
         points.extend(vec![G::zero(); padding]);
+        points.push(self.h);
+        let h_index = padded_length;
 
         let mut scalars = vec![G::ScalarField::zero(); padded_length + 1];
         assert_eq!(scalars.len(), points.len());
@@ -793,13 +894,13 @@ impl<G: CommitmentCurve> SRS<G> {
                 let terms: Vec<_> = s.par_iter().map(|s| sg_rand_base_i * s).collect();
 
                 for (i, term) in terms.iter().enumerate() {
-                    scalars[i + 1] += term;
+                    scalars[i] += term;
                 }
             }
 
             // TERM
             // - rand_base_i * z2 * H
-            scalars[0] -= &(rand_base_i * opening.z2);
+            scalars[h_index] -= &(rand_base_i * opening.z2);
 
             // TERM
             // -rand_base_i * (z1 * b0 * U)
@@ -844,7 +945,7 @@ impl<G: CommitmentCurve> SRS<G> {
 
         // verify the equation
         let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
-        VariableBaseMSM::multi_scalar_mul(&points, &scalars) == G::Projective::zero()
+        backend.multi_scalar_mul(&points, &scalars) == G::Projective::zero()
     }
 }
 
@@ -1063,6 +1164,102 @@ mod tests {
         }];
 
         assert!(srs.verify(&group_map, &mut batch, rng));
+
+        // the same proof verifies when using precomputed tables
+        let mut srs = srs;
+        srs.add_precomputed_table(8);
+        for b in batch.iter_mut() {
+            b.sponge =
+                DefaultFqSponge::<_, SC>::new(mina_poseidon::pasta::fq_kimchi::static_params());
+        }
+        assert!(srs.verify(&group_map, &mut batch, rng));
+    }
+
+    #[test]
+    fn test_sparse_commitments() {
+        let n = 64;
+        let domain = D::<Fp>::new(n).unwrap();
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+
+        // also check chunked commitments
+        for srs_size in [n, n / 4] {
+            let mut srs = SRS::<VestaG>::create(srs_size);
+            srs.add_lagrange_basis(domain);
+            let basis = srs.lagrange_bases.get(&n).unwrap();
+
+            for num_non_zero in [0, 1, 5, n] {
+                let mut evals = vec![Fp::zero(); n];
+                for i in 0..num_non_zero {
+                    evals[(i * 7) % n] = Fp::rand(rng);
+                }
+                let sparse_evals: Vec<_> = evals
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, e)| !e.is_zero())
+                    .map(|(i, e)| (i, *e))
+                    .collect();
+
+                let evals = Evaluations::from_vec_and_domain(evals, domain);
+                let dense = PolyComm::<VestaG>::multi_scalar_mul(
+                    &basis.iter().collect::<Vec<_>>(),
+                    &evals.evals,
+                );
+                let expected = srs.commit_non_hiding(&evals.interpolate_by_ref(), n / srs_size);
+                assert_eq!(dense, expected);
+
+                assert_eq!(srs.commit_evaluations_non_hiding(domain, &evals), dense);
+                assert_eq!(
+                    srs.commit_sparse_evaluations_non_hiding(domain, &sparse_evals),
+                    dense
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_precomputed_table_commitments() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let mut srs = SRS::<VestaG>::create(32);
+
+        // polynomials of up to three chunks
+        let polys: Vec<_> = [0, 1, 17, 32, 33, 90]
+            .iter()
+            .map(|&len| {
+                let coeffs: Vec<_> = (0..len).map(|_| Fp::rand(rng)).collect();
+                DensePolynomial::<Fp>::from_coefficients_vec(coeffs)
+            })
+            .collect();
+        let expected: Vec<_> = polys.iter().map(|p| srs.commit_non_hiding(p, 3)).collect();
+
+        for window_bits in [1, 6, 10] {
+            srs.add_precomputed_table(window_bits);
+            assert_eq!(
+                srs.precomputed_table.as_ref().unwrap().window_bits(),
+                window_bits
+            );
+            for (p, expected) in polys.iter().zip(expected.iter()) {
+                assert_eq!(&srs.commit_non_hiding(p, 3), expected);
+            }
+        }
+
+        srs.remove_precomputed_table();
+        assert!(srs.precomputed_table.is_none());
+    }
+
+    #[test]
+    fn test_compressed_commitments() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let srs = SRS::<VestaG>::create(16);
+
+        let coeffs: Vec<_> = (0..40).map(|_| Fp::rand(rng)).collect();
+        let poly = DensePolynomial::<Fp>::from_coefficients_vec(coeffs);
+        let mut comm = srs.commit_non_hiding(&poly, 4);
+        comm.elems.push(VestaG::zero());
+
+        let bytes = comm.to_compressed_bytes();
+        assert_eq!(bytes.len(), 5 * 33);
+        assert_eq!(PolyComm::from_compressed_bytes(&bytes), Ok(comm));
+        assert!(PolyComm::<VestaG>::from_compressed_bytes(&bytes[1..]).is_err());
     }
 }
 
@@ -3,7 +3,9 @@ mod combine;
 pub mod commitment;
 pub mod error;
 pub mod evaluation_proof;
+pub mod msm;
 pub mod pairing_proof;
+pub mod shplonk;
 pub mod srs;
 
 #[cfg(test)]
@@ -80,6 +82,23 @@ pub trait SRS<G: CommitmentCurve>: Clone {
         plnm: &Evaluations<G::ScalarField, D<G::ScalarField>>,
         rng: &mut (impl RngCore + CryptoRng),
     ) -> BlindedCommitment<G>;
+
+    /// Commits to the polynomial whose evaluations over `domain` are given as
+    /// `(index, value)` pairs, the other evaluations being zero.
+    /// The cost is proportional to the number of given evaluations, which
+    /// makes it suitable for selectors and lookup multiplicities.
+    /// The Lagrange basis for `domain` must have been added beforehand.
+    fn commit_sparse_evaluations_non_hiding(
+        &self,
+        domain: D<G::ScalarField>,
+        evals: &[(usize, G::ScalarField)],
+    ) -> PolyComm<G> {
+        let basis = self
+            .get_lagrange_basis(domain.size())
+            .unwrap_or_else(|| panic!("lagrange bases for size {} not found", domain.size()));
+        commitment::commit_sparse_evaluations(basis, evals.iter().copied(), &msm::ArkworksMsm)
+    }
+
     ///for now needed by snarky-rs
     fn create(depth: usize) -> Self;
     fn add_lagrange_basis(&mut self, domain: D<G::ScalarField>);
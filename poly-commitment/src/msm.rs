@@ -0,0 +1,291 @@
+//! This module defines the multi-scalar multiplication (MSM) backends used to
+//! compute commitments and to verify opening proofs.
+//!
+//! Every MSM performed by [crate::srs::SRS] goes through the [MsmBackend]
+//! trait, so that alternative CPU implementations can be swapped in without
+//! touching the commitment scheme itself.
+//! Two implementations are provided:
+//!
+//! - [ArkworksMsm], the default, which forwards to arkworks' variable-base
+//!   Pippenger implementation.
+//! - [PrecomputedMsm], a signed-digit Pippenger variant using precomputed
+//!   tables for a fixed set of bases (typically the `g` vector of the SRS).
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use rayon::prelude::*;
+use std::cmp::{min, Ordering};
+
+/// The representation of the scalars consumed by a [MsmBackend].
+pub type ScalarRepr<G> = <<G as AffineCurve>::ScalarField as PrimeField>::BigInt;
+
+/// A multi-scalar multiplication implementation.
+pub trait MsmBackend<G: AffineCurve> {
+    /// Computes `sum_i scalars[i] * bases[i]`.
+    /// As with arkworks, if the two slices don't have the same length, the
+    /// longest one is truncated.
+    fn multi_scalar_mul(&self, bases: &[G], scalars: &[ScalarRepr<G>]) -> G::Projective;
+}
+
+/// The default backend, using arkworks' [VariableBaseMSM].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ArkworksMsm;
+
+impl<G: AffineCurve> MsmBackend<G> for ArkworksMsm {
+    fn multi_scalar_mul(&self, bases: &[G], scalars: &[ScalarRepr<G>]) -> G::Projective {
+        VariableBaseMSM::multi_scalar_mul(bases, scalars)
+    }
+}
+
+/// A fixed-base backend using signed-digit Pippenger over precomputed tables.
+///
+/// For each base `g_i` and each window `j`, the table contains
+/// `2^(window_bits * j) * g_i`. A scalar `s_i` is decomposed in signed digits
+/// `d_ij` in `[-2^(window_bits - 1), 2^(window_bits - 1)]`, so that
+/// `sum_i s_i g_i = sum_(i, j) d_ij (2^(window_bits * j) g_i)`, which is
+/// computed with a single bucket pass and no doublings.
+///
+/// The table takes `n * (bits / window_bits + 1)` points for `n` bases: a
+/// larger `window_bits` uses less memory and fewer table points per scalar,
+/// but more buckets.
+///
+/// If the bases given to [MsmBackend::multi_scalar_mul] don't start with the
+/// precomputed bases, the computation falls back to [ArkworksMsm]. Bases
+/// beyond the precomputed ones are handled by [ArkworksMsm] as well.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedMsm<G> {
+    window_bits: usize,
+    num_windows: usize,
+    /// `table[i * num_windows + j] = 2^(window_bits * j) * bases[i]`
+    table: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedMsm<G> {
+    /// The maximum supported window size, in bits.
+    pub const MAX_WINDOW_BITS: usize = 16;
+
+    /// Precomputes the tables for the given `bases`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `window_bits` is not in `1..=MAX_WINDOW_BITS`.
+    pub fn new(bases: &[G], window_bits: usize) -> Self {
+        assert!(
+            (1..=Self::MAX_WINDOW_BITS).contains(&window_bits),
+            "window size must be between 1 and {} bits",
+            Self::MAX_WINDOW_BITS
+        );
+        let num_bits = G::ScalarField::size_in_bits();
+        // one extra window absorbs the carry of the signed-digit decomposition
+        let num_windows = num_bits / window_bits + 1;
+
+        let table = bases
+            .par_iter()
+            .flat_map_iter(|base| {
+                let mut multiples = Vec::with_capacity(num_windows);
+                let mut acc = base.into_projective();
+                for _ in 0..num_windows {
+                    multiples.push(acc);
+                    for _ in 0..window_bits {
+                        acc.double_in_place();
+                    }
+                }
+                G::Projective::batch_normalization(&mut multiples);
+                multiples.into_iter().map(|p| p.into_affine())
+            })
+            .collect();
+
+        PrecomputedMsm {
+            window_bits,
+            num_windows,
+            table,
+        }
+    }
+
+    /// The number of precomputed bases.
+    pub fn len(&self) -> usize {
+        self.table.len() / self.num_windows
+    }
+
+    /// Whether there are no precomputed bases.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// The window size, in bits.
+    pub fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    /// The i-th precomputed base.
+    pub fn base(&self, i: usize) -> G {
+        self.table[i * self.num_windows]
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` over the first `scalars.len()`
+    /// precomputed bases.
+    fn fixed_base_msm(&self, scalars: &[ScalarRepr<G>]) -> G::Projective {
+        let num_buckets = 1 << (self.window_bits - 1);
+        let chunk_size = (scalars.len() / rayon::current_num_threads()).max(1);
+
+        scalars
+            .par_chunks(chunk_size)
+            .zip(self.table.par_chunks(chunk_size * self.num_windows))
+            .map(|(scalars, table)| {
+                // buckets[k] accumulates the points with digit +-(k + 1)
+                let mut buckets = vec![G::Projective::zero(); num_buckets];
+                for (scalar, multiples) in scalars.iter().zip(table.chunks(self.num_windows)) {
+                    let digits = signed_digits(scalar, self.window_bits, self.num_windows);
+                    for (digit, point) in digits.into_iter().zip(multiples) {
+                        match digit.cmp(&0) {
+                            Ordering::Greater => {
+                                buckets[(digit - 1) as usize].add_assign_mixed(point)
+                            }
+                            Ordering::Less => {
+                                buckets[(-digit - 1) as usize].add_assign_mixed(&-*point)
+                            }
+                            Ordering::Equal => (),
+                        }
+                    }
+                }
+
+                // sum_k (k + 1) buckets[k], using a running sum
+                let mut running_sum = G::Projective::zero();
+                let mut res = G::Projective::zero();
+                for bucket in buckets.into_iter().rev() {
+                    running_sum += &bucket;
+                    res += &running_sum;
+                }
+                res
+            })
+            .reduce(G::Projective::zero, |a, b| a + b)
+    }
+}
+
+impl<G: AffineCurve> MsmBackend<G> for PrecomputedMsm<G> {
+    fn multi_scalar_mul(&self, bases: &[G], scalars: &[ScalarRepr<G>]) -> G::Projective {
+        let len = min(bases.len(), scalars.len());
+        let (bases, scalars) = (&bases[..len], &scalars[..len]);
+
+        let n = min(len, self.len());
+        if !bases[..n]
+            .iter()
+            .enumerate()
+            .all(|(i, b)| *b == self.base(i))
+        {
+            return ArkworksMsm.multi_scalar_mul(bases, scalars);
+        }
+
+        let mut res = self.fixed_base_msm(&scalars[..n]);
+        if len > n {
+            res += &ArkworksMsm.multi_scalar_mul(&bases[n..], &scalars[n..]);
+        }
+        res
+    }
+}
+
+/// Decomposes `scalar` in `num_windows` signed digits of `window_bits` bits,
+/// least significant first, each in `[-2^(window_bits - 1), 2^(window_bits - 1)]`.
+fn signed_digits<B: AsRef<[u64]>>(scalar: &B, window_bits: usize, num_windows: usize) -> Vec<i64> {
+    let limbs = scalar.as_ref();
+    let mask = (1u64 << window_bits) - 1;
+    let half = 1u64 << (window_bits - 1);
+
+    let mut digits = Vec::with_capacity(num_windows);
+    let mut carry = 0;
+    for window in 0..num_windows {
+        let bit = window * window_bits;
+        let (limb, offset) = (bit / 64, bit % 64);
+        let mut word = limbs.get(limb).map_or(0, |l| l >> offset);
+        if offset + window_bits > 64 {
+            word |= limbs.get(limb + 1).map_or(0, |l| l << (64 - offset));
+        }
+        let digit = (word & mask) + carry;
+        if digit > half {
+            digits.push(digit as i64 - (1 << window_bits));
+            carry = 1;
+        } else {
+            digits.push(digit as i64);
+            carry = 0;
+        }
+    }
+    debug_assert_eq!(carry, 0, "not enough windows to decompose the scalar");
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{BigInteger, UniformRand};
+    use mina_curves::pasta::{Fp, Vesta};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_signed_digits() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        for window_bits in [1, 3, 7, 8, 13, 16] {
+            let num_windows = Fp::size_in_bits() / window_bits + 1;
+            for _ in 0..10 {
+                let x = Fp::rand(rng);
+                let digits = signed_digits(&x.into_repr(), window_bits, num_windows);
+                let two_c = Fp::from(1u64 << window_bits);
+                let recomposed = digits.iter().rev().fold(Fp::zero(), |acc, d| {
+                    let d = if *d < 0 {
+                        -Fp::from(d.unsigned_abs())
+                    } else {
+                        Fp::from(*d as u64)
+                    };
+                    acc * two_c + d
+                });
+                assert_eq!(recomposed, x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_precomputed_msm() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let bases: Vec<Vesta> = (0..100)
+            .map(|_| {
+                Vesta::prime_subgroup_generator()
+                    .mul(Fp::rand(rng))
+                    .into_affine()
+            })
+            .collect();
+        let scalars: Vec<_> = (0..100).map(|_| Fp::rand(rng).into_repr()).collect();
+
+        let expected = ArkworksMsm.multi_scalar_mul(&bases, &scalars);
+
+        for window_bits in [1, 4, 8, 11] {
+            let backend = PrecomputedMsm::new(&bases[..60], window_bits);
+            assert_eq!(backend.len(), 60);
+
+            // more bases than precomputed
+            assert_eq!(backend.multi_scalar_mul(&bases, &scalars), expected);
+
+            // fewer scalars than precomputed bases
+            assert_eq!(
+                backend.multi_scalar_mul(&bases, &scalars[..30]),
+                ArkworksMsm.multi_scalar_mul(&bases[..30], &scalars[..30])
+            );
+
+            // bases that are not precomputed
+            assert_eq!(
+                backend.multi_scalar_mul(&bases[1..], &scalars[1..]),
+                ArkworksMsm.multi_scalar_mul(&bases[1..], &scalars[1..])
+            );
+        }
+
+        // edge-case scalars
+        let backend = PrecomputedMsm::new(&bases[..4], 5);
+        let scalars = [Fp::zero(), Fp::from(1u64), -Fp::from(1u64), Fp::from(16u64)]
+            .iter()
+            .map(|x| x.into_repr())
+            .collect::<Vec<_>>();
+        assert!(scalars[0].is_zero());
+        assert_eq!(
+            backend.multi_scalar_mul(&bases, &scalars),
+            ArkworksMsm.multi_scalar_mul(&bases, &scalars)
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use crate::commitment::*;
 use crate::evaluation_proof::combine_polys;
+use crate::msm::{ArkworksMsm, MsmBackend};
 use crate::srs::SRS;
 use crate::{CommitmentError, PolynomialsToCombine, SRS as SRSTrait};
-use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine};
-use ark_ff::{PrimeField, Zero};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{One, PrimeField, Zero};
 use ark_poly::{
     univariate::{DenseOrSparsePolynomial, DensePolynomial},
     EvaluationDomain, Evaluations, Polynomial, Radix2EvaluationDomain as D, UVPolynomial,
@@ -43,10 +44,20 @@ impl<Pair: PairingEngine> Clone for PairingProof<Pair> {
     }
 }
 
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    bound = "Pair::G1Affine: ark_serialize::CanonicalDeserialize + ark_serialize::CanonicalSerialize, Pair::G2Affine: ark_serialize::CanonicalDeserialize + ark_serialize::CanonicalSerialize"
+)]
 pub struct PairingSRS<Pair: PairingEngine> {
     pub full_srs: SRS<Pair::G1Affine>,
     pub verifier_srs: SRS<Pair::G2Affine>,
+    /// The power series `gamma x^i G1` of the blinding generator, used by
+    /// [HidingPairingProof]. Empty unless created with
+    /// [PairingSRS::create_hiding].
+    #[serde(default)]
+    #[serde_as(as = "Vec<o1_utils::serialization::SerdeAs>")]
+    pub h: Vec<Pair::G1Affine>,
 }
 
 impl<Pair: PairingEngine> Default for PairingSRS<Pair> {
@@ -54,6 +65,7 @@ impl<Pair: PairingEngine> Default for PairingSRS<Pair> {
         Self {
             full_srs: SRS::default(),
             verifier_srs: SRS::default(),
+            h: Vec::new(),
         }
     }
 }
@@ -63,6 +75,7 @@ impl<Pair: PairingEngine> Clone for PairingSRS<Pair> {
         Self {
             full_srs: self.full_srs.clone(),
             verifier_srs: self.verifier_srs.clone(),
+            h: self.h.clone(),
         }
     }
 }
@@ -78,8 +91,35 @@ impl<
         PairingSRS {
             full_srs: SRS::create_trusted_setup(x, n),
             verifier_srs: SRS::create_trusted_setup(x, 3),
+            h: Vec::new(),
         }
     }
+
+    /// Same as [PairingSRS::create], but with the power series
+    /// `h_i = gamma x^i G1` of the blinding generator `H = gamma G1`, as
+    /// required by [HidingPairingProof]. The blinding generator of
+    /// `full_srs` is set to `h_0`, so that commitments are blinded with `H`.
+    /// Both `x` and `gamma` are toxic waste.
+    pub fn create_hiding(x: F, gamma: F, n: usize) -> Self {
+        let mut srs = Self::create(x, n);
+        let mut h_pow = gamma;
+        srs.h = (0..n)
+            .map(|_| {
+                let res = G::prime_subgroup_generator().mul(h_pow);
+                h_pow *= x;
+                res.into_affine()
+            })
+            .collect();
+        srs.full_srs.h = srs.h[0];
+        srs
+    }
+
+    /// Commits to `plnm` in the basis of the blinding power series, i.e.
+    /// computes `[plnm(x)] H`.
+    fn commit_blinding(&self, plnm: &DensePolynomial<F>) -> G::Projective {
+        let scalars: Vec<_> = plnm.coeffs.iter().map(|c| c.into_repr()).collect();
+        ArkworksMsm.multi_scalar_mul(&self.h[..scalars.len()], &scalars)
+    }
 }
 
 impl<
@@ -219,44 +259,89 @@ impl<
 }
 
 /// The polynomial that evaluates to each of `evals` for the respective `elm`s.
-fn eval_polynomial<F: PrimeField>(elm: &[F], evals: &[F]) -> DensePolynomial<F> {
+pub(crate) fn eval_polynomial<F: PrimeField>(elm: &[F], evals: &[F]) -> DensePolynomial<F> {
     assert_eq!(elm.len(), evals.len());
-    let (zeta, zeta_omega) = if elm.len() == 2 {
-        (elm[0], elm[1])
-    } else {
-        todo!()
-    };
-    let (eval_zeta, eval_zeta_omega) = if evals.len() == 2 {
-        (evals[0], evals[1])
-    } else {
-        todo!()
-    };
 
-    // The polynomial that evaluates to `p(zeta)` at `zeta` and `p(zeta_omega)` at
-    // `zeta_omega`.
-    // We write `p(x) = a + bx`, which gives
+    // We use Lagrange interpolation, i.e. we write
     // ```text
-    // p(zeta) = a + b * zeta
-    // p(zeta_omega) = a + b * zeta_omega
+    // p(x) = sum_i evals[i] * prod_{j != i} (x - elm[j]) / (elm[i] - elm[j])
     // ```
-    // and so
-    // ```text
-    // b = (p(zeta_omega) - p(zeta)) / (zeta_omega - zeta)
-    // a = p(zeta) - b * zeta
-    // ```
-    let b = (eval_zeta_omega - eval_zeta) / (zeta_omega - zeta);
-    let a = eval_zeta - b * zeta;
-    DensePolynomial::from_coefficients_slice(&[a, b])
+    // With two points `zeta` and `zeta_omega`, this is the line going through
+    // `(zeta, p(zeta))` and `(zeta_omega, p(zeta_omega))`.
+    let mut res = vec![F::zero(); elm.len()];
+    for (i, (x_i, y_i)) in elm.iter().zip(evals.iter()).enumerate() {
+        // coefficients of prod_{j != i} (x - elm[j]), lowest degree first
+        let mut numerator = vec![F::one()];
+        let mut denominator = F::one();
+        for (j, x_j) in elm.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            numerator.insert(0, F::zero());
+            for k in 0..numerator.len() - 1 {
+                let c = numerator[k + 1];
+                numerator[k] -= c * x_j;
+            }
+            denominator *= *x_i - x_j;
+        }
+        let scale = *y_i / denominator;
+        for (r, c) in res.iter_mut().zip(numerator) {
+            *r += scale * c;
+        }
+    }
+    DensePolynomial::from_coefficients_vec(res)
 }
 
 /// The polynomial that evaluates to `0` at the evaluation points.
-fn divisor_polynomial<F: PrimeField>(elm: &[F]) -> DensePolynomial<F> {
+pub(crate) fn divisor_polynomial<F: PrimeField>(elm: &[F]) -> DensePolynomial<F> {
     elm.iter()
         .map(|value| DensePolynomial::from_coefficients_slice(&[-(*value), F::one()]))
         .reduce(|poly1, poly2| &poly1 * &poly2)
         .unwrap()
 }
 
+/// The quotient of `p - I` by the divisor polynomial of `elm`, where `I` is
+/// the polynomial interpolating `p` on `elm`.
+/// Returns `None` if the division fails.
+fn quotient_polynomial<F: PrimeField>(
+    p: &DensePolynomial<F>,
+    elm: &[F],
+) -> Option<DensePolynomial<F>> {
+    let evals: Vec<_> = elm.iter().map(|pt| p.evaluate(pt)).collect();
+    let eval_polynomial = eval_polynomial(elm, &evals);
+    let divisor_polynomial = divisor_polynomial(elm);
+    let numerator_polynomial = p - &eval_polynomial;
+    let (quotient, remainder) = DenseOrSparsePolynomial::divide_with_q_and_r(
+        &numerator_polynomial.into(),
+        &divisor_polynomial.into(),
+    )?;
+    if !remainder.is_zero() {
+        return None;
+    }
+    Some(quotient)
+}
+
+/// The commitments combined with the powers of `polyscale`, using the given
+/// [MsmBackend].
+fn combined_commitment<G: CommitmentCurve>(
+    evaluations: &[Evaluation<G>],
+    polyscale: G::ScalarField,
+    backend: &impl MsmBackend<G>,
+) -> G::Projective {
+    let mut scalars: Vec<G::ScalarField> = Vec::new();
+    let mut points = Vec::new();
+    combine_commitments(
+        evaluations,
+        &mut scalars,
+        &mut points,
+        polyscale,
+        G::ScalarField::one(), /* TODO: This is inefficient */
+    );
+    let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
+
+    backend.multi_scalar_mul(&points, &scalars)
+}
+
 impl<
         F: PrimeField,
         G: CommitmentCurve<ScalarField = F>,
@@ -271,21 +356,7 @@ impl<
         polyscale: F,                      // scaling factor for polynoms
     ) -> Option<Self> {
         let (p, blinding_factor) = combine_polys::<G, D>(plnms, polyscale, srs.full_srs.g.len());
-        let evals: Vec<_> = elm.iter().map(|pt| p.evaluate(pt)).collect();
-
-        let quotient_poly = {
-            let eval_polynomial = eval_polynomial(elm, &evals);
-            let divisor_polynomial = divisor_polynomial(elm);
-            let numerator_polynomial = &p - &eval_polynomial;
-            let (quotient, remainder) = DenseOrSparsePolynomial::divide_with_q_and_r(
-                &numerator_polynomial.into(),
-                &divisor_polynomial.into(),
-            )?;
-            if !remainder.is_zero() {
-                return None;
-            }
-            quotient
-        };
+        let quotient_poly = quotient_polynomial(&p, elm)?;
 
         let quotient = srs.full_srs.commit_non_hiding(&quotient_poly, 1).elems[0];
 
@@ -301,20 +372,25 @@ impl<
         polyscale: F,                     // scaling factor for polynoms
         elm: &[F],                        // vector of evaluation points
     ) -> bool {
-        let poly_commitment = {
-            let mut scalars: Vec<F> = Vec::new();
-            let mut points = Vec::new();
-            combine_commitments(
-                evaluations,
-                &mut scalars,
-                &mut points,
-                polyscale,
-                F::one(), /* TODO: This is inefficient */
-            );
-            let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
+        match &srs.full_srs.precomputed_table {
+            Some(table) => {
+                self.verify_with_backend(srs, evaluations, polyscale, elm, table.as_ref())
+            }
+            None => self.verify_with_backend(srs, evaluations, polyscale, elm, &ArkworksMsm),
+        }
+    }
 
-            VariableBaseMSM::multi_scalar_mul(&points, &scalars)
-        };
+    /// Same as [PairingProof::verify], using the given [MsmBackend] to
+    /// combine the commitments.
+    pub fn verify_with_backend(
+        &self,
+        srs: &PairingSRS<Pair>,           // SRS
+        evaluations: &Vec<Evaluation<G>>, // commitments to the polynomials
+        polyscale: F,                     // scaling factor for polynoms
+        elm: &[F],                        // vector of evaluation points
+        backend: &impl MsmBackend<G>,
+    ) -> bool {
+        let poly_commitment = combined_commitment(evaluations, polyscale, backend);
         let evals = combine_evaluations(evaluations, polyscale);
         let blinding_commitment = srs.full_srs.h.mul(self.blinding);
         let divisor_commitment = srs
@@ -337,11 +413,199 @@ impl<
     }
 }
 
+/// A KZG opening proof that does not reveal the blinding factors of the
+/// commitments, for SRSs created with [PairingSRS::create_hiding].
+///
+/// A commitment is `C = [f(x)] + [r(x)] H`, where `H = gamma G1` and `r` is
+/// the blinding polynomial, committed with the power series
+/// `h_i = gamma x^i G1` (kimchi's blinders are constant polynomials). With
+/// `I` the polynomial interpolating `f` on the evaluation points and `Z`
+/// their vanishing polynomial, the prover picks a random `s` and sends
+/// ```text
+/// W = [(f - I)(x) / Z(x)] + s H
+/// B = [(r - s Z)(x)] H
+/// ```
+/// and the verifier checks that `e(C - [I(x)] - B, G2) = e(W, [Z(x)]_2)`.
+/// `W` is uniformly distributed thanks to `s`, and `B` is then fully
+/// determined by the verification equation, so the proof leaks nothing
+/// about `f` besides its evaluations.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    bound = "Pair::G1Affine: ark_serialize::CanonicalDeserialize + ark_serialize::CanonicalSerialize"
+)]
+pub struct HidingPairingProof<Pair: PairingEngine> {
+    /// The blinded commitment to the quotient polynomial
+    #[serde_as(as = "o1_utils::serialization::SerdeAs")]
+    pub quotient: Pair::G1Affine,
+    /// The correction term for the blinding factors
+    #[serde_as(as = "o1_utils::serialization::SerdeAs")]
+    pub blinding: Pair::G1Affine,
+}
+
+impl<Pair: PairingEngine> Default for HidingPairingProof<Pair> {
+    fn default() -> Self {
+        Self {
+            quotient: Pair::G1Affine::prime_subgroup_generator(),
+            blinding: Pair::G1Affine::prime_subgroup_generator(),
+        }
+    }
+}
+
+impl<Pair: PairingEngine> Clone for HidingPairingProof<Pair> {
+    fn clone(&self) -> Self {
+        Self {
+            quotient: self.quotient,
+            blinding: self.blinding,
+        }
+    }
+}
+
+impl<
+        F: PrimeField,
+        G: CommitmentCurve<ScalarField = F>,
+        G2: CommitmentCurve<ScalarField = F>,
+        Pair: PairingEngine<G1Affine = G, G2Affine = G2>,
+    > crate::OpenProof<G> for HidingPairingProof<Pair>
+{
+    type SRS = PairingSRS<Pair>;
+
+    fn open<EFqSponge, RNG, D: EvaluationDomain<F>>(
+        srs: &Self::SRS,
+        _group_map: &<G as CommitmentCurve>::Map,
+        plnms: PolynomialsToCombine<G, D>, // vector of polynomial with commitment randomness (blinders)
+        elm: &[<G as AffineCurve>::ScalarField], // vector of evaluation points
+        polyscale: <G as AffineCurve>::ScalarField, // scaling factor for polynoms
+        _evalscale: <G as AffineCurve>::ScalarField, // scaling factor for evaluation point powers
+        _sponge: EFqSponge,                // sponge
+        rng: &mut RNG,
+    ) -> Self
+    where
+        EFqSponge: Clone + FqSponge<<G as AffineCurve>::BaseField, G, F>,
+        RNG: RngCore + CryptoRng,
+    {
+        HidingPairingProof::create(srs, plnms, elm, polyscale, rng).unwrap()
+    }
+
+    fn verify<EFqSponge, RNG>(
+        srs: &Self::SRS,
+        _group_map: &G::Map,
+        batch: &mut [BatchEvaluationProof<G, EFqSponge, Self>],
+        _rng: &mut RNG,
+    ) -> bool
+    where
+        EFqSponge: FqSponge<G::BaseField, G, F>,
+        RNG: RngCore + CryptoRng,
+    {
+        batch.iter().all(|proof| {
+            proof.opening.verify(
+                srs,
+                &proof.evaluations,
+                proof.polyscale,
+                &proof.evaluation_points,
+            )
+        })
+    }
+}
+
+impl<
+        F: PrimeField,
+        G: CommitmentCurve<ScalarField = F>,
+        G2: CommitmentCurve<ScalarField = F>,
+        Pair: PairingEngine<G1Affine = G, G2Affine = G2>,
+    > HidingPairingProof<Pair>
+{
+    pub fn create<D: EvaluationDomain<F>, RNG: RngCore + CryptoRng>(
+        srs: &PairingSRS<Pair>,
+        plnms: PolynomialsToCombine<G, D>, // vector of polynomial with optional degree bound and commitment randomness
+        elm: &[F],                         // vector of evaluation points
+        polyscale: F,                      // scaling factor for polynoms
+        rng: &mut RNG,
+    ) -> Option<Self> {
+        let (p, blinding_factor) = combine_polys::<G, D>(plnms, polyscale, srs.full_srs.g.len());
+        let quotient_poly = quotient_polynomial(&p, elm)?;
+
+        // the blinding power series must cover `r - s Z`
+        if srs.h.len() <= elm.len() {
+            return None;
+        }
+
+        let s = F::rand(rng);
+        let quotient = {
+            let mut quotient = srs.h[0].mul(s);
+            quotient.add_assign_mixed(&srs.full_srs.commit_non_hiding(&quotient_poly, 1).elems[0]);
+            quotient.into_affine()
+        };
+        let blinding = {
+            // r - s Z, with r the constant combined blinder
+            let mut blinding_poly = DensePolynomial::from_coefficients_vec(
+                divisor_polynomial(elm)
+                    .coeffs
+                    .into_iter()
+                    .map(|c| -c * s)
+                    .collect(),
+            );
+            blinding_poly.coeffs[0] += blinding_factor;
+            srs.commit_blinding(&blinding_poly).into_affine()
+        };
+
+        Some(HidingPairingProof { quotient, blinding })
+    }
+
+    pub fn verify(
+        &self,
+        srs: &PairingSRS<Pair>,           // SRS
+        evaluations: &Vec<Evaluation<G>>, // commitments to the polynomials
+        polyscale: F,                     // scaling factor for polynoms
+        elm: &[F],                        // vector of evaluation points
+    ) -> bool {
+        match &srs.full_srs.precomputed_table {
+            Some(table) => {
+                self.verify_with_backend(srs, evaluations, polyscale, elm, table.as_ref())
+            }
+            None => self.verify_with_backend(srs, evaluations, polyscale, elm, &ArkworksMsm),
+        }
+    }
+
+    /// Same as [HidingPairingProof::verify], using the given [MsmBackend] to
+    /// combine the commitments.
+    pub fn verify_with_backend(
+        &self,
+        srs: &PairingSRS<Pair>,           // SRS
+        evaluations: &Vec<Evaluation<G>>, // commitments to the polynomials
+        polyscale: F,                     // scaling factor for polynoms
+        elm: &[F],                        // vector of evaluation points
+        backend: &impl MsmBackend<G>,
+    ) -> bool {
+        let poly_commitment = combined_commitment(evaluations, polyscale, backend);
+        let evals = combine_evaluations(evaluations, polyscale);
+        let divisor_commitment = srs
+            .verifier_srs
+            .commit_non_hiding(&divisor_polynomial(elm), 1)
+            .elems[0];
+        let eval_commitment = srs
+            .full_srs
+            .commit_non_hiding(&eval_polynomial(elm, &evals), 1)
+            .elems[0]
+            .into_projective();
+        let mut numerator_commitment = poly_commitment - eval_commitment;
+        numerator_commitment.add_assign_mixed(&-self.blinding);
+
+        let numerator = Pair::pairing(
+            numerator_commitment,
+            Pair::G2Affine::prime_subgroup_generator(),
+        );
+        let scaled_quotient = Pair::pairing(self.quotient, divisor_commitment);
+        numerator == scaled_quotient
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{PairingProof, PairingSRS};
+    use super::{eval_polynomial, HidingPairingProof, PairingProof, PairingSRS};
     use crate::commitment::Evaluation;
     use crate::evaluation_proof::DensePolynomialOrEvaluations;
+    use crate::msm::PrecomputedMsm;
     use crate::srs::SRS;
     use crate::SRS as _;
     use ark_bn254::Fr as ScalarField;
@@ -371,6 +635,7 @@ mod tests {
         let srs = PairingSRS {
             full_srs: srs,
             verifier_srs,
+            h: vec![],
         };
 
         let polynomials: Vec<_> = (0..4)
@@ -426,5 +691,116 @@ mod tests {
 
         let res = pairing_proof.verify(&srs, &evaluations, polyscale, &evaluation_points);
         assert!(res);
+
+        // the commitments are combined with the backend
+        let bases: Vec<_> = evaluations
+            .iter()
+            .flat_map(|e| e.commitment.elems.clone())
+            .collect();
+        let res = pairing_proof.verify_with_backend(
+            &srs,
+            &evaluations,
+            polyscale,
+            &evaluation_points,
+            &PrecomputedMsm::new(&bases, 4),
+        );
+        assert!(res);
+    }
+
+    #[test]
+    fn test_eval_polynomial() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        for n in 1..5 {
+            let elm: Vec<_> = (0..n).map(|_| ScalarField::rand(rng)).collect();
+            let evals: Vec<_> = (0..n).map(|_| ScalarField::rand(rng)).collect();
+            let p = eval_polynomial(&elm, &evals);
+            assert!(p.degree() < n);
+            for (x, y) in elm.iter().zip(evals.iter()) {
+                assert_eq!(p.evaluate(x), *y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hiding_pairing_proof() {
+        let n = 64;
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+
+        let srs = PairingSRS::<Bn<Parameters>>::create_hiding(
+            ScalarField::rand(rng),
+            ScalarField::rand(rng),
+            n,
+        );
+
+        let polynomials: Vec<_> = (0..4)
+            .map(|_| {
+                let coeffs = (0..63).map(|_| ScalarField::rand(rng)).collect();
+                DensePolynomial::from_coefficients_vec(coeffs)
+            })
+            .collect();
+        let comms: Vec<_> = polynomials
+            .iter()
+            .map(|p| srs.full_srs.commit(p, 1, rng))
+            .collect();
+
+        let polynomials_and_blinders: Vec<(DensePolynomialOrEvaluations<_, D<_>>, _)> = polynomials
+            .iter()
+            .zip(comms.iter())
+            .map(|(p, comm)| {
+                let p = DensePolynomialOrEvaluations::DensePolynomial(p);
+                (p, comm.blinders.clone())
+            })
+            .collect();
+
+        let evaluation_points = vec![ScalarField::rand(rng), ScalarField::rand(rng)];
+
+        let mut evaluations: Vec<_> = polynomials
+            .iter()
+            .zip(comms)
+            .map(|(p, commitment)| Evaluation {
+                commitment: commitment.commitment,
+                evaluations: evaluation_points
+                    .iter()
+                    .map(|x| vec![p.evaluate(x)])
+                    .collect(),
+            })
+            .collect();
+
+        let polyscale = ScalarField::rand(rng);
+
+        let proof = HidingPairingProof::<Bn<Parameters>>::create(
+            &srs,
+            polynomials_and_blinders.as_slice(),
+            &evaluation_points,
+            polyscale,
+            rng,
+        )
+        .unwrap();
+
+        // the commitments are combined with the backend, with the same result
+        let bases: Vec<_> = evaluations
+            .iter()
+            .flat_map(|e| e.commitment.elems.clone())
+            .collect();
+        let backend = PrecomputedMsm::new(&bases, 4);
+        assert!(proof.verify(&srs, &evaluations, polyscale, &evaluation_points));
+        assert!(proof.verify_with_backend(
+            &srs,
+            &evaluations,
+            polyscale,
+            &evaluation_points,
+            &backend
+        ));
+
+        // a wrong evaluation is rejected
+        evaluations[2].evaluations[1][0] += ScalarField::from(1u64);
+        assert!(!proof.verify(&srs, &evaluations, polyscale, &evaluation_points));
+        assert!(!proof.verify_with_backend(
+            &srs,
+            &evaluations,
+            polyscale,
+            &evaluation_points,
+            &backend
+        ));
     }
 }
@@ -0,0 +1,561 @@
+//! This module implements the multi-point opening of KZG commitments described
+//! in [BDFG20](https://eprint.iacr.org/2020/081), section 4 ("shplonk").
+//!
+//! Each polynomial `f_i` can be opened at its own set of points `S_i`, and the
+//! proof is made of two group elements regardless of the number of
+//! polynomials and points.
+//! Writing `T` the union of all the `S_i`, `Z_S` the vanishing polynomial of a
+//! set `S`, `r_i` the polynomial interpolating the claimed evaluations of
+//! `f_i` on `S_i`, and `gamma` the `polyscale`, the prover
+//!
+//! 1. commits to `h = sum_i gamma^i Z_{T \ S_i} (f_i - r_i) / Z_T` as `W`,
+//! 2. squeezes a challenge `z` from the sponge after absorbing `W`,
+//! 3. commits to `L / (X - z)` as `W'`, where
+//!    `L = sum_i gamma^i Z_{T \ S_i}(z) (f_i - r_i(z)) - Z_T(z) h`.
+//!
+//! The verifier computes the commitment `F` to `L` from the commitments to
+//! the `f_i`, and checks that `e(F + z W', [1]) = e(W', [x])`.
+//!
+//! Chunked commitments are handled by considering each chunk as a separate
+//! polynomial opened at the same points, in the same order as
+//! [crate::commitment::combine_commitments].
+
+use crate::commitment::*;
+use crate::msm::{ArkworksMsm, MsmBackend};
+use crate::pairing_proof::{divisor_polynomial, eval_polynomial, PairingSRS};
+use crate::{evaluation_proof::DensePolynomialOrEvaluations, PolynomialsToCombine, SRS as _};
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_ff::{PrimeField, Zero};
+use ark_poly::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    EvaluationDomain, Evaluations, Polynomial, UVPolynomial,
+};
+use mina_poseidon::FqSponge;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// A KZG opening proof of many polynomials, each at its own set of points.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    bound = "Pair::G1Affine: ark_serialize::CanonicalDeserialize + ark_serialize::CanonicalSerialize"
+)]
+pub struct ShplonkProof<Pair: PairingEngine> {
+    /// The commitment `W` to the quotient by the vanishing polynomial of all
+    /// the evaluation points
+    #[serde_as(as = "o1_utils::serialization::SerdeAs")]
+    pub quotient: Pair::G1Affine,
+    /// The commitment `W'` to the quotient of the linearized polynomial by
+    /// `X - z`
+    #[serde_as(as = "o1_utils::serialization::SerdeAs")]
+    pub opening: Pair::G1Affine,
+    #[serde_as(as = "o1_utils::serialization::SerdeAs")]
+    pub blinding: <Pair::G1Affine as AffineCurve>::ScalarField,
+}
+
+impl<Pair: PairingEngine> Default for ShplonkProof<Pair> {
+    fn default() -> Self {
+        Self {
+            quotient: Pair::G1Affine::prime_subgroup_generator(),
+            opening: Pair::G1Affine::prime_subgroup_generator(),
+            blinding: <Pair::G1Affine as AffineCurve>::ScalarField::zero(),
+        }
+    }
+}
+
+impl<Pair: PairingEngine> Clone for ShplonkProof<Pair> {
+    fn clone(&self) -> Self {
+        Self {
+            quotient: self.quotient,
+            opening: self.opening,
+            blinding: self.blinding,
+        }
+    }
+}
+
+/// A polynomial to open at the given `points`, on the prover side.
+pub struct PolynomialQuery<'a, F: PrimeField, D: EvaluationDomain<F>> {
+    pub polynomial: DensePolynomialOrEvaluations<'a, F, D>,
+    /// The blinders used to commit to the polynomial, one per chunk
+    pub blinders: PolyComm<F>,
+    pub points: Vec<F>,
+}
+
+/// A commitment opened at the given `points`, on the verifier side.
+pub struct CommitmentQuery<G: AffineCurve> {
+    pub commitment: PolyComm<G>,
+    pub points: Vec<G::ScalarField>,
+    /// The evaluations of each chunk, per evaluation point
+    pub evaluations: Vec<Vec<G::ScalarField>>,
+}
+
+/// The polynomials sharing the same set of evaluation points, combined with
+/// their respective powers of `polyscale`.
+struct PointSet<F: PrimeField> {
+    points: Vec<F>,
+    /// `sum_i gamma^i f_i`
+    polynomial: DensePolynomial<F>,
+    /// `sum_i gamma^i r_i`
+    interpolant: DensePolynomial<F>,
+    /// `sum_i gamma^i b_i`
+    blinding: F,
+}
+
+impl<F: PrimeField> PointSet<F> {
+    /// Returns the set for `points`, creating it if needed.
+    fn find_or_insert<'a>(sets: &'a mut Vec<Self>, points: &[F]) -> &'a mut Self {
+        match sets.iter().position(|set| set.points == points) {
+            Some(i) => &mut sets[i],
+            None => {
+                sets.push(PointSet {
+                    points: points.to_vec(),
+                    polynomial: DensePolynomial::zero(),
+                    interpolant: DensePolynomial::zero(),
+                    blinding: F::zero(),
+                });
+                sets.last_mut().unwrap()
+            }
+        }
+    }
+}
+
+/// The union of the given sets of points.
+fn all_points<'a, F: PrimeField>(sets: impl Iterator<Item = &'a [F]>) -> Vec<F> {
+    let mut res: Vec<F> = vec![];
+    for x in sets.flatten() {
+        if !res.contains(x) {
+            res.push(*x)
+        }
+    }
+    res
+}
+
+/// The vanishing polynomial of `all \ points`, evaluated at `z`.
+fn complement_vanishing_eval<F: PrimeField>(all: &[F], points: &[F], z: F) -> F {
+    all.iter()
+        .filter(|x| !points.contains(x))
+        .fold(F::one(), |acc, x| acc * (z - x))
+}
+
+/// The vanishing polynomial of `all \ points`.
+fn complement_vanishing_polynomial<F: PrimeField>(all: &[F], points: &[F]) -> DensePolynomial<F> {
+    let complement: Vec<_> = all
+        .iter()
+        .filter(|x| !points.contains(x))
+        .copied()
+        .collect();
+    if complement.is_empty() {
+        DensePolynomial::from_coefficients_vec(vec![F::one()])
+    } else {
+        divisor_polynomial(&complement)
+    }
+}
+
+impl<
+        F: PrimeField,
+        G: CommitmentCurve<ScalarField = F>,
+        G2: CommitmentCurve<ScalarField = F>,
+        Pair: PairingEngine<G1Affine = G, G2Affine = G2>,
+    > ShplonkProof<Pair>
+{
+    /// Creates a proof that each of the `queries` polynomials evaluates to
+    /// its committed values at its respective points.
+    /// The `sponge` must have absorbed the commitments and the evaluations
+    /// beforehand.
+    ///
+    /// Returns `None` if some polynomial cannot be opened, i.e. if the
+    /// evaluation points are not distinct, if it has more chunks than
+    /// blinders or if the quotient computation fails.
+    pub fn create<D: EvaluationDomain<F>, EFqSponge: FqSponge<G::BaseField, G, F>>(
+        srs: &PairingSRS<Pair>,
+        queries: &[PolynomialQuery<F, D>],
+        polyscale: F,
+        mut sponge: EFqSponge,
+    ) -> Option<Self> {
+        let srs_length = srs.full_srs.g.len();
+
+        // Group the chunks by set of evaluation points, scaling them by
+        // successive powers of polyscale.
+        let mut sets: Vec<PointSet<F>> = vec![];
+        let mut scale = F::one();
+        for PolynomialQuery {
+            polynomial,
+            blinders,
+            points,
+        } in queries
+        {
+            let polynomial = match polynomial {
+                DensePolynomialOrEvaluations::DensePolynomial(p) => (*p).clone(),
+                DensePolynomialOrEvaluations::Evaluations(evals, sub_domain) => {
+                    let stride = evals.evals.len() / sub_domain.size();
+                    let evals = evals.evals.iter().step_by(stride).copied().collect();
+                    Evaluations::from_vec_and_domain(evals, *sub_domain).interpolate()
+                }
+            };
+            // each chunk of the polynomial has its blinder
+            if polynomial.coeffs.len() > blinders.elems.len() * srs_length {
+                return None;
+            }
+            let set = PointSet::find_or_insert(&mut sets, points);
+            for (j, blinder) in blinders.elems.iter().enumerate() {
+                let start = std::cmp::min(j * srs_length, polynomial.coeffs.len());
+                let end = std::cmp::min((j + 1) * srs_length, polynomial.coeffs.len());
+                let chunk =
+                    DensePolynomial::from_coefficients_slice(&polynomial.coeffs[start..end]);
+                let evals: Vec<_> = points.iter().map(|x| chunk.evaluate(x)).collect();
+                let interpolant = eval_polynomial(points, &evals);
+
+                set.polynomial += (scale, &chunk);
+                set.interpolant += (scale, &interpolant);
+                set.blinding += scale * blinder;
+                scale *= polyscale;
+            }
+        }
+
+        let points = all_points(sets.iter().map(|set| set.points.as_slice()));
+        let vanishing = divisor_polynomial(&points);
+
+        // h = sum_i gamma^i Z_{T \ S_i} (f_i - r_i) / Z_T
+        let quotient_poly = {
+            let mut numerator = DensePolynomial::zero();
+            for set in sets.iter() {
+                let complement = complement_vanishing_polynomial(&points, &set.points);
+                numerator += &(&complement * &(&set.polynomial - &set.interpolant));
+            }
+            let (quotient, remainder) = DenseOrSparsePolynomial::divide_with_q_and_r(
+                &numerator.into(),
+                &vanishing.clone().into(),
+            )?;
+            if !remainder.is_zero() {
+                return None;
+            }
+            quotient
+        };
+        let quotient = srs.full_srs.commit_non_hiding(&quotient_poly, 1).elems[0];
+
+        sponge.absorb_g(&[quotient]);
+        let z = sponge.challenge();
+
+        // L = sum_i gamma^i Z_{T \ S_i}(z) (f_i - r_i(z)) - Z_T(z) h
+        let mut blinding = F::zero();
+        let opening_poly = {
+            let mut linearized = DensePolynomial::zero();
+            let mut constant = F::zero();
+            for set in sets.iter() {
+                let scale = complement_vanishing_eval(&points, &set.points, z);
+                linearized += (scale, &set.polynomial);
+                constant += scale * set.interpolant.evaluate(&z);
+                blinding += scale * set.blinding;
+            }
+            linearized += (-vanishing.evaluate(&z), &quotient_poly);
+            linearized -= &DensePolynomial::from_coefficients_vec(vec![constant]);
+
+            let divisor = DensePolynomial::from_coefficients_vec(vec![-z, F::one()]);
+            let (quotient, remainder) =
+                DenseOrSparsePolynomial::divide_with_q_and_r(&linearized.into(), &divisor.into())?;
+            if !remainder.is_zero() {
+                return None;
+            }
+            quotient
+        };
+        let opening = srs.full_srs.commit_non_hiding(&opening_poly, 1).elems[0];
+
+        Some(ShplonkProof {
+            quotient,
+            opening,
+            blinding,
+        })
+    }
+
+    /// Verifies that each of the `queries` commitments opens to its
+    /// evaluations at its respective points.
+    /// The `sponge` must be in the same state as the one given to
+    /// [ShplonkProof::create].
+    pub fn verify<EFqSponge: FqSponge<G::BaseField, G, F>>(
+        &self,
+        srs: &PairingSRS<Pair>,
+        queries: &[CommitmentQuery<G>],
+        polyscale: F,
+        sponge: &mut EFqSponge,
+    ) -> bool {
+        if srs.verifier_srs.g.len() < 2 || srs.full_srs.g.is_empty() {
+            return false;
+        }
+
+        let points = all_points(queries.iter().map(|q| q.points.as_slice()));
+        if points.is_empty() {
+            return false;
+        }
+
+        sponge.absorb_g(&[self.quotient]);
+        let z = sponge.challenge();
+
+        // F = sum_i gamma^i Z_{T \ S_i}(z) ([f_i] - r_i(z) [1]) - Z_T(z) W - blinding H
+        let mut scalars: Vec<F> = vec![];
+        let mut bases: Vec<G> = vec![];
+        let mut constant = F::zero();
+        let mut scale = F::one();
+        for CommitmentQuery {
+            commitment,
+            points: query_points,
+            evaluations,
+        } in queries
+        {
+            if evaluations.len() != query_points.len()
+                || evaluations
+                    .iter()
+                    .any(|e| e.len() != commitment.elems.len())
+            {
+                return false;
+            }
+            let vanishing_scale = complement_vanishing_eval(&points, query_points, z);
+            for (j, chunk) in commitment.elems.iter().enumerate() {
+                let evals: Vec<_> = evaluations.iter().map(|e| e[j]).collect();
+                let interpolant = eval_polynomial(query_points, &evals);
+                let chunk_scale = scale * vanishing_scale;
+
+                bases.push(*chunk);
+                scalars.push(chunk_scale);
+                constant += chunk_scale * interpolant.evaluate(&z);
+                scale *= polyscale;
+            }
+        }
+        let vanishing_eval = points.iter().fold(F::one(), |acc, x| acc * (z - x));
+
+        // the generator of G1 is the first element of the SRS
+        bases.push(srs.full_srs.g[0]);
+        scalars.push(-constant);
+        bases.push(self.quotient);
+        scalars.push(-vanishing_eval);
+        bases.push(srs.full_srs.h);
+        scalars.push(-self.blinding);
+        // F + z W'
+        bases.push(self.opening);
+        scalars.push(z);
+
+        let scalars: Vec<_> = scalars.iter().map(|x| x.into_repr()).collect();
+        let lhs = ArkworksMsm.multi_scalar_mul(&bases, &scalars);
+
+        Pair::pairing(lhs, srs.verifier_srs.g[0])
+            == Pair::pairing(self.opening, srs.verifier_srs.g[1])
+    }
+}
+
+impl<
+        F: PrimeField,
+        G: CommitmentCurve<ScalarField = F>,
+        G2: CommitmentCurve<ScalarField = F>,
+        Pair: PairingEngine<G1Affine = G, G2Affine = G2>,
+    > crate::OpenProof<G> for ShplonkProof<Pair>
+{
+    type SRS = PairingSRS<Pair>;
+
+    fn open<EFqSponge, RNG, D: EvaluationDomain<F>>(
+        srs: &Self::SRS,
+        _group_map: &<G as CommitmentCurve>::Map,
+        plnms: PolynomialsToCombine<G, D>, // vector of polynomial with commitment randomness (blinders)
+        elm: &[<G as AffineCurve>::ScalarField], // vector of evaluation points
+        polyscale: <G as AffineCurve>::ScalarField, // scaling factor for polynoms
+        _evalscale: <G as AffineCurve>::ScalarField, // scaling factor for evaluation point powers
+        sponge: EFqSponge,                 // sponge
+        _rng: &mut RNG,
+    ) -> Self
+    where
+        EFqSponge: Clone + FqSponge<<G as AffineCurve>::BaseField, G, F>,
+        RNG: RngCore + CryptoRng,
+    {
+        let queries: Vec<_> = plnms
+            .iter()
+            .map(|(polynomial, blinders)| PolynomialQuery {
+                polynomial: match polynomial {
+                    DensePolynomialOrEvaluations::DensePolynomial(p) => {
+                        DensePolynomialOrEvaluations::DensePolynomial(*p)
+                    }
+                    DensePolynomialOrEvaluations::Evaluations(e, d) => {
+                        DensePolynomialOrEvaluations::Evaluations(*e, *d)
+                    }
+                },
+                blinders: blinders.clone(),
+                points: elm.to_vec(),
+            })
+            .collect();
+        ShplonkProof::create(srs, &queries, polyscale, sponge).unwrap()
+    }
+
+    fn verify<EFqSponge, RNG>(
+        srs: &Self::SRS,
+        _group_map: &G::Map,
+        batch: &mut [BatchEvaluationProof<G, EFqSponge, Self>],
+        _rng: &mut RNG,
+    ) -> bool
+    where
+        EFqSponge: FqSponge<G::BaseField, G, F>,
+        RNG: RngCore + CryptoRng,
+    {
+        for BatchEvaluationProof {
+            sponge,
+            evaluations,
+            evaluation_points,
+            polyscale,
+            evalscale: _,
+            opening,
+            combined_inner_product: _,
+        } in batch.iter_mut()
+        {
+            let queries: Vec<_> = evaluations
+                .iter()
+                .map(
+                    |Evaluation {
+                         commitment,
+                         evaluations,
+                     }| CommitmentQuery {
+                        commitment: commitment.clone(),
+                        points: evaluation_points.clone(),
+                        evaluations: evaluations.clone(),
+                    },
+                )
+                .collect();
+            if !opening.verify(srs, &queries, *polyscale, sponge) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::srs::SRS;
+    use ark_bn254::{Fr as ScalarField, G1Affine as G1, G2Affine as G2, Parameters};
+    use ark_ec::bn::Bn;
+    use ark_ff::{One, UniformRand};
+    use ark_poly::Radix2EvaluationDomain as D;
+    use mina_poseidon::{constants::PlonkSpongeConstantsKimchi, sponge::DefaultFqSponge};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    type Proof = ShplonkProof<Bn<Parameters>>;
+    type Sponge = DefaultFqSponge<ark_bn254::g1::Parameters, PlonkSpongeConstantsKimchi>;
+
+    fn sponge() -> Sponge {
+        Sponge::new(mina_poseidon::bn254::fq_kimchi::static_params())
+    }
+
+    fn create_srs(n: usize, rng: &mut StdRng) -> PairingSRS<Bn<Parameters>> {
+        let domain = D::<ScalarField>::new(n).unwrap();
+        let x = ScalarField::rand(rng);
+        let mut full_srs = SRS::<G1>::create_trusted_setup(x, n);
+        full_srs.add_lagrange_basis(domain);
+        PairingSRS {
+            full_srs,
+            verifier_srs: SRS::<G2>::create_trusted_setup(x, 3),
+            h: vec![],
+        }
+    }
+
+    #[test]
+    fn test_shplonk_proof() {
+        let n = 64;
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let srs = create_srs(n, rng);
+
+        let shared_point = ScalarField::rand(rng);
+        let point_sets: Vec<Vec<_>> = vec![
+            vec![ScalarField::rand(rng)],
+            vec![shared_point, ScalarField::rand(rng)],
+            vec![shared_point],
+            vec![ScalarField::rand(rng), ScalarField::rand(rng), shared_point],
+            vec![shared_point],
+        ];
+
+        // one of the polynomials is split in two chunks
+        let polynomials: Vec<_> = [63, 20, 64, 1, 100]
+            .iter()
+            .map(|&len| {
+                let coeffs = (0..len).map(|_| ScalarField::rand(rng)).collect();
+                DensePolynomial::from_coefficients_vec(coeffs)
+            })
+            .collect();
+        let comms: Vec<_> = polynomials
+            .iter()
+            .map(|p| srs.full_srs.commit(p, 1, rng))
+            .collect();
+
+        let queries: Vec<PolynomialQuery<_, D<_>>> = polynomials
+            .iter()
+            .zip(comms.iter())
+            .zip(point_sets.iter())
+            .map(|((p, comm), points)| PolynomialQuery {
+                polynomial: DensePolynomialOrEvaluations::DensePolynomial(p),
+                blinders: comm.blinders.clone(),
+                points: points.clone(),
+            })
+            .collect();
+
+        let mut commitment_queries: Vec<_> = polynomials
+            .iter()
+            .zip(comms.iter())
+            .zip(point_sets.iter())
+            .map(|((p, comm), points)| CommitmentQuery {
+                commitment: comm.commitment.clone(),
+                points: points.clone(),
+                evaluations: points
+                    .iter()
+                    .map(|x| {
+                        p.coeffs
+                            .chunks(n)
+                            .map(|c| DensePolynomial::from_coefficients_slice(c).evaluate(x))
+                            .collect()
+                    })
+                    .collect(),
+            })
+            .collect();
+        assert_eq!(commitment_queries[4].commitment.elems.len(), 2);
+
+        let polyscale = ScalarField::rand(rng);
+        let proof = Proof::create(&srs, &queries, polyscale, sponge()).unwrap();
+        assert!(proof.verify(&srs, &commitment_queries, polyscale, &mut sponge()));
+
+        // a wrong evaluation is rejected
+        commitment_queries[3].evaluations[1][0] += ScalarField::one();
+        assert!(!proof.verify(&srs, &commitment_queries, polyscale, &mut sponge()));
+        commitment_queries[3].evaluations[1][0] -= ScalarField::one();
+
+        // a wrong polyscale or sponge is rejected
+        assert!(!proof.verify(
+            &srs,
+            &commitment_queries,
+            polyscale + polyscale,
+            &mut sponge()
+        ));
+        let mut other_sponge = sponge();
+        other_sponge.absorb_fr(&[polyscale]);
+        assert!(!proof.verify(&srs, &commitment_queries, polyscale, &mut other_sponge));
+    }
+
+    #[test]
+    fn test_shplonk_missing_blinders() {
+        let n = 64;
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let srs = create_srs(n, rng);
+
+        // a polynomial of two chunks, with the blinder of its first chunk only
+        let coeffs = (0..100).map(|_| ScalarField::rand(rng)).collect();
+        let polynomial = DensePolynomial::from_coefficients_vec(coeffs);
+        let mut blinders = srs.full_srs.commit(&polynomial, 1, rng).blinders;
+        let point = ScalarField::rand(rng);
+        let queries = |blinders: &PolyComm<ScalarField>| -> Vec<PolynomialQuery<_, D<_>>> {
+            vec![PolynomialQuery {
+                polynomial: DensePolynomialOrEvaluations::DensePolynomial(&polynomial),
+                blinders: blinders.clone(),
+                points: vec![point],
+            }]
+        };
+        let polyscale = ScalarField::rand(rng);
+        assert!(Proof::create(&srs, &queries(&blinders), polyscale, sponge()).is_some());
+
+        blinders.elems.truncate(1);
+        assert!(Proof::create(&srs, &queries(&blinders), polyscale, sponge()).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! This module implements the Marlin structured reference string primitive
 
 use crate::commitment::CommitmentCurve;
+use crate::msm::PrecomputedMsm;
 use crate::PolyComm;
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
@@ -14,6 +15,7 @@ use serde_with::serde_as;
 use std::array;
 use std::cmp::min;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[serde_as]
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Eq)]
@@ -30,6 +32,11 @@ pub struct SRS<G> {
     /// Commitments to Lagrange bases, per domain size
     #[serde(skip)]
     pub lagrange_bases: HashMap<usize, Vec<PolyComm<G>>>,
+
+    /// Optional precomputed multiples of `g`, used to speed up commitments
+    /// and opening proof verification (see [SRS::add_precomputed_table])
+    #[serde(skip)]
+    pub precomputed_table: Option<Arc<PrecomputedMsm<G>>>,
 }
 
 impl<G> PartialEq for SRS<G>
@@ -95,6 +102,28 @@ impl<G: CommitmentCurve> SRS<G> {
         self.g.len()
     }
 
+    /// Precompute multiples of the `g` vector with windows of `window_bits`
+    /// bits, and use them for subsequent commitments in coefficient form and
+    /// opening proof verifications.
+    ///
+    /// The table holds `(255 / window_bits + 1) * g.len()` points for 255-bit
+    /// scalar fields, so larger windows trade commitment speed for memory.
+    /// Windows of 8 to 12 bits are usually a good compromise.
+    /// Commitments are identical with and without the table.
+    pub fn add_precomputed_table(&mut self, window_bits: usize) {
+        if let Some(table) = &self.precomputed_table {
+            if table.window_bits() == window_bits {
+                return;
+            }
+        }
+        self.precomputed_table = Some(Arc::new(PrecomputedMsm::new(&self.g, window_bits)));
+    }
+
+    /// Drop the table computed by [SRS::add_precomputed_table], if any.
+    pub fn remove_precomputed_table(&mut self) {
+        self.precomputed_table = None;
+    }
+
     /// Compute commitments to the lagrange basis corresponding to the given domain and
     /// cache them in the SRS
     pub fn add_lagrange_basis(&mut self, domain: D<G::ScalarField>) {
@@ -232,6 +261,7 @@ impl<G: CommitmentCurve> SRS<G> {
             g,
             h,
             lagrange_bases: HashMap::new(),
+            precomputed_table: None,
         }
     }
 }
@@ -261,6 +291,7 @@ impl<G: CommitmentCurve> SRS<G> {
             g,
             h,
             lagrange_bases: HashMap::new(),
+            precomputed_table: None,
         }
     }
 }
@@ -295,6 +326,7 @@ where
             g,
             h,
             lagrange_bases: HashMap::new(),
+            precomputed_table: None,
         }
     }
 }
@@ -0,0 +1,114 @@
+use ark_ec::AffineCurve;
+use ark_ff::UniformRand;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mina_curves::pasta::{Fp, Vesta, VestaParameters};
+use mina_poseidon::{
+    constants::{
+        PlonkSpongeConstantsKimchi, PlonkSpongeConstantsKimchiPartial,
+        PlonkSpongeConstantsPoseidon2, SpongeConstants,
+    },
+    pasta,
+    poseidon::{ArithmeticSponge, ArithmeticSpongeParams, Sponge as _},
+    sponge::DefaultFqSponge,
+    FqSponge as _,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Hashes pairs of nodes up to the root of a binary Merkle tree.
+fn merkle_root<SC: SpongeConstants>(
+    params: &'static ArithmeticSpongeParams<Fp>,
+    leaves: &[Fp],
+) -> Fp {
+    let mut sponge = ArithmeticSponge::<Fp, SC>::new(params);
+    let mut layer = leaves.to_vec();
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| {
+                sponge.reset();
+                sponge.absorb(pair);
+                sponge.squeeze()
+            })
+            .collect();
+    }
+    layer[0]
+}
+
+/// Absorbs commitments and squeezes challenges, as a transcript does.
+fn transcript<SC: SpongeConstants + Clone>(
+    params: &'static ArithmeticSpongeParams<<Vesta as AffineCurve>::BaseField>,
+    points: &[Vesta],
+) -> <Vesta as AffineCurve>::ScalarField {
+    let mut sponge = DefaultFqSponge::<VestaParameters, SC>::new(params);
+    for chunk in points.chunks(10) {
+        sponge.absorb_g(chunk);
+        sponge.challenge();
+    }
+    sponge.challenge()
+}
+
+pub fn bench_poseidon(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Poseidon");
+    let rng = &mut StdRng::from_seed([0u8; 32]);
+
+    let leaves: Vec<Fp> = (0..1 << 10).map(|_| Fp::rand(rng)).collect();
+    group.bench_function("merkle root, 2^10 leaves (kimchi)", |b| {
+        b.iter(|| {
+            merkle_root::<PlonkSpongeConstantsKimchi>(
+                pasta::fp_kimchi::static_params(),
+                black_box(&leaves),
+            )
+        })
+    });
+    group.bench_function("merkle root, 2^10 leaves (kimchi partial)", |b| {
+        b.iter(|| {
+            merkle_root::<PlonkSpongeConstantsKimchiPartial>(
+                pasta::fp_kimchi_partial::static_params(),
+                black_box(&leaves),
+            )
+        })
+    });
+    group.bench_function("merkle root, 2^10 leaves (poseidon2)", |b| {
+        b.iter(|| {
+            merkle_root::<PlonkSpongeConstantsPoseidon2>(
+                pasta::fp_poseidon2::static_params(),
+                black_box(&leaves),
+            )
+        })
+    });
+
+    let points: Vec<Vesta> = (0..100)
+        .map(|_| {
+            Vesta::prime_subgroup_generator()
+                .mul(<Vesta as AffineCurve>::ScalarField::rand(rng))
+                .into()
+        })
+        .collect();
+    group.bench_function("transcript, 100 points (kimchi)", |b| {
+        b.iter(|| {
+            transcript::<PlonkSpongeConstantsKimchi>(
+                pasta::fq_kimchi::static_params(),
+                black_box(&points),
+            )
+        })
+    });
+    group.bench_function("transcript, 100 points (kimchi partial)", |b| {
+        b.iter(|| {
+            transcript::<PlonkSpongeConstantsKimchiPartial>(
+                pasta::fq_kimchi_partial::static_params(),
+                black_box(&points),
+            )
+        })
+    });
+    group.bench_function("transcript, 100 points (poseidon2)", |b| {
+        b.iter(|| {
+            transcript::<PlonkSpongeConstantsPoseidon2>(
+                pasta::fq_poseidon2::static_params(),
+                black_box(&points),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, bench_poseidon);
+criterion_main!(benches);
@@ -1,7 +1,7 @@
 mod vectors;
 use inner::*;
 
-/// "Usage: cargo run --all-features --bin export_test_vectors -- [hex|b10] [legacy|kimchi] <OUTPUT_FILE>",
+/// "Usage: cargo run --all-features --bin export_test_vectors -- [hex|b10] [legacy|kimchi|kimchi_w5|kimchi_w9|kimchi_partial|poseidon2|bn254_kimchi|bn254_poseidon2] <OUTPUT_FILE>",
 fn main() {
     inner::main();
 }
@@ -35,6 +35,12 @@ mod inner {
     pub enum ParamType {
         Legacy,
         Kimchi,
+        KimchiW5,
+        KimchiW9,
+        KimchiPartial,
+        Poseidon2,
+        Bn254Kimchi,
+        Bn254Poseidon2,
     }
 
     impl FromStr for ParamType {
@@ -44,6 +50,12 @@ mod inner {
             match input.to_lowercase().as_str() {
                 "legacy" => Ok(ParamType::Legacy),
                 "kimchi" => Ok(ParamType::Kimchi),
+                "kimchi_w5" => Ok(ParamType::KimchiW5),
+                "kimchi_w9" => Ok(ParamType::KimchiW9),
+                "kimchi_partial" => Ok(ParamType::KimchiPartial),
+                "poseidon2" => Ok(ParamType::Poseidon2),
+                "bn254_kimchi" => Ok(ParamType::Bn254Kimchi),
+                "bn254_poseidon2" => Ok(ParamType::Bn254Poseidon2),
                 _ => Err(()),
             }
         }
@@ -78,7 +90,7 @@ mod inner {
             }
             _ => {
                 println!(
-                "usage: cargo run -p export_test_vectors -- [{:?}|{:?}] [legacy|kimchi] <OUTPUT_FILE>",
+                "usage: cargo run -p export_test_vectors -- [{:?}|{:?}] [legacy|kimchi|kimchi_w5|kimchi_w9|kimchi_partial|poseidon2|bn254_kimchi|bn254_poseidon2] <OUTPUT_FILE>",
                 Mode::Hex,
                 Mode::B10,
             );
@@ -1,8 +1,8 @@
 use super::{Mode, ParamType};
-use ark_ff::{fields::PrimeField as _, UniformRand as _};
+use ark_ff::fields::PrimeField;
 use ark_serialize::CanonicalSerialize as _;
-use mina_curves::pasta::Fp;
 use mina_poseidon::{
+    bn254,
     constants::{self, SpongeConstants},
     pasta,
     poseidon::{ArithmeticSponge as Poseidon, ArithmeticSpongeParams, Sponge as _},
@@ -37,24 +37,39 @@ pub struct TestVector {
 
 /// Computes the poseidon hash of several field elements.
 /// Uses the 'basic' configuration with N states and M rounds.
-fn poseidon<SC: SpongeConstants>(input: &[Fp], params: &'static ArithmeticSpongeParams<Fp>) -> Fp {
-    let mut s = Poseidon::<Fp, SC>::new(params);
+fn poseidon<F: PrimeField, SC: SpongeConstants, const WIDTH: usize>(
+    input: &[F],
+    params: &'static ArithmeticSpongeParams<F>,
+) -> F {
+    let mut s = Poseidon::<F, SC, WIDTH>::new(params);
     s.absorb(input);
     s.squeeze()
 }
 
 /// generates a vector of `length` field elements
-fn rand_fields(rng: &mut impl Rng, length: u8) -> Vec<Fp> {
+fn rand_fields<F: PrimeField>(rng: &mut impl Rng, length: u8) -> Vec<F> {
     let mut fields = vec![];
     for _ in 0..length {
-        let fe = Fp::rand(rng);
+        let fe = F::rand(rng);
         fields.push(fe)
     }
     fields
 }
 
-/// creates a set of test vectors
-pub fn generate(mode: Mode, param_type: ParamType) -> TestVectors {
+/// serializes a field element
+fn encode<F: PrimeField>(mode: &Mode, elem: F) -> String {
+    let mut bytes = vec![];
+    elem.into_repr()
+        .serialize(&mut bytes)
+        .expect("canonical serialiation should work");
+    match mode {
+        Mode::Hex => hex::encode(&bytes),
+        Mode::B10 => BigUint::from_bytes_le(&bytes).to_string(),
+    }
+}
+
+/// creates a set of test vectors for the given hash function
+fn test_vectors<F: PrimeField>(mode: &Mode, hash: impl Fn(&[F]) -> F) -> Vec<TestVector> {
     let mut rng = &mut rand::rngs::StdRng::from_seed([0u8; 32]);
     let mut test_vectors = vec![];
 
@@ -62,50 +77,80 @@ pub fn generate(mode: Mode, param_type: ParamType) -> TestVectors {
     for length in 0..6 {
         // generate input & hash
         let input = rand_fields(&mut rng, length);
-        let output = match param_type {
-            ParamType::Legacy => poseidon::<constants::PlonkSpongeConstantsLegacy>(
-                &input,
-                pasta::fp_legacy::static_params(),
-            ),
-            ParamType::Kimchi => poseidon::<constants::PlonkSpongeConstantsKimchi>(
-                &input,
-                pasta::fp_kimchi::static_params(),
-            ),
-        };
-
-        // serialize input & output
-        let input = input
-            .into_iter()
-            .map(|elem| {
-                let mut input_bytes = vec![];
-                elem.into_repr()
-                    .serialize(&mut input_bytes)
-                    .expect("canonical serialiation should work");
-                match mode {
-                    Mode::Hex => hex::encode(&input_bytes),
-                    Mode::B10 => BigUint::from_bytes_le(&input_bytes).to_string(),
-                }
-            })
-            .collect();
-        let mut output_bytes = vec![];
-        output
-            .into_repr()
-            .serialize(&mut output_bytes)
-            .expect("canonical serialization should work");
+        let output = hash(&input);
 
         // add vector
         test_vectors.push(TestVector {
-            input,
-            output: match mode {
-                Mode::Hex => hex::encode(&output_bytes),
-                Mode::B10 => BigUint::from_bytes_le(&output_bytes).to_string(),
-            },
+            input: input.into_iter().map(|elem| encode(mode, elem)).collect(),
+            output: encode(mode, output),
         })
     }
 
+    test_vectors
+}
+
+/// creates a set of test vectors
+pub fn generate(mode: Mode, param_type: ParamType) -> TestVectors {
+    let test_vectors = match param_type {
+        ParamType::Legacy => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsLegacy, 3>(
+                input,
+                pasta::fp_legacy::static_params(),
+            )
+        }),
+        ParamType::Kimchi => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsKimchi, 3>(
+                input,
+                pasta::fp_kimchi::static_params(),
+            )
+        }),
+        ParamType::KimchiW5 => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsKimchiW5, 5>(
+                input,
+                pasta::fp_kimchi_w5::static_params(),
+            )
+        }),
+        ParamType::KimchiW9 => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsKimchiW9, 9>(
+                input,
+                pasta::fp_kimchi_w9::static_params(),
+            )
+        }),
+        ParamType::KimchiPartial => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsKimchiPartial, 3>(
+                input,
+                pasta::fp_kimchi_partial::static_params(),
+            )
+        }),
+        ParamType::Poseidon2 => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsPoseidon2, 3>(
+                input,
+                pasta::fp_poseidon2::static_params(),
+            )
+        }),
+        ParamType::Bn254Kimchi => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsKimchi, 3>(
+                input,
+                bn254::fr_kimchi::static_params(),
+            )
+        }),
+        ParamType::Bn254Poseidon2 => test_vectors(&mode, |input| {
+            poseidon::<_, constants::PlonkSpongeConstantsPoseidon2, 3>(
+                input,
+                bn254::fr_poseidon2::static_params(),
+            )
+        }),
+    };
+
     let name = match param_type {
         ParamType::Legacy => "legacy",
         ParamType::Kimchi => "kimchi",
+        ParamType::KimchiW5 => "kimchi_w5",
+        ParamType::KimchiW9 => "kimchi_w9",
+        ParamType::KimchiPartial => "kimchi_partial",
+        ParamType::Poseidon2 => "poseidon2",
+        ParamType::Bn254Kimchi => "bn254_kimchi",
+        ParamType::Bn254Poseidon2 => "bn254_poseidon2",
     }
     .into();
 
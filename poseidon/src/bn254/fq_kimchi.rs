@@ -0,0 +1,834 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use ark_bn254::Fq;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 --curve bn254 rust 3 kimchi */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fq> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fq::from_str(
+                    "12985054854135799464584285501547163650360176211515899036341230780852663150847",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6156720652490737849859050212787654661461971773661099260076428532953698514291",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7589391094930185839895542953670183236971141922654606731780158414077959950719",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "801997891511072965695612552932368349276135334998040205745008202792039234692",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1798012675793564404564327428247650446623265445951908333497671439931642309389",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3647246330030319238221164628990182478385290985973174922814587071307643818108",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18628765119837095988576340969058963573356150391391076869924272065802510420255",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18843051892870670993161113978406756147380771556032560832606232076545162989152",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21516376020203745163724724017365641665616477020870336882767554058084413547302",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fq::from_str(
+                    "2557021479192902228721614065645111171512827899752202099246489144848291532845",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20990586563711368482579450702132141832170081646025231637335266031173889142345",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8944588917073827926621158559895562688492991491967081489118231428740652829589",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5576924857710558412991851333584486588142077862362779742964622443439640456774",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "440330522990178375521961248439659997490329056992650363154192213372219229770",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6335044428527411142690096164267000740973951018500614995402580797580571698947",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11409200329061062605936605236730752901776914013744363223609830361041513165026",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "900983761216337288677485039505416012492119590778891447354719654019333853106",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8095843828024440468481080993927011955433332419184478927238505039287780037052",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5912848424557373163274830735111765366050248472229597312643649371875639370611",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9230151187859918703360982202374677505575673539831704018665442314528230895100",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2166120423911420377178091489830652088908134272810314946443500263339608422389",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9768791760667555736026152671356469646755901834501817926719467949504265044835",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9353570147615503980972572404264367711826399525531808963359797865884672605219",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13438206501703341146705856925561410245540706660713166467673696307257992567691",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "86041333191545317486067586137949434169697847723876556170072601765841800743",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "352514960749604441579294287169644179614068885714633966032530495744999052336",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20670819515819188483052545068248711067358441929052272887487916914226754009173",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2975917688022039828887416634112620331268040842187055625239659482456468642021",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19205941517628366536995931452098352727087351584182961969041304592005108190739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14036228381944737013047859200118503216200571495057623194613458557679012204551",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "4672204921638884226033622232475181796936505963117443120439596026650443551169",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "758816660995663262041350372880658335922200349557387258008673304365101117945",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1317136985701641162332033508162321875426155803585118179783944321840808725454",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6581597044643870502355850950873785837712024362830257244633982372975482628515",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5399998386498490720186613334386863841596660536026921806503855764152267254488",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9705597649519692226467371044570208046058617369741638493596333857492421156295",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "13163890535469261998720657697883252000173044211096500313885341729757778793828",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2008680884136253098344134899120418766018186743249917692079057816658238519664",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17890376287661286168731273944636400321245039144595684575009901766421235632770",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21356852684193172688717827520030647120483464736118233960235037108848854562746",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10099110291420878586844206717271506414431942221173627396334923440392804792430",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4548995381947088309990520690321926335417713861734475104805984751535797193744",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5347303536614693835758442387124687668239961670315139551355365425064078351150",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10747018155263724626479325949639618785827853585849839842446239432504382861776",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8727060382596645768678610292053559272212034133224988720836555085782814197370",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10957825165311830382229655458554173669130556745651270599134513946752401095478",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9854725232100606046748371225277828222257288683684050892476643248656946964060",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16004037728501265047496902706373993026072556293142632787208094434877323312850",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18233383195815353520307395381138879445047990002048631046146211395170447128550",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15683819296520104923489992017439800239336824977832311264778812337065501346330",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6487560754881389668795987736503347779666559436261977324288984401111067824502",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17813652985069971374365769506953087637529394211394784627785990220187171546362",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15401820873079232198143531549803155918245332711943736120185845663538549330963",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1401104314218655669482331648725436525042828527139284150867237744501011384603",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18643477435920881150582896884583990389754549074897925072306796111578675678604",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17788940730862022367077876910771005252957544178160839720153625961622097389733",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7094545736833821545397570017427331212646284758982312336219903454527533008622",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21372069340855243439770760566890643405973457970573382076736216846867772430734",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18536159439943588312847096975001419657157884010283297786295261612645744054828",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8089743371545680615459256482799455422209319532981622326226093463173907313949",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16220261273415136119053908363707334500027850658957564615893883177456599602192",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20017613280507276735647339845628226257890279008803826452330214044661464478830",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13536856037301277892928965568840261781857805869792582531569103652981799691492",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2385618775067645379637134238838609122557595920627043298277160988801513066443",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7249060688246170836037067755140825169722343071936409027971347200123467080897",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7002810945201404541439240766531546350441264998586101520065505697040371193750",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20364153870146031833122579725896967158614332861488539578377552765905884723483",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1928241331845181944583376606092796763444936870993097656763761117020036444845",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12930977747292255329305737320866730516012855187874041386766266411430654608816",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5150780755307641492443494122729982293104744658377495116841278334197364647039",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11951927518287779660177923497754259285504793900179126991852070742560384545314",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16776280622529900299138510015867174133048042867829859483234873448731499322887",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8192928695164457358307009402920137611045709046736027255702954825908054018321",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13079380561397233924272478933837529171769849922789681438199467296717124570242",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "48969557601221305563583956912836368303443813849535653097874682749141741407",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8185788177329841923643707335805482260660583832804973131095302634972385546955",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19615522282914656191417764205153171902072984431621644922781167214137343178038",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21001308140419578406479705163512816987027002402054673975803902696109798146578",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6948313369275615330545763026845882586718190527598061659291648512933182053956",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6997023887676748168241357011438586839509113153271351778332272829387183227358",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16049043407545817002841676076380189860822958674951818150364054110437980445173",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9063580251233276605442775891131950841024590502948528320283123479010277227803",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5041572863937105139856044516623115858641034300062428522456038425320074889233",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4094778681894589108461207479646337182929727395876332071886319274699864283792",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12536616390889749801611063360137254317468594562261728294160643168620962038853",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4773025594209042767863653656377942855410115165300327820741061094709225425446",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10450350267247901290458069302650832090614184929522624279212635860938461231063",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3573747198024109676075495939463924407888571834693809351013526063287804773443",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "224018626251933850880117196445035460162422408396285461591286519752141242277",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3375659886239898891609833925106868955311090284149368308248239328923632567768",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "19967118499430967398618807957143327566981684274347618349755004304271965090639",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5025500259647002134409725270680708731483021664278078343586090227098313713938",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7016418770035926821454798439205033395679940288630702641161791619508347460402",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "488242851566174533086208050097975729377696350712065022746239249860848469449",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14329142343854000906310997387552169248391403954101663270644575827667678576753",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15912943243258044179951996889245929340075288240106143580910636109106238285585",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "323338558801591733675838655101764738863820958274795087961249038014906390873",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6733585830457269731535100798900989776563418270377533821303715323560439087606",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15754520774636651045610652339591432300034388533045643830512862863911514113435",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9214329302178338417863844172359499440161603132264076263924200922042154710677",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12718650596452627307807861532386866218848483860539645518918367264720262433854",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2178688756681814363665018057750833765435053354446432038836551108322554163402",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "7155897965915901698749855347148290089114581199424112032059977592981290674391",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10716294226198020059206498437956301598743269933719459331178250053411453385950",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9361315293206612811286137092431274551099095298703841637949625272483815755145",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1429796017092747714855647115415182696746143885331376183992102681909480249970",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21296531075409864042998216046699195597836570853320052715082861495872017875432",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21631308956960416017007918455356781028093492865886182221748336241422618682279",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "7652294145551810170210931522060255291278800389415313541540509064303064400606",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9604582696665922066017067865307241147843112231736354413484654386325823215154",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21575684563894299359826745306686940920555079424283431990250243721580176939605",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2598224088552210509510349150654868216983522953624973969878130360655657223521",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21084338988022147913258692923134800018897768823920647139846710718664207208386",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13791137196981873870530579593389644134824267742081864682919252857814544252951",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9710185811468021703577207108549653090883699998139416018927167236683061925353",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21202377350951680986335366047246338007380355769506447702490077976505514826143",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "749758256565988489695510039440410529254083023857732427680683033378545588866",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20350100645592691373837507881856644463850992279028506764630848005317435731049",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7222201179233237414030945343598460581936716098868763101563313456035738842072",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7673124885279106264595520866102303004639861182207377274890622490658434900335",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2619108839504484537310919151302604629368772124929899242745815690989447445908",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6919914688581112604661959601598491500065907039970718664948373571261611014714",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9152224078727944389373015405233729330327267314559832655282015432579066864199",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14324133806210026462536304709348191381072470160613622670857187389535158710625",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17485060973975059664268534579885662658095101632771110960956067460823151011479",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7008164981326863305543501872177804788099723845749456516497000633959828177141",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8095641417799782719576838289077320232763878615922659375006569706680949295982",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7756565920489773833115768291251907896196448782980448922256807884858729769049",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13905913022905954723732500438241090594834963181481067530211599300777924173711",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5837719331562301044517139679856387390561049921309471071116123459452279983684",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13734349773202415020221532403269846248925862329378258161573560163515110373742",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13541338669221554093785670009519379835040221936239496983936899168099914607840",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "4516430260746728462615395053415181851056164306238091098686853007409560507093",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8478999564868801927693487225883286057661783601308179564436221867355842145635",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10299250870049750541146393256135939349345452665800708147807404139973592852167",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14469415361353797561869976662705513407572899774421954370415843570211571308488",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2575660360116658441588792956492999266438460079575167746688803345994981566283",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "764822268746048381800185758630693806558415550059756502759800721296281358686",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9866185233805233302013949329075567682074873231282889603483276999402020391205",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15264662830443492593621621331667083323723980051423677557945758990467922029262",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21770817363812475671298688152099824623734077043838412857258404935144849671271",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5009248037360880194862194827242851544804472225794658356369556746484474099961",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20281865922270961191906274420266345149144211774273623829180803790199474681327",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13894734368228976830107774387288925181216660386115694348964318901332463410855",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "857595488294119845111481382341793539645145893065717440833850803683451558665",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17533728710156213596944335566792070584993177968724763978223615672064477457271",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4220882416426792778817517634833297727305987377737735900025669252809302773796",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20319848968420324387207261449856064706241010826935683008117647382177936847339",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8747826002485773365490430795299706564610603825920536251607137714883146481028",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21454765765541026960360491623856413880749973784521177935070835390179660101055",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5587648815481613590832910073324623036066590862183838590811450421729360566308",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20731274757192829042064858724161720599853147472158789247111149252310867318153",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1835875080623608506759051745917757788647729532859133369862866478073360791271",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3248743411429600380782786217970213307862422761802354036420074323919434884455",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2151145619834024190818387295508672487470697846465454415367666634275433186359",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21762365608536508381750650406556285146195698229877616252604895737743724255422",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15577661904273609888682490381527308998322404186488743438596350701376323049754",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15726496995160464253726614219483287157370592339349943313011929828797434230165",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11315763362059332547644402277925194832156522367636371182352731771180371579473",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17797004959768811321544745962428161092077864315748629910013163532870776889833",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8607347761110368733269939354590558584167394312501855393635077466516173514363",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12336566617421981225891704819499386556814378045023706575812192639965589817948",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17678901328697430417786353507578490465108341123227985914494948927048128917414",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2235349448931445184500214704302608846222061096330846601487624543635755716593",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14840631137141988377656621470311104138054701229088376052789163863258791107481",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3440413462798307158264019650444597465702196388959012505555645562292942991466",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6726844328967926966693288521078674890143298845962428461587162738638316705220",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "970161464893600922622440583661235462838526891911339097472546265309779071185",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18153819770269182126510978113231889296265202299525832662676506450559678608612",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7444348720217612362685941320082098095021393984976179404248818308346850886076",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21460489507668787809643928440579091150824770312429235511293198822190278323526",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1955271010238139901989241119767254176097117019762507463657518786964960761052",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6636075052249921201879153973284376436642310767305149107104257039259627348145",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16197645628257662046846912944534799425195990864444873066774084259912217511330",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fq sponge params
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fq> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fq>> = Lazy::new(params);
+    &PARAMS
+}
@@ -0,0 +1,834 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use ark_bn254::Fr;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 --curve bn254 rust 3 kimchi */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fr> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fr::from_str(
+                    "18107395716847851741809970607892466698435236583167654446524062597886084054374",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7249100972845998345311066108898085409595351885137022754934487683342659831365",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "18786132972046538919425262133710913072608386033491378029417543770811420556050",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "420309885224867937087727706621181713541239983378649757608385662480031829168",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7434172337098651285871185777754493443405086887789343550740432442648044016347",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "19065494149813463098242892334216050113714013705428952215512075781571777630377",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "2404223684579085961134992741232366499492267913830180884683109458625236540923",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7959883734855060175871244543033256859586889280333307516970014339432481151332",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13049309576695862970776720288678792048574013957457466763981015661815429359293",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fr::from_str(
+                    "1614493434942393040388200450290088824298962691226783182304736318391304138446",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "9449375994602642556397004922328004302633686000654483625703653051630797394695",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "18896438250839903401382470622339460790426682536197875955465487242099163616773",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "17896512814443292696627344785661510872498928885036711757949754332105985858089",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13215522785871756723829953585401264853224915998267840788662334139752693957327",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14817305553175500954247235832865945209815040567126302107981826031906114443717",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "9034477563460898097254775636924250248275948892690510354442174849649965976944",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17393019528691497841012157261620088652444194027178903840503299565169915184455",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "6651385315523221091405516810507408674732908561885697240726456303619347951882",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "3837020844527637872264044380636715130083522638612917274334753443884456904335",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13407434703326526230734384815192052718285827938402684817157755704174185448019",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13465466961617012601843328380938435433827827114378942597868168983057335656593",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "2424470676393159118104330451920171292190702519658830859262126145543004536535",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "18854879530680663591952534182085346559813561484528785926201634039665499048745",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "12452260965344426032277513469143657733962162547044310963428072344494177022296",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "11294073457257827877012209472896038712313217225257828007039273969598567940568",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "8660951025783352400734031233243938353087802781531263555426151764631473099969",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "6000622602262675163012018522800170225325289986343688354277632003439112671213",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "5114291077264675244579466299405324295898102995279439833002081725521463695947",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "16252849431809304243122096913056834163420399040232246418342714060257293105302",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "4037767257076948421548293637241670172963432902244394918277109812249778418504",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "1645081013041507309012715535803984403220785187767659388024209581094709004911",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10329537398013202847084250867457702052349849470196525623714740381459708602207",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17178265412810486083261799850548425626757188100739969090150757392866207362645",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "14997626641740395924540909637921899377434016328824157182680562306572015582626",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17113235421986141240785449007476679253889162536420094382748990648950313727792",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17457669504582841745688110715533089131250753563849475139093444493007716193186",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "12033266548478802257827533948192850115880153596359272185456314818238663368472",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14875393465848029595451812628342020305237553634322901831196624807022394508940",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13902132417420109904527200788417908954666930477972739459479037109744536265572",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "14806073005299504447025827471798725666854825662698600844253447019855279960436",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "4480882192811860622354677072990551471361573142179225624266598991641309916775",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "15822740191623790960577000084327957234078583769954874690929450184331688638848",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "6633161517155560200205822256744945401917068524364522670150224159606392697554",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14079593387667946444948477499282939374156908511979089914639204075550926578476",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "1933212577126323779816557153193730280215121607479204016053458770949700612961",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "18571084055478569055669155680373040205680043374626383505180667162634412495647",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "945984145230129688482828001347084142567887659994026433913384512488234834856",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "11504860110878801243476880250223062624732811801835180586908783326458407666558",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "3767999372092975634066161377084326664932446787679291195330768609838852997236",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14253684295560263642258563603553193184822255996365728072557974748145003528312",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "11525498524305125114199278659134892292206086696006565759152710888994613176822",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "17950245000166062575932936964470709917498630675553874560526162485412783915936",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "20088508550718314974571208885064103359930969212740918161272490547532449873906",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "19365605933696673016880296403836652472797781959734468104711503897931347014800",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "11001912438552675401841895078125433818687078299111585126278736812763467780546",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10203541429708190131496289646813203901118696784806842185946408792007965156868",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14508645970439859952736315467217542415408831686910180542546557445340942576880",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16828562132375857507297537767953679642318489825350569489054733416591266827147",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "21467907502867578596957224626764209415791190695663514983031005500469359642183",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "8417290898442706192363580809238354413564479042853282906247002390694578009085",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "14161200328518365124807933880945745490731348081867050500190321501411981046721",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "20325064803248407989986560532564200081346842840460318101222131374868382972583",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "3161217152464913538970280011202858329154411963085724641482989069418378184239",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "6848890924342415882417380534228192049423057465130883229638607590543511761794",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13394804030876825933299840885605518047905530632198413521511047415374032089423",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14018895944896761091909298063367386905545085757899399642244738498944710167200",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "15879227688867775443072993175625345620536173580123025417092920911442167177331",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17641111691925475567001831576198966799413044885573297554515128181742412419493",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "5322434367679702183591798947642242883913851037470484041986486939261219804241",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "5491487772914390480946082354540277745324013207119166158911834370971921976805",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "1818809984748852533379824005412146486507683770621636609834537819430695355104",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17723745779128243485348663244613681978597464399993432950414957735128773172959",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16694009475217999198577484741617680310660920162781928167646834158905477830029",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "1663048555345910838126392759141098423891469360142088750462298371597477784735",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "9415237944428380381083290090195183340165129063459052205195557426433792989290",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "18027656890468209748865700591513217275084765227224702146405816577535909840426",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7605981923104180814419746012438657917938667314520625814233779988334008915422",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "5955489093265084685433043563037469433054401849277377934735898022162949047844",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "20825554608799925054598602404983448967938890328624481265740936982072410958905",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13780647015667713126221535718762769101098258545452333796084077067330307825390",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "8246965288196198498394586205711530294914927630666910891135422470481053425868",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "5127432294402119822321948719620445927487625655666888605605814900760961178135",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "15954719574386142002913274115436340426304551608925785409396769526503210124646",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "21732063935311294217849021916864031939331879027182869685029166497297351258026",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "12263271669698287861580970648355265022371821650265877568408726067729315962986",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "8852966740264110515173143800339316857541363193067461052069099456113250007235",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10345233327851216683369782546926091960310264887115198127149243547252956659603",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "11096620637409484193208663113845750308291754636096128287180727690462623396564",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2571342291423898550235336751123066081682533342391173982864932945793803343209",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17493320291694134327316813487730396603468034238189873996594764410083923888323",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "12120511653673706179023134889241709363996462740676296896636303747251450429720",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17348723964834980118451951092205075507403903069426567654451069583198554262141",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7639688266142879313999745453045645081626622791063438729734086109658660526049",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "5814543606731966889097558416530764423297456316189563026062047989494819767165",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7955782784521973127665078883313968152342727362470156959521050885906303868711",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14755063956320345281628088715080487499003386713200848469005574362088057961689",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "19262096753290591487154137323976014070375327531633419491639927763146408209870",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10226382817486559856290490380695041581465700559461784864648353438547200534300",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "535799400013225708710441232632782824520595280228790662450223899621663824441",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "1366331097720106671948385649246448816516218090398628856263626024305201890405",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "11790152421846096712298204009883970704264493872651435187621569656295582264996",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13344681926501802506462951495937703546354601543816841537396740375020057452806",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "15593964743994494090850101657821556084803336504545706934073621829867380637679",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "21336709526339999256579491953108409372244592012089266816732986097944849278296",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10051595228029450412228166221698039667107236921278093874290696874989941443014",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "9882451548016277285142647235835560964980228451505664372938756667670023300705",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "12783440008050710737873446391502778951499926260719695089148282539509790881769",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "15910646247713141013749874477162645868360406424715809159819392792914576377297",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16096819703749044537222199038921502111535344690401377338819423930196786517687",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "18786247774006149768638900171392102689863484172724816402138628421941980082998",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "18669838584406216181628936012789468314013332740658519459033279531465920386653",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "7423100950055643925634866176943339642537320686420949063850613063701364578740",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "5826667931877565852088817523478088341925242186783689418720452679308726343005",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "15612100796956477405482028866042774055417454840718051928856464940121705857178",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16630408319071867385561908468360483497018456327639309959017713512914898687657",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2847492480447129339553773633893880876795805681939179631180368254666464824573",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "16428999325032726291381339468749206740672764099258501141568010118943357609923",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "15206729608900260408829014841852332000413601788725472567817824966683623486009",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "11055333974059927666629184620882686910651381025057690411956376346953773919935",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "12667288128815845342801183474666096016573814471983339670024261819983384944953",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "11694826802733807119293053127112068510739985733248169523550397615514039752359",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "20670261412666466161663121061890543653627466175746656454309765578211747702492",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13949849626750482713676939771147280977581414338959838876465723856158436795259",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "18756198265943404402512312331258567514369665611945946404896863318481388432610",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "20123631819090480238471680938940676885295276424196093391758607694129508005817",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "21196444761445072329685478205342462288594952533952886258360694981915169722724",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "11008164299298418531372744171792571391470989941298812439005861855331230815581",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "13785219199060111611529804036527524611538171922615774087483000262460360839167",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "227489424010364333044150352477264183215679550226170809524131515529054347505",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16469795386169410881959236175281255834878332370509151748995233018770819124957",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10648066710955210568858999356481695061337955568414671410666470749467222524464",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2767311749633688550178037919869704914579325902913899970313043105548849964723",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "2442516454098978195270556867708410287878943430816946570981695943003502192995",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "9085558053998186886045599659785089106136048303896232748936008699480836322555",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2534483032963895511517087033317626209032260280769681779532837023820744252371",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "20813247710961648279024377448353221313027307069291775858767366301577683629272",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "6525924127715514631869231843662169134767010680872089949520283185021536330821",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "45602324236280444167402144748158363407130256667757749399743677400938149445",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16227177261522137310696693643942136861188823079021032578264157219963858728857",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "9428306758484750874379454908292919037650916497937249357781138543762431194307",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2841947434444352738579964074793620802445237050507652263500528356522171708065",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "16105850909964704815957092871238065800308689295992064743963282898045629130443",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "11201787836657193660750955635963876085707665942914396466095528608711905124552",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "15167628255948250440280277590581234012080690416028028774145655337066463855579",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "12157951742357465861821888345683798451174561199071503429827446208228379277306",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "5927858671621069882298759669982210327046127307470159819216087635753292683534",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "2599593395128252113102598303068508280373884864558272409588796765568298923090",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "20809221768717348508064140074729949976341463136554291307070360427308686678012",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "5578372744081995345443958044548081734319117505313132713763233730550168965205",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "823195570560239509933564328576198356909491079585578373935580253065752988399",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "14233177536051443372695072879753727029407433894658403484707940733119415876731",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "3048080057428493398035573471880532195144249897368469238354310622517107599727",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14114973408540560349071841053930678406825184694275240589828161552619825862162",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "15919862095392527202623252085837102672948529450586599080215781345071125531435",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "176875678702309727210849194601924871030342320946131152528434536077065249726",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "9032391829811692597926785483504735276535794032888534812715221200660888519080",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "1986628872279693643884413340237369555594271602889724575000925293132841984174",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "14378587959128773366873138457671739652568664880685187442227373899437834220082",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "12409538351962035474638640498397788193957649260298566810984813206883553913119",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "5763274198351572240024492830454120532567149542509287450629806293847184480841",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "19135906405352001009679268912785965281713545700010158343555876262889009166837",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17980294750838938464114354074196275501847585833984571961477072914696581041601",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "6686124662526436655976943758804947759165732980670675736434618354513625533981",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "6003284534651752326140836315884608493389610456706038862685453359261248014966",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "19430208806906340768901261247581528507120387125425591182633129967413322350214",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "20480739138656758971653620711320471473987968678053754851578179941274089383741",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "5603161390847923375456088551476672314525188566020327082110459349559596717103",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "6999533314224314649942431552101334752923357491866560507187080313044679167689",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "2871383097007722002425504548174913128481906267825504193016459768005783112805",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "7830043951627791374527623845553780561018900582697315617480860522461328259844",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "21118683993609901300927791998864855420945643197350572918975047987543096202579",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fr::from_str(
+                    "18627145840658983974500371429833687984780485255753336092049319119043821648234",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "17569791539602271944776386212827514411831729273158504379934954467060419923486",
+                )
+                .unwrap(),
+                Fr::from_str(
+                    "10080266651330285616935240872230851616136082296333146817322413837247583320597",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fr sponge params
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fr> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fr>> = Lazy::new(params);
+    &PARAMS
+}
@@ -0,0 +1,3 @@
+pub mod fq_kimchi;
+pub mod fr_kimchi;
+pub mod fr_poseidon2;
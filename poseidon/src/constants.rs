@@ -8,6 +8,11 @@ pub trait SpongeConstants {
     const PERM_SBOX: u32;
     const PERM_FULL_MDS: bool;
     const PERM_INITIAL_ARK: bool;
+    /// Use the Poseidon2 permutation instead of the Poseidon one.
+    /// In that case, `PERM_HALF_ROUNDS_FULL` external rounds are applied at the
+    /// beginning and at the end of the permutation, and `PERM_FULL_MDS` and
+    /// `PERM_INITIAL_ARK` are ignored.
+    const PERM_POSEIDON2: bool = false;
 }
 
 #[derive(Clone)]
@@ -39,3 +44,77 @@ impl SpongeConstants for PlonkSpongeConstantsKimchi {
     const PERM_FULL_MDS: bool = true;
     const PERM_INITIAL_ARK: bool = false;
 }
+
+/// The kimchi constants for a sponge of width 5, with a rate of 4.
+#[derive(Clone)]
+pub struct PlonkSpongeConstantsKimchiW5 {}
+
+impl SpongeConstants for PlonkSpongeConstantsKimchiW5 {
+    const SPONGE_CAPACITY: usize = 1;
+    const SPONGE_WIDTH: usize = 5;
+    const SPONGE_RATE: usize = 4;
+    const PERM_ROUNDS_FULL: usize = 55;
+    const PERM_ROUNDS_PARTIAL: usize = 0;
+    const PERM_HALF_ROUNDS_FULL: usize = 0;
+    const PERM_SBOX: u32 = 7;
+    const PERM_FULL_MDS: bool = true;
+    const PERM_INITIAL_ARK: bool = false;
+}
+
+/// The kimchi constants for a sponge of width 9, with a rate of 8.
+#[derive(Clone)]
+pub struct PlonkSpongeConstantsKimchiW9 {}
+
+impl SpongeConstants for PlonkSpongeConstantsKimchiW9 {
+    const SPONGE_CAPACITY: usize = 1;
+    const SPONGE_WIDTH: usize = 9;
+    const SPONGE_RATE: usize = 8;
+    const PERM_ROUNDS_FULL: usize = 55;
+    const PERM_ROUNDS_PARTIAL: usize = 0;
+    const PERM_HALF_ROUNDS_FULL: usize = 0;
+    const PERM_SBOX: u32 = 7;
+    const PERM_FULL_MDS: bool = true;
+    const PERM_INITIAL_ARK: bool = false;
+}
+
+/// The kimchi constants with partial rounds: 5 full rounds, 45 partial rounds and 5 full rounds.
+/// The permutation uses the same MDS matrix as [PlonkSpongeConstantsKimchi], and the same
+/// total number of rounds, so that it fits in the same number of rows of a kimchi circuit.
+///
+/// The numbers of rounds follow the security analysis of the Poseidon paper
+/// (<https://eprint.iacr.org/2019/458>, Section 5.5, with the bound of <https://eprint.iacr.org/2023/537>):
+/// for 128 bits of security, a width of 3 and `alpha = 7`, 8 full rounds and 41 partial rounds are
+/// secure, and the recommended security margin (2 more full rounds, 7.5% more partial rounds) gives
+/// 10 and 45. This is checked by `src/pasta/round_numbers.py`.
+#[derive(Clone)]
+pub struct PlonkSpongeConstantsKimchiPartial {}
+
+impl SpongeConstants for PlonkSpongeConstantsKimchiPartial {
+    const SPONGE_CAPACITY: usize = 1;
+    const SPONGE_WIDTH: usize = 3;
+    const SPONGE_RATE: usize = 2;
+    const PERM_ROUNDS_FULL: usize = 10;
+    const PERM_ROUNDS_PARTIAL: usize = 45;
+    const PERM_HALF_ROUNDS_FULL: usize = 5;
+    const PERM_SBOX: u32 = 7;
+    const PERM_FULL_MDS: bool = true;
+    const PERM_INITIAL_ARK: bool = false;
+}
+
+/// The constants of the Poseidon2 permutation of width 3, see
+/// [crate::permutation::poseidon2_block_cipher].
+#[derive(Clone)]
+pub struct PlonkSpongeConstantsPoseidon2 {}
+
+impl SpongeConstants for PlonkSpongeConstantsPoseidon2 {
+    const SPONGE_CAPACITY: usize = 1;
+    const SPONGE_WIDTH: usize = 3;
+    const SPONGE_RATE: usize = 2;
+    const PERM_ROUNDS_FULL: usize = 8;
+    const PERM_ROUNDS_PARTIAL: usize = 56;
+    const PERM_HALF_ROUNDS_FULL: usize = 4;
+    const PERM_SBOX: u32 = 5;
+    const PERM_FULL_MDS: bool = false;
+    const PERM_INITIAL_ARK: bool = false;
+    const PERM_POSEIDON2: bool = true;
+}
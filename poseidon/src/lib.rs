@@ -1,9 +1,11 @@
+pub mod bn254;
 pub mod constants;
 pub mod dummy_values;
 pub mod pasta;
 pub mod permutation;
 pub mod poseidon;
 pub mod sponge;
+pub mod transcript;
 
 pub use sponge::FqSponge; // Commonly used so reexported for convenience
 
@@ -0,0 +1,834 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use mina_curves::pasta::Fp;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 --mds-name kimchi rust 3 kimchi_partial */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fp> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fp::from_str(
+                    "12035446894107573964500871153637039653510326950134440362813193268448863222019",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25461374787957152039031444204194007219326765802730624564074257060397341542093",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27667907157110496066452777015908813333407980290333709698851344970789663080149",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4491931056866994439025447213644536587424785196363427220456343191847333476930",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14743631939509747387607291926699970421064627808101543132147270746750887019919",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9448400033389617131295304336481030167723486090288313334230651810071857784477",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10525578725509990281643336361904863911009900817790387635342941550657754064843",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27437632000253211280915908546961303399777448677029255413769125486614773776695",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27566319851776897085443681456689352477426926500749993803132851225169606086988",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fp::from_str(
+                    "12719067213055411882142241819605864290483311682279091699969836887578906586202",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4479840113481682933427190572734508350386696579282669106249139521982882425398",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16439813805529433690764231130490771605945931639359084313319582529628089704832",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "18658865498503571748494647315323511629886847449956987087506451052310042312752",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18682009374387508897381434219476768938027773946483127034364118607170545673678",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25445114371337649380923594968467693495569151272351899232836157919905035712443",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10111619232135384724334614240158106826661675598037085026824514260371488537054",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24958554044300440259902808365116604269885557389892241947638414591037205690671",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8569853974635468829879178676803112276510600996544369996114688311362476112355",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13513426766203435474902789481133415317715510062318436451535363609173579862922",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1033981834872270601882153716623933358619312656926969415412377638571052189993",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15378120516200170085556066318936474743647183631365945616659438345732154929984",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4728438459105745955068682557382887554665077825026637961056001275250482181098",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6612151442774624864719324215355765028061138250985631745758458612782188974769",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15533492185686174703085070558493046582409191555115249850538188265375741966356",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20815132751350681714933321255559897012926673303558083341933076242052852251676",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26286500660091291096294776369705610861825940114349493643871158268841689766106",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26819495716559072316641057584127270043844451217774067287620487633493088787109",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "1700175586316125036141153183912919929643525783893146963368598121187436416905",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24754466306862145534712796575357805449447559004047227885441051716629533522259",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4323558772070177932734637725214167101546589133257567898874805673807908830780",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "15544444138296654680980659184157183227402531457800701933256664250439098324918",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26704260477580960865135158818197047584704285871999113330963160643588336694745",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19412711991283438855126009905729765968212756091835739758966833530028715105829",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13668608295968010160685624574682450924259563626262829721344985058375636313730",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3086915613671629472206369416693628504340707353880985709540641276552689937923",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1134432956192390816517858331395899537953765540163180631409876257692817450077",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "23344985303799991048339063828130639510689224711404268653921250637841187163505",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "718522704236484963872365428478816604129726048118844846861699414367307293377",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8274446632313191438118318111676026025764467220948882329059770531543167058652",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "3312021798989423034005242326367865902174334820686240869244673034429404838213",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27174392638181059549946487085257008658430314172023673823160380933112892914271",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17865327737593225914138161277980950284551648560282933945923530391906549496501",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26402701121448065726142571197693180779925364585163954280251663058443102843892",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13503517239158851612897714274450752777878034607020913215311229002120808978334",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8123842997238166365440011797009151457234184445651979594884768492459934489008",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "3824977725448230933099232123927427815936416515917550535033188192442321258262",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28369809875339891546873709694490841203386111001536293998863833168418102683228",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6164645116628870670031876748414944660274519003956862968456756981691803767389",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "15726908005210323675652983753544724042640121651935426767795749653636777774284",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8923351778474214583692901108657602981894406861526576465321831300799263934982",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "815578387437441841873581381018909119936350286044128248446351869484188374949",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20654385366940678022185134242408969195500790884931531031195338462019345699123",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19776299420702879377045512485943267565278109909502348726330902488122796038960",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16682535670390897515706279057985603615888039887690480074469683163377682819843",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "27470266803952263184011126524894876352003096591270182569269108069088895187174",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27215065696386067400598230131289546856680131431534112233617329787105639275517",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21491563241051542038582241575909976870737258905418195071521765980418870383052",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6304436860092524024151487746394554730932527643883042139809267660978393761916",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11071079323238160193447413152137975001069637389244874244153819507730280375583",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5115097723268338601975906404571063162511363968864030296876305021884212390533",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22606993338227565826262715751690543628522014140602490372832260713201766975204",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15425624736057210977351124185584546751122319329471873447279397507513262778361",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13686159725945594221386469109645225045665492034693945866092490030602802149397",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "11904682944485281283324860594280075441681233768604265177865992769990825356259",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26188903687116290061103043563495378555756838816155890871819067432595953264576",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22020046292951783468036388635245866877894498576311746116619662376799820454845",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "12750503870213429982024031768847458773017053550950901161496346338337470556188",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7768124258388146765204385971031293312898733508668194995420378740745136614761",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14222022108645224030788708805584515032056465355371876400144760324970326136483",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19686940183331129424572829673449900727926389833671904176193318866967480098703",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27549760496602240140620955828868739488480778005913194758941580191137065438414",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11516753571753660745185774770291637237400913501215962477240457010437011575367",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4199276485937301438420295955842259725755403839885673495296068433991755705543",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3401425265476707597418793873912095848114710270761685961244067105202558464816",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26925671408687354117992658759076432165693124588527704940387529054887769838078",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9066820145896799044170529597558819440789436520809559565104025164288325210115",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25648719386179448256574995201223107783187632532901638283404150877279923423762",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21600706120185813401615086107366899820776743887112536807687568020284430558062",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9653684358705885521633527739821888217886773443797911202845888506186013760550",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8641280171666372190218837088477506918039287140144035164089565009906025490654",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12333708849604568872774258529008696724969421902822048031921689064188216072642",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13697876731617075383606871430154984790222384896094518759897134476454933689491",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "736484107918967104965539216442747978092399519344290088638654396506231175824",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12596621069770372179740497862890500553285365894476667850819349050112296646099",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "18959672065273964937170758954020268491901285312047617225852404655292496226361",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19340312440206189008785624600610453975411783986459656752058427320043871358390",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25125996113725141386991606226586776197289527633781348478531503427906489743102",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9460341729952377750920265888085846647796676169136878933483241803942005834522",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18977922453653204047379192258153493499619273056364611258334103509768715054864",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10968722735861698674561055137334751254435160384229201105727467527797820023751",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2995061420018848254159891984122474146988843814176601314969367169008109349630",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1893981626745394868997217076475147712718279099618004109871641482777537660824",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21176637113697612123201901989848204507967735987912750072287832136852170089392",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26258307118583579282399636046141834369992774111711572921729911432277534691027",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18858633817637876488084202087494305944694985145411547055232770423366281191373",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6973902548811745669496680719030312562397148128106772469713841854818688791214",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4122284338007322582293382643047096634716828187012930939355183662953341727924",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23621305996253160282717994952890359803725497917153849408646595554677638677051",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23825221272506237932635956500359936761378135737098106424990529218341587389143",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "7528157600275518431763655762562039307512234462413588510436582982538668610569",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8533551940225497839424205114404534633968061451996960448112452890901443536978",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22621155332775895144608985226449042717417176509602959993172279557883948350292",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13919569439612386783426497285876892489330794701382089896798909165051370976233",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28668865571157050989339987326427239449435041470676355238428801952493823694350",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6010491521732345077347965429040542992258067898964218919937385261281067496255",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10800808594302757033836620779328044944712977572608209237152056975907931128451",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4984476577915505727839479571899970151947953568622199920371230728591741738623",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10521620449403355050439154056991550416157357097599618476937619532216235639289",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10430703505767331156107743852013194955633007534890183598271860200295786244263",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7489713149668487333429206712582486112831398034813162672256241728390886399804",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22088248176250009722743366241443160453375664177128800204314131160050913306732",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "5708257801032935673637490288158646854388345446413828320951484805019585293589",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12516930098427280019261942965044911021967979757977684693860094334531374692038",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14253412863864061803786550664596507776246026677786590000700027984486749230581",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "12759312845562077112371244814758924210327591203790637764583638327381727993797",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "238019380388050577434455205106186440631552091664438847566475624045593790291",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19352466295862723078473067909604559520053903330918074714123983475503975947054",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "881599299964463262202218035811601701544559775159398748790592058337826570328",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26068287382966116210815246438041086566700287187644006269926951311713753885176",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13880678267042390256135035159387577495479647225088892683314563092029894120137",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19788193372355609780161269810375614682477488958813958036264443800704098186845",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14097787570380373952412615978762726654865538565509121733827783004984219966154",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4007846297365621918632195005648042715408126603754789550194804394352102164890",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "25453521937480616478185813541978674222740286632689466645324184987493859473793",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4910951315385663561632467009822759801518088286402876754753252258870812518963",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5032481713581092043102645848727281407582833106435215656316640706960447232343",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6197352050813409207827051071132381983346282643108468299773580928562499281439",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9547804820911423664588679375002022185189235139090711507284070488576610276965",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10762044129487293447846284528176778212350479815273032459340672022138044378866",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "14646019855227333705058821842325226497150187433997588570081309011168343782548",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8025963416094304479503305097049162833259209542442410863576530781565450128822",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25346090228611715545879679129056253568160381207227073701662677274663310737734",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "28707422949167154915442377084432372865749753393011243919068126091382956694435",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12979265843429049813808023309683733639765222774400681223742253290439639790176",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10032538211261103779956276559026934913479921792795484960107769659896199274117",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "11130158796447580160113122635768475884229784566194296309497996466386275045979",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1995944836507107678417845230759884979915710088164603000291993678341921113207",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3938454473560227948566244010081787017145766257428798286711806937780836892792",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4328898165739849789072341695237855051317494270518508336962599283833716247360",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17545296866795504262226961023261767115590115933074941710113385035813343509216",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "214758433372481436975759538288793885393649071739441199996812204621400297325",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "28447173363830348541561060466999674956565356261151832312868025675926547200612",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16276190748511104970094679097926966550430520681048238064013377669289258146347",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15146676481563493981611936894295364345552638909949304252613347455488696084693",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "17987492124651812776536689639212091983662199052001327050927921477754087268612",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17940297354575878539106791630746880216595817620485348940521038540123681543097",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5833644671471887854512918006976674903993110477324366593884360420562269439195",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9810178382746921953158237655656183642772713333047628152342579031915342000607",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23134737369681635770858640112466595888375471169839964316531768276865230652051",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6704220854071891061071208430669283541273189836962893342187487446136961173849",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26677289419217777167405400939024957202392255787087886815426665946766761041010",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26521194366320015944280487659894677383339820173646009963240390486705546979667",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17492625131809673585752394532511144564398541191653027260451891228679167728856",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20054158028712120731949803533498952424198490257763333883980418917771467156695",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8177819998110417472107032429590928765939998121711147372903935668922138359824",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12464713112803211991762464777603242682451375764228924534617308904605716742467",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "1544147897227085020167039988408079132137126522461029210150320343590736626245",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22595108825246145831037410564999612094832276754100129143875720918304639122862",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18209394984764217690993222694905754537157998973898557694045298900751950096434",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16641080850527796513330875924960560724379828113590530372599648264428324419833",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4412951033137171723099024875720628384376554726823044452329976040608756839784",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13481785320841412108346775802298322515153491783347674659873329667265736128174",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "7109712134783928967339397976020074068265641173861624290582684084696867053254",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11583542369038335138388496541737379876525399551149087870437001863297297008506",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28065263671209845342605885588690761657304529525229865246440248606406258803305",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "23064882353369310277440873338035299453341967560427384204171044534154898900916",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15950488219672203735245785650582273638684136614665871580209326304028447750851",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6546576665709028991556724428624317140175342609706974263890603253622509306978",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19388754650981566120932898465115955083985031128069487238175122632541383459448",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18105042673805520669356246186335728554898965970471048024983643659317993032175",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20505309222950349261976309724276375933722740115255653874102821113847569556524",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4952377862450898167591942328276866623813109202848740138980633118560181274102",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12823535788991722368363855627151568030006242423108430970038045265712562540951",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5155171439324220990644661489121926855830846571641816941818862131263411144402",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fp sponge params for the kimchi sponge with partial rounds
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fp> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fp>> = Lazy::new(params);
+    &PARAMS
+}
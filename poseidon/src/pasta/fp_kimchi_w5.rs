@@ -0,0 +1,1342 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use mina_curves::pasta::Fp;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 rust 5 kimchi_w5 */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fp> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fp::from_str(
+                    "3164534151414726744571168158123626802071166012806264689746783946740367723729",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24907344734648887548930953820198486326719897590545888528579874164763122803502",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21198789878225217319284380998935515562210719834376932791072190069998686308194",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9659834740805096890955337682506689313456737812603578607284979803254181881172",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2762004389445309740524845804103628794273953838853406176326090984487556383867",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "7894725269065172602257252591947779108657638411794249590764479130496771478677",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10002456414335404902662504670994430599519704981361496762214279993714335238429",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19050837290504711575632608226016385153316647623079160155769017971601765426847",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19943329713757236788899670410430375386080993424909100314013373260183957588196",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5199329753958709806666759260341438008714939064320156967417285744234117355416",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2890304951587263209194873594395880951678518418653670060121880865090802663",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11973646627896061975526648904848363522160493154839200215764179508271394989794",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10944115840519317507034826710546116058802733155100266403156937280953601902442",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27142840465436759316086070901703228877691404568478145822001279226957120288772",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5363199735211229146596099853223867495285585203912869495400306822671837891174",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "5043568296782464817691377000956845737119186811412555805292699520486244189201",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25623242466974603727344403402542587059579759274509168419748349215537600990365",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24360101329290861375430920215462515331018918214052923603935069118411801366823",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18631344163130850436172321611594711330684758142963425809021302312799434549142",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3239101080621399135942032833781736174436525976412412282063787355344192666095",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10499063117403431290658974511081019794653795470939060114553153773140263561058",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21078507984070932993778002836255239862068124562400573402235623598767542416679",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19518575552703189123138500095093357935708874527213709105395386551317404027620",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7722042447476283346937528794558170189888237226323594937671586621559142303919",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6152386302773843772936249179628577189183320849837974825838554949502765922027",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fp::from_str(
+                    "25843028532773087620930267365905379734868381832505018634809046069192179919508",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22669016194194267004895050741663537489320362401908929147838255314451365413921",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11067974914500938644228753058451711837906852568342238086669549071271430809726",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4882041436198023335985164635014902467381529733631273758500343180111239138925",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "794445321272892237999300031720218914496122348930088381954487775041523081539",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22435655153244205633820427887875038682081557301150022479851095056799028668244",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24694585218764111379357835730860390530193400848844876073205386633920262820221",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27463322132764430860661335561473777693939476617722805872328710277045454579597",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11723797231017766980594168077378218059688664176082393563760168202274418158272",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14284212938321988107441845693701949326779251781307449945057147587767059875310",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16068312627712274820369529397686560352208769068128283884990055293642321142878",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19325124970877398701666293233266495951955708596807945396656067523393905407954",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4632434913239991680195259555437340260141846097768672733775444493900349202074",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3616919467527425434970930882979564798159768288689421831837668889051207349716",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13408591399846074822527195239159648980280394389594511234481518035593066089260",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6938111186308099302000507869781369794011110743145576419744377787780072424845",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14387742881232811946902009510046111986145180124149534693227204862166788837131",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28712669405382029567336954933644243895113679136703179986639322910001434796165",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20944768280790834557402220449916363884859100079957963562292317100835807037952",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21899759868594774846967167852975003444001262342248388581396443231720342516901",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20644193378170835282428301828666344016250395672600796859637343890529295191827",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23909740720229474910326264730765584758412315652958783917053143983385287559982",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "565641153290014358567176337450651525596822344964394524637780663284515211909",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27331671942294649724940510541548851603176544348161601622208609726012627689760",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26345565791794685413183696097257221495798837322954969088522028620375872773815",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24072688010258322669843488666741939267771618621810138229697697367639152869461",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1276941036397944035750319433696157341230858031737376044225975106087846151524",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26932982200932634000422833500880431004866178832374001897749789547884273763460",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4695991754405602212963499615464058729611883367158949241576144653752752956514",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26266971833159789778287542312947037413116259167483731997834569195800146739795",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "14359434142158611852048707196466473838740259930685748609880277732539292593026",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5405914726490588199060572862237782595496731051368621285597239658850682472257",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9468406520605268644683041202904809354204887615358053754611722466722961656177",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1220636784103275548708394244924713635039204389930221197010234157413123468585",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18687572423291281843864410990830962949933300943616207005535745290375759094156",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "25484146141546716118814104963498708835763587776052803710754303153660801728742",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26172488821941633953996917794511143968112953196079679917743991769252320614561",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15001916347096390655892093464176068324615913357004732090752967804219771738146",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20245551222310749126436831588595104494035449003273808708039924463078847592350",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9477936641997927746901541767428308320762743086370127755842647471468315137814",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "5929387313568820132839094450702148158653706027061795933123943132115607616593",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "89547475203621586749188350973420345719493402984197852124063117262865066422",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2376473821713448675949731045674579885550557962412130304928320731698271252783",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2004448778877920697657521177005444703169701552977516160203892227248912692511",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3779333915187113624454253314368057208601065538730760797769291135127614185564",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "12021915589830073455535402579471062064063440816860790993085215127153062290828",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6861515399886977941888031813078210187189350597950062223420977744012269224991",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8564710344282565150010055960461899289009977963641229060731956318382455144330",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19281223870494909036864621452051121721791410122477409927233680212833714404531",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27644970147109608043417748747394282466669926539543793229425825552251636764993",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26466315125200327511321525053766767141052250592219145607977783483203764312759",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28139551473879616249835215869915376949994490436106630251672449155902360690456",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6489768397166022327634043770280555956308885024702443587736140345008826054987",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18422612438676735648760713993634038872381056565417446541853656586725939548154",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3167928934509187165389009509359927658273142014213872241650361932068660145386",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10253124756441605508252556183866246200232110246724399951139520004700124044366",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12817763053804503492121046716202411462480180031963106905060665146318814017291",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19097214636531829600878100520002206230760616685239949738080019153214304270241",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26380341311103374025772681114072541121327166245258741380722635130368472997240",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15447881365126035201022576072795356689358533372936117522288643076393324453028",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9377505542597022188313375297031261861507556755621977710291766292725290963248",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2513102129904553666197193892131924097025242886521263590775914998187117503355",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25338479163743352806194657066318753174310920534284749908985275171105157212992",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27262604671083823419542614439491196696282422193341610553520544687175088084157",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9893658993261302210773930411192296960853929483335919078804061550736387774622",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "25121199506646894967794516479584057342137369090210865516080369262470441831683",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22139622782028870198691675446634965336452244727755142634888564891175630459740",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26481381511073822290799375322261404899556154363622439656124087329806747702684",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17173472561606394591921452621854793030080170468098845671874056717604546178969",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16263364944685467928042570425112158938522274241685180627119076520210892498291",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "1559389718580237557083755457287814422880918727639138560270192018048435949009",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "115759140450230850832080727658606282727719606942020726422576518387336657425",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7593105238865606433299087151595973323040940081599134058430288706656263581701",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13589220686884393181946577105308011487241130360415902975850291462890028162918",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2612297197480072164001354178574688443961626224372878974640548419893403720757",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "1312420858445868681433765725638697378468957395617255895647330358197103427766",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28331427882318735447955838158664613754657357234001011695574924468654814629127",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19098593748416829692060332945001881316042372268478069444728354034882989427272",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18038335951838779625652930537981809995023352398439388068537212235194047764022",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12433759476200609703629269894964275289936671062079442209290940990132564532541",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "18415490146339040066605395446904138810968299292484383792428660313304970037913",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11179292874214639948222375641713200551238180800991091176561657635915345943915",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1230851199881192256848270671069566421340993031784080490779429599487562176254",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10650392773569726379635345389912771713745831411326783176155766409780897573295",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22999508015867471703906772778713171191118301669642562020789756739041412588844",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "27754840309899958906458971370907116902010313277354628835162147144875597131223",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7181245279739822722515458439558778660864115641492257179385640510330686353117",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "981703684194675785571699473490116338224797234609722592116115550855557720955",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24106486418081636874178855531035746570311141324743013475965800105634850855003",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2823801469640297572932202986171275753315976127534984193189696730142270939309",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9015885599934003188964593375599599607806244319122996156908814169403972574599",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "746379534976495850600883705745579951662830540879860924313632046475423218215",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11036539525581140660860023319815441305593851802271197303410364139181161865050",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13744647997883881784630789634827100806407731649118089453457244754730566809645",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6985841885033347785157593169896413571365787405271013381714556340040323770723",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24220327022108418419630186187845778232112393210928904299778124292564917720248",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25605441217929124263197287558019550340247055917414659673150439044373793389539",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5648625022096052400201653196741486263605364373481392816459619131293462061330",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24930843617221506673807893724834213693795404028844912650043106903848503588879",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18494746602960871189873675323985051758308127515799694343455553514813614279574",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "8527459105737372807285646833277545164502051047804653134659012099702568582635",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8214528676968417773779741001566656377030120799620995214036259738906003209416",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16884234086745552318167891313294239300997979048117447765466217804235619449588",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15945371297687570586736749612333323465340698197455213946906439902077193823012",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19637469260809381258153253481206934296426335417058596208175972566080561732102",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4981894803848546423994629824742307129290612295742319055069428374000410586118",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19795492555227787101753643333408321215858965696405839967051058618647473135320",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9121217580669980124555100384586515854002134641564594372840841408186877176133",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15398876757717954221032558585023455450506735047621268596440926875270911906322",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22010055899937897541099212576025963873482971190970106732007104279839819345944",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24285768868900845601363166321960159087538288903430443356084671060069564815246",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18829732088444312780404029484224178144101760699253965681958963656013771209843",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23360366758961651679600922948906408201708032184027279972091935266249631354478",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13870971288502162383300681878645354867464925162561382776190817624006767736787",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11888863305767433049192764947524254365526976425193458294178547187352271868044",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24289415761131013560842771550537953892956970490852942999421643407801614766467",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2450014661072128479722659974899455179966307558445492765091842255953770486956",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10427432607049701202337350628259521460853305589594624298471100447315799231047",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4864795839552502206106410408680306559452091324163351552910531183428286599790",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26649724309932499934485016636573600237981408804008968774320077542665271982631",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19394472294066516131207085482119134131649086699707875595085443645066293804916",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3724087953920083560542076105125734873767902708574849919312004486234798681647",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9532632987218720172480459235910629351564817614565542972352195207199886055915",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22969840273179856509327591170633374770363763946594812992731144649733116095592",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8223002489799227954675771652708603286533228657759174536609062850509788893793",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "25420377785488046101131963084387502479554224802211524267702455003182968280198",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "956027574738400606067414569094554283871563355928516690571324719828256496596",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5418518700644211051072931533121311852535145282365890518396373741597789736008",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8259324032729683851641759780405244870899886118813317259000056754822885568758",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10668185680380964037918115002973849511401830360102107513310870506758645179867",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22380263804869914715362430071730823056092599389971318416079419745177367586398",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2610111843702421455859312832920900796083639880116435717706811225406505180526",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14973230626065180710290109648451902671988769397696217651553498177216478844267",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9782838301858508355115062580673799153697126396662428697795931190878106600567",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2599759341237579068809480350716598023475731548996111636868393775203341634038",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6985762093702796343331413702009134476692313440238792420432007076685871305377",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18624150811224422941146701214895792021527723508437786104248910590858216069646",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21627158966300701648335414881927314328440851678962238731656737419724120847164",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23516993632717588380029622884951118244652598496460111401481266073801112415841",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2933048622500552283274206006564358535373181059494087787380742744527249280901",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19871459815371629844220998221502081906023618502551024051454734432518198901294",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19948125189277568114326250429276727074531767431440757126562772606771906542477",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14267978815771869453532704626939762019404752333806272204394564547448674180524",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27544114073764731111120585297313743222355975759202723279611498809217680677121",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23381612191295029193885186637107697362806528596439260480830495847739652980510",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2859491527207177261103730268952630569483829837441675066905209053590567044072",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10378227683535414034146546229474616570213511727616724766661102189260316517231",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21505601935663198542532398670249762910884722934660457476654341721641343783344",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18550360249203973733076873585168698060756705621340702080585002117551921075448",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15174235894178941936005593786407715606825814567000706868804426010522124349191",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2481250299877424605315235133658792256080169023558708976830296262524850694777",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1554300796826536463468989480616610747737026968123271799925150616826719073721",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21256411454794778080775270944488443628489426568422811746829459128008295054203",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16664300045965788890278547119450562287575047198433953601456808895973934221402",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22575478642619772558850588193735663153640934193784136284657672040359115483660",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10622084576626135342390490234988809609532826819715723026207277903181400674808",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2285846283779564108939188587455227198757749864659681250546168083289787265887",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19089919892869382355255046640898374652413609671251671989204267708560450126348",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28130509436599403612550764059756696343643503547371055101891087636366182757649",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17436693051616394721260174873951536435589196810286110488227881031110017393376",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2495663160075895532608584968864744183624897413865138040255364822047987214655",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9287070794643601145992565177665546845869770843255749964665422973846573415607",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5485695988513763484685095116334316321231424556024712596472662164677299762534",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27712983279002795438395153569597012012683529354793851025311985507255773200144",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28534991469478247786778966031957564694537964590736917246179965679129079307831",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "11986725856460794634442015124847483103273946914156097720685371955075552947328",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28655427784710128769762439979474125315277681392782278452160121891029452913823",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7050515024929619849785576941075345779007046108406251754959232079580225129523",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25269419856346046773526605059833991140248416951414563837694174742691914540401",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27893288962203562748376830906950032582479816807187813129381917625587024358914",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6417808667030883972096942970184109442302324103883057345426821488527262997603",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23159870219179230310145718531039496191652186898117228212321257925163460117638",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24381070600570848484132192319265549956878947061300957716479887048097663519510",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26678604917631835327058776446135579829014470998101259883777187409965608522903",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6952507009800588834154610250483971058837945785140471269689673767196121525802",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2489961826732778184421411690209308116164390407775697471622622920628792799892",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1911692441871113900104892377685253856675951709173142306598515656105442964863",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14680775449713665573739633840791788301380893598244892682942478321670589060881",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6562876198370144430338263358518805020954512306161947558987223854401127538084",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12442227507667569582808011632107355243489891296232151788456497534761777630481",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24695846072923505560058578481678106202559011092232824249661965553037040884638",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1979696818672390637023377738070033962648656277534678272417460991108092847304",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14737666730122065383579667831954551143615338373390229798736053761768337036513",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19018306078155477683302371687801771247871168259946236149270970425013374721683",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6730866028906797342557977975664588042313847547383026217588160647255497948896",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20051162040346566418106311996064362457456280443888757581331272478089227227193",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17267329578370556590311320800095974312114639175182548223329791501220844068789",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23332164900699479162389470468316581495562969163560445704920076940154486400733",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19213724942988449662044096042002312315373255686517057472400064242434403554253",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25773681235974390245307136217995202783783801990491629446266298445287125962274",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "3118895568619198734838463967092115236240593800741553753869451776073616845180",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11693673687908357631857075794333091282927470237877542094073540857027479087346",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12881645736040219739970905384169277197112330417036839210836338645987875414798",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27644219413042186646686510487182664016247726938722680629905534551095925064283",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27857664167587999100558957658141452883281114758107204097815311677953761146395",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4065455552892865284273742484359479973980715733637834548031889865301775023967",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19263389230669197490017795816446934626451689180980149027278193826666941865432",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15100562566900415475380300621271866725643486042267543977098395868397018376672",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4020036585689374937098653837677276881759281520609911010767099431764362954488",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12577181608757224468575172437901880202989434839854647835933323995927642235184",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26097105519833975331498671719933899583302709744686141790152239000554202597816",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15312181285365292276875346672168249319517640620493479124386499347708418494005",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10412877277088465571907548812311606163914848862013845353564622124847850099347",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22053226304243020178031901785646022008506376910433630718625873234040318407624",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2632388161409157704008653469964163669580319008570909971910694778009690547337",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2961647223037830845708784061057139152976176324679190174652257073555816538426",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7717527252172953805479709921175139989425615944207485147000881969424319780002",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18603350505833942894479971305470700793786766780658890343496139774178796987664",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26076799748881953258493444970043471189668469961951672800346368036764591904002",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25777693573249918912551477280584048090363887451529668527589137942404887623779",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22125161596744225015165380818086690375657070634717621155349057288143498129505",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25481620602019506271297138108915490686956855202555543873867328111904567228242",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22456198049401887510125650145838000723088519201505098661711756042130396041937",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25708740865359233534441925444013912911948307748422266728148857934495578280316",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26356292956222177347630488366845860838893726714025310546857347900861487723601",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9294834146554348553098151857385874240306806352132476040051819665095057178316",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5846758225739623503969649384434484423547625496997491947848881366241392314651",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5886214873694014106105606080671536298481322966265304396828245886680944773222",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25255496211610226824251372039612389010056640255740613699510698578414497471865",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11522081098484707085398142845855861538981404795182692210588437448060040609219",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2223786761381203097837798180192695522539879313175018507336156136050303519455",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14511900086091410367638920807063055370080857388525051491542903089767336628655",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16032210949348205444268759019245679550841070685138116612981308988436123923950",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3059419873253141383469394538396082868160743213646947766271309390413008409655",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12923119660519525343388224448253683740915702726442338408088624985685161588041",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "11412724172745822815779865214314316682424818795767846880748054263231583903298",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11041979379226905478631227486694484921620626434544047700981946342355713912127",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "597524097133062727897919630624340143392890166104225277805737573422464776846",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23070510322411369664529261254345277674577657422992306526463630560612324573115",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5214909570040275443386986493009699351253981864776155942423251435899893424239",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26544584692549303101835097908173119566589766510758551724742164482420400781148",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10319149889805103915100259432767487473494107100368751492205752178945122853422",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9500290583691161284955474002949842275250202401468317945001906376522747585649",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22012117390924476183203086672268635155911920307730335794464675687550660333248",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24881688282391241001376569439998771097669304098939700413565915298647858841622",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "11862072803849384994008304128175239741531902996912838142078778591957964366021",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5061297152297455091581850126599570597456349685371768534043278035301514184702",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16133603464400486295050754099072589784418059476809071003754980784594713075178",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12312381334977962509151903802693800224009196963434494456168137719449289120770",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4162783892036457286927296454132658058129808276502645467504768326605218278612",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "28435870855691229779993606878294055670517345279065241765778200104002355162704",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28737192488366561128333981460409127179537700923001038383997543484035880750989",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9429010245769906741006038875432364150857382396158018063806071575921058230869",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20856565441508140794964072324066992378360750024260049015912945272185196428660",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18143523695151513822839967004083827028130247749258354478172629687338753097439",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "3093241869402438245178120652384769974270098094530146645387608634146202681606",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8319809413689834395356295519724608058749365543018170574339668953840735304028",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21074287780604541602908502427363491781807456780894516591885046748184371328181",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8845018602429127747251279798250493059415570887908648185220883900159512713092",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27781548398295019580201948618381923314820308454816482501883023229711553741447",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "21012387905771197874853148215211179840415357980458638492938271049461668211128",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28139751006988745222172290842462784732129051066496576461348943535385490232721",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25595991158447388323464239320687957482621846148404317391849361393409473315543",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14645542548565798117476748429033766958560718086615157361208398206196469593066",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8208209021266406736286598900671279193257522041962463932018298836413945186174",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "8995912573722940358463291216344304754948581413629556670240759380809545623983",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25332716453931995121917554682134770759439653315808520898279260184472115263803",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27298650092314914993807590655834084784479399181330374465697724305129332268535",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4577945138331022117107344318070165905639343477404344595850047268339055965158",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22770990620696321843324674807911745203563338243586282189952413122870011661863",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4923130468968015123814228884556542487809323852810126975628388839947993342503",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21752662195288851293444331382119924043677445986221647472170970374856615354973",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9918623049868768793408729400071529958350265551244571718358896161480445599461",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20228821066663466975168218210586714704671286256426875380090042713503273333951",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1333712708691414852303688545575546936206360869437995241986572177718682846125",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13469439517895620903675357309462987008166249398113865860912673470356803261520",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4539011942238487657124073560311715290028378712580872995522015076577067149134",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14366171942876154561673892596776091824844331156837251645190645457568350050489",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2754795582340645008243657215343929931943352627241778498839501658409539873236",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2849423371447739793599887513412509992294848624120357126833654606450664527061",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "7143575111245427328181694550698814181284875272065044651830888262201938852288",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25792521786978326169099884711768417131551417010595901758246836271447605423289",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2082339061314671145190049242298982378459574636410766735174131023011489906692",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4305183426167714034658427800517705894441657160501220013913584383633191534221",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23306384226800336077727934355874266702793707235521715231104346916927495786947",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fp sponge params for a width-5 sponge
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fp> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fp>> = Lazy::new(params);
+    &PARAMS
+}
@@ -0,0 +1,2454 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use mina_curves::pasta::Fp;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 rust 9 kimchi_w9 */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fp> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fp::from_str(
+                    "26570755013596147022566472516066232604117558585934443204648317250400643106953",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18422938584730678398869030757087396050241893770735407931360350496857533459058",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27842665492940986493928436789874776178163317154663298898907197886112720407280",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16908479780560385340126834967938409677454541114788222310785839075943939501121",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9634121319229303686901082268963417738621537461015677718291363043902890175770",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17000407291869246238808793451800680860466227373904218362444408210915019644610",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6000112724550526440004802989429902603125958833834742148550070948158205515994",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11998023581640947183971263709538947978784411518312123251827494373790869174558",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18000855517750606176979804855101625888726560912119710068260273644811490203813",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9201985264908318838812337038243736748079466753083869913745284611526472066995",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "138569448965657081149200594364055761267934935746339281786854892353881010314",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18797264767708386353788051835460961894635251270649179985043484283033614697702",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26644580353118250835062170341541514190909506407500324196690486621740795468554",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25595349437592091373298550649318738807114379492655037719793890836988425046365",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11257428070832192115022164295856583517538805252819289954599918648347391708315",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1806731606481515486874621604395553614744079212924714400486378442367972947817",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15276525573587402804761578532802743253966900059727095792267922914401037026995",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13951919105231244615521261332622955598364266446449736121651100437655663421817",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26664000931121312644493851721865966318452628483317063308562393688067357120099",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14700629853848345699820768714365905623754750554651601685235018216384910057599",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14330450185981863855232170270263372827525552475232906704728571369932591788263",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27100877988790217522880209351044072041384460244874873957118149870404259139771",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13347381258048361926698382748763356193985141765550933542941691595137438765302",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22273293700415178539629862830749612723719309123515069923956373185342218698497",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2035348574007464493381978292519757448386810399154954399043471529730312597940",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8820664410365771543802069593192987424412181992385010083325945316065423918999",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13717893584087692525277528799451018780529757374154367712964359401740376454960",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16787055124572133476914756875273484775334796502191795507764085842041776890340",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19601522144197319042804119924955937821851209472658364434790988250076943847576",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16602588892491383969215327335109409740468305567115221628787535551020652872518",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9696406938452488755235377486052639384937480387119778298348147395748828461584",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5812030698685956283536632336587221730485893232478326414180359759797581532059",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18660158140985454963562731361162968668925512880689561339256485120124528089149",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28745019530744361082603210892986417756383088320190211570983334892144629234498",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12024805068248420545779376411098183581504392204188876497030619492376620006188",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1553651463291421355031880711262983487864502468303848475638944832665552642562",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "17625920639280643266440731551326468329694348984867743851500482389078453908375",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6715065321892512444078919457558508712995459415936515642772386968673961040571",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25599313995139618924325842998657382074220022697896221631564606969791056495955",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22819333812933462629269439449381788973488502226218751782366186188516432646202",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2930204155710012935110402155303027105435483662628292005432048584302092624328",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20751673493348932686517233796126886212533453339178836831064029192813021731328",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23420439787483016789835484144243399191830691718442163860158858431094533041138",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14536239491792742727355475430401383570616330597273927648909359752587433859384",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19883557927617452235861418737501883351652377294021487959940682258256532621426",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "14730836393866858376500103441135814252389475637840234597580149657006768665351",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3925914576651097890830423321817286554501197041979050040476736733936330488904",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12663141053028108248589197698790362106933358242896007618341665743887739469726",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3800581211845881197396083348028973644190127085461738748720035337593702743060",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21595605258247850976078413683626670069439992441799601253693723711711191267475",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6201958055548290398133714216509859672679489847967928345807808583908538153100",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18817394838462008415907940378558596571250711014272617958137229442485452365439",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10339578682444016857088183847183754881220745918846687522694794956280536930176",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8353509234699165339417924700766210205976720345432602397789141072255779498104",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "1726371009976167479273431567891188998398535164071306418498471058176708378272",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18833032255257200886512749874711216359215465279427947431259085229511313803302",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16015114890836147152682708859629583329224711837386460088569302148800975616639",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19518069992242594101725030353808589957690167177206179358690247045545130811108",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21253598633515729302099536855040796286436064974865397398629629991485946793918",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2973379510985875147173469686599715519164163138728119741239226896096908696707",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11855596789737719922181540319707979329090250008130096579596555603999470654852",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23519665612339041881076358039998238960743705217748541754666858166693095344557",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27855783061556760273791105291384864850951729040348623530343060694484868083753",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "28792855686292720258758373000348388549265393314110847283961601956994882609870",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21768344296793219029147365753867284561931139825494520866577759426395113261506",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24635013620157103297407665019497715828230994907771528333771070381059698823732",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12541809231542166489071705943820287076104415743962700595459483779834653704551",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13909281414666514041380029454874601787826081825747510541238690022699505486803",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22835465196481415025097606187879466759518750984225694141580773492483218217223",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9146303172045230930758677017402171999230385393913257880031852868405966237622",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24192993484639465368504064852374663113154831843579406112605673822870224977657",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26683516542333058914908780169727485687774463546682817422687147366956266673323",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "5801767565943530232659290883495691948602207842355454383338159066713666110403",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9033142711748812526709521763908109093918839002091562902996246392065702646903",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25854156484054890398910877490505027499656066029350525050441090587831409684030",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20724444878390381862024778705977053169483079989573513707873762898378522897847",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13446641229968443781614139363876148724956724395660061032549962964692394982677",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26272867550923026653104533137361154749475794964955134760096469441949192255773",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26470399201308317296600451584909329892935461326290812316663732958673939950743",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6345686396299254927577992301375466254211332866843847588821448743592065646133",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27992539327070481997154494998003552043080706414603396315017687528207591480726",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fp::from_str(
+                    "12452878586124114268034933348997035423664307219886953982127619315812081583804",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24540382084040056803048350205572819138062421669480456926036841999367876865935",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1825958052285313757274149512341951398473431995281356064930113416616962861767",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28069982272515763262900988760668135861125339564815477724204456787627025914943",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16060696565526248678459377773436493290820257935563833876927636744995792792126",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23188677070901683857134855259947948638945716319440298018606207751813819179839",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17800018634984347151617460419894585569742950766214777142906768597161189662820",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17566028696214555114909425200267175379483309473488095400004910812679542166549",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21681261980742623238611846561116637530510865322067446148252830060210387119625",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19678488444739168758438725504009467030435031592276569225405684755672943894393",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7922972471526105070668684591070856585004216377597167555669504730055628939557",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14021542516201968740659901960053609265641681069976149848671108736580738065822",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4455504828569524503348145047454094666604217595421683439428615135186510778456",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15396621781307778344978689453231192097655127171786003835488437477935195869553",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24926873733036757067363636782160181005240262125781744779920456783943379710302",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4783401965703386895371334255661710583918096824967880815422002948157179485030",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5188635212712246722002714410850844205597643785892849132777094801986673901440",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10581510114273270815017642616852866079180732159588731817045225011422221374996",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16667095968473176503055726733081679640598875575572565182363559203013329326953",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9571412081224445912867780960847468125451858135839320255598637960430672996177",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15880065967818905516027530017443118696789720150684540722634968482536072567203",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23354195373773718689720711610249052961827445018786501625988580609285348489767",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12545855728313028327776813841877352123709981026827051354168534964734500600080",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12625198366629354714084143115871385394235196453642147143785263628696178994360",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2301740842834112594729856911798237407654054138597715409292798706010344227726",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22311155068113413363729823010955620440443117506544352189295440963656525090705",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21286966652621352364307867814803431790518838131985114411661200229608100178677",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "23964285732854289449396094255692067903112025590686479983680771124447301300936",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16518364179807644650902636605579551127985499051976392498383369363157024956959",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16191983155167372986611031528125176297819997077004271119307517541783089216529",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26282691311944448989354013294842589220280452456200567329738671092413010150970",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13083597419441331353713554300087843605062342734788482587797862164904133234885",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25212750807855897113910237963415408115011849501972883250517335484297882255815",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11537984667886662856006824616706331539843534954327276146952670174676002107453",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3215992153744016389957889036096959317268924768906629603366656245743463007482",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23311790981943888393648531429980747120253420974374197930874090942224698064909",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "18764202260567417444353438140415506478790727856030854198573084788367194893993",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22453482795407821148199760669607070277660071499092828921689630284794856154899",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15409504404840401394875460417442656910659400430915801647473449300953759695894",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7660494815919116887286782713742524914030991907558852533561149413464769906499",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9984893947171158062158062501322202685600590287715007504693736541053671010597",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1351440835413980148831772772836996388845566200080314118150344427472900046946",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23591211832070261187594946213729494005864102649372040535681407566189118736135",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23827446126154616524241540673057194375091409593561223026453738318464734250297",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10826061014491368523074120030294208511204084642899695735526765502487026860973",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "21801790335692510430735395287822351992990959736472752242126156021666273903865",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14756427830504627923928159136391758538258534309393398469861253463103725754738",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14204587331225330750321425594241142825511580099863340983490910940228934984557",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18153906571724596307717592618683826916739408343727560219348140958065770792758",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19189594320908204268684049044888015270839789327730013465757706760568917382577",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6422635092208845849145834712539039969413758488807345432707408858612308851012",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10798794064058030605556739784074325108813286496397324617262717720127514469772",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18476291269144264238642593214281206523618542442749393005696416952060462735012",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15949413770057358240887823764092160497745108556526343628217929635945099739501",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "783399110538118955269373664054192469211922763285198357784926100460987273162",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27592447239186446931476117999689174190443751906599555699404220446795742118705",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22974529275001253868749585161377845945409495974367143124494364320159012619061",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8754621115299066296029120102359420743153939874251568740827968041366625593232",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24389182900369347736095338112633976114067272221668030357566183522061503172568",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22245300003006996089046023127761917831681928645487874606480003651474630820093",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7668911281757424481009182782059422757854469739574226302718525722098660048616",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26561656298340787296005618173210062607726292268326235039744897660633919224182",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22411331025979966390032621389197446639654368792322122573514378945204726797319",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "18022118288517871319910764155844798481108425699205129442851617019511130740490",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9011901142232571222356710917897095666239806394105632190195687711565489266846",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1577860780741179284679003840863789786935697437572358189627193491512712681886",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4996826153136999394983873282348984931108155458617775952802444056929525542212",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20103496903631421299904060951233528917303889064155321585437488429070950607905",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25436523751678067503085591044223416627117012824296148134379902586348463514008",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11089465167749380296742929953271837655385764044917239326825549558236913966710",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20264075628366270717798138543474727625924762243398176786011931940850136554071",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10329238185073188971463411260654085116810680259692960208553268401174780702468",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "28248690601055069092019196000027041069338427868122731071505853192337065434038",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25953003104311065438864967609337499971975074659399334942784790518076984256640",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13209831878784261978804621770423691499902384507127004673096793328816841793869",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13343751084762914477933812178051738775368806881636990710975598540044318568714",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25475778552250445995440032988029440794230808589653578631350020638141161950887",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5622143255033984077229521485115867894496018362176486759081471181738477438615",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19647058879077438375959181554189178884952702254419335020280352817651468158559",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18772094182422470908397648767864686918314055972623147719517395283098971678983",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26233286796997366179602877590714683713521722003976288833716661942370451459502",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "3508608475801253577663885289517752406067976039827425880106813260977104288777",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6676597151161394715494054714733711603500244364759014557212579080414237043550",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26820554759835745789231417328133777668078927989309432074975728015185514273106",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1604188149138987026458464679505841814334399972680915918465876509557152949984",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5434645271483871708975625083471243911703207913475235880015398904143483951032",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9824619714660525678360426717622367455093872138400059589919035773322130663188",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7686038824024539996259761075950972049325680470915142950639693214793942945281",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16265154084303881154274447726231654655077793256047098085583137871232001470132",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15485097544549393257145619022800575462605324384865011841339281558272999557693",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24700741910644915750970217101761614756400090086656486197833006829667979516156",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15736261330976030866811604648488221611571847361629990506236837446404751389903",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28217232157846303335504510819035901209264742170199757147057399378952736975587",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6308813138123499667085666435380582246342834188423854274361526480501939297588",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3469442828714269876151953015938543622149921370725030947903153447102074818747",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2444450549697012991377242714905516684527563395852892372459240598185574697738",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22059254748051911965946454757454460644851630667042793239664282553295256984920",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15201179953408049391794432567138522190280854265666347725149091660086453323774",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4624505696460790098261753471553897887737858909221515453812724932615351730981",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24927496390324600504745334032034594056901501845181395611285861803839611842201",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27441893138333768466675331883454458622340122122676109239793133024095749346303",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23523325312889789651100307442778235077424891923258037910264492206204556306528",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19251988195292673820554370970733086510827530381110471602894592250104680230453",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23691271155184038306788299675629509019161985660687842958976476997897804786157",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8539038945395719638781632139232195414880644570832274115836270394882759620621",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25465666033907950302986102796957658151287972798896915435344633936810083055089",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19584883146892889665146447227247450752352693692643801448794107447453026332843",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21298602697326027983731205891185195967987074846098479663713606047939900806904",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "23464289204059341445916403727772419908139465014041968201871867068207998155348",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3446725001049688620585516980030366087829439222264919869052825212492084201291",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19233094887786860974545715995615563999681992204346925701170910518714322882332",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24640691081123458548127559630019781498757167570363444858257785695337996088616",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7067466161752340604283093556570764680108166972007112313528172710955401891962",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7693796523103303468689364770317883770492153289362423611533886622862143572702",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5607823320793788628419718547966475410336291883382538722201050963261038332990",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23079525194326415832028376795600096339491704209165016929590966457749747587659",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15034065131891366358655709076587741679797902068126598940886844515644922158121",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16009747784564703806176695842833747954329587648175912621385124370572077314980",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11663590198038721244651576712483977062866808258218090413892788676253265151072",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3414824676385020117620665601648631858341609046172436648480247960546273433143",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8852075760678108784904455221585401553642574637452587995939103296660216856324",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22286033289386733544339122845848956534193471648625422900840520020873193181378",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16412527886595500746180823422761790642383953725493081914636818072777783884423",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19834442753796500719677781747067041164900196908629122662285463153370768021704",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23964055854790153031179815677745967410235863726713963677901733959244934702384",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6570675058326114514443844055905551648594838745620589063618669976468266037752",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6125438614110793437664662565642591811955444966991391891854687456666585957799",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20084491138938986696592827164637790469117458201091051327190502285825041961063",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8220232791589199528459126153674928511918473426609982598882820290901440757568",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9453223648676632817776605252749771693842125448366854786731707816303804641165",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15974076664767716980304159028767907794779934185113717053452093908014772198262",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11051286084167972403109856642771319549609446290413642321194645187111796279436",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21773545386146974102011396488418313412908108448950057584709393922664241555954",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22712685099843835455246095022542956519410281643074210395449991650294725856386",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8473171600513439367212622252579589529410179571390121150358925507442818133400",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4793998668146860433812117636641070173004945279699080838293326379476363693909",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25660896445344167959688173824405729421669874244525825573786368562186680233613",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14395522491482196639130807427986229351240546460404025824152956858573703213258",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14565785432645848384933676413326515602912867614354463106520666277802025469762",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12302724381252882171387614379492121039951003024442276259057029328979326458370",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2418987912263323177321266550151964547366402942448547109183801983403034654731",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14936091382954286407210710930421443829637388132665487446463483384424622536952",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18887569330085026992703917471427179009382706384806071388075623762471911507876",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22778985827105887015135101138038474944355388435424004014924684695261568048370",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6931612025343534550422009300867614055245176067794764780014196704471220611482",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24446997138665722789760235735423682112641388826219669521718745852151701163845",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8083358045895504817120390066344198936687119278578530915784304072066673674755",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13010504741520857920472112057412357713464961727894977319746581343505128756020",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2851584085272520749583285834353035183521526002271078630502100725193105215383",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8692783652211621302524144079508867046436954504606630220940593825843805872620",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15512202345553764383705601812762194988629908122614818479201117789368331910119",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27094870473138669418257801820414409076371917343141353515466394938893577350903",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10082956263161378638862297609733179675146589720008580251971171347161418799706",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6373929587192199177823693586431543471139573704983329956569129078679841716061",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2292817199372747695731965126926050406796816140927887268587262789101994921945",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19959441020841545940790868924452554661546320494550693588606225412950953507361",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7551989744391371504092913024708273506001877951766979759128442549215511356201",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4080316244526407904662341534088435477218125935584935202895018490157324819475",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27075483029640431556750650881353174791928694603164026128146405717559173021322",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1657198047764307156004865510254281151685721084954271357315863688260957645596",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2190247253953570214573319791587223728017541224695495589694431129322151116599",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8009280793183455790737212225198380603768551945117266003998734378086956864114",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "12160329342242083975395518298463749878884806751953627598626119534273060342641",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16054965927629481003946547258550378810623300366835489528588916784601612450868",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20470957957633835325682588758782621639588963648591797383858346082237670565749",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10542413170454069405135471301848359418438052465685926940977469316092012464584",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "250506329569578642172593374755053053109828747341073386068701821260791919291",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3316053978080896228215155958864777653536295645605930545246382191645843988671",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28128584258455898314100735545125930054752973544319041026585129321748946345524",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17208257455361202370957741237683789939227517678073219809347375206550642586097",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2149860659919168858236931957735936602843877350391984216908213810395834394976",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26094846806417082918121268070068178204643354057137214652700359620242525403726",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1130805861443912161150788346954809113215518461875272563292668302205983567572",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26325702295416648338751693461810783404868299626212309869190182650589607237023",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20135532683824515985560367031611233394805462613214082249250855649831161464776",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21887635631955521511988499220616616650644006784837789283848173344356677331835",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13476082764346521784385680699343768095399387745756927365183618856112474574818",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9116709262616162858626687486597376968767359376880550020227802210437114620585",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4149033807289462969359360119623000905770710689269484580519147468166394598107",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20372831462435739583552396611808341351272803592990409727923756517426740883326",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26258622114485518189714375383831456872064902875338153904104313025786518704400",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16694930188223669019617520188280110279586451289901265674790859767276027480753",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26936848394279071010421549981001031227426410922679183505143464586744720945240",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22440513889532197525749576236664226160325648533333546591246510542291572901009",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15273309763119869566987173740015060049738372981177918430945918389193090824811",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13658021708364291588263408462382319797402985440628479906185081503465962457363",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18746192203853787530623355024680769028570003340440918617580891634288031736375",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18127638545323993442086015532516890764606644681544823602687329582106558270342",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13676349756046408994382854434438822191407797516876382359606137166704124733554",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20065116581747558855391561379955714332333513418047630629633639388987096502576",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4223501480608626131029054507236418923381713920985485414234109321621470432480",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28509597144273487340891621808609150442284359603786898299729220034805032116173",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28031273279681078588925273345981704292019542810113111992874857230687065888709",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8527785618107099609158172018046934655032317285385412406720703258078324540985",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27640703155365384708071438193431086704412516955271592339657049493834403590825",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13384083788652740847658813035919021144140861490336604614882786662215622896952",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1934178696893087475108074017873936891942439818311794397021185511713515095720",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21235219537314036255187446394999420832162667142651568572195964793391929838006",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "17523765541549229038119567782346722715131976478231355634446175916536146099334",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17285227936194032830668125830946887880674500839020516987942527168267541736440",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5191957464771807070244141595361561908485427299603568711798477107136892162424",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13533886394492293998179035646273517452874732181132461263146833739909905310613",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3600504362659346871798628161021820055615925648817116516588804448072737745627",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11554326229836803319104263216361676202168067762369031511787145916597244606126",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22365608895641913479891459765694914231427008612656654812026597878244075331201",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18201394273125330902893682525597165603970414109911489609972993741487647598224",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22213058045717086834263874508843089285526824059894453808771369178872480962107",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "5336047245228347326439420599960110341233064839299004100259228532810911504840",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24614592887317079654528162823869093023482053983599880977877197607571631614423",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13139297066777367516623906083524228590470988129315351209468095854695980349253",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13553647543124781451392057631890845159221043970980954752272281412613154319757",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7987387301191197794409851363838256465815205971516051356655051036494237090710",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25357158290558859428115452271382509357082399090689460134064612381030033625715",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24619605077525133250752827795173361534396086249729320294245969627685312355337",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11614736670449956075839833331680016232057132445452475688942910184674336250993",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22395570577955462062462736621448261253233927873697041558242719979936910300967",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20569604824304817639854199285744264529424486894148737770178333785316282610898",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14954977050746241279548055018659893876654204951529257759125143008686110166561",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14367911206839142732973557157746254291327285827032237018020458305326122507758",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9030978716564758512894022549406673995835201677667871934717009346164631980961",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27482437592483262195014720041937251926074366676815927432675414467597198524741",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6247362742641156768235000812283100923414659981856781526140666560184455944083",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17881130741604619316181554302991559468185798943782158954664657189194315251879",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14992669236755620040714787307863330096682490554349725642626455313621338938135",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17268864563393695507767532723912831806272030531146348913603676244143675855315",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20523743965720071239973821079773472001073670483104824190628549032989728898086",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23783328817932685283208795376501713514436007771060826963040981837553488512411",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28334169644849907219507973755076264254535444002395265158802535870946202649841",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2337170758826195972754036578618350570176516391511020545992904504776835262766",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3957152217680158529965497885785442432983439331230208292898448536391232087663",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8828323398931195689130131373363903213068353443244457961318298545000373156873",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7709346128246707978765781532926582940579113240168471534976915130487593082698",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17392924124709276968534360056390371932769042186000555225045326490208862914668",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12592237367555453677679859168199819705834109519254989494944422756592574011127",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "17833673968323520047102441498928915511353951296344241375432741055008203379096",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28323402382889832045006993129274000298566391332075716471602525973492270823707",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16047076218563858200342188338344677202284166599204818107439737816996328988435",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19227690008766257719690963080950511588766235908102795323365237332446327112029",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17462522869252813248983370621368391013123390953781374489522798449179022020450",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7808134047664858217829141797557970918948857727405593537242949520713756544520",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20165247091171641755361264369889454428267375582700747953192008883184979631818",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28078035640330157352811958961402934207027807823426194755482826308467456716094",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22482200177105083797087747658644192387357876901467794183804984675470405244996",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16798110734872826980369798837082720652850976789870938950807170780796061326178",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12370902115544075215857379527322732008325560067257070813736971927430003403482",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16154989934562186644318870527685314779435191489351806767840917072319062577394",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6343951849060987472556590815442440308468592137406459847353856362264005286879",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23341869792053886599126420969963939480645023749991323547894346768134683830649",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21448168999501152979414213037824735110210020810053881595134033561908912439428",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11067376671124512238127768260376016054375654700983767752340848467893623354616",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21229547614889396125285603992937970277312503016444184585621387421266661635377",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16997080735929756805358515388388035909600726541877179064671257018456085786991",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9608755749826844532401770512275080881966370824280688273498553389522571764141",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2260669158872475639679177203497728668209095301439406391217219718599034730764",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12140277451446772625557699828223107898721191091710347655716196820987842105625",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4956248917519863145394820827972513992986243742257559545872900870754225233749",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15653607934509788117609935817650301797296492785799946054715474413041722581017",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6080265309621897986245602899505162679282929224882497165327944293250032936960",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "161439839197208863211698714955625948211127391719481095643430088924500517254",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14293440864578413142192169710527540536902880741662784285997658813287521270490",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17199965383360019881605392704056508051785829515183407218687481045427170895046",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "24770447336950436874354726326502782357248979462478468590390339454150579896048",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16999149657153265906583223722506042226163015360669628116113391607383411532220",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20092504266078566108527851080971015293301263866670255003565303614711501755076",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25869618681100227611971842542309813829671321460883436837958426780821327709831",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18415362267410782037844353866143306060323614406328524062112699235252481653133",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17202944603586525273225135372636116623870642251932480807071425777691488231101",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19732154695178956841453984064156486832453834691259572021624376815460868340963",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13363533519831439333954340784153886940967308285489069706169693653684129800111",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17916526461598080300639678901515789761831793598967674754881816316080196783035",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10984746777037631863346274846352272327929242198178982064622049829281260348765",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28765037324997025671563438127753517789411634328022306109981384792366425573803",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6673337150772164664924245450996131269151287932779573252006344507715760401605",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5763034147610791253505053236021944032915813101757756992553875827521894485497",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25060559725854501469266849700731453679618026901005150491515204843292983413463",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23135167579457072320018116462341658505642926599142211511100012907240352316705",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "185682175185499447088806729136572294621555759769654510236005535288894013969",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26352093546837400656121798539368436280221397175321499459697202031541405235985",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6794738200335718506402725670835413771949374039019257946382641502260821970663",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22768621381193426537343579566810228831496329585340487478442322765316049242754",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15255602123617617714463893772286877560410220794050870188568301668343976605308",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23365041691887194037343445895830061446167571283602442610189375177304650073769",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7108644487625714140583423620679483227766955142281899119297823155383683962824",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18913317284575662466014750604383786885424139948281309991065147546262762477025",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22663358033468361527124980576572936283427881542743791356512549405101859923344",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "351199672244678615997001235748857051199284585267734812169528393835256329855",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10710782610833818790932476553621883155047016957505720095876403405302767238141",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14940111972709661734708953354121281324106942408278437159421272328045542286256",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10405902294165539807790436388960314654156033823695694188126823988926369631731",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18418866493371146994199552529165579872664256803900497632672263090062115549173",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6793922275912072062015164799273504647167958083433138868208982458608649550014",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21760823248671094984717018584652508543048640274956977243408215271282837470823",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20270847001843016040696139399845423131236845781660586729623376814071693587672",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15936666010988456500737465095973115350436012148640194005411753302708947226563",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14302008225037418021301584574867587275065768339729359249680109058619553992369",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "184641504068058274761168910610109345066354896730143304143469869805853733659",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12543278656835824422748407807161728904188901497030359198016302369620716002650",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "21889268282816621291328182602236722823393296091601952398493890620575872475075",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7894523340435488157496923128926437315384576112581674795719886191284362784629",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26077012862206335443904253158746711076575307604423788532180176945322687567642",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28751468616959528208550607948677454964392262327137010243660880131532100438368",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "287448557404363550598629621432894400112895434900597203265432110867100373905",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25244648418309356236269584206234197512517826423385316748029274828487035532328",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17343435291866354264394545210795844795973002829326606621436265890789311767777",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9406318702845559485086699894610421785254413463462865258820279669303185373270",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28711449683823499299516304819180468298235354747840920506729284384080817873784",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "10821599611756609319513455984725345892961958328558910198860925894515259434110",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21186006099288183278057870223762401330543856995245288864727023001588880791260",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3808447894306397820869401716493510226563074093243519138750770954923348079680",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6587091818043824342797140156359793247604409181892311845474633750777510835010",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16331070458463874399828670286589904654047192408043468443532527407108303852795",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1592506199436794662941083025697787241881860107411944560721300191537085641505",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10590129368042610847411101479264296802026146168552165565988148512499394697234",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21734135362323617765819028352917580773111128168854881797200995060865418723634",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2546512897402479382834109419037293537342357722455673031960667581588375215194",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "14558349492101391192824996936548914099693610809986163534818981268270388260237",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21519847875437771934881024296517297625083163015888545923627650100660027370352",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21744201433356096697349901113723367420301328460587167404364457697360972498774",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11165767377754318432899393663559211526468602829771498132852632236967414804434",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11158284372274205561874208842534297903924041725188567800511822569381556825119",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27704508742305509488663199346788658859622781273043258999583365472214975489416",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24896614318736405329417995004397292328626284001923448161394401710981046286333",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23614820182377157870718672584382504204821023727078533486557301484002192609484",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17882572007348151192125452268118997997563767133521195823417764981257766066743",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "19818151520522153951962243713571011518029518924266010228286723739063098780253",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9291287778901829462729257741969647915893559311651897438853966491264592986775",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21549764965901559165434952889231958802022247785771938230478294559634111370636",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11983394071801010402892216221130946679577323634602620366383832591112682813573",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2027917978283545543631642449906126908704336942255944040183218870453763002714",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26271321636381881466333606754074859653342683763612917858380842778255742517931",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1440312539007050444617351754249302384094611128760622417828380001144126466246",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2489541420326886411596624872476790795789045074593172687377977490234950209349",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15971649010157166795531943957266230169309183462948127153813920724833051598874",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26106002500746634026334491708557760762117982547311284313304800741991429829140",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26129171462146509835003221459297442213060389852477048598843051306703040405062",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12872419693212292588335730611396541910154495102638786465795110741705327581440",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3881329754401822954200403852893187779235819949303310211484445995194509633365",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5682114783684554735148775719800751848507713946342350933189497537854072597616",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7563405257182538169720656839746410433204794964834926378130316694227646573657",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6871851241842930754723616135931398402702928160993963567307416742114954439377",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15182057233265574430920645745106671928373283832699230190319991169976053882291",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3326250608770247905726505773756370687105811513722265978464548209386013004889",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "20911825944690535300909479064561361708674985706432147362095816162946457932157",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25391374111078131083189160072560349814289368174278962123205304156446632471981",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4101382040829425182353823573827862447924881195507976236554905579750980094065",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14137035783677981827277360612647893580446225525963473045831526274205653997859",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3905495518760613711737723925176499178907962284085055603389840567392123563545",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19296229797344492671094519804577485087826233838826974686486921619742614833000",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15039676010143447821058155615947538946732615986530980417464348982644631488776",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28797844759111084516103205414833804393990659707875483968144591230208210767819",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19684041727902443971969445428906411747813186333727639892850036171328894498669",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "16685124938160839878000215255168581626682984342239550355166349274897981668591",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12123179208071725700800482131990352411784233242062761201657753527500769908935",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27590170365726467294607095141512320744261561932754901904489927781249202108040",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28650732603172714685747648162025481737303728545970941452347153394829012214790",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22343894460380726518487147819407747140094769368143493072686917011940452620498",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1440589827694656879746435081042739891440991111123179938816534492186639830673",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "80316319046432533150795812506850931560570015338519564249665426758299751021",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16644405091430090268198100154000035354489467550625369638067527446223405536633",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18874875586646916937207283590178732550405377997061400053869016998430159130764",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "5566724866786743716104429599317913631448342433127270311358264936377252826526",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19140074012272159021398298783471517076527944197738079105643042138102959840766",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17103268984749608230667944615424711940843485088240469989435505654733966547345",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18404288850289680360738512909202664574413627291022748472557613794199103146239",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14320216512985427862299019945543799514523415010135232728445980272398636954882",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24164402913757405790573725436549023170730248381896259192103640243189727236655",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11459824238991873932803812003791992706019231145210866653735137741945125774206",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17045711359231455345339737958497998868994846341798353744545088561640231478486",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11551245680359752378929049148528121085545744976638407262743616426667704075607",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "26356829487441241274815160270748930537151944561242579183954594239564285121847",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2817540593340037982407854030212751859886278231588901447239500624777095757498",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6050784496625160461123946498265406042280738842508062673822429093190395409883",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28846184081462046328218237944653050329178600552095235486197551971975890855362",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26254659189697230835643955140470471984240760381771886782229584331558124464095",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25543300629908525010895434200508991168405424563434203565318645408076080677039",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4895623478319701265475280015176227571010152453445312422107215078492023810274",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16843130917482568854492984300675796285496847784293268260445769981293117476985",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1188652866514586222833531523748426994394285302005203606915048618616755519835",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "15615472170336111584921037395923048796858986638837094198408451789981470149689",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8857257575239560420768721262719588455845015174753679705799570017648799281811",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26348503419270751537696127539356662414634505850512216276033290845690706212874",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25914787780593610688245082897961176738264064682963106786187627005210540853175",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5459365054444012049979271281003246961417100525466945587501607430070233686802",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23366215414264816466765794660969755659955254426388248515900642496543764831636",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1146321679218123842678202672998765196309154387174956596742892616670354443836",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8815211781302683479774038910818105998125161204398984216307536291502477286753",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9019818400986228194101195862032079436115943097660152360593685624797843317529",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "7482857983360377670125147205135498001367153120710012292460935044419685629533",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24115532019854159875230118325998776028450724602103727796088354857250442381650",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5768399931357681154409248564419780668689954028815015112265566576612767161187",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7443069589441437587305886716829227807856886427600718012287978683573841125226",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6925711310933072125298062375408869164101981115286200494561331527534445839879",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12486021123138489326184389321598003819604163907013855470454781954873707833467",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1245390639351118209866373374628629558699146228843069199801763725842623227162",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14304117469615729298009740112287559615690675460049302134336793817126293025238",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18532535707737916398277083959321437809827845465950978739045972874167166773452",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "9368174756861308978807043596338328439378860647930151081345095460188931886040",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14447617428338934388140410215092538774468378144493472126502781251663178047338",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14581304983139655863890766301239460341813938627938405389991393443026344244569",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1509839539452968612897965973458874162478671681863186707293784732111919657092",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2684912089135053803865052592184343932436012294156995382664636747611742018708",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7677726701105642963237056208301319553369296925604456069500307396608629965092",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22557903615067220601713764785717742169447920921920048876641826891112886644849",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12438254171181647850730047588253633053857730694383676937451623711029465311624",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21223473039206372702351557526670466796354503402525168620821161210080241768568",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "11738851142395740362137395073447784634806656954646142141862749044297683721497",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22347696435833542018412362599325155106139114164918002360456827984384844619814",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17504865211410933292072062953389295737393077657365508773036290542716112980479",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24000357124976590317637788855536169028463859224264427916313479158488869531666",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18554280345331348923329738336219667732557131092040733711036355018866546499601",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19250901236311140744153939784515261325936124882588327649940757483680979072439",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15824785959002134260636014609455566441636909161703807867933648087300142032871",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9156902913162186014103563789072096201393188540432740354262155877436858229204",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26470918888698132980344850384406301086951707893190701435716578632972991113705",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "4831811385213106291985931676909623065736442865310115764719262822645988811031",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27793347973992588530716030827281256456403726893199559971208222835681722138017",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26748719115146315986881288251065960875246771479945990909472014187136792306890",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8985523710043692927556464908686689159623156591411800291997267617002020768304",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17051488961758081605987101849584892426039870073695279747786670491029203978370",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "15825857932653548020284522735650402661994495756119349080900768045870599228157",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12812566175778235967691903344078116426994969100273882094158596717600024674623",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25718984259754921560360972396187522389285023462190128028036412210450021405334",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "16399092942732682227300131099615495474295087738038915004922001471954697634713",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "25039890256219116218670277216785907981168716862265175265585797689098828891427",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2718593035051839602933231227827711036885110594457330087267267766745849773375",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9207551314991874258322327501371366697220302845728810233869810513682718812397",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22733509080538219107754635303054117827619099832029102235529755062569392743482",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "10425417430902532080763881154268531037764477460568953249944804863069468706396",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19491696760932016994693812272896342313114285799354552041003741367813719857933",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4166065800892933436591849091724546861652690132449216396653310717556930633402",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9130422640630295653033052416131834614184715831562086672848663255842268593922",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19594517296136575093368464939517139324794543610967259815801709264853706372067",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13205844949413057631910072938627763843031760075481765728242902269909672042265",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20517496570115801379268041181108251935984765608834905810169912524756077575553",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "9659409778956856159932221126763672408989980186877902364446477180160794391151",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14872599088504158458176254242195751335021424771805939015972361113051855136445",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1329099731196446179892985530001416449100283417644945739386261908340927114255",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26862053858492427014423828313438591796108211767087230694561560151307178054710",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22808501200946631301204969526539123757891571734153907929985392264319380858274",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27346062823771417457441641412559313256471586487576138787457113065060272555235",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13130011453397128960978195355810048871980423126408552675152377117549025167302",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22948300977310315860828854599987324923827995238114764592427146836680284642793",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "8788907732520249249177593635489859327061772455843826540730535760740065566112",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12106704913382822851840969520648049050934970841807979177361786626402445225688",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25853936802212978082270591886603177030601288332785340284356910949245857376325",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5934685057184347980532569468343420178610009852514548439428113933864737250924",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24244245439528137788965171222918362849668045789602600456963460016443988428929",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "5681449019936328432701778877902237894659057835599201741085334527704650894190",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14089464100583348098178660616042308529150228983122855266067320225707496324565",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6712571293018757798851724480727349526372893045964092430430654960534893124092",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "2677289872490570889562168464650631518153494482434923198370830219282470194950",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25226594832461675156920659658731792246611799811081099403831764405095641420466",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "3022893407392921001255897812049741291604843729631372081551781708722241696568",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2526299747605861498930290200522236997360595158996632025250102893589070547559",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20680178968228251527592986883031412559910782544200702496863352214447152596934",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "4720540861708626481593513384633140543538324093808678177960072964282854426137",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11472745724743271649812724125212459266019364448711963725629158226683929635441",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "25193856206652811434423599773595276714794147127490995609064345661276060512859",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22998651725815462379727599293685178364240055653688609310737311506657398543987",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "6739928828134808172929599675869686873546190654319199785778971695000255238678",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "6803742328904133176839572738851766558224799282633634644491970968064485291730",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "28290590578779187590646139891912691276428441599064678471584771672064280714696",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18040154645056437334094714151848869523592305070488944409838545783460575313263",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27097314471197719916423285900034940479149843814984928546542556466204582962601",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22251140722453916365480830454862678161217325873216826767799259747660945394160",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "19552014746521246144078266356030115590006659534858989717432170686961659684634",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "2681344039094803615047977835000933959263316735365967832238523310496350272912",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1551715086461396739216670115550708898301732201695102585632295023860927599098",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "22328271619038554564694889316069705961729383370383409784294875105354240520878",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13939554204326457424427557064619911232751491669097619734589032713641129544814",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "18296202920685355519183134836497158750443181590598316479515914081481838398400",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26858197235556306375163357296860282819473475921121822391018456475015469013375",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "13768475775168996953573018848149128141617030132509252597460182749571115420156",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "11068591373672752678850447949298308203282294880077800626434581843595855720098",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "12698008813796774171729124244701831973992169439104252687380222421849787633108",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "24865026229806513634614889769931779169978452690125545380643395123510810104877",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26285354867392010887860218462465329065676214528249100430435822374357362072309",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "17488044696161347655693328956688618374653144699738846869674940920322948774868",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "1275066351819375228494132910249323730088857184196521400341537860107757520899",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7852815240068655388626138772345105179426652709857824372048362047662381489952",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "7074781071825048831899195334244978908131123276866052090303583425777524424111",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "14659799509718760609453255972663529434228803225276545991091610184898665182889",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26490061764425398797081809468483126855414000916959512976418487470281715957902",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21745580814177925614727174819062757362080881800128742466741750587654137739111",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20299646245858185118621220969807511958799355323723390705799472653230170820428",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27745686638833864066614646582205357793563574327147738592222194415767149732696",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fp::from_str(
+                    "13857938454632040174704776641534816538612425891855364804481310209446903745750",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "22962554617791017189671764247969693888450767883554780633673049498999003548233",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "23587013035155761259909733367325438134165474146017877892074057261592091402549",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17170723809269995548557044708314333818310481877033622873391666923381344456968",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "26245102200658749222547111343638809333327028705546720262426743730340784949849",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "17654081404941062798421720809409215050951625112167501967057824070922207105368",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "27345234769345586991809232691892188538993455098682241288274415724445669120303",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "21704194189723792038808301918396521729625254373988439930782668764945846608898",
+                )
+                .unwrap(),
+                Fp::from_str(
+                    "20704446203764124936041296462637368024740899814958373221630362637283279350379",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fp sponge params for a width-9 sponge
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fp> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fp>> = Lazy::new(params);
+    &PARAMS
+}
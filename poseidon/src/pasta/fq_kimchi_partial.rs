@@ -0,0 +1,834 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use mina_curves::pasta::Fq;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 --mds-name kimchi rust 3 kimchi_partial */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fq> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fq::from_str(
+                    "28115781186772277486790024060542467295096710153315236019619365740021995624782",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22098002279041163367053200604969603243328318626084412751290336872362628294144",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10518156075882958317589806716220047551309200159506906232124952575033472931386",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8515206633865386306014865142947895502833797732365705727001733785057042819852",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19310731234716792175834594131802557577955166208124819468043130037927500684373",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "361439796332338311597104753147071943681730695313819021679602959964518909239",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2193808570710678216879007026210418088296432071066284289131688133644970611483",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1201496953174589855481629688627002262719699487577300614284420648015658009380",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11619800255560837597192574795389782851917036920101027584480912719351481334717",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fq::from_str(
+                    "1043437683344638003839100397451255275511905977643521216328028361575848526821",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9926459798916564060842937776016397947680526671522765219287330317500714518695",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17869992325510038929018024402010443216777718031361619679503633663167815056949",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11547276483312000804712743069923131201819177997503230784622768599140690196410",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17442270367802409639061358882493876794167134345184478135652334505365143768386",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24121592194506958315453287229119376701189680337900010558038586167680373374611",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14378953689141963923192344967831051676336322195230634281978480158148327874253",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16730553367532314851777331373544264184893802084710347623276355785820656634811",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13140343270675589434268216981343612223505742319897933581693305080391588943640",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16128062243947106559497716934439215754725170642914362715391425588280084768618",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15974981824145523524119314342361525818165180547632998461456972973173802253192",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5307499927071068985335678544390121859499805953472182381123858858587194695348",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6464030997097972408588303216795214759092785288387247479516634219677517963951",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2216419889663691669057675818912843207542414302295227029684036927311097746919",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18510178518777105261102359201028822184265641955905449321989752602212814903609",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10135636018253954460453708055720469364037670679368165118581593695532379320224",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27215354527423508531980788069277290420667791924890102433707362346944531562102",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7533836668977526709063233421070278881247998580277901188469633419863952344338",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "25050165837955650807724270025988814575730499818965468905777381078815066265160",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4050472309833173782905472722956382160640741135620553617377084420271553709784",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24199280049278989684515290527628822130586090898251497800294095060939525233506",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "19036909467571406417756063384938207987754701252325582997383331978029730264794",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6915694588767546788242474340812882451208402785153380395988548909895876878451",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27239459980556696914657298977049967840623790812069534542453113873516459028630",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15904990823443158040945303714390891648052179695627477289690817471197668738591",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19740546613976143882401898103298252538652688249444188657423157280173519535119",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6975372058582040450683367361409896864067251821053162845507168582565597111699",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "25304052945076897352170577675786647256328624993177006779598025016770023163926",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18672753187041140561772910253186690642657562691611018567049451510789580625788",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6560090513936336792037812623317434897896625766383770944066104850592072099323",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6481428629765857375834816839582693295451673513020552899778237842535953059373",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11318675724047179862920304454723977447730038000391124859072217122847642475056",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18863207967451086084358815772978762879138891793938696454245637609307581870784",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14190768133451317379666422925009362322328034803668886672783207444528261472279",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14823550664441193055071480788722231749137910730941566454987227368050467328275",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15718374384050158582031635719678449670386679148789710393143401549605993091813",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28501325132835211892589177814117997601906992610127299846536756863514944077409",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11199711543616806662445531318096256391184302857391249116999536728155443680572",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26556854132483915200261158804484817904425476198066438130747712783498131796016",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6336494743902046448231091922586740492026095277314564938400095342045678882498",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "574618832846307030044829088877617343267322854920569161580506530462459162494",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10392500388899558154333064995914035896256978312954497470981545869220621031057",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11012518688006067431130910956736470309816267213073884304394290000581445456939",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20674830300854261251701365578353917431033591561433206420404117494334306859482",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19604855264102723728536888459221240406545204305817728567528073868558500036620",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "4788751074750054304023280764311224674452043146390955575907216176201786082919",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25323464251419333463883113627734197440979585626171228368598077806436025965922",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4383092681928014819429469930280177916296534117271000711175840578513958736035",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14191390602835179587181322422535156654572117202634216211498049726911101192533",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17072718284725617617326537648506628191376638954789004998896748190076824679780",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21102626710340201361210955806287676634443627954653335691421541263793987928169",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "13698800729624288387253639624766512090814911924207418858892163486971114616921",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8288958114126521437943889001503383884249458480801832870815773662675612033934",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "52793619053026988542954463806833303058267658375346332525747891151580144523",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17558712343736688242854877269006746211757243385374926333448603316366144286890",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2132200156696458523640387157859260811952989772166879581770635808323412584764",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "405917268896070233894218200981430639428563239953465500503709956349354809471",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "26477444132185416781846040924360206379661375665035577707154152947232270195305",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9059621973807273103838739953964101305545299034379821509643002257271864374087",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13445956569387443198239713047430623656480981432217351333481847620060592796654",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24922283097822109833096073760362051659381477282147385314230987552224810964442",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14960854712307462671887350127354526919716444205985422841537866207420371374329",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6638400161574197030154875895590855451736821293262808695087107490103050646239",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5046169334663588050949817065536106985611731872977999371839054741682863989793",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5198736891163381974558902705021642209137628552066745065637249538040465522174",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23168813077141173615652112833751354187632313900018862166719188685811037906642",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21170574108263595719081080048351211553738323971024008466651969789391994978619",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20669615220299153738622334868568975187197291510880462194387878627127405464933",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12843442346589805437996579478833638922583073844291642902053115767030636891834",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24439960387975983523874841490808287254318004419022978776397045486303953762113",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23822508352699636998025835260505007762343814477955321857207323527604147544720",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5205126622741461009618482161726564667203722226786311255364140198907166572735",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12027697669148613310639219629893128627793029179632604348850045729480904884984",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18409544578523518405793862806080895022411502312383094539984437563773757295943",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25966775085342353234362114031748223322504100760369990881938567129283186890602",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12214775191628751606945966237563502671231974283619850761805598222945089066096",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23467902141299398264224763918844181966563834454947798456881323061159148381595",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9654624956589324444010865393705796090437081593899163432626416233590967905014",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5538954273973441874286562603138536387433181767036254436630890838669795009324",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28725606211170713635239076718558358780656875019485884069828692785112174502266",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25583693252093033430280946824062226257502396789488638904667805088648095395404",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27075452529617123393797391984646320525231704324462852973317234200755400923559",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3554766557439831558788100069258002496167175236907789162321672159922272789603",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23406273779587941143309798071629512373580117315244901065066246237078866505104",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21887344690817509177653978542337885915678343925631160802473431329953662150478",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21397148560690726705045865559501545200554254733651195761386083450677382430415",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4703420555656144489945314950131493879630275576766945842208811115766712184756",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24150749180406788238742285289529618101975289269299819173771131770186568147037",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16794863115788230354824491561293300585784461845226919943357606853912029197185",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17311027643254874605388207758978073986461525691505094473156736850211115647331",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28152194409954998001573689745920286242728275744930930830214733902619641898103",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13319665831016947884733472097773173140707146277245660954961839456413083669603",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27214329069085362649369041822044486266639901956797595910544755776431843505818",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5275939834402705257105722625887586435114309581591456474699382057352869458560",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5888154243712520875753063236210385021860504878843445941548201603039881565880",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19209097944747673254172740355624786505673987483248703844792394656303775116658",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1127058039818973405147142115064325683254799026757604056176170426383935051909",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13802696797416486209364738079483521025473345272912194913320141094788994855349",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14827190784930976134215149101531870330094692563555765436477910300625612172617",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "479109670849440792700848773496137130678329597110248771766189290328449711221",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18686254750762246598868684993983753394558678891980816259759638521369112837258",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4900757161236769042534655673371458515903042356059239869711608078271912495181",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1191028800250910037210346455658898757577843380085668423301954637806051146147",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10173037265879997286382508132437801066556521003148983183382416653120496640940",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14874230996098345874193766108718126234539888061998798962991222825654626587333",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12383894765164958484075204106715507859072299729090725303860630566191887238526",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4156774425713395763175398012906881559627954402162065758383518357985663218791",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9633635036848984318726241461276189658841557546064461950488449876000091279415",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11493801223206793113666107672677055551973353651366534621347753571161303094209",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21679864701755694873226036587754726372713359865599716748783198347204073509875",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "166403919862236079658580321361681327850366268919195019438862071834575284843",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10745993775318811253804219310277692104817040417871142092260228151428895497184",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21832641274325329226850015446725408190384259242084270046161015250653228267674",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13452459551905496674192742090282780570563753858485403438809471859310612254603",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27162133472404868900579412991557064670215410016437036166714017679415491664692",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1571885399635364531635216604332681600080606609652206236771367528106014631109",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4067419101197754270962863944913360599051929958155362528097050652115702105341",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21205515726933691265031010829309108226410037334448629672473054709458324986623",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24416301459561884645358610454159377028812094408262922681523168346937189907523",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21131367592520114525362863118281715542362152532773390229786539725317422605877",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3502443628153292821692630875975983819802595735438601266251260041254918428565",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21951320837116255922029243211350688528204755497057167203043284936635901077497",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12171153267563104237930373461498841798108546601699460585590580867395245747791",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12174634643335009762663596359549381899643589036056726132091845486709276824659",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26195676714054703612174812604240339111086739435801893664317205608826250137615",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28163069894850707227436126739130292409780585554496700224115906277317282087699",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9944125521271548293362086639062133064421844502752584583268609229784990956451",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25493620947064335096654113604610181446489993823853431459001925600670906145356",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27507683641482747692219097742095921864549426890301732210904442178726223668184",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14314511778152388788978817035138428940116877368878297517491721007831700615542",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27213813881987791465090424252602794089435499793495648260781673731492206999954",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11322398464514804243253393543254246951598670427564299784397221709614509947050",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28184085884666194277526329186055585031268013521242743412582781153242561047896",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15518186847973146180058329781399212239283273449592028775181236171935892504298",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14976837654634399954153711619277989004478479832134339430009562723107677334926",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18839426646588618948129858231610320770375672571110103192820276344201628697402",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10570897682138184407565383886990999506113706877830324338668567138411764250491",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9718453908154685760242334482815888821573442158649666999575730797571511400757",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12788104960244200604374510171064751644852273918341262777545192467080949999612",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13883254438442680546594792372058600903478787223308239574726663960679564339777",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17109246424282827532816852721496697949413668283926162742868806029613170781061",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "23780860819355966224784429231690553090709738557127320356432617510639192144989",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24386975160798430702012359502076981703838213556400473328220131126036706818390",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12317558325332887036400341543726131386224534502255930496565542917679825820471",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28044687712153874008793209292765064717247080739606408223508432815449140580874",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15026453533553031523321591772726412812690969324735065212457551636815818989492",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18341271416850953527976661016806298516745637818540569193558093260415559683469",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20997209156452886259775290752839105844396420647141036849519845784120630056700",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8436164967505689411721213917474740891633172116737362813578943018880283521297",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9415054963115466886552784779011638454109818395922927101352264834506085154086",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27504675166913936717706305822747642677148463379105512944401239234613708700265",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23735753034042157087698248094775250100778002856418373245826233216363644762879",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24617972624574704652779655296893374210861499244407593466943866768436832009397",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15915908292802599920313158745170951290247264334510953957288874279369398342538",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22728654952598221348631157160785241954050946268766004930241746150085356299398",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12090354563811271421687384492492282032978921181787617647611882765753490498990",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2100293726219516645724975299592506614235778179190259094192506058005789359123",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28749217860269698464448426168364868620052707700092278298226871123406075079825",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27045318438429166988076723231401066619533222791934019825200574367056963229019",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9860575361028736777432988394141583142997913520574383225072372006736089814220",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26152993612715149179784543425157729724427942506013801007934143516866234690715",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2736216769319041771529776550833139984069075433676673829775327559356449774340",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5662459748922467497895473862532041186553929593759980788495438311518088813995",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2681668489856065896433377080837066666485370727204532364844656063701764050210",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23253200751424851599549093905484797990957079201060276605150981370959213228194",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fq sponge params for the kimchi sponge with partial rounds
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fq> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fq>> = Lazy::new(params);
+    &PARAMS
+}
@@ -0,0 +1,1342 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use mina_curves::pasta::Fq;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 rust 5 kimchi_w5 */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fq> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fq::from_str(
+                    "4409596977863949652542238868322209034723683827666053823914036293014190693661",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4413846956817105195471957493770307202011862937780194152481213057659480439803",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22485515180821172345498588396794267978742827719141014181300889514698790128929",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22951491062984420693443881104191772086055298057131426678123682247385812115406",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22792792782769050764175648470279274482438849730400972218006362070498555431397",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24837721890076043423975051991135697332788228111003046571836237685617819814873",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "739085838428921602856050005444453374359640852208788775625064235767726318716",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3984121075453295761402099212582398976542242666197732983762530084942829050368",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14547779195732479563566070471348198012991159342777587789660833193334262007711",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2073373348317451391057527887560908100513400271397314951129643652667150639636",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "383584874818692263014949955936532774339960813573062975349682342974536940260",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2144988452139383590644106672296888439066403721796940139852450274877374402792",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11781699850820321530711136755558035754873162576362167415277383806837789693608",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20736846680485046698959397865257401168368956012766456151148937684904958348723",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27506427688756747885542190853093899769632595800314476638749486388879319380726",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17015349688906095909224312797029667843220492060497243240715112740057417427006",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21446156595526220736373927926040469840548028417899365141005900583428149599094",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14320394509573877522115745976417632707708684846552340972267508749602795479331",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23074078458072517633567587903891837128536242794274876119444529278547041722861",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25170213043627609314310989399476145708958173466394523784210325399459687129399",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6843759202299435778642154035774464973906280532938025560812490138910374027328",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17486950154607930281411174123527979696307488081894083953768744047290829819063",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27114055883428199914429992882960255093320445367047477978580665051004136470528",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18495362108749044070580507702705276763811056388239208886986102759941853413850",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20995681476246057962213439567333335405254126538680992887365596314506162393744",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fq::from_str(
+                    "24806270217803990139388705125173680378643001144761892932226439042640438607896",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13162446047949043236588978783050639239823392488652576596334459886749242277498",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8964529610840616986480919908837294624697848631622510879227995914892805010706",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24135339196693050210187409928981263611932206962176002239559381443504206134183",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10617778004845604698872617566438554138410422547399043424015075365288957281560",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18927585170943539875195387417397969353308174040314803776008319054842568846511",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5295953352804288258493439033799365158929970516893132042190805979117303627095",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7646073924849370564385069118052169743386811219938843200247544477028714444010",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18114683574758201767901802452121280769755966107519910151274687270343529031166",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10694068308330382845394158567451520860060764917574761348327333230495939675243",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27431366773786265343859678366805490260867143605390874518693485938327534987935",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6545990053589123657953797358097425932320324233653135529775827761138756358329",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28862334642084445187084034835491208388752653349721701957314200904572357514584",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12488787322707982546374303721290291448959498154437575902984998857011227919878",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19393729129979156698625987038928482304979518443577439269525285239192893307300",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21649286305408520354342148499811367426596341244217790220592774250886861363739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19199533168451868054249679978266799490298760813637418348228281669365724782740",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1720774365672607511093978815264419748372830716557524378031959042207732874056",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24194893191350125124139835037958856049652631363768431474136992338604109929715",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2908441752173439224687855079795006156483809508579206963391141208640564867545",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21888702952305567637178240951005709522398367241895110208225260620374506506460",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23112243700944298168795149896035133062548241973432763164793204155609076204136",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24932210207894125382603829147855965134043937426860262501049627824996512070300",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10968048186528956845165941439940996791169842615429559341835186950036356450490",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15397625921847554222087450437540872995168238666204268412249996404015394902625",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11222020775111069483828586534514710889292826109210272330171845141168801192675",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23702572276092201204105944253450828156303700379095306949121182452633655128877",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22120005659757190038174439424579527525413150949045265554553766028298292060266",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21746383715404076672435068733348133984047104380930157424212967780796657504193",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15379067896318641218568567249178329162092384435057829842240726518681814018305",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8653224316710567375614423863170565634709459483379501360564147007919449914176",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14291403940099484137001397924411718415423343364540423442330300134170146258873",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11859816261220667776752956796262155016104454947793994935983899350784346698536",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22652199055023211959218997638735532386656230264277152922207440907458978321839",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26423145778070680319052127658180034413381571675033500867926967345735385571010",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "296578893106122235855664966121896011988080688654129971513792151509236461042",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6204763284616206831887680315924871782754956176657278221277197707816250503622",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6345283208013686889689231038120197644027840393763575208083178056605088260488",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20484435947355245287474819803970788556083297641145582796445886910819287536349",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19170590265726430711157276956651347060117753702613504464280466091652621816344",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "23116867064558151719808436170545284092799302053635518989281823218034961749116",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4754926839311388599899210847794159960093497934413800598601661788798089328865",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19440553726356987665167859647208418829536106984707682006252373519939804342936",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2463362767152066197007743650220751741008489516952494627823918346530998158921",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19078348649511363012515522282011768364119063834719148529379161568928600912590",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12080671152147379498981694282816320201092805401844968525166972656311632805555",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18723745127331291445509460224002462458093419925973506105698869178985320292112",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7004132192843591149340031621174855053199449844054145265677172648675835400725",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27257817704045272706812828653168065895774480372060919901294702181958557993172",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25560033476806631016420347207329931157008116962976649571992946840834368999006",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24585831583804592454832388066392892709866427593615381208802824521904470774587",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22745124605804935490198209524050703038489692801940248050038465766501557276225",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21584779908024419368868278777601220128213164542912944670875681369638119653077",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28328788390203940701427900917064429962181594098911486671819674065563807599272",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23184845506936418638174332200358414238994975044053102480693294504540867831655",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17640772195725904198820871160936481365923161634139836461115361346214321140065",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21596621615929025244047632967590011517869245120698028317593046976610002172528",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28321818398490284614944366680784524454695646723610908395439486785544656443600",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17286073711975027608486831418203665744015600776290747034251125671724096175674",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25403298342265335299140709376744159814362354751735057753121374227874788868125",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6308793333999346109648371169462219948308895807405534299011745403064770291454",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25986988723407565504237049363316375808375495758385787286071247373893206080002",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15309396612162139409219086285897541100351362133367648085320379445694552164321",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16150574427186586266015373621578562941919927784241716327412368531957865164474",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17725295581279848718856786727354291361279182872106603397998133697985345440980",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11478288029331288066836662772862813269991686331518815707952491167276059629098",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26943963828686448526323327152098246951381147615757009862117365248456048845661",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5946698466083191068852386024385791804877646521200126649502748536686345004418",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3251601185070663940638430611361141610589582210675406489943044816433937013686",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1025054301333355255651666209364612466540271535322342142262899940953679196330",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2856464597641729463086638300599180087522498729265166601062579548680635638927",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24809965838952157732220128356886782526483246005137201755661435005434640660638",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12685273829884834789189299815918529254580548610560654726447282450347602866801",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6221656405066844015165247229182418428048592530453478844848437033488959693435",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21861660310938299406826548377664478847001327092078950637548339587599983575012",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "574049360860362620728391191654360966273192542064342546985832237814837090801",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27566876332772977458979487087975668099642307243586697360587243865024705940087",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5423825608920274114120081840161052600339547580297943874574722729520182555967",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4603045370427325177338670282288622604067314389650349792565376382335115901833",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28429734779527689318707898964133993010356525174055562154439859233609098542887",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17018011911473927000855430689007238354395073830907074417508288810473954013970",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "931056261212348958729053129378265671043010244524150264844331310036589224260",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8183840110984848655165547790831630061683745477516586545933731153189551164467",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17666140693276708427389720452602660804747849611970816761984160617325181801712",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5321474851765267634989511442043668953833362068375780326046572438595812138212",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24187316015563779353646538470981325113343407879522313679120182358866843786287",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28344202100527688754102555061920598369098770775427073301262775355429245156834",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16970008248299616664254125661929269227981648817051598753483344173968982858877",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26606541128808190195230826150665086954030605286785275202814703174539923798665",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12891543201560600122308919134886810062786133917149315967253483320471755824583",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10458194693720497751055954791952727554555410529070702009634182404158907565020",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11424058387502736286433156951492443320037862271789587401444539556513777741871",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21782120740182512136543713531336791858099979879751542297420968896987319907965",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13223754657561893595769984812493907116107189789453571295163601710037408677644",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14870738414493034817974041264115482841310890458512681002094885392706977840102",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24467921871775163383484333888733329641406651256711744264976404930067547554054",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15590746904067529504769260404525587666414610377836330929376610670251061141534",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16933040308659180437036394949714233997905796213059187459530880768711762784037",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "283922027174830747229424138669178453939232577459643518385010367526021886807",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20595781103909490570500467788993845063092348339507907431444514229153717714222",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10627934656962733628610281303543752108698669676394787491657922877046165597331",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28615524017957110691136208401366157822469693617118127416483394585993303405994",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20218823165531530342980170859424860052953361745703969370703074488804043295455",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6401353263853294781139638971079413970578398694270414945014659395664417611990",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26872816864010202092459303785310885151013598159426870511875684360852654405406",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3705594393368520181699371964475703943122667239399906515121422255591431573162",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25939098616055759069377446868816528290213896132762312392645719548521897163739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2969622456198558856997354657132740589650273616296262851178106960712874558302",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10510834214531850067364469856216238540075527972942241987997935988810548790359",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7903646045226063046167849541182909066140154250991823873085847778149844595556",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2056180919873517221191052367124296501464399164396895220434305049159400517186",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1382497786864222057373116571117890402347958021471907507057034513658294256484",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18261045357886889003654511166749003256061876682654933772186320080914823110823",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "765281553039286190186662536054377261221615019521197844760082794479401368240",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14129180578112109760335284988257999789831355988163555787897051788124514013485",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14536768684353036603682825122297253047088648083024557151459046656688603944073",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6162537457998111640622049195180389495607527886953401574628936385703170735047",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23067728684318345052832656437092775807745473128583119330113194327924301395008",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15528253143080176804057272275826071116977343647010852561567797830734498399884",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27867851654598131213128474036120323801629379286115190327702513607071741923750",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21889723597632501098372142971358582356299862180217579054112509550742520495369",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9246654766519579474473506685906672573817955496228159480324956399084750468852",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24245334303004390612080740004022661955406965743861654777176251747413008385357",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23435901465158168958877017825583286240027744671079136518693258399679129579579",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21303971272299186331333406338505033642278582477177541404021060808232215445337",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "22911093399680293613228602198352115890906255029415981820473980302987033036033",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15491646639264803940901549450296469968389312608236378997066693377891604368381",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4580328547166467220853463413695960989855042145276484306377244459833014739612",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12736060837410432559074483551575229747472226924894724761670628045509307287808",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16122422886487651305725513644777893507360227476104369111504349440698064611446",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20711922318820656912934676512867526494117404335873937018743492932893165637578",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8642561429879212239133631812301146488179160181509129187541047485620108276692",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12588704693845600948613706985164704659850994429533495916928729013743538312928",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12844768379227348335260198200172975231218587507066973002611636184433661022850",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12949674039482917726317026186685374478789873301250433113168164703075151735123",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "13363900830718635473101109089762878719243278195935775957537405939064622734741",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10980199802765827607852631797208826160842339313758656794468320179350384669642",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27754805112236663887179773933142343571309196121114424198261560309415611107767",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2973906141221278580182810368028831558392328247908906355220317120236762381502",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6214004165466348142001487733837293264062469158130849220423156819984428876794",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16034252608999790779403786893218693814204513514910264318106455904815362505319",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24265975127000084182984822815673701198165542296484819455799393140312371296269",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21958705331797127547858148400252077259682752377914850127461300207787077978889",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25891737089544460432749450707308248515160319487874591922839189369245221185972",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19161084460934375074327016636680960680015028695849868015113772464827477281021",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1487751766759007663346468717552296210293021038084257530945108313915969744589",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9868390300118897410005564860186429357442402149052838523705951601895890310837",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10989456214491967010419900142074901687778027466876169013213242590269481366213",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17324223997355400457848189555930042505630457117806062956605251012558514497171",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27980403943154358841270327603704396633907913949238747735535531057089907855983",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15207196178902327632839440903497811041610342885731186617789754128289264781129",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24139028266787174462100046099326363609102092395194188095134162571443293599515",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3451179212584785160381258018460619638761972014377585243343587769406854287528",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19552994349325750678231116937536266842454993798483136450152005749319958480716",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17915546223397225199170661475575222182339127876043569209152635131037869005333",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27384625373453654536252694539865826891861157847311031688161014111316037705871",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7254897713739786643223110542704766454088706972405491078986741199922152291417",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22436547723286431289222350863950581479005343769113910189990569891518580622918",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "426210890832198476186269276564167493130579963552360249898983369706515528953",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1359741789454598624225661429478019917035457973788779275396191856218141025287",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1772866351034143355032835354966394332766220305208868307767668642224391368037",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17775296028654900633701913122192152145764433608971954795303324962450879393073",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6628242993249817681054976180554913209342531603610956420921575374951431073050",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6384920821549441655013358337128596945191974846852204161702074163526295305399",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28399245667885685503650690637143804127438547714505231507838362507044707565906",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12065079659678268380888062549467172371169910343245568389673704312623178888529",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24279122709642124716832825636889789697170011287222488221751593201148367072932",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12838701135497284407609229040306833655450689780000086710827386626189876364371",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17043888755021414740234120826932552715377759415435020577967643302758852056945",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "85963768963784668241508578655659709069434490248946856224714293760150770170",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "25254429921359009115001974636959337523820440311772895468586277201525615194503",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22299823410584251263308803395826650193164778694368996753207521432083057053975",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8448230692047428649163246193809616804963326318114921030054489071683542036846",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19690432176308440383696635168925093030876069334806851631749227562975336714217",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16675897667478198341978789827544692032810960208874823803187039502771376810737",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20993612818501659287407560396425368346471619837512997672442476409234853370470",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6268572902944800202909171360243925283082736508613063312688518835988676171740",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18363095107205958786003630685575103786544250251974972749345600835193675243301",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12706295626121814596665889677547537620976790363418209546294625733641970251539",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21232481556543997557591642127816822833159383504977584456805944137991980163250",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1755262461918757828399167771819929900595876560537441075991445084922822678298",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14731308312511855108855286853989518213659940114911296992488487402566303822775",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24575486691392494695908553128168575703966870551445601648774080218440378277797",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8644594793956512341687226052819481939666585999989386886170724381370628375154",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12274146487526556545326488495602632657488798176350439782785863607810193589502",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "25477452636340822038928434188936708585991166253942046192857530932007920017074",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18312276185516324722119137503591353075439946676150395020518246717480339919072",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21300154752078767766053570203481274557710243458102907871109664435302817870034",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16213907532104132622094406255041256788037669479559021997636616665083739821333",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5626993723763274769335821592522679538850670503009980101756390661124982526130",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8631925073820490444437294394896369981521183608122360408810932103851683370380",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4216089559374149133441443506161232623218818989356925259933765325277727572583",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25028627213427181842841452898290879846093524471553553808348974362581918031348",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17190195311856617411404315554236670267634656710574467638814160949889149131028",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1678562387175881012435875033402492519067006102531117310579198268530401598212",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6371264345828227724165051024946671558472478015679830701947959001257613620460",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22291074264883587584621478505482360727548632357469694868993841643478701851358",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15702241525635660807813765803623903417962752095244466679282094026519529892392",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15907756270126917438747456407551758633636697455074442728432052815622975288043",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "410493020720699338071779393880252833071163986300469918004519447318795339827",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16075711750543417031373019097771864360910108328937771656786076825366409731066",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8136246279050216555100052593103389457921252062163348559377636815290023232457",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24429408422225619207211094560768782095030329306236295179955341053242123220238",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13587248221093017503155556212409292903211778689872442173904209366381390669814",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20830902232602000732361112470019119344378079966973685067808851055817552741795",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9371059670309409270401571864247821801673836670047854203425922167352326190406",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26393696205593568722311416971171619399600914569934124515682585061308435775742",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21293002553646703762251450069221445946096362870277515415764709398896897857961",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26712443510371352034515098593886825436644156268805502658702590235022969629162",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16536967285508324073810641492068277513753149306072449427499711451069191147138",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "494907755084875055239766801367870322586628538123821911228751845288176347157",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26000367139314305543959334516907244171636589310431240587746827514551815549240",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15723324942794047728961177948046982334824826972064076681579896400385934649868",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14390913848542312311827927338999741764870887476420952609805565241604447483424",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26619701843933809344852823642891442932232873288959375587423476421144847065142",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16868348646963139304304939813933825266014646767414712556483830005103192076192",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8839533468785229912588103012340621540479925712312659516785658460309178898033",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1627643533778600902146178424442522287930550210280165996352153026319195548803",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17448315817653897088258247172626905727762907542292679746320885849049248503674",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22928495288590654947453719580606220452225750139721516008809062583047962990764",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12197782879942781015514063072018432457210834934650734908627172047365189918807",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13140839274442306609933974598466709832872690639003379846083453566126068297231",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14356963166941998127687974323895487178264736513145938057039311439514891386466",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8394488630975203305241180475992217501345818519854133302144471039775434386010",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17613500040093685535287892268979449187826863054944281575915233803562156020240",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27284365801143595608232767770998529961049919621946501788838021948190333297741",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5356114528602437042729179364408471987333031272893620997113781524871289522461",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21181518917342034127433710865888964844653914633193954494286751777211779643903",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3730649815893217776710701266700360790967021667392550860467796090566109877668",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17119523736117364522326361875620934518763374001193662646274472988108871159132",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "25955375780193331407220114551468510323151138077215780863962694645474677568026",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23254568300290005010205598482715839713246790597555519038086512126140957355707",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8401279411701636963447405679671037162376922392437190377281362075021532900263",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16624143081770107533106388512989238872916882064655134202278972382297008999131",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9432899295038737150493452241212255470232552413110633809405190485945504318836",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17001239420319982351296325292822371365503344823058094190019390693966399500446",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15764849626348789610062912802018783320015293844943525154742945677099680712604",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19099550259684005755781427519240451317709104464142147606722278605487784634164",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13643748117977406564881453043479776669418731146101480512835176775338396084347",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7942345381698955042483979274047263534261864961690551111136387033656311139002",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15109395730258120701772952577440480258706573779910643776863098477742846507257",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22049714524553716504175500953265361983411993000402434690437169052139170545052",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1702782227228908216686275139298521159649370940434481908568800522288469125009",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4839336573192303757364706717435172012798603042383086522541523909499175058962",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4455939846752024080096818544288547158603063056161637121277544851899766452676",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21062166131850442163855165085233059419174218250637440331445224264030386825899",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22957423439075614813188431201105100962091701574035576407856498500175186198132",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21139475286552183179104039858099057252265285792362611735433598197282969608960",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7292138313867405943937158601938736649455244048951737462897832001001915980959",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11000548145584123939347132641492004876383723721921733530315341061962821552426",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16951091821080996888850038505336432116572983208212968321368862127763273209459",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12785151542619933644633061169095331324359137290740678835647749817295610363715",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20241751217761531851100380622308177820121018181454137434847612815093872579612",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25173743428206168825015124515003300448064126402846953106140997007528818761500",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17462622356922307147123062196522625932202795800877696483268200419871107635626",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12956014874646918502002389110407699240567347168295254249056222255121016822167",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6872349583264627131397710345043004065659598047825716869420842143395672612559",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8335563041064599950216908155125022516438506661549876125876252193911865664608",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23076094734428508991756838698161837667138098990773877253493040201927699073821",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24078725480520583025755725237834538721989239336740757029983839556873694896035",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24360999298608027681092278139168020351686308213387854480949818904371047793627",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25303338622045162312713257911309795755337510831965863721339715992132238965797",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4395879848639133690471943937661189787689556031057183791805420621919371702804",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11805296252906467341557237194559011083982981293687405087533962048054044905563",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10247249118743633732121190902879242531006313137402306909594172788387250077703",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "825774807190157293944675024246132518144224427297271523860817999912919688011",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25606852746760160062091670775362914234499774022102400999821364516069780256248",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "237540521255694701368337571296191224795773440739623449895425154091904436556",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22025643791107248030549197409380518522189701453605350901366675496870625493087",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18282004188719616681438448881552519727907594811983409798486208603059454149819",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16186174254763269077524448707221247774834249951858203908162303853390531701768",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28228388116083324370531239362815172480818676361975303078048998741119411081689",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27371601598791264209502166587760480441149553293650710195872090139408014645853",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8842549972236026311688587406548850421614102708775951099395097108703990549557",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22892237852719366765545812703640255776030462353478200356890619306837210303024",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fq sponge params for a width-5 sponge
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fq> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fq>> = Lazy::new(params);
+    &PARAMS
+}
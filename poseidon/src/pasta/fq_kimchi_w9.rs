@@ -0,0 +1,2454 @@
+use crate::poseidon::ArithmeticSpongeParams;
+use mina_curves::pasta::Fq;
+use once_cell::sync::Lazy;
+
+/* Generated by ./params.sage --rounds 55 rust 9 kimchi_w9 */
+
+use std::str::FromStr;
+
+pub fn params() -> ArithmeticSpongeParams<Fq> {
+    ArithmeticSpongeParams {
+        mds: vec![
+            vec![
+                Fq::from_str(
+                    "14909531272716113520815093608768301512071534596091380489586289812125929766489",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11852597745766887050199074899230746026774296921180573501568568297994552778083",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6240540824159863142536385283185544517377158714521145106684089160344617318307",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "748466900328561691440851633126426334033681177636251504309899663995353951896",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11741655806058547900854622866392086084451536153480538619879268444441562957264",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10490738148291794473210679536420958177683323162420284336730762181377010392963",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25069917583613229836429634438640412886997925734090742857519308219644042695542",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3486718038459552315079608752843048632302958847839505132182185848627073289842",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13287437660493670354327264882601948870126149214093621738405644374805368639342",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "24400498974307616738285696071307499234465088329794591564685583085443885753215",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10982662946188352249753561063856162053760280565479045561148117578761754268418",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15191407082197992258616172046156326572817451882554180160075118970176585139899",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4301985389009968862573702749246473085828692511738768027872471867102505551043",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14502656437156456120092658128965983190553728318131788820968748098244067318479",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19922408829299461566662413646906566694681563313272202460957129157860419574533",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26777806390649625640307301413144161055298311025102380727927588447216902387822",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17785756483695162135754015398746674175954057538465041256735985065061839630463",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13164363278588466007288141917588974966554299133236782554065770466901286292247",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6414021557688752858694067714269008793764671647185727852608000902795398917616",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16570805244132672022541297291435963186465969630468830329778661531091616606853",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13415641548680077762813928129863197888658584569606612326029260593604248068902",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7980639300687348047232825444935559129522679453795827758707800748579570289249",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21982387503451497172994778581371832155015545274476137198861876723550192320095",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23447797929620723270863414960346963553435132801800179183209020829595502687710",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25754633732093826542071903645477616716539119855481603833651443778242224081085",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "963070601559189683610121327696081970949276557707292265284324498501025217534",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22120456280264976127585260029167830727466681697579513980913686576584490997610",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5202388371818855245042739810182942520348869742919026103605008873834485127180",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27250043209829544647152215213010691309908825649329878939391106783772272077634",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19522865969025867699702256824892357848742375956919991967875876537922262351211",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14735341946569118164271745912302548711847504880715448104937715546077535550767",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20253680023579544901394413015968814198268157519834733770058382309219427827146",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22978462439403705668326094956543500223852988088760250354137063708806319533500",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "606473979917300560169208602118487660651064220464122706384847977534615186698",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11038840005647933120267828814992065088607658424732648462914016675157793080008",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18014251891443808701801905308807881402924321655415097237885351944294267232643",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3259974062328097133431282133799611814249730747042176027692449634537936403577",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19354459540166541219141975735150496770379319555466827821995243619353140398740",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21608740065111049672755862302628584697180623074891409590968823689065143639714",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24533229110827374623365058184549893965875026143275257709875095745769126949302",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2331832884596921587446037979187700910311336630394895061445581695052941965753",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2923044100749178868863941577255880116421261868582741548376309486381175703242",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20048363577189316195116006689255651542177185482667877378573295705324833302673",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14199031327729351111339914078748998943833204907955359608690490693714352192173",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14607270180062346906412641088675327449863837070740967992569945404702826802902",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "23296361866556498372803363655538127093074006983362929964007273849229897421742",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8501792336147241712807477564261238101570400001227463108202008551427185643404",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2338967831266076507567165884119260140531196389767601697903247355194962053180",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9883810829306326527962452954784022064107234988334019613808054857998983200971",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20063419284253407449751379327801463335133885797461237239401228375300614446251",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5081874982178594616023941154440678286890259762399471379486303824740399281965",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12266139604558350361932290539829466300684974921408283463323059475411781799884",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3300584160064753551022813024756018824715577715420349894027035913201039572967",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3409017579466124300699907180735023122652179020198991224252815275857350992432",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "14489730480237853969679691238262788355658079162732379868030554712559916164090",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9438869879087064285409838109773713765990236724871346110379950010557392177759",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2020888016750635922465560847219809564461758043397523066354503854758057623814",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1976501080214237217447233659801984691768630264138253654069344620268566088315",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7940590112911115201493584092535354360952387551197461721865958014470769814056",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14731327927433643108229562498459493541385604585221426900497364190932259738566",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2346053587431464997093083855577889736119747005612168530597969638741156236780",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12520421675524404417716609477137414018270643319147797564780572300093283219811",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25160871303194369722693921343665958190639249900405227872811833133725305643463",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10282481860747029910475950042968789971036423389283578430581055563352157865765",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20399854729739118174516506471459975714245819705141078747642949198689748903559",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5035165100406972211677431520116703432290377477038671553783250462200864233267",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9644439594559434852546319304757461143531261535766970120869277379857763087997",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4306799227626693979032826871980722452155076213835187427890243386336976889157",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19084635717076068353667811210095118431214013109331239019893836551429715071963",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25537834645968714465439147386143197329509771897407758102699104153690478918162",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23727026688703043212225298992219419290607489161079266957191703510194448537993",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7299682270356889402401951171286170418017701533140969081667479174476445406800",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16067697647133521254395782770691461749349689627157205949567031561684701509516",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5583986639913013631240591346215935676477706304108627827055345825721568566582",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12317424731530769168322584123017252253593072290648605689728159552107501572282",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "851991481882755636772917937503704097712240488787094438489984922717198899584",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7671381468131681476494417469161433925610551813117019035006285194004504571227",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11528849382841365264763617175855032642276472608728153593524573362656745981128",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12323009879185678081661739126236103384335709758596956882601983969030412617739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11363530743819892780366734372424969168265606057328725527073760663296690497717",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26028712983280496404934992282598995520072328431910373058039809183884905590719",
+                )
+                .unwrap(),
+            ],
+        ],
+        round_constants: vec![
+            vec![
+                Fq::from_str(
+                    "2634156212228475234431061706124300635253416612329005714388162164707339097582",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21247488095630175917356880758750724974486815531565085286042150383173195072644",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17451703037880282704597558689126977790153481287992671713194182608035250193966",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13988586702744211638791825151205502581374773823946033042354196098231718651766",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17470778904924709635742459371604195434936598529190782241731875662235659032517",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10091935078785109493226539035129958500509730037457724313498089884005203888271",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27221472390803592007510073426322649878865814974095360684220791704269556482219",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "208376729632946571932277639810174416524118715322150231630441667083051801658",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17958747296072126307275302741511499188594784988273730668660677840563958138316",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5866599747143542019733611649638148320649264513000162346541492595414410448694",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2728684051707796566585512259788866517740203095719491572019069897111730845076",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18175693095840373852943442556944421179324780591338011465054786688959329944373",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2058546043824708483183691566093262330941603960301931015726434154261760518455",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8743519591464453657566410326793011884201904798478242233002263109100665789288",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2201303130930825113255836602533398426403063588267457571870955413199028680097",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8632131613468860945190590347687254715778467191369098614070882074527372645927",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7711211803986512544073156147208448555499659968469172257532592087519623176221",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15523261200892301895369019207695081758656236686839848884097996764619553340163",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "715054206249855838668025886662792852938953290923361707248065984084479736286",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10613290001168306645852430517313922760080553079156701758312994298039784672322",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6096065104152217657277431867461254844665898748309485009374021224655025183095",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6221634202158310650855944669444987430378197874143659673802235405807817455760",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19728425224861768484168167827695586479880301876370114329753408121086149277925",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14650460316494915569411058684928049092425649998186524258700639515710285004891",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7080697024110833476999572356312210375199536277122814538304179034863516681835",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17186633427785069090286805448457711970688727706485322360267843206974461272005",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21098429179064931057343237157129120612065326779620866231313108558173783179139",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "7523304755076594204167624186148299716677252743014958457797678405306255131118",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8892464367647244519206739083993407153489771234816158979539540962420993234331",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5900104883680240503338935803904472649531439486981353587590963083678161142757",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25040775280667632517513306772796492818887663798808803032197487170005800537411",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18461882698411149572470034765295201425532186535821925742891794530063177555138",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21388152090871224525865143293097479378158687467412738193798753567654090266608",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "654277152241339996673153115651215330723522038246404600784336949191585458715",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28936266742114517658888163555295433412912068074632872445046157455575627879194",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "640961292187377420313933937270807083892400836184373110914375736745717970509",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "26880648757897742489428521958446556272476522647190350661084747705590671895945",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10662403857026538035285393577277570913288674297096206259152912572189375949715",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19973223381340146412606187458719100042522398412333537028494926129565852411684",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "700855275954029995139777476584004567494967343284590220108724913731935426032",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9264557108683202152635257410995765077187287355650443881587388852014322357522",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2919596656143838330154094602111308140614661831096479300503219980384197540204",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22166036590987778730963259922655032270368285981930528996108246634920831331416",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6926432468763131519457177950860363586732824634253260528131356476141462757244",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11851617950521586867902968616737630445176459109423265446875948195548434640220",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15944367019069488970964485011070537666338661812326910169981745617818108812772",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13983566300822434490551090625098664485264472979999315673272278285287184693893",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5463878474901082580006450678124135138730666254712067553718093202947609681463",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20542300427764061587454138671395588630933421505947502264939526388330805566572",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16877133670190488780831586806338564464101864680396884483183153455007428208029",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22183126834249047629816700951206471721513521298261857594158394750755556408864",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27381562557546740004096905424292802017495952573754349693630762544016560768194",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25362042006138979792188200915246733226998273743327652824350424902890799897794",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14112917292839573983329036725330590545847225023286795338070251780679960460765",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28286633873990829119895965506023754377601958035077196346438430252766656971785",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22381484098572104868864192391466168858781744346804948382967355165738803022488",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12293250235871731190703245482132033319270850720958118370465337339612494294183",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23138906732353839446640153288196653076891432024667038561161855307593877199254",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6151053997756607104991379427951246801168096517819952949360231705108570270498",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7518534710938492575962002325041463684583241759788466974663402422040929671504",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8148160088429500298685095716401682835268883726163716937306802619978728878831",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23739128130448069284479898085682892098872047148395901242666535676868415390831",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2904469862155679322863769532075240802776276079849069978441323284312552990266",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "13057849419371245940078471170382838654165116885914126598564876118846310508837",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11179769501937047274474895100332281903053358220435414915792054584483414419739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "666371586319046362945337111079996891857230558718862801407472115310491558187",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7293821004955559463853815110185684013230606808772730832666494039783981225401",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26894349382190173957703332254373635472286444948190074321953496550033225014913",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22566268507571743588215196242673502183865133310832417292255717687489268358372",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19650264835477153009681637847017369671000903405596914049929379157393194848549",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11414532765018760225480801524166790738720702263796129214003039413043840929237",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23956337016369782980336134347316531256288930215469552518427254585774135539920",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2923714590687040145760166167345430085905547812105047687128977248656221234230",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13336510773323142574567277063082337450706698129417366549105299120285247945824",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1118320703578148815564216750603942656519485134351584048352570710407966858667",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27027234706124447499880582063118922149878205995034079986249311235303522043663",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15107187530867434597567459080263493276552514087790118314906336236886730350147",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17119012964220297092942295399138888619141904480002887578805917611889315849768",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20025566789911346538758369923295944847000702775472181721546664825771234802018",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "393028474357564787455192623810577446605577634478662991877209634848468108369",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16347101848585653751034618967309397777932587236466485480012465258029925133535",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "6391119887194809117931566203719044406387103683031044491978062918596847287406",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1871241758133153190077695233914276324628459335589703830793274414909458953503",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5044734057710089122442896109043577809105083039636721034876823897907350580752",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4233177264042449062075341214018406901811087169039378230328881702785919398093",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8505347920932836375080639531579720833694406966882749680900912514480162676355",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10287897775074172698670268838557546082137461548177874188972304808395697658628",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20855731306568521575526457496275079374562807693826832242199494345389715838057",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20936753833900693596881268589353926068983819849435039944375437335263825335651",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27952151001748387386672235961951318279447815323623204426266887815570735357896",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16527954964101499110111317635315213563215891643040755709573933451501240345067",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26621384680485890951654584775717216525796787441194862383386830204948849228169",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23254903586441178249986821685517126644893693460961532045393373502245435315976",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2007990564194489658763788382666843121778939363725443006121216535178395668712",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21983103324363429776271062313876995357125453874487696591668033115698011739207",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21162082725319264240904450524497621751415766459760842213857897845073463543450",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3384359176508135191825024692006078493398295002753478867588942590427947676164",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6003258726610322433935113684545041388714487180397235198448859357442850177280",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4785581939897640515257186572881629635103643741969380739252203820966260492842",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28178048095559037148630115687189509602504518062784775754541365597584082848395",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17131950656506350101177971198918359135221715851159183771452373126034614621632",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19993933610897273127754818728235216494215392282388457659489793039330834175072",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10328241806420901846692232022830757214341980095741539268587998879681803134515",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20641275133878537077159310986703855540843415688678027185161074456661794510176",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9132159685518259579982036406922248506051856274948505016077180599353033249399",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24279231571051420840146812753989126849082559347204346438048608048744882286463",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11236554807353555948857605790614880182618193368598371494384214821196882771869",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4552301173414284731669599509793619539486761808875931314811057114512152026342",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1508874933313309190134518895125508402619367840376287054909065559098338549695",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19436064238355282819142340106764513158575748196375965310778294969068799110399",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2211144103919272447555026737167569849112546824103723953241581849217357327274",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11290158763369241824842335185824772642602940854083325864395485353594402806095",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19294143449292469706006476277469843190942545706127597552261290154105874313180",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15883542058565903140243850822006285784070383477351370674901722441032021683259",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3782441905282799688812082598551784891166776344529280535458607945059122623230",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19338947366053452780606592288272916020368733861293647525480519584296453737424",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11243293534967892086273961031154345473775988317075158868746489238938078695433",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "17348141672367953498850012866814173766540545891217533779480875166684943943757",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27258142221553476265787804977673542759582523288961768975633820410406007807517",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25150623940773149803561658006843272936347159578101271744067611493131958163891",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "315949891193069653509871891564336049912783234646624791101258502054090456751",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12583807328544347044311020983337721859294158528565918658996078886818556417729",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22326389529413695757330373163000653450293183990881958430051833012886525368412",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4086529830179756384150275274374812660899417320860321087841057300664323459375",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2260661164215327096001294723222103626645920661669868532375062213197929948675",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12737135977763560237307869170726079716316077090808862423279782228379809397983",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3774900737651550364862535151477057828840210765206257839093394077932559954918",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19097836425895616465289213498324886370729143988888761148654644616003589945529",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25684780819605442100225377774238361665940937468617330183763270928522710974318",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26551883522514257680265330353635570393847268167651991272156170015463119090430",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24167738503708737124873083496263582320269974938397105293449989737312281436934",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16658419011073608638354890831248019528789738765385128298879151246322065507726",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9027547400184764549995282037680395647004154450871063950885640043509125692352",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22370342629417587119080709487934788377711556326942816960579645360683336136933",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14944008346496159080940412136856112548192337335507854000300502831032519426200",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20036496478190088983404051033572352876142125626530101306629862076782303011000",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15258988480911750557245227550239178976850775821489096663830718675733828143241",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17222560112856049280293134264971454292180618722025226326618632300790793867025",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23952544227335034633018868810932692320566121096225415685187259617344650133289",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1853534261826124303783745961659430503664782081192217228977519706255833432067",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25288697647796586888375631107446000243654930117094953539003636888008691065202",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8775372727964829462684892144023352603656248705929433171676260636524131787211",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6075526572699287581740122966586161963103109572405674618934296187578434920363",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21992791890303905920121532636151161120130203051297234203592785449366045524938",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "21884362119356344554316919385023387957532736122226316493025580462603663812178",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16449575621812518383878436577273370513337116864891256969926495313199220146976",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21112611093560414566041388677372082262843438595333610012564238768065179687912",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6510687446953470819841088726403004102880436858886456294073003181762743634272",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11913683335808221971702624893675049258366980361262154545525082234440441306099",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13164837294549159627542324913345061117572582714409097349762148781044941180806",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22495908385270384997530689337538655472243840645221765113881065289579609508265",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11940524155553573549224175932301053434149547134291730249693540643185874028534",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20531465869721011933795811896198198714530246675924650093358747532530944128773",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12895215875534769245763182875237472466023480857792915820357593783504872694030",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10852036047552761100569190750711673905975407898184443599454968716652980044860",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4639240293288885427861611802054234984760670117869155033783549336584643345005",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7477563193539106198480829685308965057184720735840153780272643895083406436875",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17495668259230688799228646564350380314923914492088998278470522354874162171870",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11675414234816523062759948782976316346793860925355887228473553032073449723519",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6384828655138344976250343599626602317959797287286083672423202602321461088170",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1501529189421525994525786920104288801610270750299353354999896480087091722679",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26943464500369506168938426047673926168876754412067914560360670235242222516676",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "10324318101557351170203486171006850095855525104704572309540506496652255311976",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7208658974662061523502194046062356983092613659956191478168033160163858892945",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18160784707007108324385141616013238547798252727998151826898761945280511453274",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26909857411489083680933521696449480684307416328458793221140924172270171736496",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10247215021030881951531997894410810826788326022861558687738745781830398747525",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1356580271124071331614052672840503455486084475370405413171202407416016950413",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1492018226147959293991401752350217869931765686474616912563267968991022191529",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5714777775665182647705181036146394554586082150487867515736201687909671576671",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11840874977509999067231038731787190059672889405638545209056086656547950066755",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "28015869029289597662985309091163258629512548391016655583982162495859356729603",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14234146766144783131735259337338860401859458589598430999047077738281196635868",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "249102457873186769226805618925024275845967660528691287983881324535223900913",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21662092303166740339099935549178359725946747945281949060279814390080779830383",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25376650770229960355282949011640819496472721832179089935708834660067403052340",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2558897696612034909858953798715728050883994215803744141714196210263482255609",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25400530522538196953344780918633084076012264394020900068189763027570704270558",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9352088238015650794871658582995857537815590809902291691559519287311637505898",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11755820848013760929749234161691320203184562878536168839137755230486926040424",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "724579198488207996674779303401552178074922348301237530702993484988624765844",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27343980775000709212257615983454969325210656579130398534201435451486032951946",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12552410331327737098726428932556819942155501069134905816820968091019315828465",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "812588274513895646055192117985123895485656020601361981615363764001923781465",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12061962511426585495131339088771063794931349793609448108428856379569625853290",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17085623953287458289295348667815792554765308616255145493009081541000494952352",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28029375770763895647622708114645469969042559080189476088798329520111160620313",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28090016220759910990110714975014059141831355498044059010113097137202255548330",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27844518213383151682335192576814052613474333310269365603763422217586752083937",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27920006698731094321252769486193080606623857431310578954588636391119825661249",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24879730872728513216892126531747812980006439481685474895103312190877495378643",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28766383963944590287666801344772804894796883979343493230118179305508408106983",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1281918348296477517160622338971509357493398910782005489011765816566942198117",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7384879220936679845977649170707818259843000572882156227431205659805768169665",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10889272728515843239835709684940965341921176256035673704715419170226330823650",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23341135407618186175145797727359572874989189700996676668075065365711638701631",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18771151724581609957751263678949525546003582769197050774574099313517118628559",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3577277002418600476140066581312627197563600481344139450378005385078369296291",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27110353404621276863018607007514977259194920094885973572002153965662397952727",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26654597873982613301833681092137621297887554076800213164834925884860447497249",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8991403619751357004476811248125513481805321698214049377411326137287645308811",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7015537969628523274956594884653370628017997590882248810740012208611768283039",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7698067253977944178616453747096989195749893576349586846510095555021030077222",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19497388719016249016639658529939985257801835038072341427653581917500103807338",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1806329220590690714577913157492444149956717585357784410869522517652383725590",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24579210684209713263617665548915946306812446670107200163687186563962694079725",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16886521510769797907285196331333103226403926851902367768192333308025522129771",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "19159975170848892357187264901303067868294310764849509054763921446950578401368",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1520400868964865564501438124213348254372682232290066199309791003236302533033",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17759586887077870537106186441470558722729727753904468907457963369192836783639",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6051689445340352406362854441821706324096464572888157813589975210062785205054",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11465713054647193024342527096443418225915559566678699891250172995701325014284",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24776743869210346545959057199554388683004349653003581032961085390107566493610",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13019487229102286451339675772739578357129340537702188316775651138057726628876",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14661683783675100379000314389275880134288263381850130126399922939926155724471",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2260987328059212265843832922047647351653063269503760057990288620041789682535",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9880106598222095552146188035316946587312425623346876381749581268098125419437",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9074407079326418420013740561890622613797598670940304022248081740716128161659",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28654508428708035024367443885096080518264532490001682596482137606449006598738",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11397367934923431678655613541685106399150565636471756464976431255868375189982",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2102387160451268897308764782033779692054766886167595207456286387906898650353",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14880061447395143597339342467051929199753667889463959170074021025297728060420",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "554345724636752793725623772146519443328484589724375032365676000096478834459",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19101620787515899704687128949738511315260054768046768132719688254427522655302",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25729590259082916757116681834188613455641445601167781450632340938385168830477",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16307906369682133724565588699919198808113959784968802714185002420584318287442",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11991783818456256525598865937341923854034327274655034232233843172522641786952",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20925112575911590965767298868274034437859702475386913601731906223014691206136",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8511342876791836911405643367410412431295790081730132402057639633723327892672",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13416426990690606231031656224743858155148882602319598013735094855181498437984",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26890187362695070395758787360031424936238973418160992047178938983831357808135",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7810593153864555155652051179224141876869008939354968775322286066430073862235",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23435827041598383477027302352753900770668283261600432066590814268926350679123",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1725284492151121180571970450188010110753730247765815561812494538910063336667",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5910052682773576049588491759777539221892448261789156258727516858442371899740",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18481900882395539055779928286764229489550539307217638689737975645545513980195",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20581833442024876867570918113509054544962207429846932091190022820240889751148",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20739443893194357975641647102412778847365897101126722238136308771827996758174",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21483183097563233437408814545705244971032749146029921199319711887089923332790",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6311812191470771408490657866189037363179104159441581951461446626592951556949",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "919475567521761757604356542880715311245200320872873948672307751920725917465",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23169348634377078616063693138458690900680086824434071480953929985099231729014",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20572507681211717944307524436937185520324179448356229171669072282982659565635",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "25891392938984612773675236846089696227289691780451690188280829192097077170127",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7435085165106988618398164125990574423482318515558239312189316643638575221894",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17847394235790774676533826554343733238205702403366928710650518580020932489211",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10423947731561808593495144582677660882431130878030658589041497876682969415248",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9945984646855460984057035033721422092828267610995145360722416552360016827218",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21264378228829366464221089705604772456383569682795229525117431227367820823099",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5119543280279104407307997181083181374883000608792523082368037320102477595529",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11852032812779055865718201997305240039805615510138538725169948247193998423898",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24364266802376388190418199156072012713495028361195953535141043975028287391238",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3542745654497816824572591386676689798268263717841705841121176972314399769877",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18838953939569592950287677543507768100156688163331945618351317041452115947307",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20698495065162590252817902856460249144045082225074253483417497851467668557735",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24606465981405869640646887797054554626985888744733494278583156769040685923965",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27589633157108256672924559529097922148275444615411494856235398566327203381730",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9630297527536816793134881491242964585123372315565790364964997328964495279390",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4579318737557172482691382992048240090148904597598868526019699600751566699100",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17860135639797056364302258112733874166052557997541887203180824448949096687772",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27018585048438658285790671707879831304383876377808027696674361761065576799541",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "8981378760189043068687283807510905618958481831212328485513410665449999486002",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2923607355784053116959764530399422769509907348582243337085506233034675877416",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21995119129422999336492765148197060662531975020179940565264281228578844047998",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9451880234912589062322097971689639449831498951369104598214006619647001440120",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4535013375281110742936845085158644986576338631651670354781509979026039629346",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4827473683305669860127383655877116597408806356058843326080196261533287483865",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3030186823587498094459795077525415174386405067156307649273526393666910699701",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15110541086699854855304241757113789690193421512041817042333004190988108456142",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19102549116322979880589735917827109256059739092800187871657625479784640229188",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1566397906538290803148391385720319987100390443465981146106967485330994141560",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19353806737096845401103244824608783176301856145434364818850111477641445424879",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25159279037749423272579037757990875480495165571606618405574704100283661866230",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15799908201163458461265787656431605896324758338586359403007559971554096598902",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27934839736929816034965571664358192799294631163897278930698518176318588808499",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23748996763034840071377549241522410914727501423627331427113466254179574521615",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20951710591374566760605631393367660027599177634407468895290362387706413558255",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21492442256100748039997552638248066175877687117786954525965052344336677374906",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24538164430471220427043521305877337056549301139727242780409283388013716270994",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27464751103548701645908831567547167922511530150049470861784675136271421532253",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2301757429367872369171445504460071748957136896562914281742622570510051854680",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4808949583776017521175813484658059467353429912917447267795777132374038539061",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14490236797766166487742154857257412919531905372934110583456209648250081134493",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11846355464678116014308932656842634287809657931865277894557752766386145051355",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7589405243949250601418480354352281569671867486731608717367449528198487036971",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25607196692560985651041456497120267891536291021225229822080230032047496392292",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6692428135088630432892597982856866366405329021501094235972647583189482159988",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12921154420418530665082645427882404790657385731147937384314280675253493177815",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "20307868952556191961580504095941962125598096730500263751363473664371282543674",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18190508449888378736198707568942285289784764955286995694876616629483299678724",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19785638547346888696171099337230052150090026636646277696363585279010384430559",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26103375745730573633368962514299327307694187983941371420046208851677533866597",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15749204208821145159974149944700415599043028512135784846684212646788137824273",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2760745996290250086597420958554838516682699630487306543228375427899282738474",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13221169615944978100139590911048513672807001004384821050715520824956179718856",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8495853403089553725122635956107580179830570578319222776585145663293149752353",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19453334225359120190355281751937527677903848663731698752979115967253717504271",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18099980573280300037166246043859408285587886312190973202280073424798267128529",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5110960715370925465389708065700350724787507214388805319095779079622991442179",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28000759002364074818121411384590849434197495872150450009216657904418255406310",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18495024406084970584087404248147181907275302846521628333999438213299552974263",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28341444687241924614306988277691802544280863120092346297519794759057335555147",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26970855287038627612167046115690318396754733453139360159333952715580005356697",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22672857847469771576132384110552198959941193935109333588127847343392689984499",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25557699330086967679099993831786611596358559977588687314949427044519444445772",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7016952521108665808670441348518680315190330580175391920013994849382145664903",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27997009359074292201478529669735626809529108190556091810589580347573413582492",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2770443390083335809507047235719546274899286034501554213971845389833321942943",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23282155105868486984870383780070381576546381003055004518362444618103762595249",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26653193220150499805892158647261002719260134778273096137237226713479503138872",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15581917091056308791157350746836994674596128750909564557361710374599647002853",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15560723930016715739099139969907450952080097611401869829220869762835544318217",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26694114266322067520924293417581700907483999834473268127370454795502422187660",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3381561522059285071366338640786331738548032211751560034317804275702811884397",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3075565345085640249964496519830779061316778896110477179222266471285441744438",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "19341648673871221972467140663595257428367883495284309064809790193701088430242",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20975131832606853434990969401153338241367976274255637485665284293456414718453",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27764562499061550075885695387824153666720650917745384228081884552455194719093",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14943382984562102674623700757364858559590468883664110329600767032486656879105",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22657493512339357725811145113945879515552551672508264571257033915916376099460",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28469919543449116366263789491610714538114451558982494763961110512805152320856",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2100732125867468829767353706489105589521184056130920092429275649360813455651",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18554009136521211495970689836387583569294449133575489260457658915281366070894",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27485944178991236185956255345023259769657562438743223508938578860724888002760",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "5474980254698240196047365560512340772146994764695245458784901008102473120370",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14284687529102591746932531047202234759355116782597226341953813402722493875542",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28695424846362142551574297126804319432534818379042317605508672199099569151641",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10008867816182417288458341852669165389877643035757405240165142063070283886934",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "72996176152989913886337782762740688697445992093795097352400698112659612562",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20070302027691062699293231010420541210368024541008506700798687662847973896286",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23997135579616121511066189186098217524037409877116473937739134173388273621503",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25901979699522917778396156816226065025546954556859462247178909925473116189464",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27709188793078496139425335482901243646960213199968449877542452879364722779201",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "16265058625694159161054492142607185637907197294666000430977902132533670616010",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24090876707636432812521672548767545656661616005698568299495413086875363399603",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14202781451920578572763667992258765711540058122708973721690407902351506752661",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22384360745985344319642441548898467165776120793654384585209833331614160380897",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28194984937584017518050688793432438190127709618481156182986419643774162735394",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27752173149567706446121345307928251045183931884432576079001025124357196272335",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18704710951763041863936496156254120475546970048563026218764528911035585667018",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5254997597765333908763227553217170608228513487670695124613692816263272406547",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20933880103753704074854346043822045816744101049336639468545894734563259600931",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18288448364373176867825534096305207753164725709356085280992896004819551533108",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22547215349736760527023118280132223763481492251152408314586676357555835951677",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2090454508680663931227166879577113257445257906032133766832597959743240996053",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "916054906012484481873645411998459535518018340156245995158185499276122129141",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13115423324495945731291259642392611439611616551125691270328475820767346846747",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16806405691021493837980354426170467356799852617671348089153229603146276618707",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9321657914808817543580932165448716054034549150301600146552137113746717391873",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5350641080226281690562924666184707485188549976586607846242710683040422917460",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7020587432963399521885105353319549519586659819006771300881472282000750662438",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11815047171267579149103592577207425455020175993637870176116834595353872084235",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8094747334555166030446912306016694316986402515844895994955788758068948811351",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10126609128035975481734470087149346388965882293036420151439056674453808828533",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18410567960178881460990004009031249186092771198569148369471394772706241876389",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14909223282302204894715054578681827245967026490125641858978327957886807660268",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "24642864401843403350071864163961966958289275039039818932147224927747603021156",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23572550588191954704184173134706448370500132951671562225016887977227765195017",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16293927917886253857367337656230752394231470607151973867881052211117093217379",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16675385309520168948869132911337208037767342784608051487517696450421518202726",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18215505319328273394121844211114531332027098640029723743733267616151634794977",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2088569056006212884797958055687347371446302178805034971353241985789446153484",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14149394348038763710962012281802410115823173038178949220522936143496267897377",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "431573543367013433459302493323347025918114831892055281668318239916573397830",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23228225070348136286331651611149801191713195127912295588340355668536657321387",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22677100325086796722564475015189628589011608589924502876881728716918746187357",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20236783560548366517123858105664988784738035411695495748731548225564491345748",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9623787925870462524264715438807088458376298305995953145172495558315279028384",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20705517304616122561762748674834981530742013339132901965416885992610583078269",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11516657625436544347485598992226960262303723866686954356577529408302975995797",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19494821702925890892011814900087860267676712895782251751215423154033313367776",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22509335444487861600996984913789123215148603389520027327601052735439719279928",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28905602703404716089659786800611527561140762728951399381088857435020152561321",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6016989784925300006605307996719544820635832142510922490636013655683817298827",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17078457601491318673330258740090283499699303286564150904712427969002729367688",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27255895643754814714096383052648322218498446465521224064368592771841493119236",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12300977670306897763938106859348456466929269967183637016585173950822075989145",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13445284690568900906314704263387607681547276870345984565858239370632544502570",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "3157002653401666922034381803139388573507476501116154841731630299590273184821",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7228885245139330853724647151772985014459324576166536899132345365968450800893",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22355328737906537829895475869159819742176460320171534486284618805601259596640",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9958221093620241671008266731899240839091323546014225303183475976445454789395",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26964031008105432985895535639765670535184584042395642580836814244408385912133",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23682417493087709773802032792671374133198460634461828475952919242683151910284",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5305972280033094993748974732925691373159706549040985762951291837800249158669",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10951578835534247306975147876609887652780961791952833165687375286127842318987",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8111793531070224286588652790265071831577281673061861256067025182922469620049",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "12193416429917614901405581285698192844799722158458004692574446641975237303103",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25939096445047370493001397675062731460446057842926417801634436037203147520061",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6479707735484013269007984697217689371925749450114470632015132004709805354715",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21738670954561780347244738391527915040854021642247464465815563999444862811507",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7877862240987859143666279950571576191995781223391740565200095956068467604420",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2304073371821583327629234576606410620988070200507653494172921489926828791432",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20209443523221945041587837356999008976347730645751072979293339745916702008705",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12503518603004303398218497997716653828607840862160338141653943970520295338043",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11546104599013141092332346094065205440860282853456605488151208809956734643831",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "15883611469358428233424495486196217398228222478040120884843635126287167550860",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19505075741152964763430709725638217006804504944847751056799192135576553171180",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23176296712085019113902484684844345703719961956143218999855509073183667726636",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "602092278362206798209842062558626064988972777553468511827767916110346618076",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5332384341854787703124650766741402790968002083179317218159025190489324314541",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22746411269862021063096172322337036573466257844921589103510925330975450516915",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2793947792781875105325920640730616645667485356854845579695390312868242394149",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15601163914309469840760009482222926527380926136387896921951277097864868233140",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20748465216139981037893074847631586959207137829890212486535449735455278885665",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "9594255215787349329959984702527003176763531078396334321067885438498174167808",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23684367044787011905157338935241144918472663979611755348576468753919527453946",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3486102195369393726038791893264536462009593628098348700191119007948555273873",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21129457214513571988020938230026222879159193823267164539530163920793093152050",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15278275654560578212284838619802921648462577989547170653888284131735376650896",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14648953602499970087999202365398759316005371127276689998970430644820626565805",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19041364678300189933826613971320109957680864064621162771221541361622499894798",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8389476339999873972691231487928918806536952621769616108768042701457932234175",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7651140151060631912483827806506736930765131962368148427550406167296075230174",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "4035678373389149395588353815708329070035755137085795927372272055522870433142",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2529441431450146609163290570101215181628296110165220538441205765566263852816",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2158194346144424813614840716338613076453024441405222460024482384957427426045",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "26464590337772477364935764159151263672226899386226659124819613026607813140133",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8067902099021518888754757930037332220753340922682734038391678972863137761942",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4874051324365035280020312496845427050417353152739723072841348559562312512865",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12292684239567089637318308697087991024141895062503304599582620703364922332594",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17409512418211339903343691846471986847844528906477460952297559746311579286935",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19971158336969750551839786744412782219293169056647730201595007821491951294795",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "7165017533624727192362981764363790231501006625898955667477868703416411777754",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17589027531890220739753976601952178659661461077725440308157991120464548958159",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16415042044451363406028944095299791115890518406691215943824786854326684483580",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23155225689071049288896477374171772775683466309961900513776610075323007638165",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13456313929349618446703600419370502413659731381422744957625154173148268562479",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1291591448477075698858678919997751756529623189708120490001196350310207693747",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7487714160123694622343264788855783953187134373278774865398514368262894188694",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6105227135536484050414300420059601373049933289938920674810390094289379663595",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3032470602086336960076396294448512215838490888618561277555200928798951648367",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2193974547591422973190365581479263875319605777697746349472855172930388944518",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "19631277722547404726926661765745538268520526057850034767647963452562241347091",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14310055624516512738077417808454843436045586374004324601332459874995373967717",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2102784827820315255454383695203556051055232570724110026014480583465036984147",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "9716612793950767701791243727806499703066261828807676017858215489603727941805",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12210778376832038890828740888723344361679055226937218070169588278433113858434",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "1456465367663759360978802293149229477249289508363552262237470396966160308318",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23086266183139693277486238379634791945281162789886273897333017928986947795359",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20699589823233911097778371281573154632189803760780777544535636142004575664196",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "18211304703903431882906149208989165840932884705370973758047860603848253801732",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13350052033048088594275856220538031193519881218929870775261903411722692092031",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4935721081444439153814680009736603593484261646074122644581675716133672070318",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6756302715672204418817844086540300417837917461059661640736469983267835472516",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21174147710076559916021224810391891480849207655247887547737213896153224285614",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4934915506814116744817685396273100771641369699277417285020143617215204525560",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "691250869509262167387326787356989581526694746663143837239629960657187882511",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8155997951943759349382533556430320605920568239499409156678508855784136762108",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "23752313586831698702992833733033477361470673193993198579447439768385451273459",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "27189463292506206032579779738661587985577691319373037790976486840749679977317",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "12426331254970333208416480681073633528227161070538563390929091481018719250112",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "25668355228887381665763397663919400660729259649655398004458999677629394915682",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14347106346342416632454131705724709060519338431410832608128610276985027718729",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "5215158569909404950366448119634165508738681558820404614828362558508441827396",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15787074658168424871762963004668171257620519865709830489249583604791768134262",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "517746240017054840280915390248673766791586615335379077287854406190181700071",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17626092205221445601077065679114824130450579602884384686619944022005224716817",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "10779981722727375884034006364169027602053129500875477318056135411915584041318",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "1950772172890386350938814753311561546817926234421736657901408352832767238789",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20833604207591776135506396115618062428178320580281087623703954274399710094424",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27611706776501916697453952016476415525076762720002412980189043686394935892472",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "20709304123127746837449693677010194173731668308021829751704169651080955844739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "18754628014417655146828388876773003117308803919603653817028967310638239971577",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2955113392885620550811504764793708022449987262776177594491689546633167200616",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "2302507521090520578647403601922081305409923308243976724422146295090743394530",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28820131965274959623966654386846961796438873074876046703892114838692631349739",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "11335251057029654023161759332628493738119841486327244903918137082875522749733",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "587546128300804877929239095964595620789539098355814223540995640439689210016",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4926135919019763842045928065262551583559809186093826811963834740212607725969",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "14468653005344515460470449142107533723596499944252625612010342894610090532116",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16799363463123335016777803752383788147295731877167456776351893929630135688734",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "3325047392891900503080142747240354864191715742010993323629803290640651115413",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13844992780012488876816899795580125556493436170169765186254592570384136596215",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7732242070878732842511175463784011980263988897111832212002863033767684690646",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "7509042860786337626370555418828219401772121441715714555751475378280005604752",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "15290687071986896630016430614808567671829545479006610010747375346597806808643",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "2355044748158798994262371686886034636254193759651200252929462063207263213805",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "28663607448518385806564896094191257842465080020994558231769910494411013165841",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "975168388441438389037963752937399005946005925556128443786045004346875012020",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27605734503321898130294203689682536457736732011600267548740559184836186009452",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "219266476056383069373418571259404675717150333291684401760426149024385071360",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "6820256022300547174130732640122070941962610398324721639129875216267066314583",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17176389840707431092161132520441319111761795695413749273185705446488725023552",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "8292280363285824806710492740912267159677627160978278709950229010099315684060",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "22144504506355693206962846613109957301532527033684576583666131610488447517574",
+                )
+                .unwrap(),
+            ],
+            vec![
+                Fq::from_str(
+                    "11933375245504806294701152434966329677922366221734952819846924929858255285166",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17217625625003679982484406089278172437790146690441749729091232293640964788111",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "4444397158904364611958814862494052943218592904062720943023230790397434520650",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "17012239805992170511140363239903941400657873046006758897706176347873100720989",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "27452987702100115583851903807614307902857905601410439975733688363140323280070",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "985093791676114696279635385294843782898856673511489815267936665165969057632",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "13615323803475104462769369543407175706980285185623526529536494974675195752880",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "16357975767576362001215179229667695303547609738084144709983696425880932410145",
+                )
+                .unwrap(),
+                Fq::from_str(
+                    "21683376517709463616518503952795728991663665676293554989090233912994074981098",
+                )
+                .unwrap(),
+            ],
+        ],
+    }
+}
+
+/// the fq sponge params for a width-9 sponge
+pub fn static_params() -> &'static ArithmeticSpongeParams<Fq> {
+    static PARAMS: Lazy<ArithmeticSpongeParams<Fq>> = Lazy::new(params);
+    &PARAMS
+}
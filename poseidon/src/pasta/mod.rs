@@ -1,4 +1,12 @@
 pub mod fp_kimchi;
+pub mod fp_kimchi_partial;
+pub mod fp_kimchi_w5;
+pub mod fp_kimchi_w9;
 pub mod fp_legacy;
+pub mod fp_poseidon2;
 pub mod fq_kimchi;
+pub mod fq_kimchi_partial;
+pub mod fq_kimchi_w5;
+pub mod fq_kimchi_w9;
 pub mod fq_legacy;
+pub mod fq_poseidon2;
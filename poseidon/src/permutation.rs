@@ -1,122 +1,189 @@
-//! The permutation module contains the function implementing the permutation used in Poseidon
+//! The permutation module contains the function implementing the permutation used in Poseidon,
+//! as well as the [Poseidon2](https://eprint.iacr.org/2023/323) permutation.
+//!
+//! The functions are generic over the width `WIDTH` of the state, which must
+//! match the `SPONGE_WIDTH` of the sponge constants and the size of the
+//! parameters (MDS matrix and round constants) used.
 
 use crate::constants::SpongeConstants;
 use crate::poseidon::{sbox, ArithmeticSpongeParams};
 use ark_ff::Field;
+use std::array;
 
-fn apply_mds_matrix<F: Field, SC: SpongeConstants>(
+fn apply_mds_matrix<F: Field, SC: SpongeConstants, const WIDTH: usize>(
     params: &ArithmeticSpongeParams<F>,
-    state: &[F],
-) -> Vec<F> {
+    state: &[F; WIDTH],
+) -> [F; WIDTH] {
     if SC::PERM_FULL_MDS {
-        params
-            .mds
-            .iter()
-            .map(|m| {
-                state
-                    .iter()
-                    .zip(m.iter())
-                    .fold(F::zero(), |x, (s, &m)| m * s + x)
-            })
-            .collect()
+        array::from_fn(|i| {
+            state
+                .iter()
+                .zip(params.mds[i].iter())
+                .fold(F::zero(), |x, (s, &m)| m * s + x)
+        })
     } else {
-        vec![
-            state[0] + state[2],
-            state[0] + state[1],
-            state[1] + state[2],
-        ]
+        // for a width of 3, this is [s0 + s2, s0 + s1, s1 + s2]
+        array::from_fn(|i| state[i] + state[(i + WIDTH - 1) % WIDTH])
     }
 }
 
-pub fn full_round<F: Field, SC: SpongeConstants>(
+fn add_round_constants<F: Field, const WIDTH: usize>(
     params: &ArithmeticSpongeParams<F>,
-    state: &mut Vec<F>,
+    state: &mut [F; WIDTH],
     r: usize,
 ) {
-    for state_i in state.iter_mut() {
-        *state_i = sbox::<F, SC>(*state_i);
-    }
-    *state = apply_mds_matrix::<F, SC>(params, state);
     for (i, x) in params.round_constants[r].iter().enumerate() {
         state[i].add_assign(x);
     }
 }
 
-/// Same as full round but works better with arrays.
-pub fn full_round2<F: Field, SC: SpongeConstants>(
+pub fn full_round<F: Field, SC: SpongeConstants, const WIDTH: usize>(
+    params: &ArithmeticSpongeParams<F>,
+    state: &mut [F; WIDTH],
+    r: usize,
+) {
+    for state_i in state.iter_mut() {
+        *state_i = sbox::<F, SC>(*state_i);
+    }
+    *state = apply_mds_matrix::<F, SC, WIDTH>(params, state);
+    add_round_constants(params, state, r);
+}
+
+/// Same as full round but takes the state by value.
+pub fn full_round2<F: Field, SC: SpongeConstants, const WIDTH: usize>(
     params: &ArithmeticSpongeParams<F>,
-    state: [F; 3],
+    state: [F; WIDTH],
     r: usize,
-) -> [F; 3] {
-    let state = state.map(sbox::<F, SC>);
-    let state = apply_mds_matrix::<F, SC>(params, &state);
+) -> [F; WIDTH] {
+    let mut state = state;
+    full_round::<F, SC, WIDTH>(params, &mut state, r);
     state
-        .into_iter()
-        .zip(params.round_constants[r].iter())
-        .map(|(s, x)| s + x)
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap()
 }
 
-pub fn half_rounds<F: Field, SC: SpongeConstants>(
+/// A partial round, where the S-box is only applied to the first element of the state.
+pub fn partial_round<F: Field, SC: SpongeConstants, const WIDTH: usize>(
+    params: &ArithmeticSpongeParams<F>,
+    state: &mut [F; WIDTH],
+    r: usize,
+) {
+    state[0] = sbox::<F, SC>(state[0]);
+    *state = apply_mds_matrix::<F, SC, WIDTH>(params, state);
+    add_round_constants(params, state, r);
+}
+
+/// Applies `PERM_HALF_ROUNDS_FULL` full rounds, `PERM_ROUNDS_PARTIAL` partial rounds,
+/// and `PERM_HALF_ROUNDS_FULL` full rounds again.
+/// Rounds are structured as the full rounds of [full_round].
+pub fn half_rounds<F: Field, SC: SpongeConstants, const WIDTH: usize>(
     params: &ArithmeticSpongeParams<F>,
-    state: &mut [F],
+    state: &mut [F; WIDTH],
 ) {
+    let offset = usize::from(SC::PERM_INITIAL_ARK);
+    if SC::PERM_INITIAL_ARK {
+        add_round_constants(params, state, 0);
+    }
+
     for r in 0..SC::PERM_HALF_ROUNDS_FULL {
-        for (i, x) in params.round_constants[r].iter().enumerate() {
-            state[i].add_assign(x);
-        }
-        for state_i in state.iter_mut() {
-            *state_i = sbox::<F, SC>(*state_i);
-        }
-        apply_mds_matrix::<F, SC>(params, state);
+        full_round::<F, SC, WIDTH>(params, state, offset + r);
     }
 
     for r in 0..SC::PERM_ROUNDS_PARTIAL {
-        for (i, x) in params.round_constants[SC::PERM_HALF_ROUNDS_FULL + r]
-            .iter()
-            .enumerate()
-        {
-            state[i].add_assign(x);
-        }
-        state[0] = sbox::<F, SC>(state[0]);
-        apply_mds_matrix::<F, SC>(params, state);
+        partial_round::<F, SC, WIDTH>(params, state, offset + SC::PERM_HALF_ROUNDS_FULL + r);
     }
 
     for r in 0..SC::PERM_HALF_ROUNDS_FULL {
-        for (i, x) in params.round_constants
-            [SC::PERM_HALF_ROUNDS_FULL + SC::PERM_ROUNDS_PARTIAL + r]
-            .iter()
-            .enumerate()
-        {
-            state[i].add_assign(x);
-        }
-        for state_i in state.iter_mut() {
-            *state_i = sbox::<F, SC>(*state_i);
-        }
-        apply_mds_matrix::<F, SC>(params, state);
+        full_round::<F, SC, WIDTH>(
+            params,
+            state,
+            offset + SC::PERM_HALF_ROUNDS_FULL + SC::PERM_ROUNDS_PARTIAL + r,
+        );
     }
 }
 
-pub fn poseidon_block_cipher<F: Field, SC: SpongeConstants>(
+pub fn poseidon_block_cipher<F: Field, SC: SpongeConstants, const WIDTH: usize>(
     params: &ArithmeticSpongeParams<F>,
-    state: &mut Vec<F>,
+    state: &mut [F; WIDTH],
 ) {
-    if SC::PERM_HALF_ROUNDS_FULL == 0 {
+    debug_assert_eq!(WIDTH, SC::SPONGE_WIDTH);
+    if SC::PERM_POSEIDON2 {
+        poseidon2_block_cipher::<F, SC, WIDTH>(params, state);
+    } else if SC::PERM_HALF_ROUNDS_FULL == 0 {
         if SC::PERM_INITIAL_ARK {
-            for (i, x) in params.round_constants[0].iter().enumerate() {
-                state[i].add_assign(x);
-            }
+            add_round_constants(params, state, 0);
             for r in 0..SC::PERM_ROUNDS_FULL {
-                full_round::<F, SC>(params, state, r + 1);
+                full_round::<F, SC, WIDTH>(params, state, r + 1);
             }
         } else {
             for r in 0..SC::PERM_ROUNDS_FULL {
-                full_round::<F, SC>(params, state, r);
+                full_round::<F, SC, WIDTH>(params, state, r);
             }
         }
     } else {
-        half_rounds::<F, SC>(params, state);
+        half_rounds::<F, SC, WIDTH>(params, state);
+    }
+}
+
+/// The external linear layer of Poseidon2, `circ(2, 1, 1)` for a width of 3.
+fn poseidon2_external_linear_layer<F: Field, const WIDTH: usize>(state: &mut [F; WIDTH]) {
+    assert!(
+        WIDTH == 2 || WIDTH == 3,
+        "Poseidon2 is only implemented for a width of 2 or 3"
+    );
+    let sum: F = state.iter().sum();
+    for state_i in state.iter_mut() {
+        state_i.add_assign(sum);
+    }
+}
+
+/// The internal linear layer of Poseidon2, `1 + diag(mu)`.
+/// The matrix is given by the `mds` field of the parameters.
+fn poseidon2_internal_linear_layer<F: Field, const WIDTH: usize>(
+    params: &ArithmeticSpongeParams<F>,
+    state: &mut [F; WIDTH],
+) {
+    let sum: F = state.iter().sum();
+    for (i, state_i) in state.iter_mut().enumerate() {
+        *state_i = *state_i * params.mds[i][i] - *state_i + sum;
+    }
+}
+
+fn poseidon2_external_round<F: Field, SC: SpongeConstants, const WIDTH: usize>(
+    params: &ArithmeticSpongeParams<F>,
+    state: &mut [F; WIDTH],
+    r: usize,
+) {
+    add_round_constants(params, state, r);
+    for state_i in state.iter_mut() {
+        *state_i = sbox::<F, SC>(*state_i);
+    }
+    poseidon2_external_linear_layer(state);
+}
+
+/// The Poseidon2 permutation.
+///
+/// The round constants of the internal (partial) rounds are the first
+/// elements of the rows `PERM_HALF_ROUNDS_FULL..PERM_HALF_ROUNDS_FULL + PERM_ROUNDS_PARTIAL`.
+pub fn poseidon2_block_cipher<F: Field, SC: SpongeConstants, const WIDTH: usize>(
+    params: &ArithmeticSpongeParams<F>,
+    state: &mut [F; WIDTH],
+) {
+    poseidon2_external_linear_layer(state);
+
+    for r in 0..SC::PERM_HALF_ROUNDS_FULL {
+        poseidon2_external_round::<F, SC, WIDTH>(params, state, r);
+    }
+
+    for r in 0..SC::PERM_ROUNDS_PARTIAL {
+        state[0].add_assign(params.round_constants[SC::PERM_HALF_ROUNDS_FULL + r][0]);
+        state[0] = sbox::<F, SC>(state[0]);
+        poseidon2_internal_linear_layer(params, state);
+    }
+
+    for r in 0..SC::PERM_HALF_ROUNDS_FULL {
+        poseidon2_external_round::<F, SC, WIDTH>(
+            params,
+            state,
+            SC::PERM_HALF_ROUNDS_FULL + SC::PERM_ROUNDS_PARTIAL + r,
+        );
     }
 }
@@ -1,7 +1,7 @@
 //! This module implements Poseidon Hash Function primitive
 
 use crate::constants::SpongeConstants;
-use crate::permutation::{full_round, poseidon_block_cipher};
+use crate::permutation::{full_round, partial_round, poseidon_block_cipher};
 use ark_ff::Field;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -41,40 +41,51 @@ pub struct ArithmeticSpongeParams<F: Field> {
     pub mds: Vec<Vec<F>>,
 }
 
+/// A Poseidon sponge, with a state of `WIDTH` field elements.
+///
+/// `WIDTH` must be equal to `SC::SPONGE_WIDTH`, and the parameters given to
+/// [Sponge::new] must have been generated for this width.
 #[derive(Clone)]
-pub struct ArithmeticSponge<F: Field, SC: SpongeConstants> {
+pub struct ArithmeticSponge<F: Field, SC: SpongeConstants, const WIDTH: usize = 3> {
     pub sponge_state: SpongeState,
     rate: usize,
-    // TODO(mimoo: an array enforcing the width is better no? or at least an assert somewhere)
-    pub state: Vec<F>,
+    pub state: [F; WIDTH],
     params: &'static ArithmeticSpongeParams<F>,
     pub constants: std::marker::PhantomData<SC>,
 }
 
-impl<F: Field, SC: SpongeConstants> ArithmeticSponge<F, SC> {
+impl<F: Field, SC: SpongeConstants, const WIDTH: usize> ArithmeticSponge<F, SC, WIDTH> {
     pub fn full_round(&mut self, r: usize) {
-        full_round::<F, SC>(self.params, &mut self.state, r);
+        full_round::<F, SC, WIDTH>(self.params, &mut self.state, r);
+    }
+
+    pub fn partial_round(&mut self, r: usize) {
+        partial_round::<F, SC, WIDTH>(self.params, &mut self.state, r);
     }
 
     fn poseidon_block_cipher(&mut self) {
-        poseidon_block_cipher::<F, SC>(self.params, &mut self.state);
+        poseidon_block_cipher::<F, SC, WIDTH>(self.params, &mut self.state);
     }
 }
 
-impl<F: Field, SC: SpongeConstants> Sponge<F, F> for ArithmeticSponge<F, SC> {
-    fn new(params: &'static ArithmeticSpongeParams<F>) -> ArithmeticSponge<F, SC> {
-        let capacity = SC::SPONGE_CAPACITY;
-        let rate = SC::SPONGE_RATE;
-
-        let mut state = Vec::with_capacity(capacity + rate);
-
-        for _ in 0..(capacity + rate) {
-            state.push(F::zero());
-        }
+impl<F: Field, SC: SpongeConstants, const WIDTH: usize> Sponge<F, F>
+    for ArithmeticSponge<F, SC, WIDTH>
+{
+    fn new(params: &'static ArithmeticSpongeParams<F>) -> ArithmeticSponge<F, SC, WIDTH> {
+        assert_eq!(
+            SC::SPONGE_CAPACITY + SC::SPONGE_RATE,
+            WIDTH,
+            "the width of the sponge doesn't match its constants"
+        );
+        assert_eq!(
+            params.mds.len(),
+            WIDTH,
+            "the parameters don't match the width of the sponge"
+        );
 
         ArithmeticSponge {
-            state,
-            rate,
+            state: [F::zero(); WIDTH],
+            rate: SC::SPONGE_RATE,
             sponge_state: SpongeState::Absorbed(0),
             params,
             constants: std::marker::PhantomData,
@@ -123,7 +134,7 @@ impl<F: Field, SC: SpongeConstants> Sponge<F, F> for ArithmeticSponge<F, SC> {
     }
 
     fn reset(&mut self) {
-        self.state = vec![F::zero(); self.state.len()];
+        self.state = [F::zero(); WIDTH];
         self.sponge_state = SpongeState::Absorbed(0);
     }
 }
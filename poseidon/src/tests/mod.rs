@@ -1 +1,2 @@
 mod poseidon_tests;
+mod transcript_tests;
@@ -1,15 +1,29 @@
 use crate::{
-    constants::{PlonkSpongeConstantsKimchi, PlonkSpongeConstantsLegacy},
+    bn254::fr_kimchi as SpongeParametersBn254Kimchi,
+    bn254::fr_poseidon2 as SpongeParametersBn254Poseidon2,
+    constants::{
+        PlonkSpongeConstantsKimchi, PlonkSpongeConstantsKimchiPartial,
+        PlonkSpongeConstantsKimchiW5, PlonkSpongeConstantsKimchiW9, PlonkSpongeConstantsLegacy,
+        PlonkSpongeConstantsPoseidon2,
+    },
     pasta::fp_kimchi as SpongeParametersKimchi,
+    pasta::fp_kimchi_partial as SpongeParametersKimchiPartial,
+    pasta::fp_kimchi_w5 as SpongeParametersKimchiW5,
+    pasta::fp_kimchi_w9 as SpongeParametersKimchiW9,
     pasta::fp_legacy as SpongeParametersLegacy,
+    pasta::fp_poseidon2 as SpongeParametersPoseidon2,
+    permutation::poseidon2_block_cipher,
     poseidon::ArithmeticSponge as Poseidon,
     poseidon::Sponge as _,
 };
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
 use mina_curves::pasta::Fp;
 use o1_utils::FieldHelpers;
 use serde::Deserialize;
 use std::fs::File;
 use std::path::PathBuf; // needed for ::new() sponge
+use std::str::FromStr;
 
 //
 // Helpers for test vectors
@@ -26,9 +40,10 @@ struct TestVector {
     output: String,
 }
 
-fn test_vectors<F>(test_vector_file: &str, hash: F)
+fn test_vectors<F, H>(test_vector_file: &str, hash: H)
 where
-    F: Fn(&[Fp]) -> Fp,
+    F: PrimeField,
+    H: Fn(&[F]) -> F,
 {
     // read test vectors from given file
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -41,13 +56,13 @@ where
     // execute test vectors
     for test_vector in test_vectors.test_vectors {
         // deserialize input & ouptut
-        let input: Vec<Fp> = test_vector
+        let input: Vec<F> = test_vector
             .input
             .into_iter()
-            .map(|hexstring| Fp::from_hex(&hexstring).expect("failed to deserialize field element"))
+            .map(|hexstring| F::from_hex(&hexstring).expect("failed to deserialize field element"))
             .collect();
         let expected_output =
-            Fp::from_hex(&test_vector.output).expect("failed to deserialize field element");
+            F::from_hex(&test_vector.output).expect("failed to deserialize field element");
 
         // hash & check against expect output
         assert_eq!(hash(&input), expected_output);
@@ -81,3 +96,98 @@ fn poseidon_test_vectors_kimchi() {
     }
     test_vectors("kimchi.json", hash);
 }
+
+#[test]
+fn poseidon_test_vectors_kimchi_w5() {
+    fn hash(input: &[Fp]) -> Fp {
+        let mut hash = Poseidon::<Fp, PlonkSpongeConstantsKimchiW5, 5>::new(
+            SpongeParametersKimchiW5::static_params(),
+        );
+        hash.absorb(input);
+        hash.squeeze()
+    }
+    test_vectors("kimchi_w5.json", hash);
+}
+
+#[test]
+fn poseidon_test_vectors_kimchi_w9() {
+    fn hash(input: &[Fp]) -> Fp {
+        let mut hash = Poseidon::<Fp, PlonkSpongeConstantsKimchiW9, 9>::new(
+            SpongeParametersKimchiW9::static_params(),
+        );
+        hash.absorb(input);
+        hash.squeeze()
+    }
+    test_vectors("kimchi_w9.json", hash);
+}
+
+#[test]
+fn poseidon_test_vectors_kimchi_partial() {
+    fn hash(input: &[Fp]) -> Fp {
+        let mut hash = Poseidon::<Fp, PlonkSpongeConstantsKimchiPartial>::new(
+            SpongeParametersKimchiPartial::static_params(),
+        );
+        hash.absorb(input);
+        hash.squeeze()
+    }
+    test_vectors("kimchi_partial.json", hash);
+}
+
+#[test]
+#[should_panic(expected = "the width of the sponge doesn't match its constants")]
+fn poseidon_width_mismatch() {
+    Poseidon::<Fp, PlonkSpongeConstantsKimchiW5>::new(SpongeParametersKimchiW5::static_params());
+}
+
+#[test]
+fn poseidon_test_vectors_bn254_kimchi() {
+    fn hash(input: &[Fr]) -> Fr {
+        let mut hash = Poseidon::<Fr, PlonkSpongeConstantsKimchi>::new(
+            SpongeParametersBn254Kimchi::static_params(),
+        );
+        hash.absorb(input);
+        hash.squeeze()
+    }
+    test_vectors("bn254_kimchi.json", hash);
+}
+
+#[test]
+fn poseidon_test_vectors_poseidon2() {
+    fn hash(input: &[Fp]) -> Fp {
+        let mut hash = Poseidon::<Fp, PlonkSpongeConstantsPoseidon2>::new(
+            SpongeParametersPoseidon2::static_params(),
+        );
+        hash.absorb(input);
+        hash.squeeze()
+    }
+    test_vectors("poseidon2.json", hash);
+}
+
+#[test]
+fn poseidon_test_vectors_bn254_poseidon2() {
+    fn hash(input: &[Fr]) -> Fr {
+        let mut hash = Poseidon::<Fr, PlonkSpongeConstantsPoseidon2>::new(
+            SpongeParametersBn254Poseidon2::static_params(),
+        );
+        hash.absorb(input);
+        hash.squeeze()
+    }
+    test_vectors("bn254_poseidon2.json", hash);
+}
+
+#[test]
+fn poseidon2_bn254_reference_permutation() {
+    // test vector of the reference implementation, https://github.com/HorizenLabs/poseidon2
+    let mut state = [Fr::from(0u64), Fr::from(1u64), Fr::from(2u64)];
+    poseidon2_block_cipher::<Fr, PlonkSpongeConstantsPoseidon2, 3>(
+        SpongeParametersBn254Poseidon2::static_params(),
+        &mut state,
+    );
+    let expected = [
+        "5297208644449048816064511434384511824916970985131888684874823260532015509555",
+        "21816030159894113985964609355246484851575571273661473159848781012394295965040",
+        "13940986381491601233448981668101586453321811870310341844570924906201623195336",
+    ]
+    .map(|x| Fr::from_str(x).unwrap());
+    assert_eq!(state, expected);
+}
@@ -0,0 +1,90 @@
+use crate::{
+    constants::PlonkSpongeConstantsKimchi,
+    pasta::fq_kimchi,
+    sponge::DefaultFqSponge,
+    transcript::{Transcript, TranscriptMode},
+    FqSponge,
+};
+use ark_ec::AffineCurve;
+use mina_curves::pasta::{Fp, Fq, Vesta, VestaParameters};
+
+type Sponge = DefaultFqSponge<VestaParameters, PlonkSpongeConstantsKimchi>;
+type VestaTranscript = Transcript<Fq, Vesta, Fp, Sponge>;
+
+fn labelled() -> VestaTranscript {
+    VestaTranscript::new(fq_kimchi::static_params(), b"test")
+}
+
+#[test]
+fn transcript_compatible_mode_matches_sponge() {
+    let g = Vesta::prime_subgroup_generator();
+
+    let mut sponge = Sponge::new(fq_kimchi::static_params());
+    sponge.absorb_g(&[g]);
+    sponge.absorb_fr(&[Fp::from(42u64)]);
+    let chal = sponge.challenge();
+    sponge.absorb_fq(&[Fq::from(1u64)]);
+    let chal_fq = sponge.challenge_fq();
+
+    let mut transcript = VestaTranscript::compatible(fq_kimchi::static_params());
+    assert_eq!(transcript.mode(), TranscriptMode::Compatible);
+    transcript.domain_separator(b"ignored");
+    transcript.absorb_points(b"g", &[g]);
+    transcript.absorb_scalars(b"scalar", &[Fp::from(42u64)]);
+    assert_eq!(transcript.challenge(b"chal"), chal);
+    transcript.absorb_base(b"base", &[Fq::from(1u64)]);
+    assert_eq!(transcript.challenge_fq(b"chal_fq"), chal_fq);
+    assert_eq!(transcript.digest(), sponge.digest());
+}
+
+#[test]
+fn transcript_labels_separate_challenges() {
+    let scalars = [Fp::from(1u64), Fp::from(2u64)];
+
+    let mut t1 = labelled();
+    t1.absorb_scalars(b"a", &scalars);
+    let mut t2 = labelled();
+    t2.absorb_scalars(b"b", &scalars);
+    assert_ne!(t1.clone().challenge(b"chal"), t2.challenge(b"chal"));
+
+    // the label of the challenge is absorbed too
+    assert_ne!(t1.clone().challenge(b"chal"), t1.challenge(b"other"));
+
+    // as well as the domain separator of the protocol
+    let mut t3 = VestaTranscript::new(fq_kimchi::static_params(), b"other protocol");
+    t3.absorb_scalars(b"a", &scalars);
+    let mut t1 = labelled();
+    t1.absorb_scalars(b"a", &scalars);
+    assert_ne!(t1.challenge(b"chal"), t3.challenge(b"chal"));
+}
+
+#[test]
+fn transcript_bytes_are_length_prefixed() {
+    let mut t1 = labelled();
+    t1.absorb_bytes(b"bytes", &[0]);
+    let mut t2 = labelled();
+    t2.absorb_bytes(b"bytes", &[0, 0]);
+    assert_ne!(t1.challenge(b"chal"), t2.challenge(b"chal"));
+
+    // also in the compatible mode
+    let mut t1 = VestaTranscript::compatible(fq_kimchi::static_params());
+    t1.absorb_bytes(b"bytes", &[0]);
+    let mut t2 = VestaTranscript::compatible(fq_kimchi::static_params());
+    t2.absorb_bytes(b"bytes", &[0, 0]);
+    assert_ne!(t1.challenge(b"chal"), t2.challenge(b"chal"));
+}
+
+#[test]
+fn transcript_forks() {
+    let mut transcript = labelled();
+    transcript.absorb_base(b"x", &[Fq::from(3u64)]);
+
+    let mut left = transcript.fork(b"left");
+    let mut right = transcript.fork(b"right");
+    let left_chal = left.challenge(b"chal");
+    assert_ne!(left_chal, right.challenge(b"chal"));
+    assert_ne!(left_chal, transcript.clone().challenge(b"chal"));
+
+    // forking with the same label is deterministic
+    assert_eq!(transcript.fork(b"left").challenge(b"chal"), left_chal);
+}
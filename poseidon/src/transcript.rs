@@ -0,0 +1,165 @@
+//! A Fiat-Shamir transcript built on top of a sponge implementing [FqSponge].
+//!
+//! In the default, labelled, mode, a transcript starts by absorbing a domain
+//! separator identifying the protocol, and every value absorbed or squeezed is
+//! bound to a label. Labels, domain separators and byte strings are encoded as
+//! base field elements, prefixed by a tag and their length, so that two
+//! different sequences of operations never absorb the same field elements.
+//!
+//! In the [TranscriptMode::Compatible] mode, labels and domain separators are
+//! ignored, and the transcript absorbs and squeezes exactly as the underlying
+//! sponge. It is used to keep existing transcripts, like kimchi's, unchanged.
+
+use crate::{poseidon::ArithmeticSpongeParams, sponge::FqSponge};
+use ark_ff::PrimeField;
+use std::marker::PhantomData;
+
+/// The tags prefixing the encoding of byte strings.
+#[derive(Clone, Copy)]
+enum Tag {
+    DomainSeparator = 1,
+    Absorb = 2,
+    Challenge = 3,
+    Fork = 4,
+    Bytes = 5,
+}
+
+/// How labels and domain separators are handled by a [Transcript].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranscriptMode {
+    /// Labels and domain separators are absorbed by the sponge.
+    Labelled,
+    /// Labels and domain separators are ignored.
+    Compatible,
+}
+
+/// A Fiat-Shamir transcript over the sponge `S`, which absorbs points of `G`
+/// and elements of its base field `Fq` and of its scalar field `Fr`.
+#[derive(Clone)]
+pub struct Transcript<Fq, G, Fr, S> {
+    sponge: S,
+    mode: TranscriptMode,
+    _types: PhantomData<(Fq, G, Fr)>,
+}
+
+/// Encodes the tag and the length of `bytes`, followed by the bytes,
+/// packed in chunks fitting in a field element.
+fn encode_bytes<F: PrimeField>(tag: Tag, bytes: &[u8]) -> Vec<F> {
+    let chunk_size = (F::size_in_bits() - 1) / 8;
+    let header = F::from(tag as u64) + F::from(bytes.len() as u64) * F::from(256u64);
+    std::iter::once(header)
+        .chain(bytes.chunks(chunk_size).map(F::from_le_bytes_mod_order))
+        .collect()
+}
+
+impl<Fq, G, Fr, S> Transcript<Fq, G, Fr, S>
+where
+    Fq: PrimeField,
+    S: FqSponge<Fq, G, Fr>,
+{
+    /// Creates a labelled transcript for the protocol identified by `domain_separator`.
+    pub fn new(params: &'static ArithmeticSpongeParams<Fq>, domain_separator: &[u8]) -> Self {
+        let mut transcript = Self::from_sponge(S::new(params), TranscriptMode::Labelled);
+        transcript.domain_separator(domain_separator);
+        transcript
+    }
+
+    /// Creates a transcript in the [TranscriptMode::Compatible] mode.
+    pub fn compatible(params: &'static ArithmeticSpongeParams<Fq>) -> Self {
+        Self::from_sponge(S::new(params), TranscriptMode::Compatible)
+    }
+
+    /// Creates a transcript from an existing sponge.
+    pub fn from_sponge(sponge: S, mode: TranscriptMode) -> Self {
+        Transcript {
+            sponge,
+            mode,
+            _types: PhantomData,
+        }
+    }
+
+    pub fn mode(&self) -> TranscriptMode {
+        self.mode
+    }
+
+    /// The underlying sponge, e.g. to pass it to an opening proof.
+    pub fn sponge(&self) -> &S {
+        &self.sponge
+    }
+
+    pub fn into_sponge(self) -> S {
+        self.sponge
+    }
+
+    fn absorb_label(&mut self, tag: Tag, label: &[u8]) {
+        if self.mode == TranscriptMode::Labelled {
+            self.sponge.absorb_fq(&encode_bytes(tag, label));
+        }
+    }
+
+    /// Absorbs a domain separator, e.g. at the start of a sub-protocol.
+    pub fn domain_separator(&mut self, domain_separator: &[u8]) {
+        self.absorb_label(Tag::DomainSeparator, domain_separator);
+    }
+
+    /// Absorbs points of the curve.
+    pub fn absorb_points(&mut self, label: &'static [u8], points: &[G]) {
+        self.absorb_label(Tag::Absorb, label);
+        self.sponge.absorb_g(points);
+    }
+
+    /// Absorbs elements of the scalar field.
+    pub fn absorb_scalars(&mut self, label: &'static [u8], scalars: &[Fr]) {
+        self.absorb_label(Tag::Absorb, label);
+        self.sponge.absorb_fr(scalars);
+    }
+
+    /// Absorbs elements of the base field.
+    pub fn absorb_base(&mut self, label: &'static [u8], elements: &[Fq]) {
+        self.absorb_label(Tag::Absorb, label);
+        self.sponge.absorb_fq(elements);
+    }
+
+    /// Absorbs a byte string. The bytes are absorbed, with their length,
+    /// in all modes.
+    pub fn absorb_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.absorb_label(Tag::Absorb, label);
+        self.sponge.absorb_fq(&encode_bytes(Tag::Bytes, bytes));
+    }
+
+    /// Squeezes a challenge in the scalar field (see [FqSponge::challenge]).
+    pub fn challenge(&mut self, label: &'static [u8]) -> Fr {
+        self.absorb_label(Tag::Challenge, label);
+        self.sponge.challenge()
+    }
+
+    /// Squeezes a challenge in the base field.
+    pub fn challenge_fq(&mut self, label: &'static [u8]) -> Fq {
+        self.absorb_label(Tag::Challenge, label);
+        self.sponge.challenge_fq()
+    }
+
+    /// Returns a copy of the transcript, which is separated from this one by `label`.
+    pub fn fork(&self, label: &'static [u8]) -> Self
+    where
+        S: Clone,
+    {
+        let mut fork = Transcript {
+            sponge: self.sponge.clone(),
+            mode: self.mode,
+            _types: PhantomData,
+        };
+        fork.absorb_label(Tag::Fork, label);
+        fork
+    }
+
+    /// Returns a scalar field digest of the transcript (see [FqSponge::digest]).
+    pub fn digest(self) -> Fr {
+        self.sponge.digest()
+    }
+
+    /// Returns a base field digest of the transcript.
+    pub fn digest_fq(self) -> Fq {
+        self.sponge.digest_fq()
+    }
+}
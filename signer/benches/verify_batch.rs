@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+use mina_signer::{transaction::Transaction, Keypair, NetworkId, PubKey, Signer};
+use rand::{rngs::StdRng, SeedableRng};
+
+pub fn bench_verify_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Schnorr verification");
+    group.sample_size(10).sampling_mode(SamplingMode::Flat);
+
+    let rng = &mut StdRng::from_seed([0u8; 32]);
+    let receiver = PubKey::from_address("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt")
+        .expect("invalid address");
+    let mut ctx = mina_signer::create_legacy::<Transaction>(NetworkId::TESTNET);
+
+    for size in [10, 100, 1000] {
+        let batch: Vec<_> = (0..size)
+            .map(|i| {
+                let kp = Keypair::rand(rng).expect("failed to create keypair");
+                let tx = Transaction::new_payment(
+                    kp.public.clone(),
+                    receiver.clone(),
+                    1000,
+                    2000000000,
+                    i,
+                );
+                (ctx.sign(&kp, &tx), kp.public, tx)
+            })
+            .collect();
+
+        group.bench_function(format!("one by one, {size} signatures"), |b| {
+            b.iter(|| {
+                black_box(&batch)
+                    .iter()
+                    .all(|(sig, public, tx)| ctx.verify(sig, public, tx))
+            })
+        });
+        group.bench_function(format!("batch, {size} signatures"), |b| {
+            b.iter(|| ctx.verify_batch(black_box(&batch)))
+        });
+    }
+}
+
+criterion_group!(benches, bench_verify_batch);
+criterion_main!(benches);
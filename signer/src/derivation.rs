@@ -0,0 +1,235 @@
+//! Hierarchical deterministic key derivation
+//!
+//! Derivation of Mina secret keys from BIP39 mnemonics, as Mina wallets do:
+//!
+//! 1. The mnemonic is converted into a seed (BIP39)
+//! 2. A secp256k1 secret key is derived from the seed along the BIP44 path
+//!    `m/44'/12586'/account'/0/0`, where 12586 is the coin type of Mina (BIP32)
+//! 3. The two most significant bits of the (big-endian) secp256k1 secret key are
+//!    cleared, so that it is smaller than the order of the Pallas scalar field
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey};
+use sha2::Sha512;
+use thiserror::Error;
+
+use crate::SecKey;
+
+/// BIP44 coin type of Mina
+pub const MINA_COIN_TYPE: u32 = 12586;
+
+/// Offset of the hardened child indices
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// HMAC key of the BIP32 master key
+const MASTER_KEY_HMAC_KEY: &[u8] = b"Bitcoin seed";
+
+/// Derivation errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DerivationError {
+    /// Invalid BIP39 mnemonic
+    #[error("invalid mnemonic")]
+    Mnemonic,
+    /// Account index out of the range of hardened indices
+    #[error("invalid account index")]
+    AccountIndex,
+    /// Derived key outside of the secp256k1 scalar field (with negligible probability)
+    #[error("invalid derived key")]
+    DerivedKey,
+}
+/// Derivation result
+pub type Result<T> = std::result::Result<T, DerivationError>;
+
+/// BIP32 extended secret key on secp256k1
+#[derive(Clone)]
+pub struct ExtendedSecKey {
+    secret: SecretKey,
+    chain_code: [u8; 32],
+}
+
+impl ExtendedSecKey {
+    /// Create the master key of `seed`
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the derived key is not a valid secp256k1 secret key.
+    pub fn master(seed: &[u8]) -> Result<Self> {
+        Self::from_hmac(MASTER_KEY_HMAC_KEY, &[seed])
+    }
+
+    /// Derive the child key of index `index` (hardened if `index >= HARDENED_OFFSET`)
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the derived key is not a valid secp256k1 secret key.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        let index_bytes = index.to_be_bytes();
+        let child = if index >= HARDENED_OFFSET {
+            Self::from_hmac(
+                &self.chain_code,
+                &[&[0u8], &self.secret.secret_bytes(), &index_bytes],
+            )?
+        } else {
+            let public = PublicKey::from_secret_key(&Secp256k1::signing_only(), &self.secret);
+            Self::from_hmac(&self.chain_code, &[&public.serialize(), &index_bytes])?
+        };
+
+        // The child secret key is the sum of the parent secret key and the derived one
+        let tweak = Scalar::from(child.secret);
+        let secret = self
+            .secret
+            .add_tweak(&tweak)
+            .map_err(|_| DerivationError::DerivedKey)?;
+        Ok(Self {
+            secret,
+            chain_code: child.chain_code,
+        })
+    }
+
+    /// Derive the descendant key along `path`
+    ///
+    /// # Errors
+    ///
+    /// Will give error if a derived key is not a valid secp256k1 secret key.
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self> {
+        path.iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Big-endian bytes of the secret key
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.secret.secret_bytes()
+    }
+
+    /// Chain code
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    // Splits HMAC-SHA512(key, data) into a secret key and a chain code
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Result<Self> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+        for chunk in data {
+            mac.update(chunk);
+        }
+        let output = mac.finalize().into_bytes();
+        let (secret, chain_code) = output.split_at(32);
+        Ok(Self {
+            secret: SecretKey::from_slice(secret).map_err(|_| DerivationError::DerivedKey)?,
+            chain_code: chain_code.try_into().expect("HMAC-SHA512 outputs 64 bytes"),
+        })
+    }
+}
+
+/// Convert the BIP39 mnemonic `phrase` into a seed, with the passphrase `passphrase`
+///
+/// # Errors
+///
+/// Will give error if `phrase` is not a valid English BIP39 mnemonic.
+pub fn mnemonic_to_seed(phrase: &str, passphrase: &str) -> Result<[u8; 64]> {
+    let mnemonic = Mnemonic::parse(phrase).map_err(|_| DerivationError::Mnemonic)?;
+    Ok(mnemonic.to_seed(passphrase))
+}
+
+/// BIP44 derivation path of the Mina account `account_index`
+///
+/// # Errors
+///
+/// Will give error if `account_index` is not smaller than [`HARDENED_OFFSET`].
+pub fn derivation_path(account_index: u32) -> Result<[u32; 5]> {
+    if account_index >= HARDENED_OFFSET {
+        return Err(DerivationError::AccountIndex);
+    }
+    Ok([
+        44 + HARDENED_OFFSET,
+        MINA_COIN_TYPE + HARDENED_OFFSET,
+        account_index + HARDENED_OFFSET,
+        0,
+        0,
+    ])
+}
+
+/// Derive the Mina secret key of the account `account_index` from `seed`
+///
+/// # Errors
+///
+/// Will give error if `account_index` is not smaller than [`HARDENED_OFFSET`],
+/// or if a derived key is not a valid secp256k1 secret key.
+pub fn derive_seckey(seed: &[u8], account_index: u32) -> Result<SecKey> {
+    let key = ExtendedSecKey::master(seed)?.derive_path(&derivation_path(account_index)?)?;
+
+    // Clear the two most significant bits, to reduce the key below the Pallas scalar field order
+    let mut bytes = key.secret_bytes();
+    bytes[0] &= 0x3f;
+    Ok(SecKey::from_bytes(&bytes).expect("reduced key is a valid scalar"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bip32_vector_1() {
+        // Test vector 1 of BIP32
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedSecKey::master(&seed).unwrap();
+        assert_eq!(
+            hex::encode(master.secret_bytes()),
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+        );
+        assert_eq!(
+            hex::encode(master.chain_code()),
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+        );
+
+        let vectors = [
+            (
+                vec![HARDENED_OFFSET],
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+            ),
+            (
+                vec![HARDENED_OFFSET, 1],
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+                "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+            ),
+            (
+                vec![HARDENED_OFFSET, 1, HARDENED_OFFSET + 2, 2, 1000000000],
+                "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+                "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+            ),
+        ];
+        for (path, secret, chain_code) in vectors {
+            let key = master.derive_path(&path).unwrap();
+            assert_eq!(hex::encode(key.secret_bytes()), secret);
+            assert_eq!(hex::encode(key.chain_code()), chain_code);
+        }
+    }
+
+    #[test]
+    fn bip39_seed() {
+        // Test vector of the reference implementation of BIP39
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(
+            hex::encode(mnemonic_to_seed(phrase, "TREZOR").unwrap()),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+
+        // Invalid checksum
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert_eq!(mnemonic_to_seed(phrase, ""), Err(DerivationError::Mnemonic));
+    }
+
+    #[test]
+    fn account_index() {
+        assert_eq!(
+            derivation_path(HARDENED_OFFSET),
+            Err(DerivationError::AccountIndex)
+        );
+        assert_eq!(
+            derivation_path(1).unwrap(),
+            [0x8000002c, 0x8000312a, 0x80000001, 0x00000000, 0x00000000]
+        );
+    }
+}
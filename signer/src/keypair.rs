@@ -2,7 +2,12 @@
 //!
 //! Definition of secret key, keypairs and related helpers
 
-use crate::{pubkey::PubKeyError, seckey::SecKeyError, CurvePoint, PubKey, ScalarField, SecKey};
+use crate::{
+    derivation::{self, DerivationError},
+    pubkey::PubKeyError,
+    seckey::SecKeyError,
+    CurvePoint, PubKey, ScalarField, SecKey,
+};
 use core::fmt;
 use rand::{self, CryptoRng, RngCore};
 use thiserror::Error;
@@ -19,6 +24,9 @@ pub enum KeypairError {
     /// point not on curve
     #[error("point not on curve")]
     NonCurvePoint,
+    /// Key derivation error
+    #[error(transparent)]
+    Derivation(#[from] DerivationError),
 }
 /// Keypair result
 pub type Result<T> = std::result::Result<T, KeypairError>;
@@ -79,6 +87,17 @@ impl Keypair {
         Keypair::from_secret_key(secret)
     }
 
+    /// Derive the keypair of the account `account_index` from the BIP39 mnemonic `phrase`,
+    /// along the BIP44 path `m/44'/12586'/account_index'/0/0` (see [`derivation`])
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `phrase` is not a valid mnemonic or `account_index` is not smaller than 2^31.
+    pub fn from_mnemonic(phrase: &str, account_index: u32) -> Result<Self> {
+        let seed = derivation::mnemonic_to_seed(phrase, "")?;
+        Keypair::from_secret_key(derivation::derive_seckey(&seed, account_index)?)
+    }
+
     /// Obtain the Mina address corresponding to the keypair's public key
     pub fn get_address(self) -> String {
         self.public.into_address()
@@ -206,4 +225,46 @@ mod tests {
             Err(KeypairError::SecretKey(SecKeyError::SecretKeyBytes))
         );
     }
+
+    #[test]
+    fn from_mnemonic() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let vectors = [
+            (
+                0,
+                "16db55140eb2f45c11b63e41bf8369686abcf103f574abe4fe359a982e7a0372",
+            ),
+            (
+                1,
+                "3771e440aa74aff511f63b006981d5e85392ecad4f51df6837fb0880b5c5e3b7",
+            ),
+        ];
+        for (account_index, secret_hex) in vectors {
+            let keypair =
+                Keypair::from_mnemonic(phrase, account_index).expect("failed to derive keypair");
+            assert_eq!(keypair.to_hex(), secret_hex);
+            assert_eq!(
+                Keypair::from_hex(secret_hex).expect("failed to decode keypair"),
+                keypair
+            );
+        }
+
+        let phrase = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        assert_eq!(
+            Keypair::from_mnemonic(phrase, 0)
+                .expect("failed to derive keypair")
+                .to_hex(),
+            "03f5cf21946d972053ed7dd2e083edf11685c2fd32edd5a8f3d0ed4c55b50a5e"
+        );
+
+        // negative tests
+        assert_eq!(
+            Keypair::from_mnemonic("abandon abandon abandon", 0),
+            Err(KeypairError::Derivation(DerivationError::Mnemonic))
+        );
+        assert_eq!(
+            Keypair::from_mnemonic(phrase, 1 << 31),
+            Err(KeypairError::Derivation(DerivationError::AccountIndex))
+        );
+    }
 }
@@ -1,11 +1,15 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+pub mod derivation;
 pub mod keypair;
 pub mod pubkey;
 pub mod schnorr;
 pub mod seckey;
+pub mod secret_box;
 pub mod signature;
+pub mod threshold;
+pub mod transaction;
 
 use mina_hasher::{DomainParameter, Hashable};
 
@@ -27,24 +31,41 @@ pub type BaseField = <CurvePoint as AffineCurve>::BaseField;
 pub type ScalarField = <CurvePoint as AffineCurve>::ScalarField;
 
 /// Mina network (or blockchain) identifier
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NetworkId {
     /// Id for all testnets
-    TESTNET = 0x00,
+    TESTNET,
 
     /// Id for mainnet
-    MAINNET = 0x01,
+    MAINNET,
+
+    /// Id of a custom network, with its own domain strings (ASCII)
+    Custom(String),
 }
 
-impl From<NetworkId> for u8 {
-    fn from(id: NetworkId) -> u8 {
-        id as u8
+impl TryFrom<NetworkId> for u8 {
+    type Error = NetworkId;
+
+    /// The id byte of testnets (`0x00`) and mainnet (`0x01`), custom networks having none
+    fn try_from(id: NetworkId) -> Result<u8, NetworkId> {
+        match id {
+            NetworkId::TESTNET => Ok(0x00),
+            NetworkId::MAINNET => Ok(0x01),
+            custom => Err(custom),
+        }
     }
 }
 
 impl DomainParameter for NetworkId {
     fn into_bytes(self) -> Vec<u8> {
-        vec![self as u8]
+        match self {
+            NetworkId::Custom(id) => {
+                // The bytes of the id in order: o1js packs the characters of the id from
+                // the last one down, so the first character is the least significant byte
+                id.into_bytes()
+            }
+            id => vec![u8::try_from(id).expect("id byte of mainnet or testnet")],
+        }
     }
 }
 
@@ -58,6 +79,22 @@ pub trait Signer<H: Hashable> {
     /// Verify that the signature `sig` on `input` (see [`Hashable`]) is signed with the secret key corresponding to `pub_key`.
     /// Return `true` if the signature is valid and `false` otherwise.
     fn verify(&mut self, sig: &Signature, pub_key: &PubKey, input: &H) -> bool;
+
+    /// Verify a batch of signatures, each as in [`Signer::verify`].
+    /// Return `Ok(())` if all the signatures are valid and otherwise `Err` with the indices of the invalid ones.
+    fn verify_batch(&mut self, batch: &[(Signature, PubKey, H)]) -> Result<(), Vec<usize>> {
+        let invalid: Vec<usize> = batch
+            .iter()
+            .enumerate()
+            .filter(|(_, (sig, pub_key, input))| !self.verify(sig, pub_key, input))
+            .map(|(i, _)| i)
+            .collect();
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid)
+        }
+    }
 }
 
 /// Create a legacy signer context with domain parameters initialized with `domain_param`
@@ -65,10 +102,7 @@ pub trait Signer<H: Hashable> {
 /// **Example**
 ///
 /// ```
-/// #[path = "../tests/transaction.rs"]
-/// mod transaction;
-/// use mina_signer::{NetworkId, self, Signer};
-/// use transaction::Transaction;
+/// use mina_signer::{NetworkId, self, Signer, transaction::Transaction};
 ///
 /// let mut ctx = mina_signer::create_legacy::<Transaction>(NetworkId::TESTNET);
 /// ```
@@ -81,10 +115,7 @@ pub fn create_legacy<H: 'static + Hashable>(domain_param: H::D) -> impl Signer<H
 /// **Example**
 ///
 /// ```
-/// #[path = "../tests/transaction.rs"]
-/// mod transaction;
-/// use mina_signer::{NetworkId, self, Signer};
-/// use transaction::Transaction;
+/// use mina_signer::{NetworkId, self, Signer, transaction::Transaction};
 ///
 /// let mut ctx = mina_signer::create_kimchi::<Transaction>(NetworkId::TESTNET);
 /// ```
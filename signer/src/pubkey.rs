@@ -3,11 +3,17 @@
 //! Definition of public key structure and helpers
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_ff::Zero;
 use bs58;
 use core::fmt;
+use mina_curves::{
+    pasta::PallasParameters,
+    serialization::{
+        field_from_bytes, field_to_bytes, from_x_and_parity, to_uncompressed_bytes, y_parity,
+    },
+};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use sha2::{Digest, Sha256};
-use std::ops::Neg;
 use thiserror::Error;
 
 use crate::{BaseField, CurvePoint, ScalarField, SecKey};
@@ -82,17 +88,13 @@ impl PubKey {
         if bytes.len() != BaseField::size_in_bytes() * 2 {
             return Err(PubKeyError::YCoordinateBytes);
         }
-        let x = BaseField::from_bytes(&bytes[0..BaseField::size_in_bytes()])
+        let x: BaseField = field_from_bytes(&bytes[0..BaseField::size_in_bytes()])
             .map_err(|_| PubKeyError::XCoordinateBytes)?;
-        let y = BaseField::from_bytes(&bytes[BaseField::size_in_bytes()..])
+        let y: BaseField = field_from_bytes(&bytes[BaseField::size_in_bytes()..])
             .map_err(|_| PubKeyError::YCoordinateBytes)?;
-        let pt = CurvePoint::get_point_from_x(x, y.0.is_odd()).ok_or(PubKeyError::XCoordinate)?;
-        if pt.y != y {
-            return Err(PubKeyError::NonCurvePoint);
-        }
-
-        let public = CurvePoint::new(x, y, pt.infinity);
-        if !public.is_on_curve() {
+        let public = from_x_and_parity::<PallasParameters>(x, y_parity(&y))
+            .map_err(|_| PubKeyError::XCoordinate)?;
+        if public.y != y {
             return Err(PubKeyError::NonCurvePoint);
         }
 
@@ -157,16 +159,9 @@ impl PubKey {
             return Err(PubKeyError::AddressVersion);
         }
 
-        let x = BaseField::from_bytes(x_bytes).map_err(|_| PubKeyError::XCoordinateBytes)?;
-        let mut pt = CurvePoint::get_point_from_x(x, y_parity).ok_or(PubKeyError::XCoordinate)?;
-
-        if pt.y.into_repr().is_even() == y_parity {
-            pt.y = pt.y.neg();
-        }
-
-        if !pt.is_on_curve() {
-            return Err(PubKeyError::NonCurvePoint);
-        }
+        let x = field_from_bytes(x_bytes).map_err(|_| PubKeyError::XCoordinateBytes)?;
+        let pt = from_x_and_parity::<PallasParameters>(x, y_parity)
+            .map_err(|_| PubKeyError::XCoordinate)?;
 
         // Safe now because we checked point pt is on curve
         Ok(PubKey::from_point_unsafe(pt))
@@ -184,23 +179,18 @@ impl PubKey {
 
     /// Convert public key into compressed public key
     pub fn into_compressed(&self) -> CompressedPubKey {
-        let point = self.0;
-        CompressedPubKey {
-            x: point.x,
-            is_odd: point.y.into_repr().is_odd(),
-        }
+        CompressedPubKey::from_point(self.point())
     }
 
     /// Serialize public key into corresponding Mina address
     pub fn into_address(&self) -> String {
         let point = self.point();
-        into_address(&point.x, point.y.into_repr().is_odd())
+        into_address(&point.x, y_parity(&point.y))
     }
 
     /// Deserialize public key into bytes
     pub fn to_bytes(&self) -> Vec<u8> {
-        let point = self.point();
-        [point.x.to_bytes(), point.y.to_bytes()].concat()
+        to_uncompressed_bytes(self.point())
     }
 
     /// Deserialize public key into hex
@@ -216,6 +206,20 @@ impl fmt::Display for PubKey {
     }
 }
 
+/// With serde, a public key is represented by its Mina address, as in the GraphQL API and o1js
+impl Serialize for PubKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.into_address())
+    }
+}
+
+impl<'de> Deserialize<'de> for PubKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let address = String::deserialize(deserializer)?;
+        Self::from_address(&address).map_err(D::Error::custom)
+    }
+}
+
 /// Compressed public keys consist of x-coordinate and y-coordinate parity.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CompressedPubKey {
@@ -234,7 +238,7 @@ fn into_address(x: &BaseField, is_odd: bool) -> String {
     ];
 
     // pub key x-coordinate
-    raw.extend(x.to_bytes());
+    raw.extend(field_to_bytes(x));
 
     // pub key y-coordinate parity
     raw.push(u8::from(is_odd));
@@ -248,6 +252,24 @@ fn into_address(x: &BaseField, is_odd: bool) -> String {
 }
 
 impl CompressedPubKey {
+    /// Create compressed public key from curve point
+    pub fn from_point(point: &CurvePoint) -> Self {
+        Self {
+            x: point.x,
+            is_odd: y_parity(&point.y),
+        }
+    }
+
+    /// Convert compressed public key into curve point
+    ///
+    /// # Errors
+    ///
+    /// Will give error if no point of the curve has the x-coordinate of the key.
+    pub fn to_point(&self) -> Result<CurvePoint> {
+        from_x_and_parity::<PallasParameters>(self.x, self.is_odd)
+            .map_err(|_| PubKeyError::XCoordinate)
+    }
+
     /// Serialize compressed public key into corresponding Mina address
     pub fn into_address(&self) -> String {
         into_address(&self.x, self.is_odd)
@@ -258,7 +280,7 @@ impl CompressedPubKey {
     ///
     /// Will give error if `bytes` do not match certain requirements.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
-        let x = BaseField::from_bytes(&bytes[0..BaseField::size_in_bytes()])
+        let x = field_from_bytes(&bytes[0..BaseField::size_in_bytes()])
             .map_err(|_| PubKeyError::XCoordinateBytes)?;
         let parity_bytes = &bytes[BaseField::size_in_bytes()..];
         if parity_bytes.len() != 1 {
@@ -271,13 +293,10 @@ impl CompressedPubKey {
         } else {
             return Err(PubKeyError::YCoordinateParity);
         };
-        let public = CurvePoint::get_point_from_x(x, is_odd).ok_or(PubKeyError::XCoordinate)?;
-        if !public.is_on_curve() {
-            return Err(PubKeyError::NonCurvePoint);
-        }
+        let compressed = Self { x, is_odd };
 
-        // Safe now because we checked point is on the curve
-        Ok(Self { x, is_odd })
+        // Check the point is on the curve
+        compressed.to_point().map(|_| compressed)
     }
 
     /// Deserialize compressed public key from hex
@@ -321,7 +340,7 @@ impl CompressedPubKey {
 
     /// Deserialize compressed public key into bytes
     pub fn to_bytes(&self) -> Vec<u8> {
-        let x_bytes = self.x.to_bytes();
+        let x_bytes = field_to_bytes(&self.x);
         let is_odd_bytes = vec![if self.is_odd { 0x01u8 } else { 0x00u8 }];
         [x_bytes, is_odd_bytes].concat()
     }
@@ -332,6 +351,20 @@ impl CompressedPubKey {
     }
 }
 
+/// With serde, a compressed public key is represented by its Mina address, as in the GraphQL API and o1js
+impl Serialize for CompressedPubKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.into_address())
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedPubKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let address = String::deserialize(deserializer)?;
+        Self::from_address(&address).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -529,6 +562,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compressed_to_point() {
+        let pub_key =
+            PubKey::from_address("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV")
+                .expect("failed to create pubkey");
+        let compressed = pub_key.into_compressed();
+        assert_eq!(compressed, CompressedPubKey::from_point(pub_key.point()));
+        assert_eq!(compressed.to_point().as_ref(), Ok(pub_key.point()));
+        assert_eq!(
+            CompressedPubKey::from_bytes(&mina_curves::serialization::to_compressed_bytes(
+                pub_key.point()
+            )),
+            Ok(compressed)
+        );
+    }
+
     #[test]
     fn compressed_to_bytes() {
         let mut bytes = vec![
@@ -562,4 +611,34 @@ mod tests {
             Err(PubKeyError::XCoordinateBytes)
         );
     }
+
+    #[test]
+    fn json() {
+        let address = "B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV";
+        let json = format!("\"{address}\"");
+
+        let pub_key = PubKey::from_address(address).expect("failed to create pubkey");
+        assert_eq!(
+            serde_json::to_string(&pub_key).expect("failed to serialize"),
+            json
+        );
+        assert_eq!(
+            serde_json::from_str::<PubKey>(&json).expect("failed to deserialize"),
+            pub_key
+        );
+
+        let compressed = pub_key.into_compressed();
+        assert_eq!(
+            serde_json::to_string(&compressed).expect("failed to serialize"),
+            json
+        );
+        assert_eq!(
+            serde_json::from_str::<CompressedPubKey>(&json).expect("failed to deserialize"),
+            compressed
+        );
+
+        let invalid = "\"B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzW\"";
+        assert!(serde_json::from_str::<PubKey>(invalid).is_err());
+        assert!(serde_json::from_str::<CompressedPubKey>(invalid).is_err());
+    }
 }
@@ -5,6 +5,7 @@
 //! Details: <https://github.com/MinaProtocol/mina/blob/develop/docs/specs/signatures/description.md>
 
 use ark_ec::{
+    msm::VariableBaseMSM,
     AffineCurve,     // for prime_subgroup_generator()
     ProjectiveCurve, // for into_affine()
 };
@@ -18,7 +19,9 @@ use blake2::{
     digest::{Update, VariableOutput},
     Blake2bVar,
 };
+use mina_curves::{pasta::PallasParameters, serialization::from_x_and_parity};
 use mina_hasher::{self, DomainParameter, Hasher, ROInput};
+use rand::{CryptoRng, Rng};
 use std::ops::Neg;
 
 use crate::{BaseField, CurvePoint, Hashable, Keypair, PubKey, ScalarField, Signature, Signer};
@@ -82,9 +85,81 @@ impl<H: 'static + Hashable> Signer<H> for Schnorr<H> {
 
         rv.y.into_repr().is_even() && rv.x == sig.rx
     }
+
+    /// Batch verification: with `R_i` the point of x-coordinate `rx_i` and even y-coordinate,
+    /// the signatures are valid iff `s_i G = R_i + e_i P_i` for all `i`. For random 128-bit
+    /// `z_i`, this is checked w.h.p. by a single MSM `(sum z_i s_i) G - sum z_i R_i - sum z_i e_i P_i = 0`.
+    /// If the check fails, the batch is bisected to find the invalid signatures.
+    fn verify_batch(&mut self, batch: &[(Signature, PubKey, H)]) -> Result<(), Vec<usize>> {
+        let mut invalid = vec![];
+        let mut terms = vec![];
+        for (index, (sig, public, input)) in batch.iter().enumerate() {
+            match from_x_and_parity::<PallasParameters>(sig.rx, false) {
+                Ok(r) => terms.push(BatchTerm {
+                    index,
+                    r,
+                    public: *public.point(),
+                    s: sig.s,
+                    e: self.message_hash(public, sig.rx, input),
+                }),
+                // No point has the x-coordinate rx
+                Err(_) => invalid.push(index),
+            }
+        }
+
+        invalid.extend(bisect(&terms, &mut rand::thread_rng()));
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            invalid.sort_unstable();
+            Err(invalid)
+        }
+    }
+}
+
+/// A signature to verify in a batch, with its recovered nonce commitment and message hash
+struct BatchTerm {
+    index: usize,
+    r: CurvePoint,
+    public: CurvePoint,
+    s: ScalarField,
+    e: ScalarField,
+}
+
+/// Returns `true` if `s_i G = R_i + e_i P_i` for all the `terms` (w.h.p.)
+fn check_batch(terms: &[BatchTerm], rng: &mut (impl Rng + CryptoRng)) -> bool {
+    let mut bases = Vec::with_capacity(2 * terms.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * terms.len() + 1);
+    let mut s = ScalarField::zero();
+    for term in terms {
+        let z = ScalarField::from(rng.gen::<u128>());
+        s += z * term.s;
+        bases.push(term.r);
+        scalars.push((-z).into_repr());
+        bases.push(term.public);
+        scalars.push((-z * term.e).into_repr());
+    }
+    bases.push(CurvePoint::prime_subgroup_generator());
+    scalars.push(s.into_repr());
+
+    VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero()
+}
+
+/// Returns the indices of the invalid signatures among `terms`
+fn bisect(terms: &[BatchTerm], rng: &mut (impl Rng + CryptoRng)) -> Vec<usize> {
+    if terms.is_empty() || check_batch(terms, rng) {
+        vec![]
+    } else if terms.len() == 1 {
+        vec![terms[0].index]
+    } else {
+        let (left, right) = terms.split_at(terms.len() / 2);
+        let mut invalid = bisect(left, rng);
+        invalid.extend(bisect(right, rng));
+        invalid
+    }
 }
 
-pub(crate) fn create_legacy<H: 'static + Hashable>(domain_param: H::D) -> impl Signer<H> {
+pub(crate) fn create_legacy<H: 'static + Hashable>(domain_param: H::D) -> Schnorr<H> {
     Schnorr::<H> {
         hasher: Box::new(mina_hasher::create_legacy::<Message<H>>(
             domain_param.clone(),
@@ -93,7 +168,7 @@ pub(crate) fn create_legacy<H: 'static + Hashable>(domain_param: H::D) -> impl S
     }
 }
 
-pub(crate) fn create_kimchi<H: 'static + Hashable>(domain_param: H::D) -> impl Signer<H> {
+pub(crate) fn create_kimchi<H: 'static + Hashable>(domain_param: H::D) -> Schnorr<H> {
     Schnorr::<H> {
         hasher: Box::new(mina_hasher::create_kimchi::<Message<H>>(
             domain_param.clone(),
@@ -136,7 +211,12 @@ impl<H: 'static + Hashable> Schnorr<H> {
     /// randomly distributed scalar field element.  It uses Mina's variant of the Poseidon
     /// SNARK-friendly cryptographic hash function.
     /// Details: <https://github.com/o1-labs/cryptography-rfcs/blob/httpsnapps-notary-signatures/mina/001-poseidon-sponge.md>
-    fn message_hash(&mut self, pub_key: &PubKey, rx: BaseField, input: &H) -> ScalarField {
+    pub(crate) fn message_hash(
+        &mut self,
+        pub_key: &PubKey,
+        rx: BaseField,
+        input: &H,
+    ) -> ScalarField {
         let schnorr_input = Message::<H> {
             input: input.clone(),
             pub_key_x: pub_key.point().x,
@@ -1,6 +1,9 @@
 //! Secret key structures and helpers
 
-use crate::ScalarField;
+use crate::{
+    secret_box::{SecretBox, SecretBoxError},
+    ScalarField,
+};
 use ark_ff::UniformRand;
 use o1_utils::FieldHelpers;
 use rand::{self, CryptoRng, RngCore};
@@ -28,6 +31,9 @@ pub enum SecKeyError {
     /// Invalid secret key version
     #[error("Invalid secret key version")]
     SecretKeyVersion,
+    /// Invalid encrypted secret key
+    #[error(transparent)]
+    SecretBox(#[from] SecretBoxError),
 }
 /// Keypair result
 pub type Result<T> = std::result::Result<T, SecKeyError>;
@@ -114,6 +120,45 @@ impl SecKey {
         Self::from_bytes(&scalar_bytes)
     }
 
+    /// Decrypt secret key from the password-protected JSON format of the Mina daemon (see [`secret_box`](crate::secret_box))
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `json` is not a valid secret box, if `password` is wrong, or if it does not contain a secret key.
+    pub fn from_encrypted_json(json: &[u8], password: &[u8]) -> Result<Self> {
+        let plaintext = SecretBox::from_json(json)?.decrypt(password)?;
+        if plaintext.len() != ScalarField::size_in_bytes() + 1 {
+            return Err(SecKeyError::SecretKeyLength);
+        }
+
+        let (version, scalar_bytes) = (plaintext[0], &plaintext[1..]);
+        if version != 0x01 {
+            return Err(SecKeyError::SecretKeyVersion);
+        }
+
+        let mut scalar_bytes = scalar_bytes.to_vec();
+        scalar_bytes.reverse();
+
+        Self::from_bytes(&scalar_bytes)
+    }
+
+    /// Encrypt secret key into the password-protected JSON format of the Mina daemon (see [`secret_box`](crate::secret_box))
+    pub fn to_encrypted_json(
+        &self,
+        password: &[u8],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> String {
+        SecretBox::encrypt(&self.to_plaintext(), password, rng).to_json()
+    }
+
+    // Versioned little-endian bytes of the secret key, as in the base58 encoding
+    fn to_plaintext(&self) -> Vec<u8> {
+        let mut scalar_bytes = self.to_bytes();
+        scalar_bytes.reverse();
+
+        [vec![0x01], scalar_bytes].concat()
+    }
+
     /// Borrows secret key as scalar field element
     pub fn scalar(&self) -> &ScalarField {
         &self.0
@@ -248,4 +293,43 @@ mod tests {
             Err(SecKeyError::SecretKeyLength)
         );
     }
+
+    #[test]
+    fn from_encrypted_json() {
+        // Encrypted with libsodium, with the password "naughty blue worm"
+        let json = br#"{"box_primitive":"xsalsa20poly1305","pw_primitive":"argon2i","nonce":"6GS98t87ruNBxnYQfooXMcMhQQVza9LnWWDNBiY","pwsalt":"9XqfPskLxEZe4zLHpHfTCPDsC65U","pwdiff":[65536,3],"ciphertext":"APBKaGxHUfr8CBQ4T9wFhR1cRYhVCEiRn2o7Qt1w4zzQYpispBsKsFtjqUHw4jLtcY113Q5Eb"}"#;
+        assert_eq!(
+            SecKey::from_encrypted_json(json, b"naughty blue worm")
+                .expect("failed to decrypt secret key")
+                .to_hex(),
+            "164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718"
+        );
+
+        assert_eq!(
+            SecKey::from_encrypted_json(json, b"naughty red worm"),
+            Err(SecKeyError::SecretBox(SecretBoxError::Decryption))
+        );
+        assert_eq!(
+            SecKey::from_encrypted_json(b"EKFS3M4Fe1VkVjPMn2a1Vv6w6gRES5oLbH3vZmP26uQESodY", b""),
+            Err(SecKeyError::SecretBox(SecretBoxError::Json))
+        );
+    }
+
+    #[test]
+    fn to_encrypted_json() {
+        let sec_key =
+            SecKey::from_hex("3d12f41e24f105366b609aa23a4ef28cbae919239177275ea27bd0cabd1debd1")
+                .expect("failed to decode sec key");
+        let rng = &mut rand::rngs::OsRng;
+
+        // As to_encrypted_json, with cheaper key derivation parameters
+        let json =
+            SecretBox::encrypt_with_params(&sec_key.to_plaintext(), b"password", (8192, 1), rng)
+                .expect("failed to encrypt sec key")
+                .to_json();
+        assert_eq!(
+            SecKey::from_encrypted_json(json.as_bytes(), b"password"),
+            Ok(sec_key)
+        );
+    }
 }
@@ -0,0 +1,288 @@
+//! Password-protected secret boxes
+//!
+//! The format of the encrypted private key files of the Mina daemon: a JSON object
+//! whose plaintext is encrypted with XSalsa20-Poly1305, under a key derived from the
+//! password with Argon2i (as libsodium's `crypto_pwhash`, with one lane).
+//! The nonce, salt and ciphertext are base58check encoded, with version byte `0x02`.
+//!
+//! ```json
+//! {
+//!   "box_primitive": "xsalsa20poly1305",
+//!   "pw_primitive": "argon2i",
+//!   "nonce": "...",
+//!   "pwsalt": "...",
+//!   "pwdiff": [134217728, 6],
+//!   "ciphertext": "..."
+//! }
+//! ```
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use crypto_secretbox::{
+    aead::{Aead, KeyInit},
+    XSalsa20Poly1305,
+};
+use rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Name of the encryption primitive
+pub const BOX_PRIMITIVE: &str = "xsalsa20poly1305";
+
+/// Name of the key derivation primitive
+pub const PW_PRIMITIVE: &str = "argon2i";
+
+/// Default memory limit of the key derivation, in bytes (libsodium's moderate limit)
+pub const DEFAULT_MEM_LIMIT: u32 = 134217728;
+
+/// Default number of passes of the key derivation (libsodium's moderate limit)
+pub const DEFAULT_OPS_LIMIT: u32 = 6;
+
+/// Version byte of the base58check encoding of the fields
+const SECRET_BOX_VERSION: u8 = 0x02;
+
+/// Length of the nonce, in bytes
+const NONCE_BYTES: usize = 24;
+
+/// Length of the salt, in bytes
+const SALT_BYTES: usize = 16;
+
+/// Length of the encryption key, in bytes
+const KEY_BYTES: usize = 32;
+
+/// Secret box errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SecretBoxError {
+    /// Invalid JSON
+    #[error("invalid secret box json")]
+    Json,
+    /// Unsupported encryption or key derivation primitive
+    #[error("unsupported secret box primitive")]
+    Primitive,
+    /// Invalid base58check field
+    #[error("invalid secret box base58")]
+    Base58,
+    /// Invalid key derivation parameters
+    #[error("invalid password hash parameters")]
+    PasswordHash,
+    /// Wrong password or corrupted ciphertext
+    #[error("secret box decryption failed")]
+    Decryption,
+}
+/// Secret box result
+pub type Result<T> = std::result::Result<T, SecretBoxError>;
+
+/// Password-protected secret box
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretBox {
+    /// Encryption primitive, [`BOX_PRIMITIVE`]
+    pub box_primitive: String,
+    /// Key derivation primitive, [`PW_PRIMITIVE`]
+    pub pw_primitive: String,
+    /// Nonce of the encryption (base58check)
+    pub nonce: String,
+    /// Salt of the key derivation (base58check)
+    pub pwsalt: String,
+    /// Memory limit (in bytes) and number of passes of the key derivation
+    pub pwdiff: (u32, u32),
+    /// Ciphertext, including the authentication tag (base58check)
+    pub ciphertext: String,
+}
+
+impl SecretBox {
+    /// Encrypt `plaintext` with `password`, with the default key derivation parameters
+    pub fn encrypt(
+        plaintext: &[u8],
+        password: &[u8],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Self {
+        Self::encrypt_with_params(
+            plaintext,
+            password,
+            (DEFAULT_MEM_LIMIT, DEFAULT_OPS_LIMIT),
+            rng,
+        )
+        .expect("default parameters are valid")
+    }
+
+    /// Encrypt `plaintext` with `password`, with the key derivation parameters `pwdiff`
+    /// (memory limit in bytes, number of passes)
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `pwdiff` are not valid Argon2 parameters.
+    pub fn encrypt_with_params(
+        plaintext: &[u8],
+        password: &[u8],
+        pwdiff: (u32, u32),
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<Self> {
+        let mut nonce = [0u8; NONCE_BYTES];
+        rng.fill_bytes(&mut nonce);
+        let mut salt = [0u8; SALT_BYTES];
+        rng.fill_bytes(&mut salt);
+
+        let key = derive_key(password, &salt, pwdiff)?;
+        let ciphertext = XSalsa20Poly1305::new(&key.into())
+            .encrypt(&nonce.into(), plaintext)
+            .map_err(|_| SecretBoxError::Decryption)?;
+
+        Ok(Self {
+            box_primitive: BOX_PRIMITIVE.to_string(),
+            pw_primitive: PW_PRIMITIVE.to_string(),
+            nonce: encode(&nonce),
+            pwsalt: encode(&salt),
+            pwdiff,
+            ciphertext: encode(&ciphertext),
+        })
+    }
+
+    /// Decrypt the secret box with `password`
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the secret box is malformed, or if the password is wrong.
+    pub fn decrypt(&self, password: &[u8]) -> Result<Vec<u8>> {
+        if self.box_primitive != BOX_PRIMITIVE || self.pw_primitive != PW_PRIMITIVE {
+            return Err(SecretBoxError::Primitive);
+        }
+        let nonce: [u8; NONCE_BYTES] = decode(&self.nonce)?
+            .try_into()
+            .map_err(|_| SecretBoxError::Base58)?;
+        let salt = decode(&self.pwsalt)?;
+        let ciphertext = decode(&self.ciphertext)?;
+
+        let key = derive_key(password, &salt, self.pwdiff)?;
+        XSalsa20Poly1305::new(&key.into())
+            .decrypt(&nonce.into(), &ciphertext[..])
+            .map_err(|_| SecretBoxError::Decryption)
+    }
+
+    /// Deserialize secret box from JSON
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `json` is not a secret box JSON object.
+    pub fn from_json(json: &[u8]) -> Result<Self> {
+        serde_json::from_slice(json).map_err(|_| SecretBoxError::Json)
+    }
+
+    /// Serialize secret box into JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("failed to serialize secret box")
+    }
+}
+
+// Derive the encryption key from the password, as libsodium's crypto_pwhash
+fn derive_key(password: &[u8], salt: &[u8], pwdiff: (u32, u32)) -> Result<[u8; KEY_BYTES]> {
+    let (mem_limit, ops_limit) = pwdiff;
+    let params = Params::new(mem_limit / 1024, ops_limit, 1, Some(KEY_BYTES))
+        .map_err(|_| SecretBoxError::PasswordHash)?;
+    let mut key = [0u8; KEY_BYTES];
+    Argon2::new(Algorithm::Argon2i, Version::V0x13, params)
+        .hash_password_into(password, salt, &mut key)
+        .map_err(|_| SecretBoxError::PasswordHash)?;
+    Ok(key)
+}
+
+fn encode(bytes: &[u8]) -> String {
+    let mut raw = vec![SECRET_BOX_VERSION];
+    raw.extend(bytes);
+
+    // 4-byte checksum
+    let hash = Sha256::digest(&Sha256::digest(&raw[..])[..]);
+    raw.extend(&hash[..4]);
+
+    bs58::encode(raw).into_string()
+}
+
+fn decode(b58: &str) -> Result<Vec<u8>> {
+    let bytes = bs58::decode(b58)
+        .into_vec()
+        .map_err(|_| SecretBoxError::Base58)?;
+    if bytes.len() < 5 {
+        return Err(SecretBoxError::Base58);
+    }
+
+    let (raw, checksum) = bytes.split_at(bytes.len() - 4);
+    let hash = Sha256::digest(&Sha256::digest(raw)[..]);
+    if checksum != &hash[..4] || raw[0] != SECRET_BOX_VERSION {
+        return Err(SecretBoxError::Base58);
+    }
+
+    Ok(raw[1..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    // Cheap key derivation parameters, for tests
+    const PWDIFF: (u32, u32) = (8 * 1024, 1);
+
+    #[test]
+    fn roundtrip() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let secret_box =
+            SecretBox::encrypt_with_params(b"plaintext", b"password", PWDIFF, rng).unwrap();
+        assert_eq!(secret_box.decrypt(b"password"), Ok(b"plaintext".to_vec()));
+
+        let json = secret_box.to_json();
+        assert!(json.starts_with(
+            r#"{"box_primitive":"xsalsa20poly1305","pw_primitive":"argon2i","nonce":""#
+        ));
+        assert_eq!(SecretBox::from_json(json.as_bytes()), Ok(secret_box));
+    }
+
+    #[test]
+    fn invalid_boxes() {
+        let rng = &mut StdRng::from_seed([0u8; 32]);
+        let secret_box =
+            SecretBox::encrypt_with_params(b"plaintext", b"password", PWDIFF, rng).unwrap();
+        assert_eq!(
+            secret_box.decrypt(b"wrong password"),
+            Err(SecretBoxError::Decryption)
+        );
+
+        let mut other = secret_box.clone();
+        other.ciphertext = encode(&[0u8; 25]);
+        assert_eq!(other.decrypt(b"password"), Err(SecretBoxError::Decryption));
+
+        let mut other = secret_box.clone();
+        other.nonce.push('1');
+        assert_eq!(other.decrypt(b"password"), Err(SecretBoxError::Base58));
+
+        let mut other = secret_box.clone();
+        other.pw_primitive = "argon2id".to_string();
+        assert_eq!(other.decrypt(b"password"), Err(SecretBoxError::Primitive));
+
+        let mut other = secret_box;
+        other.pwdiff = (0, 0);
+        assert_eq!(
+            other.decrypt(b"password"),
+            Err(SecretBoxError::PasswordHash)
+        );
+
+        assert_eq!(SecretBox::from_json(b"{}"), Err(SecretBoxError::Json));
+    }
+
+    #[test]
+    fn libsodium_vector() {
+        // Computed with libsodium's crypto_pwhash (argon2i13) and crypto_secretbox_easy
+        let salt: Vec<u8> = (100..116).collect();
+        let key = derive_key(b"naughty blue worm", &salt, (65536, 3)).unwrap();
+        assert_eq!(
+            hex::encode(key),
+            "656dab85730189d4bf8ac7d53df017f26f8ed6d36663b6ab0804f763647236c8"
+        );
+
+        let secret_box = SecretBox::from_json(br#"{"box_primitive":"xsalsa20poly1305","pw_primitive":"argon2i","nonce":"6GS98t87ruNBxnYQfooXMcMhQQVza9LnWWDNBiY","pwsalt":"9XqfPskLxEZe4zLHpHfTCPDsC65U","pwdiff":[65536,3],"ciphertext":"APBKaGxHUfr8CBQ4T9wFhR1cRYhVCEiRn2o7Qt1w4zzQYpispBsKsFtjqUHw4jLtcY113Q5Eb"}"#).unwrap();
+        assert_eq!(decode(&secret_box.pwsalt), Ok(salt));
+        assert_eq!(decode(&secret_box.nonce), Ok((0..24).collect::<Vec<u8>>()));
+        assert_eq!(
+            hex::encode(secret_box.decrypt(b"naughty blue worm").unwrap()),
+            "0118b7ef420128e69623c0c0dcfa28d47a029d462720deb769d7b5dd6f17444216"
+        );
+    }
+}
@@ -1,11 +1,51 @@
 //! Mina signature structure and associated helpers
 
 use crate::{BaseField, ScalarField};
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
 use o1_utils::FieldHelpers;
-use std::fmt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Signature errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// Invalid signature base58
+    #[error("invalid signature base58")]
+    Base58,
+    /// Invalid raw signature bytes length
+    #[error("invalid raw signature bytes length")]
+    RawByteLength,
+    /// Invalid signature checksum
+    #[error("invalid signature checksum")]
+    Checksum,
+    /// Invalid signature version
+    #[error("invalid signature version")]
+    Version,
+    /// Invalid base field component
+    #[error("invalid signature field")]
+    Field,
+    /// Invalid scalar field component
+    #[error("invalid signature scalar")]
+    Scalar,
+}
+/// Signature Result
+pub type Result<T> = std::result::Result<T, SignatureError>;
+
+/// Version byte of the base58check encoding of signatures
+const SIGNATURE_VERSION_BYTE: u8 = 0x9a;
+/// Version of the serialization of signatures
+const SIGNATURE_VERSION_NUMBER: u8 = 0x01;
+const SIGNATURE_RAW_LEN: usize = 70;
 
 /// Signature structure
-#[derive(Clone, Eq, fmt::Debug, PartialEq)]
+///
+/// With serde, a signature is represented as in o1js: `{ "field": ..., "scalar": ... }`,
+/// with the components as decimal strings.
+#[derive(Clone, Eq, fmt::Debug, PartialEq, Serialize, Deserialize)]
+#[serde(into = "SignatureJson", try_from = "SignatureJson")]
 pub struct Signature {
     /// Base field component
     pub rx: BaseField,
@@ -14,11 +54,95 @@ pub struct Signature {
     pub s: ScalarField,
 }
 
+/// The JSON representation of [`Signature`]
+#[derive(Serialize, Deserialize)]
+struct SignatureJson {
+    field: String,
+    scalar: String,
+}
+
+impl From<Signature> for SignatureJson {
+    fn from(signature: Signature) -> Self {
+        SignatureJson {
+            field: signature.rx.to_biguint().to_string(),
+            scalar: signature.s.to_biguint().to_string(),
+        }
+    }
+}
+
+impl TryFrom<SignatureJson> for Signature {
+    type Error = SignatureError;
+
+    fn try_from(json: SignatureJson) -> Result<Self> {
+        let rx = field_from_decimal(&json.field).ok_or(SignatureError::Field)?;
+        let s = field_from_decimal(&json.scalar).ok_or(SignatureError::Scalar)?;
+
+        Ok(Self::new(rx, s))
+    }
+}
+
+/// Field element of a decimal string, if it is smaller than the modulus
+fn field_from_decimal<F: PrimeField>(decimal: &str) -> Option<F> {
+    let big = BigUint::from_str(decimal).ok()?;
+    if big >= F::modulus_biguint() {
+        return None;
+    }
+    F::from_biguint(&big).ok()
+}
+
 impl Signature {
     /// Create a new signature
     pub fn new(rx: BaseField, s: ScalarField) -> Self {
         Self { rx, s }
     }
+
+    /// Deserialize base58 encoded signature, as used by the Mina GraphQL API
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `base58` string does not match certain requirements.
+    pub fn from_base58(base58: &str) -> Result<Self> {
+        let bytes = bs58::decode(base58)
+            .into_vec()
+            .map_err(|_| SignatureError::Base58)?;
+
+        if bytes.len() != SIGNATURE_RAW_LEN {
+            return Err(SignatureError::RawByteLength);
+        }
+
+        let (raw, checksum) = (&bytes[..bytes.len() - 4], &bytes[bytes.len() - 4..]);
+
+        let hash = Sha256::digest(&Sha256::digest(raw)[..]);
+
+        if checksum != &hash[..4] {
+            return Err(SignatureError::Checksum);
+        }
+
+        let (version, rx_bytes, s_bytes) = (&raw[..2], &raw[2..34], &raw[34..]);
+
+        if version != [SIGNATURE_VERSION_BYTE, SIGNATURE_VERSION_NUMBER] {
+            return Err(SignatureError::Version);
+        }
+
+        let rx = BaseField::from_bytes(rx_bytes).map_err(|_| SignatureError::Field)?;
+        let s = ScalarField::from_bytes(s_bytes).map_err(|_| SignatureError::Scalar)?;
+
+        Ok(Self::new(rx, s))
+    }
+
+    /// Serialize signature into base58, as used by the Mina GraphQL API
+    pub fn to_base58(&self) -> String {
+        let mut raw: Vec<u8> = vec![SIGNATURE_VERSION_BYTE, SIGNATURE_VERSION_NUMBER];
+
+        // components in little-endian order
+        raw.extend(self.rx.to_bytes());
+        raw.extend(self.s.to_bytes());
+
+        let checksum = Sha256::digest(&Sha256::digest(&raw[..])[..]);
+        raw.extend(&checksum[..4]);
+
+        bs58::encode(raw).into_string()
+    }
 }
 
 impl fmt::Display for Signature {
@@ -31,3 +155,87 @@ impl fmt::Display for Signature {
         write!(f, "{}{}", hex::encode(rx_bytes), hex::encode(s_bytes))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // testnet signature of the first payment of the signer tests
+    const SIGNATURE_HEX: &str = "11a36a8dfe5b857b95a2a7b7b17c62c3ea33411ae6f4eb3a907064aecae353c60794f1d0288322fe3f8bb69d6fabd4fd7c15f8d09f8783b2f087a80407e299af";
+    const SIGNATURE_BASE58: &str = "7mXQYfqpcs1uDRVcpddkKazQHfPXPJfJxC9rn2WwQ6tAARBnGK1pjiKYegk7Jwu8nq8a2wrRuMvy4agJWobBi9Ei5dr7d7tp";
+    const SIGNATURE_JSON: &str = r#"{"field":"7978049910726616927075298742385001574587620942310654323357397558995139646406","scalar":"3429352238474987065427486162608449491113877901219474382951875744532516739503"}"#;
+
+    fn signature() -> Signature {
+        let mut bytes = hex::decode(SIGNATURE_HEX).expect("invalid hex");
+        let (rx_bytes, s_bytes) = bytes.split_at_mut(32);
+        rx_bytes.reverse();
+        s_bytes.reverse();
+        Signature::new(
+            BaseField::from_bytes(rx_bytes).expect("invalid field"),
+            ScalarField::from_bytes(s_bytes).expect("invalid scalar"),
+        )
+    }
+
+    #[test]
+    fn to_base58() {
+        assert_eq!(signature().to_string(), SIGNATURE_HEX);
+        assert_eq!(signature().to_base58(), SIGNATURE_BASE58);
+    }
+
+    #[test]
+    fn from_base58() {
+        assert_eq!(Signature::from_base58(SIGNATURE_BASE58), Ok(signature()));
+
+        assert_eq!(
+            Signature::from_base58("7mXQYfqpcs1uDRVcpddkKazQHfPXPJfJxC9rn2WwQ6tAARBnGK1pjiKYegk7Jwu8nq8a2wrRuMvy4agJWobBi9Ei5dr7d7t0"),
+            Err(SignatureError::Base58)
+        );
+        assert_eq!(
+            Signature::from_base58("7mXQYfqpcs1uDRVcpddkKazQHfPXPJfJxC9rn2WwQ6tAARBnGK1pjiKYegk7Jwu8nq8a2wrRuMvy4agJWobBi9Ei5dr7d7tq"),
+            Err(SignatureError::Checksum)
+        );
+        assert_eq!(
+            Signature::from_base58("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV"),
+            Err(SignatureError::RawByteLength)
+        );
+
+        // the scalar is not smaller than the modulus of the scalar field
+        let mut raw = vec![SIGNATURE_VERSION_BYTE, SIGNATURE_VERSION_NUMBER];
+        raw.extend([0u8; 32]);
+        raw.extend([0xff; 32]);
+        let checksum = Sha256::digest(&Sha256::digest(&raw[..])[..]);
+        raw.extend(&checksum[..4]);
+        assert_eq!(
+            Signature::from_base58(&bs58::encode(raw).into_string()),
+            Err(SignatureError::Scalar)
+        );
+
+        // the version of secret keys, with the length of a signature
+        let mut raw = vec![0x5a, 0x01];
+        raw.extend([0u8; 64]);
+        let checksum = Sha256::digest(&Sha256::digest(&raw[..])[..]);
+        raw.extend(&checksum[..4]);
+        assert_eq!(
+            Signature::from_base58(&bs58::encode(raw).into_string()),
+            Err(SignatureError::Version)
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            serde_json::to_string(&signature()).expect("failed to serialize"),
+            SIGNATURE_JSON
+        );
+        assert_eq!(
+            serde_json::from_str::<Signature>(SIGNATURE_JSON).expect("failed to deserialize"),
+            signature()
+        );
+
+        // the scalar is not smaller than the modulus of the scalar field
+        let modulus = ScalarField::modulus_biguint();
+        let json = format!(r#"{{"field":"1","scalar":"{modulus}"}}"#);
+        assert!(serde_json::from_str::<Signature>(&json).is_err());
+        assert!(serde_json::from_str::<Signature>(r#"{"field":"0x01","scalar":"1"}"#).is_err());
+    }
+}
@@ -0,0 +1,574 @@
+//! Threshold Schnorr signatures
+//!
+//! A two-round `t`-of-`n` signing scheme after FROST, producing ordinary Mina Schnorr
+//! [`Signature`]s, verifiable with [`Signer::verify`](crate::Signer::verify) against the group public key.
+//!
+//! 1. Key generation: a trusted dealer splits a secret key into Shamir shares over the Pallas
+//!    scalar field ([`generate_shares`]), and publishes Feldman commitments to the coefficients
+//!    of the sharing polynomial, against which each signer verifies its share
+//! 2. Round 1: each signer samples fresh [`SigningNonces`] and publishes their [`NonceCommitment`]
+//! 3. Round 2: given the nonce commitments of at least `t` signers, each of these signers
+//!    computes its [`SignatureShare`] with [`ThresholdSigner::sign_share`]
+//! 4. The coordinator verifies the signature shares and sums them into the signature,
+//!    with [`ThresholdSigner::aggregate`]
+//!
+//! Mina requires the nonce commitment of a signature to have an even y-coordinate:
+//! if the group commitment `R = sum (D_i + rho_i E_i)` does not, all the signers negate their nonces.
+//!
+//! **Important:** signing nonces must never be reused, so [`SigningNonces`] are consumed when signing.
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, Field, One, PrimeField, UniformRand, Zero};
+use blake2::{
+    digest::{Update, VariableOutput},
+    Blake2bVar,
+};
+use mina_hasher::{DomainParameter, Hashable};
+use rand::{CryptoRng, RngCore};
+use thiserror::Error;
+
+use crate::{schnorr, CurvePoint, PubKey, ScalarField, SecKey, Signature};
+
+/// Threshold signing errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ThresholdError {
+    /// Threshold not between 1 and the number of signers
+    #[error("invalid threshold")]
+    Threshold,
+    /// Nonce commitments not sorted by signer index, or with an invalid point
+    #[error("invalid nonce commitments")]
+    Commitments,
+    /// Fewer nonce commitments than the threshold
+    #[error("not enough signers")]
+    NotEnoughSigners,
+    /// The nonce commitment of a signer is missing, or does not match its nonces
+    #[error("missing nonce commitment of signer {0}")]
+    MissingCommitment(u32),
+    /// The signature share of a signer is missing
+    #[error("missing signature share of signer {0}")]
+    MissingSignatureShare(u32),
+    /// The signature share of a signer is not valid
+    #[error("invalid signature share of signer {0}")]
+    InvalidSignatureShare(u32),
+}
+/// Threshold signing result
+pub type Result<T> = std::result::Result<T, ThresholdError>;
+
+/// Shamir share of a secret key, held by one signer
+#[derive(Clone)]
+pub struct KeyShare {
+    /// Index of the signer (non-zero)
+    pub index: u32,
+    /// Number of signers required to sign
+    pub threshold: usize,
+    /// Share of the secret key
+    pub secret: ScalarField,
+    /// Group public key
+    pub public: PubKey,
+}
+
+impl KeyShare {
+    /// Verify the share against the commitments of the dealer
+    pub fn verify(&self, package: &PublicKeyPackage) -> bool {
+        self.public == package.public
+            && self.threshold == package.threshold()
+            && CurvePoint::prime_subgroup_generator()
+                .mul(self.secret)
+                .into_affine()
+                == package.verifying_share(self.index)
+    }
+}
+
+/// Public output of the key generation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKeyPackage {
+    /// Group public key
+    pub public: PubKey,
+    /// Commitments to the coefficients of the sharing polynomial, the first one being the group public key
+    pub commitments: Vec<CurvePoint>,
+}
+
+impl PublicKeyPackage {
+    /// Number of signers required to sign
+    pub fn threshold(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Public key of the share of the signer `index`
+    pub fn verifying_share(&self, index: u32) -> CurvePoint {
+        let x = ScalarField::from(index);
+        self.commitments
+            .iter()
+            .rev()
+            .fold(
+                <CurvePoint as AffineCurve>::Projective::zero(),
+                |acc, commitment| acc.mul(x.into_repr()).add_mixed(commitment),
+            )
+            .into_affine()
+    }
+}
+
+/// Split the secret key `secret` into `participants` shares, any `threshold` of which can sign
+///
+/// # Errors
+///
+/// Will give error if `threshold` is not between 1 and `participants`.
+pub fn generate_shares(
+    secret: &SecKey,
+    threshold: usize,
+    participants: u32,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<(Vec<KeyShare>, PublicKeyPackage)> {
+    if threshold == 0 || threshold > participants as usize {
+        return Err(ThresholdError::Threshold);
+    }
+
+    // The sharing polynomial, whose constant term is the secret key
+    let mut coefficients = vec![*secret.scalar()];
+    coefficients.extend((1..threshold).map(|_| ScalarField::rand(rng)));
+
+    let commitments: Vec<CurvePoint> = coefficients
+        .iter()
+        .map(|a| CurvePoint::prime_subgroup_generator().mul(*a).into_affine())
+        .collect();
+    let public = PubKey::from_point_unsafe(commitments[0]);
+
+    let shares = (1..=participants)
+        .map(|index| {
+            let x = ScalarField::from(index);
+            KeyShare {
+                index,
+                threshold,
+                secret: coefficients
+                    .iter()
+                    .rev()
+                    .fold(ScalarField::zero(), |acc, a| acc * x + a),
+                public: public.clone(),
+            }
+        })
+        .collect();
+
+    Ok((
+        shares,
+        PublicKeyPackage {
+            public,
+            commitments,
+        },
+    ))
+}
+
+/// Secret nonces of a signer for one signature, consumed when signing
+pub struct SigningNonces {
+    index: u32,
+    hiding: ScalarField,
+    binding: ScalarField,
+}
+
+impl SigningNonces {
+    /// Sample fresh nonces for the signer of `share`
+    pub fn new(share: &KeyShare, rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        Self {
+            index: share.index,
+            hiding: ScalarField::rand(rng),
+            binding: ScalarField::rand(rng),
+        }
+    }
+
+    /// Commitment to the nonces, to publish in the first round
+    pub fn commitment(&self) -> NonceCommitment {
+        let g = CurvePoint::prime_subgroup_generator();
+        NonceCommitment {
+            index: self.index,
+            hiding: g.mul(self.hiding).into_affine(),
+            binding: g.mul(self.binding).into_affine(),
+        }
+    }
+}
+
+/// Commitment to the nonces of a signer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceCommitment {
+    /// Index of the signer
+    pub index: u32,
+    /// Commitment to the hiding nonce
+    pub hiding: CurvePoint,
+    /// Commitment to the binding nonce
+    pub binding: CurvePoint,
+}
+
+/// Share of a signature, computed by one signer in the second round
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare {
+    /// Index of the signer
+    pub index: u32,
+    /// Share of the scalar component of the signature
+    pub z: ScalarField,
+}
+
+/// Threshold signer context, for the signature algorithm of a [`Signer`](crate::Signer)
+pub struct ThresholdSigner<H: Hashable> {
+    schnorr: schnorr::Schnorr<H>,
+    domain_param: H::D,
+}
+
+/// Create a threshold signer context for the legacy signature algorithm,
+/// with domain parameters initialized with `domain_param`
+pub fn create_legacy<H: 'static + Hashable>(domain_param: H::D) -> ThresholdSigner<H> {
+    ThresholdSigner {
+        schnorr: schnorr::create_legacy::<H>(domain_param.clone()),
+        domain_param,
+    }
+}
+
+/// Create a threshold signer context for the experimental kimchi signature algorithm,
+/// with domain parameters initialized with `domain_param`
+pub fn create_kimchi<H: 'static + Hashable>(domain_param: H::D) -> ThresholdSigner<H> {
+    ThresholdSigner {
+        schnorr: schnorr::create_kimchi::<H>(domain_param.clone()),
+        domain_param,
+    }
+}
+
+/// The group commitment of the signers, with what the signers need to share a signature
+struct GroupCommitment {
+    rx: crate::BaseField,
+    /// Whether the nonces must be negated, for the group commitment to have an even y-coordinate
+    negate: bool,
+    binding_factors: Vec<ScalarField>,
+    challenge: ScalarField,
+}
+
+impl<H: 'static + Hashable> ThresholdSigner<H> {
+    /// Second round: compute the signature share of `input` of the signer of `share`,
+    /// with its `nonces` and the nonce commitments of all the signers, sorted by index
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the commitments are not valid, if they are fewer than the threshold,
+    /// or if they do not include the commitment to `nonces`.
+    pub fn sign_share(
+        &mut self,
+        share: &KeyShare,
+        nonces: SigningNonces,
+        commitments: &[NonceCommitment],
+        input: &H,
+    ) -> Result<SignatureShare> {
+        let position = commitments
+            .iter()
+            .position(|commitment| commitment.index == share.index)
+            .filter(|position| {
+                nonces.index == share.index && commitments[*position] == nonces.commitment()
+            })
+            .ok_or(ThresholdError::MissingCommitment(share.index))?;
+        let group = self.group_commitment(&share.public, share.threshold, commitments, input)?;
+
+        let mut k = nonces.hiding + group.binding_factors[position] * nonces.binding;
+        if group.negate {
+            k = -k;
+        }
+        let lambda = lagrange_coefficient(share.index, commitments);
+
+        Ok(SignatureShare {
+            index: share.index,
+            z: k + lambda * group.challenge * share.secret,
+        })
+    }
+
+    /// Verify the signature `shares` of `input` and sum them into a signature,
+    /// with the nonce commitments of the signers, sorted by index
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the commitments are not valid, if they are fewer than the threshold,
+    /// or if the share of one of the signers is missing or not valid.
+    pub fn aggregate(
+        &mut self,
+        package: &PublicKeyPackage,
+        commitments: &[NonceCommitment],
+        shares: &[SignatureShare],
+        input: &H,
+    ) -> Result<Signature> {
+        let group =
+            self.group_commitment(&package.public, package.threshold(), commitments, input)?;
+
+        let g = CurvePoint::prime_subgroup_generator();
+        let mut s = ScalarField::zero();
+        for (commitment, binding_factor) in commitments.iter().zip(&group.binding_factors) {
+            let share = shares
+                .iter()
+                .find(|share| share.index == commitment.index)
+                .ok_or(ThresholdError::MissingSignatureShare(commitment.index))?;
+
+            // z_i G = k_i G + lambda_i e Y_i
+            let mut k = commitment.binding.mul(*binding_factor);
+            k.add_assign_mixed(&commitment.hiding);
+            if group.negate {
+                k = -k;
+            }
+            let lambda = lagrange_coefficient(commitment.index, commitments);
+            let expected = k + package
+                .verifying_share(commitment.index)
+                .mul(lambda * group.challenge);
+            if g.mul(share.z) != expected {
+                return Err(ThresholdError::InvalidSignatureShare(commitment.index));
+            }
+
+            s += share.z;
+        }
+
+        Ok(Signature::new(group.rx, s))
+    }
+
+    fn group_commitment(
+        &mut self,
+        public: &PubKey,
+        threshold: usize,
+        commitments: &[NonceCommitment],
+        input: &H,
+    ) -> Result<GroupCommitment> {
+        if commitments.iter().any(|commitment| {
+            commitment.index == 0 || commitment.hiding.is_zero() || commitment.binding.is_zero()
+        }) || commitments.windows(2).any(|w| w[0].index >= w[1].index)
+        {
+            return Err(ThresholdError::Commitments);
+        }
+        if commitments.len() < threshold {
+            return Err(ThresholdError::NotEnoughSigners);
+        }
+
+        let binding_factors = self.binding_factors(public, commitments, input);
+        let r = commitments
+            .iter()
+            .zip(&binding_factors)
+            .fold(
+                <CurvePoint as AffineCurve>::Projective::zero(),
+                |acc, (commitment, binding_factor)| {
+                    let mut d = commitment.binding.mul(*binding_factor);
+                    d.add_assign_mixed(&commitment.hiding);
+                    acc + d
+                },
+            )
+            .into_affine();
+        if r.is_zero() {
+            return Err(ThresholdError::Commitments);
+        }
+
+        Ok(GroupCommitment {
+            rx: r.x,
+            negate: !r.y.into_repr().is_even(),
+            binding_factors,
+            challenge: self.schnorr.message_hash(public, r.x, input),
+        })
+    }
+
+    /// The binding factor of each signer, which binds its nonces to the message and to
+    /// the nonce commitments of all the signers
+    fn binding_factors(
+        &self,
+        public: &PubKey,
+        commitments: &[NonceCommitment],
+        input: &H,
+    ) -> Vec<ScalarField> {
+        let roi = commitments
+            .iter()
+            .fold(
+                input
+                    .to_roinput()
+                    .append_field(public.point().x)
+                    .append_field(public.point().y),
+                |roi, commitment| {
+                    roi.append_u32(commitment.index)
+                        .append_field(commitment.hiding.x)
+                        .append_field(commitment.hiding.y)
+                        .append_field(commitment.binding.x)
+                        .append_field(commitment.binding.y)
+                },
+            )
+            .append_bytes(&self.domain_param.clone().into_bytes());
+        let bytes = roi.to_bytes();
+
+        commitments
+            .iter()
+            .map(|commitment| hash_to_scalar(&[&bytes, &commitment.index.to_le_bytes()]))
+            .collect()
+    }
+}
+
+/// Lagrange coefficient at zero of the signer `index`, among the signers of `commitments`
+fn lagrange_coefficient(index: u32, commitments: &[NonceCommitment]) -> ScalarField {
+    let x = ScalarField::from(index);
+    let (num, den) = commitments
+        .iter()
+        .filter(|commitment| commitment.index != index)
+        .fold((ScalarField::one(), ScalarField::one()), |(num, den), c| {
+            let xj = ScalarField::from(c.index);
+            (num * xj, den * (xj - x))
+        });
+    num * den.inverse().expect("signer indices are distinct")
+}
+
+// Blake2b hash into a scalar, as the nonce derivation of the signer
+fn hash_to_scalar(data: &[&[u8]]) -> ScalarField {
+    let mut blake_hasher = Blake2bVar::new(32).unwrap();
+    for chunk in data {
+        blake_hasher.update(chunk);
+    }
+
+    let mut bytes = [0; 32];
+    blake_hasher
+        .finalize_variable(&mut bytes)
+        .expect("incorrect output size");
+    // Drop the top two bits to convert into a scalar field element
+    bytes[bytes.len() - 1] &= 0b0011_1111;
+
+    ScalarField::from_random_bytes(&bytes[..]).expect("failed to create scalar from bytes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{transaction::Transaction, Keypair, NetworkId, Signer};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn transaction(kp: &Keypair) -> Transaction {
+        Transaction::new_payment(
+            kp.public.clone(),
+            kp.public.clone(),
+            1000000000,
+            10000000,
+            7,
+        )
+        .set_memo_str("threshold")
+    }
+
+    // Run both rounds with the signers of `indices`
+    fn sign(
+        ctx: &mut ThresholdSigner<Transaction>,
+        shares: &[KeyShare],
+        package: &PublicKeyPackage,
+        indices: &[u32],
+        input: &Transaction,
+        rng: &mut StdRng,
+    ) -> Result<Signature> {
+        let signers: Vec<&KeyShare> = indices.iter().map(|i| &shares[*i as usize - 1]).collect();
+        let nonces: Vec<SigningNonces> = signers
+            .iter()
+            .map(|share| SigningNonces::new(share, rng))
+            .collect();
+        let commitments: Vec<NonceCommitment> = nonces.iter().map(|n| n.commitment()).collect();
+
+        let signature_shares = signers
+            .iter()
+            .zip(nonces)
+            .map(|(share, nonces)| ctx.sign_share(share, nonces, &commitments, input))
+            .collect::<Result<Vec<_>>>()?;
+        ctx.aggregate(package, &commitments, &signature_shares, input)
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let rng = &mut StdRng::from_seed([1u8; 32]);
+        let kp = Keypair::rand(rng).unwrap();
+        let (shares, package) = generate_shares(&kp.secret, 2, 3, rng).unwrap();
+        assert_eq!(package.public, kp.public);
+        assert!(shares.iter().all(|share| share.verify(&package)));
+
+        let tx = transaction(&kp);
+        for network_id in [NetworkId::TESTNET, NetworkId::MAINNET] {
+            let mut ctx = create_legacy::<Transaction>(network_id.clone());
+            let mut verifier = crate::create_legacy::<Transaction>(network_id);
+            for indices in [&[1, 2][..], &[1, 3], &[2, 3], &[1, 2, 3]] {
+                // Repeat, for group commitments of both parities
+                for _ in 0..4 {
+                    let signature = sign(&mut ctx, &shares, &package, indices, &tx, rng).unwrap();
+                    assert!(verifier.verify(&signature, &kp.public, &tx));
+                }
+            }
+        }
+
+        let mut ctx = create_kimchi::<Transaction>(NetworkId::TESTNET);
+        let signature = sign(&mut ctx, &shares, &package, &[1, 3], &tx, rng).unwrap();
+        assert!(crate::create_kimchi::<Transaction>(NetworkId::TESTNET)
+            .verify(&signature, &kp.public, &tx));
+        assert!(!crate::create_legacy::<Transaction>(NetworkId::TESTNET)
+            .verify(&signature, &kp.public, &tx));
+    }
+
+    #[test]
+    fn key_generation() {
+        let rng = &mut StdRng::from_seed([2u8; 32]);
+        let secret = SecKey::rand(rng);
+        assert_eq!(
+            generate_shares(&secret, 0, 3, rng).err(),
+            Some(ThresholdError::Threshold)
+        );
+        assert_eq!(
+            generate_shares(&secret, 4, 3, rng).err(),
+            Some(ThresholdError::Threshold)
+        );
+
+        // Any 3 shares interpolate the secret key
+        let (shares, package) = generate_shares(&secret, 3, 5, rng).unwrap();
+        let commitments: Vec<NonceCommitment> = shares[1..4]
+            .iter()
+            .map(|share| SigningNonces::new(share, rng).commitment())
+            .collect();
+        let interpolated = shares[1..4].iter().fold(ScalarField::zero(), |acc, share| {
+            acc + lagrange_coefficient(share.index, &commitments) * share.secret
+        });
+        assert_eq!(interpolated, *secret.scalar());
+
+        let mut share = shares[0].clone();
+        share.secret += ScalarField::one();
+        assert!(!share.verify(&package));
+    }
+
+    #[test]
+    fn invalid_signing() {
+        let rng = &mut StdRng::from_seed([3u8; 32]);
+        let kp = Keypair::rand(rng).unwrap();
+        let (shares, package) = generate_shares(&kp.secret, 2, 3, rng).unwrap();
+        let tx = transaction(&kp);
+        let mut ctx = create_legacy::<Transaction>(NetworkId::TESTNET);
+
+        assert_eq!(
+            sign(&mut ctx, &shares, &package, &[2], &tx, rng),
+            Err(ThresholdError::NotEnoughSigners)
+        );
+        assert_eq!(
+            sign(&mut ctx, &shares, &package, &[3, 1], &tx, rng),
+            Err(ThresholdError::Commitments)
+        );
+
+        let nonces: Vec<SigningNonces> = shares[..2]
+            .iter()
+            .map(|share| SigningNonces::new(share, rng))
+            .collect();
+        let commitments: Vec<NonceCommitment> = nonces.iter().map(|n| n.commitment()).collect();
+        let mut nonces = nonces.into_iter();
+
+        // Nonces that do not match the commitments
+        let other = SigningNonces::new(&shares[0], rng);
+        assert_eq!(
+            ctx.sign_share(&shares[0], other, &commitments, &tx),
+            Err(ThresholdError::MissingCommitment(1))
+        );
+
+        let mut signature_shares = vec![
+            ctx.sign_share(&shares[0], nonces.next().unwrap(), &commitments, &tx)
+                .unwrap(),
+            ctx.sign_share(&shares[1], nonces.next().unwrap(), &commitments, &tx)
+                .unwrap(),
+        ];
+        assert_eq!(
+            ctx.aggregate(&package, &commitments, &signature_shares[..1], &tx),
+            Err(ThresholdError::MissingSignatureShare(2))
+        );
+
+        signature_shares[1].z += ScalarField::one();
+        assert_eq!(
+            ctx.aggregate(&package, &commitments, &signature_shares, &tx),
+            Err(ThresholdError::InvalidSignatureShare(2))
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Legacy transactions
+//!
+//! Payments and stake delegations, as found on mainnet and testnet, signed with [`create_legacy`](crate::create_legacy)
+
+use mina_hasher::{Hashable, ROInput};
+
+use super::memo::{Memo, MEMO_BYTES};
+use crate::{CompressedPubKey, NetworkId, PubKey};
+
+const TAG_BITS: usize = 3;
+pub(crate) const PAYMENT_TX_TAG: [bool; TAG_BITS] = [false, false, false];
+pub(crate) const DELEGATION_TX_TAG: [bool; TAG_BITS] = [false, false, true];
+
+/// Legacy payment or stake delegation
+#[derive(Clone)]
+pub struct Transaction {
+    // Common
+    /// Fee, in nanomina
+    pub fee: u64,
+    /// Token of the fee
+    pub fee_token: u64,
+    /// Public key of the fee payer
+    pub fee_payer_pk: CompressedPubKey,
+    /// Nonce of the fee payer account
+    pub nonce: u32,
+    /// Last global slot at which the transaction is valid
+    pub valid_until: u32,
+    /// Encoded memo (see [`Memo`])
+    pub memo: [u8; MEMO_BYTES],
+    // Body
+    /// Tag of the body: payment or stake delegation
+    pub tag: [bool; TAG_BITS],
+    /// Public key of the source
+    pub source_pk: CompressedPubKey,
+    /// Public key of the receiver (or the new delegate)
+    pub receiver_pk: CompressedPubKey,
+    /// Token of the payment
+    pub token_id: u64,
+    /// Amount of the payment, in nanomina
+    pub amount: u64,
+    /// Whether the token is locked
+    pub token_locked: bool,
+}
+
+impl Hashable for Transaction {
+    type D = NetworkId;
+
+    fn to_roinput(&self) -> ROInput {
+        let mut roi = ROInput::new()
+            .append_field(self.fee_payer_pk.x)
+            .append_field(self.source_pk.x)
+            .append_field(self.receiver_pk.x)
+            .append_u64(self.fee)
+            .append_u64(self.fee_token)
+            .append_bool(self.fee_payer_pk.is_odd)
+            .append_u32(self.nonce)
+            .append_u32(self.valid_until)
+            .append_bytes(&self.memo);
+
+        for tag_bit in self.tag {
+            roi = roi.append_bool(tag_bit);
+        }
+
+        roi.append_bool(self.source_pk.is_odd)
+            .append_bool(self.receiver_pk.is_odd)
+            .append_u64(self.token_id)
+            .append_u64(self.amount)
+            .append_bool(self.token_locked)
+    }
+
+    fn domain_string(network_id: NetworkId) -> Option<String> {
+        super::signature_domain_string(network_id).into()
+    }
+}
+
+impl Transaction {
+    /// Create a payment of `amount` from `from` to `to`
+    pub fn new_payment(from: PubKey, to: PubKey, amount: u64, fee: u64, nonce: u32) -> Self {
+        Transaction {
+            fee,
+            fee_token: 1,
+            fee_payer_pk: from.into_compressed(),
+            nonce,
+            valid_until: u32::MAX,
+            memo: *Memo::empty().as_bytes(),
+            tag: PAYMENT_TX_TAG,
+            source_pk: from.into_compressed(),
+            receiver_pk: to.into_compressed(),
+            token_id: 1,
+            amount,
+            token_locked: false,
+        }
+    }
+
+    /// Create a delegation of the stake of `from` to `to`
+    pub fn new_delegation(from: PubKey, to: PubKey, fee: u64, nonce: u32) -> Self {
+        Transaction {
+            fee,
+            fee_token: 1,
+            fee_payer_pk: from.into_compressed(),
+            nonce,
+            valid_until: u32::MAX,
+            memo: *Memo::empty().as_bytes(),
+            tag: DELEGATION_TX_TAG,
+            source_pk: from.into_compressed(),
+            receiver_pk: to.into_compressed(),
+            token_id: 1,
+            amount: 0,
+            token_locked: false,
+        }
+    }
+
+    /// Set the last global slot at which the transaction is valid
+    pub fn set_valid_until(mut self, global_slot: u32) -> Self {
+        self.valid_until = global_slot;
+
+        self
+    }
+
+    /// Set the memo content to `memo`, of maximal length
+    pub fn set_memo(self, memo: [u8; MEMO_BYTES - 2]) -> Self {
+        self.set_memo_value(&Memo::from_bytes_truncated(&memo))
+    }
+
+    /// Set the memo content to the bytes of `memo`, truncated to 32 bytes
+    pub fn set_memo_str(self, memo: &str) -> Self {
+        self.set_memo_value(&Memo::from_bytes_truncated(memo.as_bytes()))
+    }
+
+    /// Set the memo
+    pub fn set_memo_value(mut self, memo: &Memo) -> Self {
+        self.memo = *memo.as_bytes();
+
+        self
+    }
+
+    /// Get the memo
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the `memo` field is not a valid memo encoding.
+    pub fn memo(&self) -> Memo {
+        Memo::from_encoded(&self.memo).expect("invalid memo encoding")
+    }
+}
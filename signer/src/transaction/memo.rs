@@ -0,0 +1,236 @@
+//! Transaction memos
+//!
+//! A memo is a 34-byte value: a tag byte, a length byte and 32 bytes of content padded with zeros.
+//! Memos are displayed in base58check, with version byte `0x14`.
+
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Length of an encoded memo, in bytes
+pub const MEMO_BYTES: usize = 34;
+
+/// Maximum length of the content of a memo, in bytes
+pub const MEMO_MAX_CONTENT_BYTES: usize = MEMO_BYTES - 2;
+
+/// Version byte of the base58check encoding of memos
+const MEMO_VERSION: u8 = 0x14;
+
+/// Tag of memos whose content is a digest
+const DIGEST_TAG: u8 = 0x00;
+
+/// Tag of memos whose content is user bytes
+const BYTES_TAG: u8 = 0x01;
+
+/// Memo errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MemoError {
+    /// Content longer than 32 bytes
+    #[error("memo content too long")]
+    TooLong,
+    /// Invalid base58
+    #[error("invalid memo base58")]
+    Base58,
+    /// Invalid checksum
+    #[error("invalid memo checksum")]
+    Checksum,
+    /// Invalid version byte
+    #[error("invalid memo version")]
+    Version,
+    /// Invalid number of bytes
+    #[error("invalid memo length")]
+    Length,
+    /// Invalid tag or content length
+    #[error("invalid memo format")]
+    Format,
+}
+/// Memo result
+pub type Result<T> = std::result::Result<T, MemoError>;
+
+/// Transaction memo
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Memo([u8; MEMO_BYTES]);
+
+impl Default for Memo {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl Memo {
+    /// The empty memo
+    pub fn empty() -> Self {
+        Self::from_bytes_truncated(&[])
+    }
+
+    /// Create a memo with content `bytes`
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `bytes` is longer than [`MEMO_MAX_CONTENT_BYTES`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() > MEMO_MAX_CONTENT_BYTES {
+            return Err(MemoError::TooLong);
+        }
+        Ok(Self::from_bytes_truncated(bytes))
+    }
+
+    /// Create a memo with content `bytes`, truncated to [`MEMO_MAX_CONTENT_BYTES`]
+    pub fn from_bytes_truncated(bytes: &[u8]) -> Self {
+        let len = std::cmp::min(bytes.len(), MEMO_MAX_CONTENT_BYTES);
+        let mut memo = [0u8; MEMO_BYTES];
+        memo[0] = BYTES_TAG;
+        memo[1] = len as u8;
+        memo[2..2 + len].copy_from_slice(&bytes[..len]);
+        Self(memo)
+    }
+
+    /// Create a memo whose content is the SHA-256 digest of `input`
+    pub fn digest(input: &[u8]) -> Self {
+        let mut memo = [0u8; MEMO_BYTES];
+        memo[0] = DIGEST_TAG;
+        memo[1] = MEMO_MAX_CONTENT_BYTES as u8;
+        memo[2..].copy_from_slice(&Sha256::digest(input));
+        Self(memo)
+    }
+
+    /// Create a memo from its 34-byte encoding
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `encoded` is not a valid memo encoding.
+    pub fn from_encoded(encoded: &[u8]) -> Result<Self> {
+        let memo: [u8; MEMO_BYTES] = encoded.try_into().map_err(|_| MemoError::Length)?;
+        let len = memo[1] as usize;
+        let valid = match memo[0] {
+            DIGEST_TAG => len == MEMO_MAX_CONTENT_BYTES,
+            BYTES_TAG => len <= MEMO_MAX_CONTENT_BYTES && memo[2 + len..].iter().all(|b| *b == 0),
+            _ => false,
+        };
+        if !valid {
+            return Err(MemoError::Format);
+        }
+        Ok(Self(memo))
+    }
+
+    /// Borrow the 34-byte encoding of the memo
+    pub fn as_bytes(&self) -> &[u8; MEMO_BYTES] {
+        &self.0
+    }
+
+    /// Borrow the content of the memo
+    pub fn content(&self) -> &[u8] {
+        &self.0[2..2 + self.0[1] as usize]
+    }
+
+    /// Deserialize memo from base58check
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `b58` is not the base58check encoding of a memo.
+    pub fn from_base58check(b58: &str) -> Result<Self> {
+        let bytes = bs58::decode(b58)
+            .into_vec()
+            .map_err(|_| MemoError::Base58)?;
+        if bytes.len() < 5 {
+            return Err(MemoError::Length);
+        }
+
+        let (raw, checksum) = bytes.split_at(bytes.len() - 4);
+        let hash = Sha256::digest(&Sha256::digest(raw)[..]);
+        if checksum != &hash[..4] {
+            return Err(MemoError::Checksum);
+        }
+        if raw[0] != MEMO_VERSION {
+            return Err(MemoError::Version);
+        }
+
+        Self::from_encoded(&raw[1..])
+    }
+
+    /// Serialize memo into base58check
+    pub fn to_base58check(&self) -> String {
+        let mut raw = vec![MEMO_VERSION];
+        raw.extend(self.0);
+
+        // 4-byte checksum
+        let hash = Sha256::digest(&Sha256::digest(&raw[..])[..]);
+        raw.extend(&hash[..4]);
+
+        bs58::encode(raw).into_string()
+    }
+}
+
+impl FromStr for Memo {
+    type Err = MemoError;
+
+    /// Create a memo with the bytes of `s` as content
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl fmt::Display for Memo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_base58check())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_memo() {
+        let memo = Memo::empty();
+        assert_eq!(memo.as_bytes()[..2], [0x01, 0x00]);
+        assert!(memo.content().is_empty());
+        assert_eq!(
+            memo.to_base58check(),
+            "E4YM2vTHhWEg66xpj52JErHUBU4pZ1yageL4TVDDpTTSsv8mK6YaH"
+        );
+        assert_eq!(
+            Memo::from_base58check("E4YM2vTHhWEg66xpj52JErHUBU4pZ1yageL4TVDDpTTSsv8mK6YaH"),
+            Ok(memo)
+        );
+    }
+
+    #[test]
+    fn memo_roundtrip() {
+        let memo: Memo = "Hello Mina!".parse().expect("valid memo");
+        assert_eq!(memo.content(), b"Hello Mina!");
+        assert_eq!(Memo::from_base58check(&memo.to_string()), Ok(memo));
+
+        let memo = Memo::digest(b"Hello Mina!");
+        assert_eq!(memo.content().len(), MEMO_MAX_CONTENT_BYTES);
+        assert_eq!(Memo::from_base58check(&memo.to_base58check()), Ok(memo));
+    }
+
+    #[test]
+    fn invalid_memos() {
+        assert_eq!(
+            Memo::from_str("012345678901234567890123456789012"),
+            Err(MemoError::TooLong)
+        );
+        assert_eq!(
+            Memo::from_bytes_truncated(b"012345678901234567890123456789012").content(),
+            b"01234567890123456789012345678901"
+        );
+
+        let mut encoded = *Memo::empty().as_bytes();
+        encoded[0] = 0x02;
+        assert_eq!(Memo::from_encoded(&encoded), Err(MemoError::Format));
+        assert_eq!(Memo::from_encoded(&encoded[1..]), Err(MemoError::Length));
+
+        // Changing a character breaks the checksum
+        assert_eq!(
+            Memo::from_base58check("E4YM2vTHhWEg66xpj52JErHUBU4pZ1yageL4TVDDpTTSsv8mK6YaJ"),
+            Err(MemoError::Checksum)
+        );
+        // Addresses have another version byte
+        assert_eq!(
+            Memo::from_base58check("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt"),
+            Err(MemoError::Version)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Mina transactions
+//!
+//! Definition of the transactions of the Mina protocol that are signed with [`Signer`](crate::Signer)
+//!
+//! * [`Transaction`] is a legacy payment or stake delegation, signed with [`create_legacy`](crate::create_legacy)
+//! * [`ZkappCommand`] is a zkApp command, whose [`TransactionCommitment`] is signed with [`create_kimchi`](crate::create_kimchi)
+//!
+//! Both carry a [`Memo`]. A signed [`Transaction`] is submitted as a [`SignedTransaction`].
+
+pub mod legacy;
+pub mod memo;
+pub mod signed;
+pub mod zkapp;
+
+pub use legacy::Transaction;
+pub use memo::Memo;
+pub use signed::SignedTransaction;
+pub use zkapp::{TransactionCommitment, ZkappCommand};
+
+use crate::NetworkId;
+
+/// Domain string of the signatures of transactions on the network `network_id`
+pub fn signature_domain_string(network_id: NetworkId) -> String {
+    // Domain strings must have length <= 20
+    match network_id {
+        NetworkId::MAINNET => "MinaSignatureMainnet".to_string(),
+        NetworkId::TESTNET => "CodaSignature".to_string(),
+        NetworkId::Custom(id) => custom_prefix(&format!("{id}Signature")),
+    }
+}
+
+/// Hash prefix of a custom network: `prefix`, truncated or padded with `*` to 20 bytes
+pub(crate) fn custom_prefix(prefix: &str) -> String {
+    const PREFIX_BYTES: usize = 20;
+    let mut custom = String::with_capacity(PREFIX_BYTES);
+    for c in prefix.chars() {
+        if custom.len() + c.len_utf8() > PREFIX_BYTES {
+            break;
+        }
+        custom.push(c);
+    }
+    while custom.len() < PREFIX_BYTES {
+        custom.push('*');
+    }
+    custom
+}
@@ -0,0 +1,747 @@
+//! Signed legacy transactions
+//!
+//! A signed payment or stake delegation is submitted to a Mina node with the `sendPayment` or the
+//! `sendDelegation` mutation of its GraphQL API, whose variables are
+//!
+//! ```json
+//! {
+//!   "input": {
+//!     "from": "B62...",
+//!     "to": "B62...",
+//!     "amount": "1000000000",
+//!     "fee": "10000000",
+//!     "validUntil": "4294967295",
+//!     "memo": "hello",
+//!     "nonce": "3"
+//!   },
+//!   "signature": { "field": "...", "scalar": "..." }
+//! }
+//! ```
+//!
+//! where the amounts are in nanomina and a delegation has no `amount`.
+//!
+//! A signed transaction is also displayed in base58check, with version byte `0x13`, as the bin_prot
+//! serialization of the legacy `Signed_command` of the Mina protocol:
+//!
+//! ```text
+//! signed command  = v v payload signer signature
+//! payload         = v v common body
+//! common          = v v v fee fee_token fee_payer_pk nonce valid_until memo
+//! body            = v v (0x00 payment | 0x01 delegation)
+//! payment         = v v source_pk receiver_pk token_id amount
+//! delegation      = v 0x00 delegator_pk new_delegate_pk
+//! signer          = v public_key
+//! signature       = v rx s
+//! public_key      = v v x is_odd
+//! ```
+//!
+//! where `v` is the version tag `0x01` of a versioned type, amounts and fees are `v v int`, tokens are
+//! `v v v int`, the nonce and the global slot are `v v int` (of their signed 32-bit value), the memo is
+//! `v` followed by its 34 bytes prefixed by their length, and field elements are 32 little-endian bytes.
+//! An `int` is variable-length: a byte below `0x80`, or `0xff`, `0xfe`, `0xfd` or `0xfc` followed
+//! by a 1, 2, 4 or 8-byte little-endian integer.
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use o1_utils::FieldHelpers;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use super::{
+    legacy::{DELEGATION_TX_TAG, PAYMENT_TX_TAG},
+    memo::MEMO_BYTES,
+    Memo, Transaction,
+};
+use crate::{BaseField, CompressedPubKey, PubKey, ScalarField, Signature};
+
+/// Version byte of the base58check encoding of signed commands
+const SIGNED_COMMAND_VERSION: u8 = 0x13;
+
+/// Version tag of the versioned types of signed commands
+const VERSION_TAG: u8 = 0x01;
+
+/// Signed transaction errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SignedTransactionError {
+    /// The fee payer is not the source of the transaction
+    #[error("fee payer is not the source")]
+    FeePayer,
+    /// The transaction is not in the default token
+    #[error("transaction not in the default token")]
+    Token,
+    /// The memo is not valid UTF-8 text
+    #[error("memo is not text")]
+    Memo,
+    /// Invalid JSON
+    #[error("invalid signed transaction JSON: {0}")]
+    Json(String),
+    /// The transaction has no signed command encoding
+    #[error("transaction is not a payment or a delegation")]
+    Body,
+    /// Invalid base58
+    #[error("invalid signed transaction base58")]
+    Base58,
+    /// Invalid checksum
+    #[error("invalid signed transaction checksum")]
+    Checksum,
+    /// Invalid version byte
+    #[error("invalid signed transaction version")]
+    Version,
+    /// Invalid serialization of the signed command
+    #[error("invalid signed command: {0}")]
+    BinProt(String),
+}
+/// Signed transaction result
+pub type Result<T> = std::result::Result<T, SignedTransactionError>;
+
+/// Legacy transaction with its signature
+#[derive(Clone)]
+pub struct SignedTransaction {
+    /// Transaction
+    pub transaction: Transaction,
+    /// Signature of the transaction by its fee payer
+    pub signature: Signature,
+}
+
+/// Input of the `sendPayment` and `sendDelegation` mutations
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SendInput {
+    from: String,
+    to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    amount: Option<String>,
+    fee: String,
+    valid_until: String,
+    memo: String,
+    nonce: String,
+}
+
+/// Signature of the `sendPayment` and `sendDelegation` mutations, in decimal
+#[derive(Serialize, Deserialize)]
+struct SendSignature {
+    field: String,
+    scalar: String,
+}
+
+/// Variables of the `sendPayment` and `sendDelegation` mutations
+#[derive(Serialize, Deserialize)]
+struct SendVariables {
+    input: SendInput,
+    signature: SendSignature,
+}
+
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| SignedTransactionError::Json(format!("invalid {name}")))
+}
+
+fn parse_field<F: PrimeField>(value: &str, name: &str) -> Result<F> {
+    let big: BigUint = parse(value, name)?;
+    // N.B. `from_biguint` reduces the values larger than the modulus
+    match F::from_biguint(&big) {
+        Ok(x) if x.to_biguint() == big => Ok(x),
+        _ => Err(SignedTransactionError::Json(format!("invalid {name}"))),
+    }
+}
+
+fn parse_address(address: &str, name: &str) -> Result<PubKey> {
+    PubKey::from_address(address)
+        .map_err(|_| SignedTransactionError::Json(format!("invalid {name}")))
+}
+
+fn bin_prot_error(reason: &str) -> SignedTransactionError {
+    SignedTransactionError::BinProt(reason.to_string())
+}
+
+/// Writer of the bin_prot serialization of signed commands
+#[derive(Default)]
+struct BinProtWriter(Vec<u8>);
+
+impl BinProtWriter {
+    fn versions(&mut self, count: usize) -> &mut Self {
+        self.0.extend(std::iter::repeat(VERSION_TAG).take(count));
+        self
+    }
+
+    fn byte(&mut self, byte: u8) -> &mut Self {
+        self.0.push(byte);
+        self
+    }
+
+    fn int(&mut self, n: i64) -> &mut Self {
+        if (0..0x80).contains(&n) {
+            self.0.push(n as u8);
+        } else if (-0x80..0).contains(&n) {
+            self.0.push(0xff);
+            self.0.push(n as i8 as u8);
+        } else if i16::try_from(n).is_ok() {
+            self.0.push(0xfe);
+            self.0.extend((n as i16).to_le_bytes());
+        } else if i32::try_from(n).is_ok() {
+            self.0.push(0xfd);
+            self.0.extend((n as i32).to_le_bytes());
+        } else {
+            self.0.push(0xfc);
+            self.0.extend(n.to_le_bytes());
+        }
+        self
+    }
+
+    fn field<F: PrimeField>(&mut self, f: &F) -> &mut Self {
+        self.0.extend(f.to_bytes());
+        self
+    }
+
+    fn public_key(&mut self, pk: &CompressedPubKey) -> &mut Self {
+        self.versions(2).field(&pk.x).byte(u8::from(pk.is_odd))
+    }
+}
+
+/// Reader of the bin_prot serialization of signed commands
+struct BinProtReader<'a>(&'a [u8]);
+
+impl<'a> BinProtReader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(bin_prot_error("too short"));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn versions(&mut self, count: usize) -> Result<()> {
+        for _ in 0..count {
+            if self.byte()? != VERSION_TAG {
+                return Err(bin_prot_error("invalid version tag"));
+            }
+        }
+        Ok(())
+    }
+
+    fn int(&mut self) -> Result<i64> {
+        let n = match self.byte()? {
+            byte @ 0..=0x7f => byte.into(),
+            0xff => (self.byte()? as i8).into(),
+            0xfe => i16::from_le_bytes(self.bytes(2)?.try_into().unwrap()).into(),
+            0xfd => i32::from_le_bytes(self.bytes(4)?.try_into().unwrap()).into(),
+            0xfc => i64::from_le_bytes(self.bytes(8)?.try_into().unwrap()),
+            _ => return Err(bin_prot_error("invalid integer")),
+        };
+        Ok(n)
+    }
+
+    fn int32(&mut self) -> Result<u32> {
+        let n = i32::try_from(self.int()?).map_err(|_| bin_prot_error("invalid 32-bit integer"))?;
+        Ok(n as u32)
+    }
+
+    fn field<F: PrimeField>(&mut self) -> Result<F> {
+        F::from_bytes(self.bytes(32)?).map_err(|_| bin_prot_error("invalid field element"))
+    }
+
+    fn bool(&mut self) -> Result<bool> {
+        match self.byte()? {
+            0x00 => Ok(false),
+            0x01 => Ok(true),
+            _ => Err(bin_prot_error("invalid boolean")),
+        }
+    }
+
+    fn public_key(&mut self) -> Result<CompressedPubKey> {
+        self.versions(2)?;
+        let pk = CompressedPubKey {
+            x: self.field()?,
+            is_odd: self.bool()?,
+        };
+        pk.to_point()
+            .map(|_| pk.clone())
+            .map_err(|_| bin_prot_error("invalid public key"))
+    }
+}
+
+impl SignedTransaction {
+    /// Create a signed transaction
+    pub fn new(transaction: Transaction, signature: Signature) -> Self {
+        Self {
+            transaction,
+            signature,
+        }
+    }
+
+    /// Whether the transaction is a stake delegation
+    pub fn is_delegation(&self) -> bool {
+        self.transaction.tag == DELEGATION_TX_TAG
+    }
+
+    /// Serialize signed transaction into the JSON variables of the `sendPayment`
+    /// (or `sendDelegation`) mutation of the Mina GraphQL API
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the transaction cannot be expressed with these variables: its fee payer is not its
+    /// source, it is not in the default token, or its memo is not text.
+    pub fn to_graphql_json(&self) -> Result<String> {
+        let tx = &self.transaction;
+        if tx.fee_payer_pk != tx.source_pk {
+            return Err(SignedTransactionError::FeePayer);
+        }
+        if tx.fee_token != 1 || tx.token_id != 1 || tx.token_locked {
+            return Err(SignedTransactionError::Token);
+        }
+        let memo = Memo::from_encoded(&tx.memo).map_err(|_| SignedTransactionError::Memo)?;
+        if memo != Memo::from_bytes_truncated(memo.content()) {
+            // digest memos have no text
+            return Err(SignedTransactionError::Memo);
+        }
+        let memo =
+            String::from_utf8(memo.content().to_vec()).map_err(|_| SignedTransactionError::Memo)?;
+
+        let variables = SendVariables {
+            input: SendInput {
+                from: tx.source_pk.into_address(),
+                to: tx.receiver_pk.into_address(),
+                amount: (!self.is_delegation()).then(|| tx.amount.to_string()),
+                fee: tx.fee.to_string(),
+                valid_until: tx.valid_until.to_string(),
+                memo,
+                nonce: tx.nonce.to_string(),
+            },
+            signature: SendSignature {
+                field: self.signature.rx.to_biguint().to_string(),
+                scalar: self.signature.s.to_biguint().to_string(),
+            },
+        };
+
+        serde_json::to_string(&variables).map_err(|e| SignedTransactionError::Json(e.to_string()))
+    }
+
+    /// Deserialize signed transaction from the JSON variables of the `sendPayment`
+    /// (or `sendDelegation`, without `amount`) mutation of the Mina GraphQL API
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `json` is not valid, or if its memo is longer than 32 bytes.
+    pub fn from_graphql_json(json: &str) -> Result<Self> {
+        let variables: SendVariables =
+            serde_json::from_str(json).map_err(|e| SignedTransactionError::Json(e.to_string()))?;
+        let input = variables.input;
+
+        let from = parse_address(&input.from, "from")?;
+        let to = parse_address(&input.to, "to")?;
+        let fee = parse(&input.fee, "fee")?;
+        let nonce = parse(&input.nonce, "nonce")?;
+        let memo =
+            Memo::from_bytes(input.memo.as_bytes()).map_err(|_| SignedTransactionError::Memo)?;
+
+        let transaction = match input.amount {
+            Some(amount) => {
+                Transaction::new_payment(from, to, parse(&amount, "amount")?, fee, nonce)
+            }
+            None => Transaction::new_delegation(from, to, fee, nonce),
+        }
+        .set_valid_until(parse(&input.valid_until, "validUntil")?)
+        .set_memo_value(&memo);
+
+        let signature = Signature::new(
+            parse_field::<BaseField>(&variables.signature.field, "field")?,
+            parse_field::<ScalarField>(&variables.signature.scalar, "scalar")?,
+        );
+
+        Ok(Self::new(transaction, signature))
+    }
+
+    /// Serialize signed transaction into the base58check encoding of its signed command
+    ///
+    /// # Errors
+    ///
+    /// Will give error if the transaction is neither a payment nor a delegation, if its token is
+    /// locked, or if it is a delegation with an amount or in another token.
+    pub fn to_base58check(&self) -> Result<String> {
+        let tx = &self.transaction;
+        if tx.token_locked {
+            return Err(SignedTransactionError::Token);
+        }
+
+        let mut w = BinProtWriter::default();
+        w.byte(SIGNED_COMMAND_VERSION)
+            // signed command and payload
+            .versions(4)
+            // common
+            .versions(3)
+            .versions(2)
+            .int(tx.fee as i64)
+            .versions(3)
+            .int(tx.fee_token as i64)
+            .public_key(&tx.fee_payer_pk)
+            .versions(2)
+            .int(tx.nonce as i32 as i64)
+            .versions(2)
+            .int(tx.valid_until as i32 as i64)
+            .versions(1)
+            .byte(MEMO_BYTES as u8);
+        w.0.extend(tx.memo);
+
+        // body
+        w.versions(2);
+        if tx.tag == PAYMENT_TX_TAG {
+            w.byte(0x00)
+                .versions(2)
+                .public_key(&tx.source_pk)
+                .public_key(&tx.receiver_pk)
+                .versions(3)
+                .int(tx.token_id as i64)
+                .versions(2)
+                .int(tx.amount as i64);
+        } else if tx.tag == DELEGATION_TX_TAG {
+            if tx.token_id != 1 {
+                return Err(SignedTransactionError::Token);
+            }
+            if tx.amount != 0 {
+                return Err(SignedTransactionError::Body);
+            }
+            w.byte(0x01)
+                .versions(1)
+                .byte(0x00)
+                .public_key(&tx.source_pk)
+                .public_key(&tx.receiver_pk);
+        } else {
+            return Err(SignedTransactionError::Body);
+        }
+
+        // the signer is the fee payer
+        w.versions(1)
+            .public_key(&tx.fee_payer_pk)
+            .versions(1)
+            .field(&self.signature.rx)
+            .field(&self.signature.s);
+
+        let mut raw = w.0;
+        let hash = Sha256::digest(&Sha256::digest(&raw[..])[..]);
+        raw.extend(&hash[..4]);
+
+        Ok(bs58::encode(raw).into_string())
+    }
+
+    /// Deserialize signed transaction from the base58check encoding of its signed command
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `b58` is not the base58check encoding of a signed payment or delegation
+    /// whose signer is its fee payer.
+    pub fn from_base58check(b58: &str) -> Result<Self> {
+        let bytes = bs58::decode(b58)
+            .into_vec()
+            .map_err(|_| SignedTransactionError::Base58)?;
+        if bytes.len() < 5 {
+            return Err(SignedTransactionError::Base58);
+        }
+
+        let (raw, checksum) = bytes.split_at(bytes.len() - 4);
+        let hash = Sha256::digest(&Sha256::digest(raw)[..]);
+        if checksum != &hash[..4] {
+            return Err(SignedTransactionError::Checksum);
+        }
+        if raw[0] != SIGNED_COMMAND_VERSION {
+            return Err(SignedTransactionError::Version);
+        }
+
+        let mut r = BinProtReader(&raw[1..]);
+        r.versions(4)?;
+
+        r.versions(3)?;
+        r.versions(2)?;
+        let fee = r.int()? as u64;
+        r.versions(3)?;
+        let fee_token = r.int()? as u64;
+        let fee_payer_pk = r.public_key()?;
+        r.versions(2)?;
+        let nonce = r.int32()?;
+        r.versions(2)?;
+        let valid_until = r.int32()?;
+        r.versions(1)?;
+        if r.byte()? != MEMO_BYTES as u8 {
+            return Err(SignedTransactionError::Memo);
+        }
+        let memo =
+            Memo::from_encoded(r.bytes(MEMO_BYTES)?).map_err(|_| SignedTransactionError::Memo)?;
+
+        r.versions(2)?;
+        let (tag, source_pk, receiver_pk, token_id, amount) = match r.byte()? {
+            0x00 => {
+                r.versions(2)?;
+                let source_pk = r.public_key()?;
+                let receiver_pk = r.public_key()?;
+                r.versions(3)?;
+                let token_id = r.int()? as u64;
+                r.versions(2)?;
+                let amount = r.int()? as u64;
+                (PAYMENT_TX_TAG, source_pk, receiver_pk, token_id, amount)
+            }
+            0x01 => {
+                r.versions(1)?;
+                if r.byte()? != 0x00 {
+                    return Err(SignedTransactionError::Body);
+                }
+                let source_pk = r.public_key()?;
+                let receiver_pk = r.public_key()?;
+                (DELEGATION_TX_TAG, source_pk, receiver_pk, 1, 0)
+            }
+            _ => return Err(SignedTransactionError::Body),
+        };
+
+        r.versions(1)?;
+        if r.public_key()? != fee_payer_pk {
+            return Err(SignedTransactionError::FeePayer);
+        }
+        r.versions(1)?;
+        let signature = Signature::new(r.field()?, r.field()?);
+        if !r.0.is_empty() {
+            return Err(bin_prot_error("trailing bytes"));
+        }
+
+        let transaction = Transaction {
+            fee,
+            fee_token,
+            fee_payer_pk,
+            nonce,
+            valid_until,
+            memo: *memo.as_bytes(),
+            tag,
+            source_pk,
+            receiver_pk,
+            token_id,
+            amount,
+            token_locked: false,
+        };
+
+        Ok(Self::new(transaction, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_legacy, BaseField, NetworkId, ScalarField, Signer};
+    use o1_utils::FieldHelpers;
+
+    fn signature(hex: &str) -> Signature {
+        let mut bytes = hex::decode(hex).expect("invalid hex");
+        let (rx_bytes, s_bytes) = bytes.split_at_mut(32);
+        rx_bytes.reverse();
+        s_bytes.reverse();
+        Signature::new(
+            BaseField::from_bytes(rx_bytes).expect("invalid field"),
+            ScalarField::from_bytes(s_bytes).expect("invalid scalar"),
+        )
+    }
+
+    fn pub_key(address: &str) -> PubKey {
+        PubKey::from_address(address).expect("invalid address")
+    }
+
+    #[test]
+    fn payment_json() {
+        // first payment of the signer tests, with its testnet signature
+        let tx = Transaction::new_payment(
+            pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV"),
+            pub_key("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt"),
+            1729000000000,
+            2000000000,
+            16,
+        )
+        .set_valid_until(271828)
+        .set_memo_str("Hello Mina!");
+        let signed = SignedTransaction::new(tx, signature("11a36a8dfe5b857b95a2a7b7b17c62c3ea33411ae6f4eb3a907064aecae353c60794f1d0288322fe3f8bb69d6fabd4fd7c15f8d09f8783b2f087a80407e299af"));
+
+        let json = r#"{"input":{"from":"B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV","to":"B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt","amount":"1729000000000","fee":"2000000000","validUntil":"271828","memo":"Hello Mina!","nonce":"16"},"signature":{"field":"7978049910726616927075298742385001574587620942310654323357397558995139646406","scalar":"3429352238474987065427486162608449491113877901219474382951875744532516739503"}}"#;
+        assert_eq!(signed.to_graphql_json(), Ok(json.to_string()));
+
+        let parsed = SignedTransaction::from_graphql_json(json).expect("failed to parse");
+        assert!(!parsed.is_delegation());
+        assert!(create_legacy(NetworkId::TESTNET).verify(
+            &parsed.signature,
+            &pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV"),
+            &parsed.transaction
+        ));
+        assert_eq!(parsed.to_graphql_json(), Ok(json.to_string()));
+    }
+
+    #[test]
+    fn delegation_json() {
+        // first delegation of the signer tests, with its testnet signature
+        let tx = Transaction::new_delegation(
+            pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV"),
+            pub_key("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt"),
+            2000000000,
+            16,
+        )
+        .set_valid_until(1337)
+        .set_memo_str("Delewho?");
+        let signed = SignedTransaction::new(tx, signature("30797d7d0426e54ff195d1f94dc412300f900cc9e84990603939a77b3a4d2fc11ebab12857b47c481c182abe147279732549f0fd49e68d5541f825e9d1e6fa04"));
+        assert!(signed.is_delegation());
+
+        let json = signed.to_graphql_json().expect("failed to serialize");
+        assert!(json.starts_with(r#"{"input":{"from":"B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV","to":"B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt","fee":"2000000000","validUntil":"1337","memo":"Delewho?","nonce":"16"},"#));
+
+        let parsed = SignedTransaction::from_graphql_json(&json).expect("failed to parse");
+        assert!(parsed.is_delegation());
+        assert!(create_legacy(NetworkId::TESTNET).verify(
+            &parsed.signature,
+            &pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV"),
+            &parsed.transaction
+        ));
+        assert_eq!(parsed.to_graphql_json(), Ok(json));
+    }
+
+    #[test]
+    fn payment_base58check() {
+        let from = pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV");
+        let to = pub_key("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt");
+        let tx = Transaction::new_payment(from.clone(), to, 1729000000000, 2000000000, 16)
+            .set_valid_until(271828)
+            .set_memo_str("Hello Mina!");
+        let signed = SignedTransaction::new(tx, signature("11a36a8dfe5b857b95a2a7b7b17c62c3ea33411ae6f4eb3a907064aecae353c60794f1d0288322fe3f8bb69d6fabd4fd7c15f8d09f8783b2f087a80407e299af"));
+
+        let b58 = signed.to_base58check().expect("failed to serialize");
+        let raw = bs58::decode(&b58).into_vec().expect("invalid base58");
+        // version byte, version tags of the signed command, payload and common, then the fee
+        assert_eq!(
+            raw[..14],
+            [0x13, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0xfd, 0x00, 0x94, 0x35, 0x77][..14]
+        );
+
+        let parsed = SignedTransaction::from_base58check(&b58).expect("failed to parse");
+        assert!(!parsed.is_delegation());
+        assert_eq!(parsed.transaction.amount, 1729000000000);
+        assert_eq!(parsed.transaction.valid_until, 271828);
+        assert_eq!(parsed.transaction.memo().content(), b"Hello Mina!");
+        assert!(create_legacy(NetworkId::TESTNET).verify(
+            &parsed.signature,
+            &from,
+            &parsed.transaction
+        ));
+        assert_eq!(parsed.to_base58check(), Ok(b58));
+    }
+
+    #[test]
+    fn delegation_base58check() {
+        let from = pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV");
+        let to = pub_key("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt");
+        let tx = Transaction::new_delegation(from.clone(), to, 2000000000, 16)
+            .set_valid_until(1337)
+            .set_memo_str("Delewho?");
+        let signed = SignedTransaction::new(tx, signature("30797d7d0426e54ff195d1f94dc412300f900cc9e84990603939a77b3a4d2fc11ebab12857b47c481c182abe147279732549f0fd49e68d5541f825e9d1e6fa04"));
+
+        let b58 = signed.to_base58check().expect("failed to serialize");
+        let parsed = SignedTransaction::from_base58check(&b58).expect("failed to parse");
+        assert!(parsed.is_delegation());
+        assert!(create_legacy(NetworkId::TESTNET).verify(
+            &parsed.signature,
+            &from,
+            &parsed.transaction
+        ));
+        assert_eq!(parsed.to_base58check(), Ok(b58));
+    }
+
+    #[test]
+    fn base58check_integers() {
+        let from = pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV");
+        let signature = signature("11a36a8dfe5b857b95a2a7b7b17c62c3ea33411ae6f4eb3a907064aecae353c60794f1d0288322fe3f8bb69d6fabd4fd7c15f8d09f8783b2f087a80407e299af");
+
+        // every width of the variable-length integers, and unsigned values above the signed range
+        for (amount, nonce) in [
+            (0, 0),
+            (0x7f, 0x80),
+            (0x8000, 0x7fff_ffff),
+            (0x8000_0000, 0x8000_0000),
+            (u64::MAX, u32::MAX),
+        ] {
+            let tx = Transaction::new_payment(from.clone(), from.clone(), amount, 1, nonce);
+            let b58 = SignedTransaction::new(tx, signature.clone())
+                .to_base58check()
+                .expect("failed to serialize");
+            let parsed = SignedTransaction::from_base58check(&b58).expect("failed to parse");
+            assert_eq!(parsed.transaction.amount, amount);
+            assert_eq!(parsed.transaction.nonce, nonce);
+            assert_eq!(parsed.transaction.valid_until, u32::MAX);
+        }
+    }
+
+    #[test]
+    fn invalid_base58check() {
+        let from = pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV");
+        let signature = signature("11a36a8dfe5b857b95a2a7b7b17c62c3ea33411ae6f4eb3a907064aecae353c60794f1d0288322fe3f8bb69d6fabd4fd7c15f8d09f8783b2f087a80407e299af");
+
+        let mut tx = Transaction::new_payment(from.clone(), from.clone(), 1, 1, 0);
+        tx.token_locked = true;
+        assert_eq!(
+            SignedTransaction::new(tx, signature.clone()).to_base58check(),
+            Err(SignedTransactionError::Token)
+        );
+
+        let mut tx = Transaction::new_payment(from.clone(), from.clone(), 1, 1, 0);
+        tx.tag = [true, false, false];
+        assert_eq!(
+            SignedTransaction::new(tx, signature.clone()).to_base58check(),
+            Err(SignedTransactionError::Body)
+        );
+
+        let tx = Transaction::new_payment(from.clone(), from, 1, 1, 0);
+        let b58 = SignedTransaction::new(tx, signature)
+            .to_base58check()
+            .expect("failed to serialize");
+        let mut raw = bs58::decode(&b58).into_vec().expect("invalid base58");
+        *raw.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            SignedTransaction::from_base58check(&bs58::encode(raw).into_string()).err(),
+            Some(SignedTransactionError::Checksum)
+        );
+
+        assert_eq!(
+            SignedTransaction::from_base58check(&Memo::empty().to_base58check()).err(),
+            Some(SignedTransactionError::Version)
+        );
+        assert_eq!(
+            SignedTransaction::from_base58check("0OIl").err(),
+            Some(SignedTransactionError::Base58)
+        );
+    }
+
+    #[test]
+    fn invalid_json() {
+        let from = pub_key("B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV");
+        let to = pub_key("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt");
+        let signature = signature("11a36a8dfe5b857b95a2a7b7b17c62c3ea33411ae6f4eb3a907064aecae353c60794f1d0288322fe3f8bb69d6fabd4fd7c15f8d09f8783b2f087a80407e299af");
+
+        let mut tx = Transaction::new_payment(from.clone(), to.clone(), 1, 1, 0);
+        tx.fee_payer_pk = to.into_compressed();
+        assert_eq!(
+            SignedTransaction::new(tx, signature.clone()).to_graphql_json(),
+            Err(SignedTransactionError::FeePayer)
+        );
+
+        let tx = Transaction::new_payment(from.clone(), from.clone(), 1, 1, 0)
+            .set_memo_value(&Memo::digest(b"hello"));
+        assert_eq!(
+            SignedTransaction::new(tx, signature.clone()).to_graphql_json(),
+            Err(SignedTransactionError::Memo)
+        );
+
+        assert!(SignedTransaction::from_graphql_json(r#"{"input":{}}"#).is_err());
+
+        // the field elements of the signature are canonical
+        assert!(parse_field::<BaseField>(
+            "28948022309329048855892746252171976963363056481941560715954676764349967630337",
+            "signature field"
+        )
+        .is_err());
+    }
+}
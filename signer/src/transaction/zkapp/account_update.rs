@@ -0,0 +1,647 @@
+//! Account updates
+//!
+//! The bodies of the account updates of zkApp commands, and their hash inputs.
+//! An optional value is `None` to keep the current value (for updates) or to
+//! ignore a precondition.
+
+use ark_ff::{One, Zero};
+use mina_curves::pasta::Fp;
+use o1_utils::FieldHelpers;
+use std::str::FromStr;
+
+use super::hash::{
+    empty_hash_with_prefix, hash_with_prefix, prefixes, zkapp_body_prefix, HashInput,
+};
+use crate::{BaseField, CompressedPubKey, NetworkId};
+
+/// Number of field elements of the state of a zkApp account
+pub const ZKAPP_STATE_LENGTH: usize = 8;
+
+/// Current transaction version, of the permission to set the verification key
+pub const TXN_VERSION_CURRENT: u32 = 3;
+
+/// Maximal length of a token symbol, in bytes
+pub const TOKEN_SYMBOL_MAX_BYTES: usize = 6;
+
+/// Hash of the dummy verification key, for account updates not authorized by a proof
+pub fn dummy_verification_key_hash() -> BaseField {
+    BaseField::from_str(
+        "3392518251768960475377392625298437850623664973002200885669375116181514017494",
+    )
+    .expect("invalid dummy verification key hash")
+}
+
+/// Values that are appended to a [`HashInput`]
+trait ToHashInput {
+    fn to_hash_input(&self) -> HashInput;
+}
+
+// N.B. Implemented for the concrete type, as the trait projection of `BaseField` overlaps
+impl ToHashInput for Fp {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new().append_field(*self)
+    }
+}
+
+impl ToHashInput for bool {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new().append_bool(*self)
+    }
+}
+
+impl ToHashInput for u32 {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new().append_u32(*self)
+    }
+}
+
+impl ToHashInput for u64 {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new().append_u64(*self)
+    }
+}
+
+impl ToHashInput for CompressedPubKey {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new().append_pubkey(self)
+    }
+}
+
+/// Hash input of an optional value: a flag, followed by the value or `dummy`
+fn flagged<T: ToHashInput>(value: &Option<T>, dummy: T) -> HashInput {
+    HashInput::new()
+        .append_bool(value.is_some())
+        .append(value.as_ref().unwrap_or(&dummy).to_hash_input())
+}
+
+/// Inclusive interval
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosedInterval<T> {
+    /// Lower bound
+    pub lower: T,
+    /// Upper bound
+    pub upper: T,
+}
+
+impl<T: Clone> ClosedInterval<T> {
+    /// The interval containing only `value`
+    pub fn exact(value: T) -> Self {
+        Self {
+            lower: value.clone(),
+            upper: value,
+        }
+    }
+}
+
+impl<T: ToHashInput> ToHashInput for ClosedInterval<T> {
+    fn to_hash_input(&self) -> HashInput {
+        self.lower
+            .to_hash_input()
+            .append(self.upper.to_hash_input())
+    }
+}
+
+/// Hash input of an optional numeric precondition, whose dummy is the full interval
+fn flagged_interval<T: ToHashInput>(
+    value: &Option<ClosedInterval<T>>,
+    min: T,
+    max: T,
+) -> HashInput {
+    flagged(
+        value,
+        ClosedInterval {
+            lower: min,
+            upper: max,
+        },
+    )
+}
+
+/// Authorization required by a permission
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthRequired {
+    /// No authorization
+    None,
+    /// A signature or a proof
+    Either,
+    /// A proof
+    Proof,
+    /// A signature
+    Signature,
+    /// Impossible to authorize
+    Impossible,
+}
+
+impl ToHashInput for AuthRequired {
+    fn to_hash_input(&self) -> HashInput {
+        // Encoded as (constant, signature_necessary, signature_sufficient)
+        let (constant, signature_necessary, signature_sufficient) = match self {
+            AuthRequired::None => (true, false, true),
+            AuthRequired::Either => (false, false, true),
+            AuthRequired::Proof => (false, false, false),
+            AuthRequired::Signature => (false, true, true),
+            AuthRequired::Impossible => (true, true, false),
+        };
+        HashInput::new()
+            .append_bool(constant)
+            .append_bool(signature_necessary)
+            .append_bool(signature_sufficient)
+    }
+}
+
+/// Permissions of an account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Permissions {
+    /// Permission to edit the zkApp state
+    pub edit_state: AuthRequired,
+    /// Permission to access the account
+    pub access: AuthRequired,
+    /// Permission to send funds
+    pub send: AuthRequired,
+    /// Permission to receive funds
+    pub receive: AuthRequired,
+    /// Permission to set the delegate
+    pub set_delegate: AuthRequired,
+    /// Permission to set the permissions
+    pub set_permissions: AuthRequired,
+    /// Permission to set the verification key, and the transaction version it applies to
+    pub set_verification_key: (AuthRequired, u32),
+    /// Permission to set the zkApp URI
+    pub set_zkapp_uri: AuthRequired,
+    /// Permission to edit the action state
+    pub edit_action_state: AuthRequired,
+    /// Permission to set the token symbol
+    pub set_token_symbol: AuthRequired,
+    /// Permission to increment the nonce
+    pub increment_nonce: AuthRequired,
+    /// Permission to set the state hash the account is voting for
+    pub set_voting_for: AuthRequired,
+    /// Permission to set the timing
+    pub set_timing: AuthRequired,
+}
+
+impl Permissions {
+    /// Permissions requiring no authorization
+    pub fn empty() -> Self {
+        Self {
+            edit_state: AuthRequired::None,
+            access: AuthRequired::None,
+            send: AuthRequired::None,
+            receive: AuthRequired::None,
+            set_delegate: AuthRequired::None,
+            set_permissions: AuthRequired::None,
+            set_verification_key: (AuthRequired::None, TXN_VERSION_CURRENT),
+            set_zkapp_uri: AuthRequired::None,
+            edit_action_state: AuthRequired::None,
+            set_token_symbol: AuthRequired::None,
+            increment_nonce: AuthRequired::None,
+            set_voting_for: AuthRequired::None,
+            set_timing: AuthRequired::None,
+        }
+    }
+}
+
+impl ToHashInput for Permissions {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new()
+            .append(self.edit_state.to_hash_input())
+            .append(self.access.to_hash_input())
+            .append(self.send.to_hash_input())
+            .append(self.receive.to_hash_input())
+            .append(self.set_delegate.to_hash_input())
+            .append(self.set_permissions.to_hash_input())
+            .append(self.set_verification_key.0.to_hash_input())
+            .append_u32(self.set_verification_key.1)
+            .append(self.set_zkapp_uri.to_hash_input())
+            .append(self.edit_action_state.to_hash_input())
+            .append(self.set_token_symbol.to_hash_input())
+            .append(self.increment_nonce.to_hash_input())
+            .append(self.set_voting_for.to_hash_input())
+            .append(self.set_timing.to_hash_input())
+    }
+}
+
+/// Vesting schedule of an account
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Timing {
+    /// Initial minimum balance
+    pub initial_minimum_balance: u64,
+    /// Global slot of the cliff
+    pub cliff_time: u32,
+    /// Amount vested at the cliff
+    pub cliff_amount: u64,
+    /// Number of slots between vestings
+    pub vesting_period: u32,
+    /// Amount vested at each period
+    pub vesting_increment: u64,
+}
+
+impl ToHashInput for Timing {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new()
+            .append_u64(self.initial_minimum_balance)
+            .append_u32(self.cliff_time)
+            .append_u64(self.cliff_amount)
+            .append_u32(self.vesting_period)
+            .append_u64(self.vesting_increment)
+    }
+}
+
+/// Hash of a zkApp URI, or of no URI
+pub fn zkapp_uri_hash(uri: Option<&str>) -> BaseField {
+    let input = match uri {
+        // A final `true` bit ensures that trailing zero bytes do not alias
+        Some(uri) => uri
+            .as_bytes()
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .chain([true])
+            .fold(HashInput::new(), |input, bit| input.append_bool(bit)),
+        None => HashInput::new().append_bool(false).append_bool(false),
+    };
+    hash_with_prefix(prefixes::ZKAPP_URI, &input.to_fields())
+}
+
+/// Field element of a token symbol, its bytes in little-endian
+///
+/// # Panics
+///
+/// Will panic if `symbol` is longer than [`TOKEN_SYMBOL_MAX_BYTES`].
+pub fn token_symbol_to_field(symbol: &str) -> BaseField {
+    assert!(symbol.len() <= TOKEN_SYMBOL_MAX_BYTES);
+    let mut bytes = symbol.as_bytes().to_vec();
+    bytes.resize(BaseField::size_in_bytes(), 0);
+    BaseField::from_bytes(&bytes).expect("invalid token symbol bytes")
+}
+
+/// Updates of the account, `None` keeping the current value
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Update {
+    /// zkApp state
+    pub app_state: [Option<BaseField>; ZKAPP_STATE_LENGTH],
+    /// Delegate
+    pub delegate: Option<CompressedPubKey>,
+    /// Hash of the verification key
+    pub verification_key_hash: Option<BaseField>,
+    /// Permissions
+    pub permissions: Option<Permissions>,
+    /// zkApp URI
+    pub zkapp_uri: Option<String>,
+    /// Token symbol
+    pub token_symbol: Option<String>,
+    /// Vesting schedule
+    pub timing: Option<Timing>,
+    /// State hash the account is voting for
+    pub voting_for: Option<BaseField>,
+}
+
+impl ToHashInput for Update {
+    fn to_hash_input(&self) -> HashInput {
+        let input = self.app_state.iter().fold(HashInput::new(), |input, x| {
+            input.append(flagged(x, BaseField::zero()))
+        });
+        let uri_hash = self
+            .zkapp_uri
+            .as_deref()
+            .map(|uri| zkapp_uri_hash(Some(uri)));
+        let token_symbol = self
+            .token_symbol
+            .as_deref()
+            .map(|symbol| TokenSymbol(token_symbol_to_field(symbol)));
+
+        input
+            .append(flagged(&self.delegate, CompressedPubKey::empty()))
+            .append(flagged(&self.verification_key_hash, BaseField::zero()))
+            .append(flagged(&self.permissions, Permissions::empty()))
+            .append(flagged(&uri_hash, zkapp_uri_hash(None)))
+            .append(flagged(&token_symbol, TokenSymbol(BaseField::zero())))
+            .append(flagged(&self.timing, Timing::default()))
+            .append(flagged(&self.voting_for, BaseField::zero()))
+    }
+}
+
+// A token symbol, packed on 48 bits
+struct TokenSymbol(BaseField);
+
+impl ToHashInput for TokenSymbol {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new().append_packed(self.0, 8 * TOKEN_SYMBOL_MAX_BYTES)
+    }
+}
+
+/// Sign of a balance change
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Sgn {
+    /// Positive
+    #[default]
+    Pos,
+    /// Negative
+    Neg,
+}
+
+/// Change of the balance of an account, in nanomina
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BalanceChange {
+    /// Absolute value
+    pub magnitude: u64,
+    /// Sign
+    pub sgn: Sgn,
+}
+
+impl ToHashInput for BalanceChange {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new()
+            .append_u64(self.magnitude)
+            .append_bool(self.sgn == Sgn::Pos)
+    }
+}
+
+/// Events or actions, each a list of field elements
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Events(pub Vec<Vec<BaseField>>);
+
+impl Events {
+    /// Hash of the events, with the list prefix `prefix` and empty list prefix `empty_prefix`
+    fn hash_with(&self, prefix: &str, empty_prefix: &str) -> BaseField {
+        self.0
+            .iter()
+            .rev()
+            .fold(empty_hash_with_prefix(empty_prefix), |hash, event| {
+                hash_with_prefix(prefix, &[hash, hash_with_prefix(prefixes::EVENT, event)])
+            })
+    }
+
+    /// Hash of events
+    pub fn events_hash(&self) -> BaseField {
+        self.hash_with(prefixes::EVENTS, prefixes::EVENTS_EMPTY)
+    }
+
+    /// Hash of actions
+    pub fn actions_hash(&self) -> BaseField {
+        self.hash_with(prefixes::ACTIONS, prefixes::ACTIONS_EMPTY)
+    }
+}
+
+/// Ledger precondition of an epoch
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EpochLedgerPrecondition {
+    /// Ledger hash
+    pub hash: Option<BaseField>,
+    /// Total currency
+    pub total_currency: Option<ClosedInterval<u64>>,
+}
+
+impl ToHashInput for EpochLedgerPrecondition {
+    fn to_hash_input(&self) -> HashInput {
+        flagged(&self.hash, BaseField::zero()).append(flagged_interval(
+            &self.total_currency,
+            0,
+            u64::MAX,
+        ))
+    }
+}
+
+/// Precondition on the data of an epoch
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EpochDataPrecondition {
+    /// Ledger of the epoch
+    pub ledger: EpochLedgerPrecondition,
+    /// Seed
+    pub seed: Option<BaseField>,
+    /// Start checkpoint
+    pub start_checkpoint: Option<BaseField>,
+    /// Lock checkpoint
+    pub lock_checkpoint: Option<BaseField>,
+    /// Length of the epoch
+    pub epoch_length: Option<ClosedInterval<u32>>,
+}
+
+impl ToHashInput for EpochDataPrecondition {
+    fn to_hash_input(&self) -> HashInput {
+        self.ledger
+            .to_hash_input()
+            .append(flagged(&self.seed, BaseField::zero()))
+            .append(flagged(&self.start_checkpoint, BaseField::zero()))
+            .append(flagged(&self.lock_checkpoint, BaseField::zero()))
+            .append(flagged_interval(&self.epoch_length, 0, u32::MAX))
+    }
+}
+
+/// Precondition on the state of the network
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NetworkPrecondition {
+    /// Snarked ledger hash
+    pub snarked_ledger_hash: Option<BaseField>,
+    /// Blockchain length
+    pub blockchain_length: Option<ClosedInterval<u32>>,
+    /// Minimum window density
+    pub min_window_density: Option<ClosedInterval<u32>>,
+    /// Total currency
+    pub total_currency: Option<ClosedInterval<u64>>,
+    /// Global slot since genesis
+    pub global_slot_since_genesis: Option<ClosedInterval<u32>>,
+    /// Data of the staking epoch
+    pub staking_epoch_data: EpochDataPrecondition,
+    /// Data of the next epoch
+    pub next_epoch_data: EpochDataPrecondition,
+}
+
+impl ToHashInput for NetworkPrecondition {
+    fn to_hash_input(&self) -> HashInput {
+        flagged(&self.snarked_ledger_hash, BaseField::zero())
+            .append(flagged_interval(&self.blockchain_length, 0, u32::MAX))
+            .append(flagged_interval(&self.min_window_density, 0, u32::MAX))
+            .append(flagged_interval(&self.total_currency, 0, u64::MAX))
+            .append(flagged_interval(
+                &self.global_slot_since_genesis,
+                0,
+                u32::MAX,
+            ))
+            .append(self.staking_epoch_data.to_hash_input())
+            .append(self.next_epoch_data.to_hash_input())
+    }
+}
+
+/// Precondition on the state of the account
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountPrecondition {
+    /// Balance
+    pub balance: Option<ClosedInterval<u64>>,
+    /// Nonce
+    pub nonce: Option<ClosedInterval<u32>>,
+    /// Receipt chain hash
+    pub receipt_chain_hash: Option<BaseField>,
+    /// Delegate
+    pub delegate: Option<CompressedPubKey>,
+    /// zkApp state
+    pub state: [Option<BaseField>; ZKAPP_STATE_LENGTH],
+    /// Action state
+    pub action_state: Option<BaseField>,
+    /// Whether the state was set by a proof
+    pub proved_state: Option<bool>,
+    /// Whether the account is new
+    pub is_new: Option<bool>,
+}
+
+impl ToHashInput for AccountPrecondition {
+    fn to_hash_input(&self) -> HashInput {
+        let input = flagged_interval(&self.balance, 0, u64::MAX)
+            .append(flagged_interval(&self.nonce, 0, u32::MAX))
+            .append(flagged(&self.receipt_chain_hash, BaseField::zero()))
+            .append(flagged(&self.delegate, CompressedPubKey::empty()));
+        self.state
+            .iter()
+            .fold(input, |input, x| {
+                input.append(flagged(x, BaseField::zero()))
+            })
+            .append(flagged(
+                &self.action_state,
+                empty_hash_with_prefix(prefixes::ACTION_STATE_EMPTY),
+            ))
+            .append(flagged(&self.proved_state, false))
+            .append(flagged(&self.is_new, false))
+    }
+}
+
+/// Preconditions of an account update
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Preconditions {
+    /// Precondition on the network
+    pub network: NetworkPrecondition,
+    /// Precondition on the account
+    pub account: AccountPrecondition,
+    /// Global slots in which the account update is valid
+    pub valid_while: Option<ClosedInterval<u32>>,
+}
+
+impl ToHashInput for Preconditions {
+    fn to_hash_input(&self) -> HashInput {
+        self.network
+            .to_hash_input()
+            .append(self.account.to_hash_input())
+            .append(flagged_interval(&self.valid_while, 0, u32::MAX))
+    }
+}
+
+/// Whether the account update may use the token of its parent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MayUseToken {
+    /// The token is not used
+    #[default]
+    No,
+    /// The token is owned by the parent
+    ParentsOwnToken,
+    /// The permission is inherited from the parent
+    InheritFromParent,
+}
+
+impl ToHashInput for MayUseToken {
+    fn to_hash_input(&self) -> HashInput {
+        HashInput::new()
+            .append_bool(*self == MayUseToken::ParentsOwnToken)
+            .append_bool(*self == MayUseToken::InheritFromParent)
+    }
+}
+
+/// Kind of the authorization of an account update
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuthorizationKind {
+    /// No authorization
+    #[default]
+    NoneGiven,
+    /// A signature
+    Signature,
+    /// A proof, for the verification key of the given hash
+    Proof(BaseField),
+}
+
+impl ToHashInput for AuthorizationKind {
+    fn to_hash_input(&self) -> HashInput {
+        let (is_signed, is_proved, verification_key_hash) = match self {
+            AuthorizationKind::NoneGiven => (false, false, dummy_verification_key_hash()),
+            AuthorizationKind::Signature => (true, false, dummy_verification_key_hash()),
+            AuthorizationKind::Proof(hash) => (false, true, *hash),
+        };
+        HashInput::new()
+            .append_bool(is_signed)
+            .append_bool(is_proved)
+            .append_field(verification_key_hash)
+    }
+}
+
+/// Body of an account update
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdateBody {
+    /// Public key of the account
+    pub public_key: CompressedPubKey,
+    /// Token of the account
+    pub token_id: BaseField,
+    /// Updates of the account
+    pub update: Update,
+    /// Change of the balance
+    pub balance_change: BalanceChange,
+    /// Whether to increment the nonce
+    pub increment_nonce: bool,
+    /// Events
+    pub events: Events,
+    /// Actions
+    pub actions: Events,
+    /// Call data
+    pub call_data: BaseField,
+    /// Preconditions
+    pub preconditions: Preconditions,
+    /// Whether the authorization is on the full commitment, including the memo and fee payer
+    pub use_full_commitment: bool,
+    /// Whether the account creation fee is paid from the balance change
+    pub implicit_account_creation_fee: bool,
+    /// Whether the token of the parent may be used
+    pub may_use_token: MayUseToken,
+    /// Kind of the authorization
+    pub authorization_kind: AuthorizationKind,
+}
+
+impl AccountUpdateBody {
+    /// Create an account update of the MINA account of `public_key`, without any effect
+    pub fn new(public_key: CompressedPubKey) -> Self {
+        Self {
+            public_key,
+            token_id: BaseField::one(),
+            update: Update::default(),
+            balance_change: BalanceChange::default(),
+            increment_nonce: false,
+            events: Events::default(),
+            actions: Events::default(),
+            call_data: BaseField::zero(),
+            preconditions: Preconditions::default(),
+            use_full_commitment: false,
+            implicit_account_creation_fee: false,
+            may_use_token: MayUseToken::No,
+            authorization_kind: AuthorizationKind::NoneGiven,
+        }
+    }
+
+    /// Hash input of the account update body
+    pub fn to_hash_input(&self) -> HashInput {
+        HashInput::new()
+            .append_pubkey(&self.public_key)
+            .append_field(self.token_id)
+            .append(self.update.to_hash_input())
+            .append(self.balance_change.to_hash_input())
+            .append_bool(self.increment_nonce)
+            .append_field(self.events.events_hash())
+            .append_field(self.actions.actions_hash())
+            .append_field(self.call_data)
+            .append(self.preconditions.to_hash_input())
+            .append_bool(self.use_full_commitment)
+            .append_bool(self.implicit_account_creation_fee)
+            .append(self.may_use_token.to_hash_input())
+            .append(self.authorization_kind.to_hash_input())
+    }
+
+    /// Hash of the account update body on the network `network_id`
+    pub fn hash(&self, network_id: NetworkId) -> BaseField {
+        hash_with_prefix(
+            &zkapp_body_prefix(network_id),
+            &self.to_hash_input().to_fields(),
+        )
+    }
+}
@@ -0,0 +1,257 @@
+//! Hashing of zkApp commands
+//!
+//! Unlike legacy transactions, which are hashed from a [`ROInput`](mina_hasher::ROInput) bit string,
+//! zkApp commands are hashed from a [`HashInput`]: field elements, followed by small
+//! values packed greedily into field elements, without being split across them.
+
+use ark_ff::{PrimeField, Zero};
+use mina_poseidon::{
+    constants::PlonkSpongeConstantsKimchi,
+    pasta,
+    poseidon::{ArithmeticSponge, Sponge},
+};
+use o1_utils::{FieldHelpers, Two};
+
+use crate::{transaction::custom_prefix, BaseField, CompressedPubKey, NetworkId};
+
+/// Hash prefixes of the zkApp commands, padded with `*` to 20 bytes as in the Mina protocol
+pub mod prefixes {
+    /// Prefix of the nodes of the call forest
+    pub const ACCOUNT_UPDATE_NODE: &str = "MinaAcctUpdateNode**";
+    /// Prefix of the lists of the call forest
+    pub const ACCOUNT_UPDATE_CONS: &str = "MinaAcctUpdateCons**";
+    /// Prefix of the account update bodies on mainnet
+    pub const ZKAPP_BODY_MAINNET: &str = "MainnetZkappBody****";
+    /// Prefix of the account update bodies on testnets
+    pub const ZKAPP_BODY_TESTNET: &str = "TestnetZkappBody****";
+    /// Prefix of the memos
+    pub const ZKAPP_MEMO: &str = "MinaZkappMemo*******";
+    /// Prefix of the zkApp URIs
+    pub const ZKAPP_URI: &str = "MinaZkappUri********";
+    /// Prefix of an event or action
+    pub const EVENT: &str = "MinaZkappEvent******";
+    /// Prefix of the lists of events
+    pub const EVENTS: &str = "MinaZkappEvents*****";
+    /// Prefix of the lists of actions
+    pub const ACTIONS: &str = "MinaZkappSeqEvents**";
+    /// Prefix of the empty list of events (not padded)
+    pub const EVENTS_EMPTY: &str = "MinaZkappEventsEmpty";
+    /// Prefix of the empty list of actions (not padded)
+    pub const ACTIONS_EMPTY: &str = "MinaZkappActionsEmpty";
+    /// Prefix of the empty action state (not padded)
+    pub const ACTION_STATE_EMPTY: &str = "MinaZkappActionStateEmptyElt";
+}
+
+/// Input of the hash of zkApp commands
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HashInput {
+    fields: Vec<BaseField>,
+    packed: Vec<(BaseField, usize)>,
+}
+
+impl HashInput {
+    /// Create a new empty hash input
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a field element
+    pub fn append_field(mut self, f: BaseField) -> Self {
+        self.fields.push(f);
+        self
+    }
+
+    /// Append a value `x` of `bits` bits, to be packed with other values
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bits` is larger than the packing capacity of a field element.
+    pub fn append_packed(mut self, x: BaseField, bits: usize) -> Self {
+        assert!(bits < BaseField::size_in_bits());
+        self.packed.push((x, bits));
+        self
+    }
+
+    /// Append a boolean
+    pub fn append_bool(self, b: bool) -> Self {
+        self.append_packed(BaseField::from(b), 1)
+    }
+
+    /// Append a 32-bit unsigned integer
+    pub fn append_u32(self, x: u32) -> Self {
+        self.append_packed(BaseField::from(x), 32)
+    }
+
+    /// Append a 64-bit unsigned integer
+    pub fn append_u64(self, x: u64) -> Self {
+        self.append_packed(BaseField::from(x), 64)
+    }
+
+    /// Append a compressed public key
+    pub fn append_pubkey(self, pk: &CompressedPubKey) -> Self {
+        self.append_field(pk.x).append_bool(pk.is_odd)
+    }
+
+    /// Append another hash input
+    pub fn append(mut self, mut other: HashInput) -> Self {
+        self.fields.append(&mut other.fields);
+        self.packed.append(&mut other.packed);
+        self
+    }
+
+    /// Serialize the hash input into field elements: the field elements, followed by
+    /// the packed values. Each packed field element holds at most 254 bits, the first
+    /// value in its most significant bits.
+    pub fn to_fields(&self) -> Vec<BaseField> {
+        let mut fields = self.fields.clone();
+        if self.packed.is_empty() {
+            return fields;
+        }
+
+        let (mut current, mut current_bits) = (BaseField::zero(), 0);
+        for (x, bits) in &self.packed {
+            current_bits += bits;
+            if current_bits < BaseField::size_in_bits() {
+                current = current * BaseField::two_pow(*bits as u64) + x;
+            } else {
+                fields.push(current);
+                current = *x;
+                current_bits = *bits;
+            }
+        }
+        fields.push(current);
+
+        fields
+    }
+}
+
+/// Transform a hash prefix into a field element, its bytes in little-endian
+fn prefix_to_field(prefix: &str) -> BaseField {
+    assert!(prefix.len() < BaseField::size_in_bytes());
+    let mut bytes = prefix.as_bytes().to_vec();
+    bytes.resize(BaseField::size_in_bytes(), 0);
+    BaseField::from_bytes(&bytes).expect("invalid prefix bytes")
+}
+
+// The sponge after absorbing the prefix, as the initial state of the Hasher,
+// with the first element of this state
+fn salted_sponge(
+    prefix: &str,
+) -> (
+    ArithmeticSponge<BaseField, PlonkSpongeConstantsKimchi>,
+    BaseField,
+) {
+    let mut sponge = ArithmeticSponge::new(pasta::fp_kimchi::static_params());
+    sponge.absorb(&[prefix_to_field(prefix)]);
+    let digest = sponge.squeeze();
+    (sponge, digest)
+}
+
+/// Kimchi Poseidon hash of `input`, with the hash prefix `prefix`
+pub fn hash_with_prefix(prefix: &str, input: &[BaseField]) -> BaseField {
+    let (mut sponge, _) = salted_sponge(prefix);
+    if input.is_empty() {
+        // N.B. The sponge would not be permuted again
+        sponge.absorb(&[BaseField::zero()]);
+    } else {
+        sponge.absorb(input);
+    }
+    sponge.squeeze()
+}
+
+/// Kimchi Poseidon hash of nothing, with the hash prefix `prefix`.
+/// This is the first element of the initial state of the sponge.
+pub fn empty_hash_with_prefix(prefix: &str) -> BaseField {
+    let (_, digest) = salted_sponge(prefix);
+    digest
+}
+
+/// Prefix of the account update bodies on the network `network_id`
+pub fn zkapp_body_prefix(network_id: NetworkId) -> String {
+    match network_id {
+        NetworkId::MAINNET => prefixes::ZKAPP_BODY_MAINNET.to_string(),
+        NetworkId::TESTNET => prefixes::ZKAPP_BODY_TESTNET.to_string(),
+        NetworkId::Custom(id) => custom_prefix(&format!("{id}ZkappBody")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packing() {
+        let input = HashInput::new()
+            .append_field(BaseField::from(7u64))
+            .append_bool(true)
+            .append_u32(2)
+            .append_bool(false);
+        assert_eq!(
+            input.to_fields(),
+            vec![BaseField::from(7u64), BaseField::from((1u64 << 33) + 4)]
+        );
+
+        // 64-bit values are not split: 3 of them fill a field element
+        let input = (0..4).fold(HashInput::new(), |input, i| input.append_u64(i));
+        assert_eq!(
+            input.to_fields(),
+            vec![
+                BaseField::two_pow(64) + BaseField::from(2u64),
+                BaseField::from(3u64)
+            ]
+        );
+
+        assert_eq!(HashInput::new().to_fields(), vec![]);
+    }
+
+    #[test]
+    fn prefixes() {
+        for prefix in [
+            prefixes::ACCOUNT_UPDATE_NODE,
+            prefixes::ACCOUNT_UPDATE_CONS,
+            prefixes::ZKAPP_BODY_MAINNET,
+            prefixes::ZKAPP_BODY_TESTNET,
+            prefixes::ZKAPP_MEMO,
+            prefixes::ZKAPP_URI,
+            prefixes::EVENT,
+            prefixes::EVENTS,
+            prefixes::ACTIONS,
+        ] {
+            assert_eq!(prefix.len(), 20);
+        }
+        assert_eq!(
+            zkapp_body_prefix(NetworkId::Custom("mynet".to_string())),
+            "mynetZkappBody******"
+        );
+        assert_eq!(
+            zkapp_body_prefix(NetworkId::MAINNET),
+            prefixes::ZKAPP_BODY_MAINNET
+        );
+        assert_ne!(
+            empty_hash_with_prefix(prefixes::EVENTS_EMPTY),
+            hash_with_prefix(prefixes::EVENTS_EMPTY, &[])
+        );
+    }
+
+    #[test]
+    fn empty_hashes() {
+        // `Actions.emptyActionState()` of o1js
+        assert_eq!(
+            empty_hash_with_prefix(prefixes::ACTION_STATE_EMPTY).to_biguint(),
+            "25079927036070901246064867767436987657692091363973573142121686150614948079097"
+                .parse()
+                .unwrap()
+        );
+
+        // the first element of the salted state, with no further permutation
+        let mut sponge = ArithmeticSponge::<BaseField, PlonkSpongeConstantsKimchi>::new(
+            pasta::fp_kimchi::static_params(),
+        );
+        sponge.absorb(&[prefix_to_field(prefixes::EVENTS_EMPTY)]);
+        sponge.squeeze();
+        assert_eq!(
+            empty_hash_with_prefix(prefixes::EVENTS_EMPTY),
+            sponge.state[0]
+        );
+    }
+}
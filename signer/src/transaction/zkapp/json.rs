@@ -0,0 +1,662 @@
+//! JSON of zkApp commands
+//!
+//! zkApp commands are read from the JSON of `ZkappCommand.toJSON` in o1js, as sent to the
+//! `sendZkapp` mutation of the Mina GraphQL API. In this JSON
+//!
+//! * integers and field elements are decimal strings, and public keys are addresses
+//! * token ids are in base58check with version byte `0x1c`, and the ledger hashes, epoch seeds,
+//!   state hashes and receipt chain hashes with version bytes `0x05`, `0x0d`, `0x10` and `0x0c`,
+//!   followed by the version number `0x01`
+//! * the forest of account updates is a list, each account update having its `callDepth`
+//!
+//! The authorizations of the fee payer and of the account updates are not read.
+
+use num_bigint::BigUint;
+use o1_utils::FieldHelpers;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::{iter::Peekable, str::FromStr};
+use thiserror::Error;
+
+use super::account_update::{
+    AccountPrecondition, AuthRequired, AuthorizationKind, BalanceChange, ClosedInterval,
+    EpochDataPrecondition, EpochLedgerPrecondition, Events, MayUseToken, NetworkPrecondition,
+    Permissions, Preconditions, Sgn, Timing, Update, TOKEN_SYMBOL_MAX_BYTES, ZKAPP_STATE_LENGTH,
+};
+use super::{AccountUpdate, AccountUpdateBody, FeePayer, ZkappCommand};
+use crate::{transaction::Memo, BaseField, CompressedPubKey};
+
+/// Version byte of the base58check encoding of token ids
+const TOKEN_ID_VERSION: u8 = 0x1c;
+
+/// Version byte of the base58check encoding of ledger hashes
+const LEDGER_HASH_VERSION: u8 = 0x05;
+
+/// Version byte of the base58check encoding of epoch seeds
+const EPOCH_SEED_VERSION: u8 = 0x0d;
+
+/// Version byte of the base58check encoding of state hashes
+const STATE_HASH_VERSION: u8 = 0x10;
+
+/// Version byte of the base58check encoding of receipt chain hashes
+const RECEIPT_CHAIN_HASH_VERSION: u8 = 0x0c;
+
+/// Version number of the versioned hashes, following their version byte
+const VERSION_NUMBER: u8 = 0x01;
+
+/// zkApp command JSON errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ZkappJsonError {
+    /// Invalid JSON
+    #[error("invalid zkApp command JSON: {0}")]
+    Json(String),
+    /// Invalid value of a field of the JSON
+    #[error("invalid {0}")]
+    Value(String),
+    /// The call depths do not describe a forest
+    #[error("invalid call depth")]
+    CallDepth,
+}
+/// zkApp command JSON result
+pub type Result<T> = std::result::Result<T, ZkappJsonError>;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ZkappCommandJson {
+    fee_payer: FeePayerJson,
+    account_updates: Vec<AccountUpdateJson>,
+    memo: String,
+}
+
+#[derive(Deserialize)]
+struct FeePayerJson {
+    body: FeePayerBodyJson,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeePayerBodyJson {
+    public_key: String,
+    fee: String,
+    valid_until: Option<String>,
+    nonce: String,
+}
+
+#[derive(Deserialize)]
+struct AccountUpdateJson {
+    body: AccountUpdateBodyJson,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountUpdateBodyJson {
+    public_key: String,
+    token_id: String,
+    update: UpdateJson,
+    balance_change: BalanceChangeJson,
+    increment_nonce: bool,
+    events: Vec<Vec<String>>,
+    actions: Vec<Vec<String>>,
+    call_data: String,
+    call_depth: usize,
+    preconditions: PreconditionsJson,
+    use_full_commitment: bool,
+    implicit_account_creation_fee: bool,
+    may_use_token: MayUseTokenJson,
+    authorization_kind: AuthorizationKindJson,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateJson {
+    app_state: Vec<Option<String>>,
+    delegate: Option<String>,
+    verification_key: Option<VerificationKeyJson>,
+    permissions: Option<PermissionsJson>,
+    zkapp_uri: Option<String>,
+    token_symbol: Option<String>,
+    timing: Option<TimingJson>,
+    voting_for: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct VerificationKeyJson {
+    hash: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PermissionsJson {
+    edit_state: AuthRequiredJson,
+    access: AuthRequiredJson,
+    send: AuthRequiredJson,
+    receive: AuthRequiredJson,
+    set_delegate: AuthRequiredJson,
+    set_permissions: AuthRequiredJson,
+    set_verification_key: VerificationKeyPermissionJson,
+    set_zkapp_uri: AuthRequiredJson,
+    edit_action_state: AuthRequiredJson,
+    set_token_symbol: AuthRequiredJson,
+    increment_nonce: AuthRequiredJson,
+    set_voting_for: AuthRequiredJson,
+    set_timing: AuthRequiredJson,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+enum AuthRequiredJson {
+    None,
+    Either,
+    Proof,
+    Signature,
+    Impossible,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VerificationKeyPermissionJson {
+    auth: AuthRequiredJson,
+    txn_version: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TimingJson {
+    initial_minimum_balance: String,
+    cliff_time: String,
+    cliff_amount: String,
+    vesting_period: String,
+    vesting_increment: String,
+}
+
+#[derive(Deserialize)]
+struct BalanceChangeJson {
+    magnitude: String,
+    sgn: SgnJson,
+}
+
+#[derive(Deserialize)]
+enum SgnJson {
+    Positive,
+    Negative,
+}
+
+#[derive(Deserialize)]
+struct IntervalJson {
+    lower: String,
+    upper: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PreconditionsJson {
+    network: NetworkPreconditionJson,
+    account: AccountPreconditionJson,
+    valid_while: Option<IntervalJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NetworkPreconditionJson {
+    snarked_ledger_hash: Option<String>,
+    blockchain_length: Option<IntervalJson>,
+    min_window_density: Option<IntervalJson>,
+    total_currency: Option<IntervalJson>,
+    global_slot_since_genesis: Option<IntervalJson>,
+    staking_epoch_data: EpochDataPreconditionJson,
+    next_epoch_data: EpochDataPreconditionJson,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EpochDataPreconditionJson {
+    ledger: EpochLedgerPreconditionJson,
+    seed: Option<String>,
+    start_checkpoint: Option<String>,
+    lock_checkpoint: Option<String>,
+    epoch_length: Option<IntervalJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EpochLedgerPreconditionJson {
+    hash: Option<String>,
+    total_currency: Option<IntervalJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountPreconditionJson {
+    balance: Option<IntervalJson>,
+    nonce: Option<IntervalJson>,
+    receipt_chain_hash: Option<String>,
+    delegate: Option<String>,
+    state: Vec<Option<String>>,
+    action_state: Option<String>,
+    proved_state: Option<bool>,
+    is_new: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MayUseTokenJson {
+    parents_own_token: bool,
+    inherit_from_parent: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthorizationKindJson {
+    is_signed: bool,
+    is_proved: bool,
+    verification_key_hash: String,
+}
+
+fn invalid(name: &str) -> ZkappJsonError {
+    ZkappJsonError::Value(name.to_string())
+}
+
+fn uint<T: FromStr>(value: &str, name: &str) -> Result<T> {
+    value.parse().map_err(|_| invalid(name))
+}
+
+fn field(value: &str, name: &str) -> Result<BaseField> {
+    let big: BigUint = uint(value, name)?;
+    // N.B. `from_biguint` reduces the values larger than the modulus
+    match BaseField::from_biguint(&big) {
+        Ok(x) if x.to_biguint() == big => Ok(x),
+        _ => Err(invalid(name)),
+    }
+}
+
+fn public_key(address: &str, name: &str) -> Result<CompressedPubKey> {
+    CompressedPubKey::from_address(address).map_err(|_| invalid(name))
+}
+
+/// Field element of its base58check encoding with version byte `version`, followed by the version
+/// number if `versioned`
+fn base58_field(b58: &str, version: u8, versioned: bool, name: &str) -> Result<BaseField> {
+    let bytes = bs58::decode(b58).into_vec().map_err(|_| invalid(name))?;
+    let header = if versioned { 2 } else { 1 };
+    if bytes.len() != header + BaseField::size_in_bytes() + 4 {
+        return Err(invalid(name));
+    }
+
+    let (raw, checksum) = bytes.split_at(bytes.len() - 4);
+    let hash = Sha256::digest(&Sha256::digest(raw)[..]);
+    if checksum != &hash[..4] || raw[0] != version || (versioned && raw[1] != VERSION_NUMBER) {
+        return Err(invalid(name));
+    }
+
+    BaseField::from_bytes(&raw[header..]).map_err(|_| invalid(name))
+}
+
+fn optional<T, U>(value: &Option<T>, f: impl FnOnce(&T) -> Result<U>) -> Result<Option<U>> {
+    value.as_ref().map(f).transpose()
+}
+
+fn interval<T: FromStr>(value: &IntervalJson, name: &str) -> Result<ClosedInterval<T>> {
+    Ok(ClosedInterval {
+        lower: uint(&value.lower, name)?,
+        upper: uint(&value.upper, name)?,
+    })
+}
+
+fn state(values: &[Option<String>], name: &str) -> Result<[Option<BaseField>; ZKAPP_STATE_LENGTH]> {
+    let state = values
+        .iter()
+        .map(|x| optional(x, |x| field(x, name)))
+        .collect::<Result<Vec<_>>>()?;
+    state.try_into().map_err(|_| invalid(name))
+}
+
+fn events(values: &[Vec<String>], name: &str) -> Result<Events> {
+    values
+        .iter()
+        .map(|event| event.iter().map(|x| field(x, name)).collect())
+        .collect::<Result<_>>()
+        .map(Events)
+}
+
+impl From<AuthRequiredJson> for AuthRequired {
+    fn from(auth: AuthRequiredJson) -> Self {
+        match auth {
+            AuthRequiredJson::None => AuthRequired::None,
+            AuthRequiredJson::Either => AuthRequired::Either,
+            AuthRequiredJson::Proof => AuthRequired::Proof,
+            AuthRequiredJson::Signature => AuthRequired::Signature,
+            AuthRequiredJson::Impossible => AuthRequired::Impossible,
+        }
+    }
+}
+
+impl TryFrom<&PermissionsJson> for Permissions {
+    type Error = ZkappJsonError;
+
+    fn try_from(permissions: &PermissionsJson) -> Result<Self> {
+        Ok(Self {
+            edit_state: permissions.edit_state.into(),
+            access: permissions.access.into(),
+            send: permissions.send.into(),
+            receive: permissions.receive.into(),
+            set_delegate: permissions.set_delegate.into(),
+            set_permissions: permissions.set_permissions.into(),
+            set_verification_key: (
+                permissions.set_verification_key.auth.into(),
+                uint(&permissions.set_verification_key.txn_version, "txnVersion")?,
+            ),
+            set_zkapp_uri: permissions.set_zkapp_uri.into(),
+            edit_action_state: permissions.edit_action_state.into(),
+            set_token_symbol: permissions.set_token_symbol.into(),
+            increment_nonce: permissions.increment_nonce.into(),
+            set_voting_for: permissions.set_voting_for.into(),
+            set_timing: permissions.set_timing.into(),
+        })
+    }
+}
+
+impl TryFrom<&TimingJson> for Timing {
+    type Error = ZkappJsonError;
+
+    fn try_from(timing: &TimingJson) -> Result<Self> {
+        Ok(Self {
+            initial_minimum_balance: uint(&timing.initial_minimum_balance, "timing")?,
+            cliff_time: uint(&timing.cliff_time, "timing")?,
+            cliff_amount: uint(&timing.cliff_amount, "timing")?,
+            vesting_period: uint(&timing.vesting_period, "timing")?,
+            vesting_increment: uint(&timing.vesting_increment, "timing")?,
+        })
+    }
+}
+
+impl TryFrom<&UpdateJson> for Update {
+    type Error = ZkappJsonError;
+
+    fn try_from(update: &UpdateJson) -> Result<Self> {
+        if let Some(symbol) = &update.token_symbol {
+            if symbol.len() > TOKEN_SYMBOL_MAX_BYTES {
+                return Err(invalid("tokenSymbol"));
+            }
+        }
+        Ok(Self {
+            app_state: state(&update.app_state, "appState")?,
+            delegate: optional(&update.delegate, |pk| public_key(pk, "delegate"))?,
+            verification_key_hash: optional(&update.verification_key, |vk| {
+                field(&vk.hash, "verificationKey")
+            })?,
+            permissions: optional(&update.permissions, |p| p.try_into())?,
+            zkapp_uri: update.zkapp_uri.clone(),
+            token_symbol: update.token_symbol.clone(),
+            timing: optional(&update.timing, |timing| timing.try_into())?,
+            voting_for: optional(&update.voting_for, |hash| {
+                base58_field(hash, STATE_HASH_VERSION, true, "votingFor")
+            })?,
+        })
+    }
+}
+
+impl TryFrom<&EpochDataPreconditionJson> for EpochDataPrecondition {
+    type Error = ZkappJsonError;
+
+    fn try_from(epoch_data: &EpochDataPreconditionJson) -> Result<Self> {
+        let state_hash =
+            |hash: &String| base58_field(hash, STATE_HASH_VERSION, true, "epochData checkpoint");
+        Ok(Self {
+            ledger: EpochLedgerPrecondition {
+                hash: optional(&epoch_data.ledger.hash, |hash| {
+                    base58_field(hash, LEDGER_HASH_VERSION, true, "epochData ledger hash")
+                })?,
+                total_currency: optional(&epoch_data.ledger.total_currency, |x| {
+                    interval(x, "epochData totalCurrency")
+                })?,
+            },
+            seed: optional(&epoch_data.seed, |seed| {
+                base58_field(seed, EPOCH_SEED_VERSION, true, "epochData seed")
+            })?,
+            start_checkpoint: optional(&epoch_data.start_checkpoint, state_hash)?,
+            lock_checkpoint: optional(&epoch_data.lock_checkpoint, state_hash)?,
+            epoch_length: optional(&epoch_data.epoch_length, |x| {
+                interval(x, "epochData epochLength")
+            })?,
+        })
+    }
+}
+
+impl TryFrom<&PreconditionsJson> for Preconditions {
+    type Error = ZkappJsonError;
+
+    fn try_from(preconditions: &PreconditionsJson) -> Result<Self> {
+        let network = &preconditions.network;
+        let account = &preconditions.account;
+        Ok(Self {
+            network: NetworkPrecondition {
+                snarked_ledger_hash: optional(&network.snarked_ledger_hash, |hash| {
+                    base58_field(hash, LEDGER_HASH_VERSION, true, "snarkedLedgerHash")
+                })?,
+                blockchain_length: optional(&network.blockchain_length, |x| {
+                    interval(x, "blockchainLength")
+                })?,
+                min_window_density: optional(&network.min_window_density, |x| {
+                    interval(x, "minWindowDensity")
+                })?,
+                total_currency: optional(&network.total_currency, |x| {
+                    interval(x, "totalCurrency")
+                })?,
+                global_slot_since_genesis: optional(&network.global_slot_since_genesis, |x| {
+                    interval(x, "globalSlotSinceGenesis")
+                })?,
+                staking_epoch_data: (&network.staking_epoch_data).try_into()?,
+                next_epoch_data: (&network.next_epoch_data).try_into()?,
+            },
+            account: AccountPrecondition {
+                balance: optional(&account.balance, |x| interval(x, "balance"))?,
+                nonce: optional(&account.nonce, |x| interval(x, "nonce"))?,
+                receipt_chain_hash: optional(&account.receipt_chain_hash, |hash| {
+                    base58_field(hash, RECEIPT_CHAIN_HASH_VERSION, true, "receiptChainHash")
+                })?,
+                delegate: optional(&account.delegate, |pk| public_key(pk, "delegate"))?,
+                state: state(&account.state, "state")?,
+                action_state: optional(&account.action_state, |x| field(x, "actionState"))?,
+                proved_state: account.proved_state,
+                is_new: account.is_new,
+            },
+            valid_while: optional(&preconditions.valid_while, |x| interval(x, "validWhile"))?,
+        })
+    }
+}
+
+impl TryFrom<&AccountUpdateBodyJson> for AccountUpdateBody {
+    type Error = ZkappJsonError;
+
+    fn try_from(body: &AccountUpdateBodyJson) -> Result<Self> {
+        let may_use_token = match &body.may_use_token {
+            MayUseTokenJson {
+                parents_own_token: false,
+                inherit_from_parent: false,
+            } => MayUseToken::No,
+            MayUseTokenJson {
+                parents_own_token: true,
+                inherit_from_parent: false,
+            } => MayUseToken::ParentsOwnToken,
+            MayUseTokenJson {
+                parents_own_token: false,
+                inherit_from_parent: true,
+            } => MayUseToken::InheritFromParent,
+            _ => return Err(invalid("mayUseToken")),
+        };
+        let kind = &body.authorization_kind;
+        let authorization_kind = match (kind.is_signed, kind.is_proved) {
+            (false, false) => AuthorizationKind::NoneGiven,
+            (true, false) => AuthorizationKind::Signature,
+            (false, true) => {
+                AuthorizationKind::Proof(field(&kind.verification_key_hash, "authorizationKind")?)
+            }
+            (true, true) => return Err(invalid("authorizationKind")),
+        };
+
+        Ok(Self {
+            public_key: public_key(&body.public_key, "publicKey")?,
+            token_id: base58_field(&body.token_id, TOKEN_ID_VERSION, false, "tokenId")?,
+            update: (&body.update).try_into()?,
+            balance_change: BalanceChange {
+                magnitude: uint(&body.balance_change.magnitude, "balanceChange")?,
+                sgn: match body.balance_change.sgn {
+                    SgnJson::Positive => Sgn::Pos,
+                    SgnJson::Negative => Sgn::Neg,
+                },
+            },
+            increment_nonce: body.increment_nonce,
+            events: events(&body.events, "events")?,
+            actions: events(&body.actions, "actions")?,
+            call_data: field(&body.call_data, "callData")?,
+            preconditions: (&body.preconditions).try_into()?,
+            use_full_commitment: body.use_full_commitment,
+            implicit_account_creation_fee: body.implicit_account_creation_fee,
+            may_use_token,
+            authorization_kind,
+        })
+    }
+}
+
+/// The forest of the account updates at depth `depth` and below, from the list of account updates
+/// with their call depths
+fn call_forest<I>(bodies: &mut Peekable<I>, depth: usize) -> Result<Vec<AccountUpdate>>
+where
+    I: Iterator<Item = (usize, AccountUpdateBody)>,
+{
+    let mut forest = vec![];
+    while let Some((call_depth, _)) = bodies.peek() {
+        if *call_depth < depth {
+            break;
+        }
+        if *call_depth > depth {
+            return Err(ZkappJsonError::CallDepth);
+        }
+        let (_, body) = bodies.next().expect("peeked account update");
+        let calls = call_forest(bodies, depth + 1)?;
+        forest.push(AccountUpdate { body, calls });
+    }
+    Ok(forest)
+}
+
+impl ZkappCommand {
+    /// Deserialize a zkApp command from the JSON of o1js
+    ///
+    /// # Errors
+    ///
+    /// Will give error if `json` is not the JSON of a zkApp command.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let command: ZkappCommandJson =
+            serde_json::from_str(json).map_err(|e| ZkappJsonError::Json(e.to_string()))?;
+
+        let fee_payer = &command.fee_payer.body;
+        let bodies = command
+            .account_updates
+            .iter()
+            .map(|account_update| {
+                let body = &account_update.body;
+                Ok((body.call_depth, body.try_into()?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            fee_payer: FeePayer {
+                public_key: public_key(&fee_payer.public_key, "feePayer publicKey")?,
+                fee: uint(&fee_payer.fee, "fee")?,
+                valid_until: optional(&fee_payer.valid_until, |x| uint(x, "validUntil"))?,
+                nonce: uint(&fee_payer.nonce, "nonce")?,
+            },
+            account_updates: call_forest(&mut bodies.into_iter().peekable(), 0)?,
+            memo: Memo::from_base58check(&command.memo).map_err(|_| invalid("memo"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::One;
+
+    #[test]
+    fn base58_fields() {
+        // `TokenId.default` of o1js
+        assert_eq!(
+            base58_field(
+                "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf",
+                TOKEN_ID_VERSION,
+                false,
+                "tokenId"
+            ),
+            Ok(BaseField::one())
+        );
+        assert_eq!(
+            base58_field(
+                "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf",
+                LEDGER_HASH_VERSION,
+                false,
+                "tokenId"
+            ),
+            Err(invalid("tokenId"))
+        );
+        assert_eq!(
+            base58_field(
+                "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf",
+                TOKEN_ID_VERSION,
+                true,
+                "tokenId"
+            ),
+            Err(invalid("tokenId"))
+        );
+        assert_eq!(
+            base58_field(
+                "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jg",
+                TOKEN_ID_VERSION,
+                false,
+                "tokenId"
+            ),
+            Err(invalid("tokenId"))
+        );
+    }
+
+    #[test]
+    fn fields() {
+        assert_eq!(field("42", "x"), Ok(BaseField::from(42u64)));
+        // the modulus
+        assert_eq!(
+            field(
+                "28948022309329048855892746252171976963363056481941560715954676764349967630337",
+                "x"
+            ),
+            Err(invalid("x"))
+        );
+        assert_eq!(field("-1", "x"), Err(invalid("x")));
+    }
+
+    #[test]
+    fn call_depths() {
+        let body = AccountUpdateBody::new(CompressedPubKey::empty());
+        let forest = |depths: &[usize]| {
+            call_forest(&mut depths.iter().map(|d| (*d, body.clone())).peekable(), 0)
+        };
+        let leaf = AccountUpdate::new(body.clone());
+
+        assert_eq!(
+            forest(&[0, 1, 1, 2, 0]),
+            Ok(vec![
+                AccountUpdate {
+                    body: body.clone(),
+                    calls: vec![
+                        leaf.clone(),
+                        AccountUpdate {
+                            body: body.clone(),
+                            calls: vec![leaf.clone()],
+                        },
+                    ],
+                },
+                leaf,
+            ])
+        );
+        assert_eq!(forest(&[]), Ok(vec![]));
+        assert_eq!(forest(&[1]), Err(ZkappJsonError::CallDepth));
+        assert_eq!(forest(&[0, 2]), Err(ZkappJsonError::CallDepth));
+    }
+}
@@ -0,0 +1,187 @@
+//! zkApp commands
+//!
+//! A zkApp command is a fee payer and a forest of account updates. Its account updates
+//! are authorized by signing (with [`create_kimchi`](crate::create_kimchi)) or proving
+//! one of its two [`TransactionCommitment`]s:
+//!
+//! * the commitment, the hash of the forest of account updates
+//! * the full commitment, which also commits to the memo and the fee payer
+//!
+//! The fee payer always signs the full commitment. zkApp commands are read from the JSON of o1js
+//! with [`ZkappCommand::from_json`].
+
+pub mod account_update;
+pub mod hash;
+pub mod json;
+
+pub use account_update::AccountUpdateBody;
+
+use ark_ff::Zero;
+use mina_hasher::{Hashable, ROInput};
+
+use account_update::{AccountPrecondition, BalanceChange, ClosedInterval, Sgn};
+use account_update::{AuthorizationKind, NetworkPrecondition, Preconditions};
+use hash::{hash_with_prefix, prefixes, HashInput};
+
+use super::memo::Memo;
+use crate::{BaseField, CompressedPubKey, NetworkId};
+
+/// Account update, with the account updates it calls
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdate {
+    /// Body
+    pub body: AccountUpdateBody,
+    /// Account updates called by this one
+    pub calls: Vec<AccountUpdate>,
+}
+
+impl AccountUpdate {
+    /// Create an account update with body `body`, that calls no account updates
+    pub fn new(body: AccountUpdateBody) -> Self {
+        Self {
+            body,
+            calls: vec![],
+        }
+    }
+
+    /// Hash of the account update and the account updates it calls
+    pub fn hash(&self, network_id: NetworkId) -> BaseField {
+        hash_with_prefix(
+            prefixes::ACCOUNT_UPDATE_NODE,
+            &[
+                self.body.hash(network_id.clone()),
+                call_forest_hash(&self.calls, network_id),
+            ],
+        )
+    }
+}
+
+/// Hash of a forest of account updates, the empty forest hashing to zero
+pub fn call_forest_hash(account_updates: &[AccountUpdate], network_id: NetworkId) -> BaseField {
+    account_updates
+        .iter()
+        .rev()
+        .fold(BaseField::zero(), |hash, account_update| {
+            hash_with_prefix(
+                prefixes::ACCOUNT_UPDATE_CONS,
+                &[account_update.hash(network_id.clone()), hash],
+            )
+        })
+}
+
+/// Hash of a memo
+pub fn memo_hash(memo: &Memo) -> BaseField {
+    let input = memo
+        .as_bytes()
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .fold(HashInput::new(), |input, bit| input.append_bool(bit));
+    hash_with_prefix(prefixes::ZKAPP_MEMO, &input.to_fields())
+}
+
+/// Fee payer of a zkApp command
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeePayer {
+    /// Public key of the fee payer
+    pub public_key: CompressedPubKey,
+    /// Fee, in nanomina
+    pub fee: u64,
+    /// Last global slot at which the command is valid
+    pub valid_until: Option<u32>,
+    /// Nonce of the fee payer account
+    pub nonce: u32,
+}
+
+impl FeePayer {
+    /// The fee payer, as the body of an account update
+    pub fn to_account_update_body(&self) -> AccountUpdateBody {
+        AccountUpdateBody {
+            balance_change: BalanceChange {
+                magnitude: self.fee,
+                sgn: Sgn::Neg,
+            },
+            increment_nonce: true,
+            preconditions: Preconditions {
+                network: NetworkPrecondition {
+                    global_slot_since_genesis: Some(ClosedInterval {
+                        lower: 0,
+                        upper: self.valid_until.unwrap_or(u32::MAX),
+                    }),
+                    ..Default::default()
+                },
+                account: AccountPrecondition {
+                    nonce: Some(ClosedInterval::exact(self.nonce)),
+                    ..Default::default()
+                },
+                valid_while: None,
+            },
+            use_full_commitment: true,
+            implicit_account_creation_fee: true,
+            authorization_kind: AuthorizationKind::Signature,
+            ..AccountUpdateBody::new(self.public_key.clone())
+        }
+    }
+}
+
+/// zkApp command
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZkappCommand {
+    /// Fee payer
+    pub fee_payer: FeePayer,
+    /// Forest of account updates
+    pub account_updates: Vec<AccountUpdate>,
+    /// Memo
+    pub memo: Memo,
+}
+
+impl ZkappCommand {
+    /// Commitment to the account updates, signed by the account updates that do not use the full commitment
+    pub fn commitment(&self, network_id: NetworkId) -> TransactionCommitment {
+        TransactionCommitment(call_forest_hash(&self.account_updates, network_id))
+    }
+
+    /// Commitment to the account updates, the memo and the fee payer, signed by the fee payer
+    pub fn full_commitment(&self, network_id: NetworkId) -> TransactionCommitment {
+        let fee_payer_hash = self
+            .fee_payer
+            .to_account_update_body()
+            .hash(network_id.clone());
+        TransactionCommitment(hash_with_prefix(
+            prefixes::ACCOUNT_UPDATE_CONS,
+            &[
+                memo_hash(&self.memo),
+                fee_payer_hash,
+                self.commitment(network_id).0,
+            ],
+        ))
+    }
+
+    /// Commitment to sign for the account update `body` of this command
+    pub fn commitment_for(
+        &self,
+        body: &AccountUpdateBody,
+        network_id: NetworkId,
+    ) -> TransactionCommitment {
+        if body.use_full_commitment {
+            self.full_commitment(network_id)
+        } else {
+            self.commitment(network_id)
+        }
+    }
+}
+
+/// Commitment of a zkApp command, signed with [`create_kimchi`](crate::create_kimchi)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransactionCommitment(pub BaseField);
+
+impl Hashable for TransactionCommitment {
+    type D = NetworkId;
+
+    fn to_roinput(&self) -> ROInput {
+        ROInput::new().append_field(self.0)
+    }
+
+    fn domain_string(network_id: NetworkId) -> Option<String> {
+        super::signature_domain_string(network_id).into()
+    }
+}
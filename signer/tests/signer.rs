@@ -1,8 +1,10 @@
-pub mod transaction;
+mod transaction;
 
 use ark_ff::Zero;
-use mina_signer::{self, BaseField, Keypair, NetworkId, PubKey, ScalarField, Signer};
-pub use transaction::Transaction;
+use mina_hasher::DomainParameter;
+use mina_signer::{
+    self, transaction::Transaction, BaseField, Keypair, NetworkId, PubKey, ScalarField, Signer,
+};
 
 enum TransactionType {
     PaymentTx,
@@ -121,6 +123,97 @@ fn signer_zero_test() {
     assert!(!ctx.verify(&sig3, &kp.public, &tx));
 }
 
+#[test]
+fn signer_batch_test() {
+    let receiver = PubKey::from_address("B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt")
+        .expect("invalid address");
+    let mut ctx = mina_signer::create_legacy(NetworkId::TESTNET);
+    let mut batch: Vec<_> = (0..20)
+        .map(|i| {
+            let kp = Keypair::rand(&mut rand::rngs::OsRng).expect("failed to create keypair");
+            let tx = Transaction::new_payment(
+                kp.public.clone(),
+                receiver.clone(),
+                1000 * i,
+                2000000000,
+                i as u32,
+            );
+            (ctx.sign(&kp, &tx), kp.public, tx)
+        })
+        .collect();
+
+    assert_eq!(ctx.verify_batch(&[]), Ok(()));
+    assert_eq!(ctx.verify_batch(&batch), Ok(()));
+
+    // Corrupt some signatures
+    batch[3].0.s += ScalarField::from(1u64);
+    batch[4].2.fee += 1;
+    batch[11].0.rx = BaseField::zero();
+    batch[12].1 = receiver.clone();
+    let kp = Keypair::rand(&mut rand::rngs::OsRng).expect("failed to create keypair");
+    batch[19].0 = mina_signer::create_legacy(NetworkId::MAINNET).sign(&kp, &batch[19].2);
+
+    let expected: Vec<usize> = batch
+        .iter()
+        .enumerate()
+        .filter(|(_, (sig, public, tx))| !ctx.verify(sig, public, tx))
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(expected, vec![3, 4, 11, 12, 19]);
+    assert_eq!(ctx.verify_batch(&batch), Err(expected));
+    assert_eq!(ctx.verify_batch(&batch[5..11]), Ok(()));
+    assert_eq!(ctx.verify_batch(&batch[11..12]), Err(vec![0]));
+}
+
+#[test]
+fn signer_custom_network_test() {
+    let kp = Keypair::from_hex("164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718")
+        .expect("failed to create keypair");
+    let tx = Transaction::new_payment(
+        kp.public.clone(),
+        kp.public.clone(),
+        1000000000,
+        10000000,
+        7,
+    );
+    let custom = NetworkId::Custom("mynet".to_string());
+
+    // Mainnet and testnet keep their id bytes
+    assert_eq!(NetworkId::TESTNET.into_bytes(), vec![0x00]);
+    assert_eq!(NetworkId::MAINNET.into_bytes(), vec![0x01]);
+    assert_eq!(custom.clone().into_bytes(), b"mynet".to_vec());
+    // `networkIdOfString` of o1js: the bits of the characters from the last one down,
+    // as a big-endian integer whose little-endian bytes are the id bytes
+    let bits: String = "mynet".bytes().rev().map(|c| format!("{c:08b}")).collect();
+    let id = num_bigint::BigUint::parse_bytes(bits.as_bytes(), 2).expect("invalid bits");
+    assert_eq!(custom.clone().into_bytes(), id.to_bytes_le());
+    assert_eq!(u8::try_from(NetworkId::MAINNET), Ok(0x01));
+    assert_eq!(u8::try_from(custom.clone()), Err(custom.clone()));
+
+    let mut ctx = mina_signer::create_legacy::<Transaction>(custom.clone());
+    let sig = ctx.sign(&kp, &tx);
+    assert!(ctx.verify(&sig, &kp.public, &tx));
+
+    // Signatures of a custom network are not valid on the other networks
+    for network_id in [
+        NetworkId::TESTNET,
+        NetworkId::MAINNET,
+        NetworkId::Custom("othernet".to_string()),
+    ] {
+        let mut other_ctx = mina_signer::create_legacy::<Transaction>(network_id);
+        assert!(!other_ctx.verify(&sig, &kp.public, &tx));
+        assert_ne!(other_ctx.sign(&kp, &tx), sig);
+    }
+
+    let mut ctx = mina_signer::create_kimchi::<Transaction>(custom.clone());
+    let sig = ctx.sign(&kp, &tx);
+    assert!(ctx.verify(&sig, &kp.public, &tx));
+    assert!(
+        !mina_signer::create_kimchi::<Transaction>(NetworkId::TESTNET)
+            .verify(&sig, &kp.public, &tx)
+    );
+}
+
 #[test]
 fn sign_payment_test_1() {
     assert_sign_verify_tx!(
@@ -1,126 +1,23 @@
-use mina_hasher::{Hashable, ROInput};
-use mina_signer::{CompressedPubKey, Keypair, NetworkId, PubKey};
-
-const MEMO_BYTES: usize = 34;
-const TAG_BITS: usize = 3;
-const PAYMENT_TX_TAG: [bool; TAG_BITS] = [false, false, false];
-const DELEGATION_TX_TAG: [bool; TAG_BITS] = [false, false, true];
-
-#[derive(Clone)]
-pub struct Transaction {
-    // Common
-    pub fee: u64,
-    pub fee_token: u64,
-    pub fee_payer_pk: CompressedPubKey,
-    pub nonce: u32,
-    pub valid_until: u32,
-    pub memo: [u8; MEMO_BYTES],
-    // Body
-    pub tag: [bool; TAG_BITS],
-    pub source_pk: CompressedPubKey,
-    pub receiver_pk: CompressedPubKey,
-    pub token_id: u64,
-    pub amount: u64,
-    pub token_locked: bool,
-}
-
-impl Hashable for Transaction {
-    type D = NetworkId;
-
-    fn to_roinput(&self) -> ROInput {
-        let mut roi = ROInput::new()
-            .append_field(self.fee_payer_pk.x)
-            .append_field(self.source_pk.x)
-            .append_field(self.receiver_pk.x)
-            .append_u64(self.fee)
-            .append_u64(self.fee_token)
-            .append_bool(self.fee_payer_pk.is_odd)
-            .append_u32(self.nonce)
-            .append_u32(self.valid_until)
-            .append_bytes(&self.memo);
-
-        for tag_bit in self.tag {
-            roi = roi.append_bool(tag_bit);
-        }
-
-        roi.append_bool(self.source_pk.is_odd)
-            .append_bool(self.receiver_pk.is_odd)
-            .append_u64(self.token_id)
-            .append_u64(self.amount)
-            .append_bool(self.token_locked)
-    }
-
-    fn domain_string(network_id: NetworkId) -> Option<String> {
-        // Domain strings must have length <= 20
-        match network_id {
-            NetworkId::MAINNET => "MinaSignatureMainnet",
-            NetworkId::TESTNET => "CodaSignature",
-        }
-        .to_string()
-        .into()
-    }
-}
-
-impl Transaction {
-    pub fn new_payment(from: PubKey, to: PubKey, amount: u64, fee: u64, nonce: u32) -> Self {
-        Transaction {
-            fee,
-            fee_token: 1,
-            fee_payer_pk: from.into_compressed(),
-            nonce,
-            valid_until: u32::MAX,
-            memo: std::array::from_fn(|i| (i == 0) as u8),
-            tag: PAYMENT_TX_TAG,
-            source_pk: from.into_compressed(),
-            receiver_pk: to.into_compressed(),
-            token_id: 1,
-            amount,
-            token_locked: false,
-        }
-    }
-
-    pub fn new_delegation(from: PubKey, to: PubKey, fee: u64, nonce: u32) -> Self {
-        Transaction {
-            fee,
-            fee_token: 1,
-            fee_payer_pk: from.into_compressed(),
-            nonce,
-            valid_until: u32::MAX,
-            memo: std::array::from_fn(|i| (i == 0) as u8),
-            tag: DELEGATION_TX_TAG,
-            source_pk: from.into_compressed(),
-            receiver_pk: to.into_compressed(),
-            token_id: 1,
-            amount: 0,
-            token_locked: false,
-        }
-    }
-
-    pub fn set_valid_until(mut self, global_slot: u32) -> Self {
-        self.valid_until = global_slot;
-
-        self
-    }
-
-    pub fn set_memo(mut self, memo: [u8; MEMO_BYTES - 2]) -> Self {
-        self.memo[0] = 0x01;
-        self.memo[1] = (MEMO_BYTES - 2) as u8;
-        self.memo[2..].copy_from_slice(&memo[..]);
-
-        self
-    }
-
-    pub fn set_memo_str(mut self, memo: &str) -> Self {
-        self.memo[0] = 0x01;
-        self.memo[1] = std::cmp::min(memo.len(), MEMO_BYTES - 2) as u8;
-        let memo = format!("{memo:\0<32}"); // Pad user-supplied memo with zeros
-        self.memo[2..]
-            .copy_from_slice(&memo.as_bytes()[..std::cmp::min(memo.len(), MEMO_BYTES - 2)]);
-        // Anything beyond MEMO_BYTES is truncated
-
-        self
-    }
-}
+use ark_ff::Zero;
+use mina_hasher::{create_legacy, Hashable, Hasher};
+use mina_signer::{
+    transaction::{
+        zkapp::{
+            account_update::{
+                AccountPrecondition, AuthRequired, AuthorizationKind, BalanceChange,
+                ClosedInterval, EpochDataPrecondition, EpochLedgerPrecondition, Events,
+                MayUseToken, NetworkPrecondition, Permissions, Preconditions, Sgn, Timing, Update,
+                ZKAPP_STATE_LENGTH,
+            },
+            call_forest_hash,
+            hash::{hash_with_prefix, prefixes},
+            AccountUpdate, AccountUpdateBody, FeePayer,
+        },
+        Memo, Transaction, TransactionCommitment, ZkappCommand,
+    },
+    BaseField, CompressedPubKey, Keypair, NetworkId, Signer,
+};
+use o1_utils::FieldHelpers;
 
 #[test]
 fn transaction_domain() {
@@ -132,6 +29,16 @@ fn transaction_domain() {
         Transaction::domain_string(NetworkId::TESTNET).expect("missing domain string"),
         "CodaSignature"
     );
+    assert_eq!(
+        Transaction::domain_string(NetworkId::Custom("mynet".to_string()))
+            .expect("missing domain string"),
+        "mynetSignature******"
+    );
+    assert_eq!(
+        Transaction::domain_string(NetworkId::Custom("a-very-long-network".to_string()))
+            .expect("missing domain string"),
+        "a-very-long-networkS"
+    );
 }
 
 #[test]
@@ -199,6 +106,11 @@ fn transaction_memo_str() {
         ]
     );
 
+    assert_eq!(
+        tx.memo(),
+        "Hello Mina!".parse::<Memo>().expect("invalid memo")
+    );
+
     // Memo > max memo length (truncate)
     let tx = tx.set_memo_str("012345678901234567890123456789012345");
     assert_eq!(
@@ -209,3 +121,592 @@ fn transaction_memo_str() {
         ]
     );
 }
+
+// `Transaction`, with a derived `Hashable` implementation
+#[derive(Clone, Hashable)]
+#[hashable(
+    domain_param = "NetworkId",
+    domain_fn = "mina_signer::transaction::signature_domain_string"
+)]
+struct DerivedTransaction {
+    #[hashable(u64)]
+    fee: u64,
+    #[hashable(u64)]
+    fee_token: u64,
+    fee_payer_pk: DerivedPubKey,
+    #[hashable(u32)]
+    nonce: u32,
+    #[hashable(u32)]
+    valid_until: u32,
+    #[hashable(bytes)]
+    memo: [u8; 34],
+    #[hashable(bool)]
+    tag: [bool; 3],
+    source_pk: DerivedPubKey,
+    receiver_pk: DerivedPubKey,
+    #[hashable(u64)]
+    token_id: u64,
+    #[hashable(u64)]
+    amount: u64,
+    #[hashable(bool)]
+    token_locked: bool,
+}
+
+#[derive(Clone, Hashable)]
+struct DerivedPubKey {
+    #[hashable(field)]
+    x: BaseField,
+    #[hashable(bool)]
+    is_odd: bool,
+}
+
+impl From<&CompressedPubKey> for DerivedPubKey {
+    fn from(pk: &CompressedPubKey) -> Self {
+        Self {
+            x: pk.x,
+            is_odd: pk.is_odd,
+        }
+    }
+}
+
+impl From<&Transaction> for DerivedTransaction {
+    fn from(tx: &Transaction) -> Self {
+        Self {
+            fee: tx.fee,
+            fee_token: tx.fee_token,
+            fee_payer_pk: (&tx.fee_payer_pk).into(),
+            nonce: tx.nonce,
+            valid_until: tx.valid_until,
+            memo: tx.memo,
+            tag: tx.tag,
+            source_pk: (&tx.source_pk).into(),
+            receiver_pk: (&tx.receiver_pk).into(),
+            token_id: tx.token_id,
+            amount: tx.amount,
+            token_locked: tx.token_locked,
+        }
+    }
+}
+
+#[test]
+fn transaction_derived_hashable() {
+    let from =
+        Keypair::from_hex("164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718")
+            .expect("failed to create keypair");
+    let to = Keypair::from_hex("3ca187a58f09da346844964310c7e0dd948a9105702b716f4d732e042e0c172e")
+        .expect("failed to create keypair");
+
+    for tx in [
+        Transaction::new_payment(
+            from.public.clone(),
+            to.public.clone(),
+            1729000000000,
+            2000000000,
+            16,
+        )
+        .set_valid_until(271828)
+        .set_memo_str("Hello Mina!"),
+        Transaction::new_delegation(from.public.clone(), to.public.clone(), 2000000000, 3),
+    ] {
+        let derived = DerivedTransaction::from(&tx);
+        assert_eq!(derived.to_roinput(), tx.to_roinput());
+        for network_id in [NetworkId::MAINNET, NetworkId::TESTNET] {
+            assert_eq!(
+                DerivedTransaction::domain_string(network_id.clone()),
+                Transaction::domain_string(network_id.clone())
+            );
+            assert_eq!(
+                create_legacy::<DerivedTransaction>(network_id.clone()).hash(&derived),
+                create_legacy::<Transaction>(network_id).hash(&tx)
+            );
+        }
+    }
+}
+
+fn zkapp_command(kp: &Keypair, account_updates: Vec<AccountUpdate>) -> ZkappCommand {
+    ZkappCommand {
+        fee_payer: FeePayer {
+            public_key: kp.public.into_compressed(),
+            fee: 100000000,
+            valid_until: None,
+            nonce: 1,
+        },
+        account_updates,
+        memo: Memo::empty(),
+    }
+}
+
+#[test]
+fn zkapp_commitments() {
+    let kp = Keypair::from_hex("164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718")
+        .expect("failed to create keypair");
+
+    let command = zkapp_command(&kp, vec![]);
+    assert_eq!(
+        command.commitment(NetworkId::TESTNET),
+        TransactionCommitment(BaseField::zero())
+    );
+
+    // The full commitment depends on the network, memo and fee payer
+    let full_commitment = command.full_commitment(NetworkId::TESTNET);
+    assert_ne!(full_commitment, command.full_commitment(NetworkId::MAINNET));
+
+    let mut other = command.clone();
+    other.memo = "Hello Mina!".parse().expect("invalid memo");
+    assert_ne!(full_commitment, other.full_commitment(NetworkId::TESTNET));
+
+    let mut other = command.clone();
+    other.fee_payer.valid_until = Some(u32::MAX);
+    assert_eq!(full_commitment, other.full_commitment(NetworkId::TESTNET));
+    other.fee_payer.nonce = 2;
+    assert_ne!(full_commitment, other.full_commitment(NetworkId::TESTNET));
+
+    // The commitment to sign depends on the account update
+    let mut body = AccountUpdateBody::new(kp.public.into_compressed());
+    let command = zkapp_command(&kp, vec![AccountUpdate::new(body.clone())]);
+    assert_ne!(
+        command.commitment(NetworkId::TESTNET),
+        TransactionCommitment(BaseField::zero())
+    );
+    assert_eq!(
+        command.commitment_for(&body, NetworkId::TESTNET),
+        command.commitment(NetworkId::TESTNET)
+    );
+    body.use_full_commitment = true;
+    assert_eq!(
+        command.commitment_for(&body, NetworkId::TESTNET),
+        command.full_commitment(NetworkId::TESTNET)
+    );
+}
+
+#[test]
+fn zkapp_call_forest() {
+    let kp = Keypair::from_hex("164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718")
+        .expect("failed to create keypair");
+    let network_id = NetworkId::MAINNET;
+
+    let mut body = AccountUpdateBody::new(kp.public.into_compressed());
+    body.events = Events(vec![vec![BaseField::from(1u64), BaseField::from(2u64)]]);
+    let child = AccountUpdate::new(body.clone());
+    body.authorization_kind = AuthorizationKind::Signature;
+    let parent = AccountUpdate {
+        body: body.clone(),
+        calls: vec![child.clone()],
+    };
+    body.increment_nonce = true;
+    let sibling = AccountUpdate::new(body);
+
+    let node = |account_update: &AccountUpdate, calls_hash| {
+        hash_with_prefix(
+            prefixes::ACCOUNT_UPDATE_NODE,
+            &[account_update.body.hash(network_id.clone()), calls_hash],
+        )
+    };
+    let cons = |hash, rest| hash_with_prefix(prefixes::ACCOUNT_UPDATE_CONS, &[hash, rest]);
+
+    let child_hash = node(&child, BaseField::zero());
+    let parent_hash = node(&parent, cons(child_hash, BaseField::zero()));
+    let sibling_hash = node(&sibling, BaseField::zero());
+    assert_eq!(
+        call_forest_hash(&[parent, sibling], network_id),
+        cons(parent_hash, cons(sibling_hash, BaseField::zero()))
+    );
+}
+
+#[test]
+fn zkapp_sign() {
+    let kp = Keypair::from_hex("164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718")
+        .expect("failed to create keypair");
+
+    let mut body = AccountUpdateBody::new(kp.public.into_compressed());
+    body.authorization_kind = AuthorizationKind::Signature;
+    let command = zkapp_command(&kp, vec![AccountUpdate::new(body)]);
+
+    let mut ctx = mina_signer::create_kimchi::<TransactionCommitment>(NetworkId::TESTNET);
+    let full_commitment = command.full_commitment(NetworkId::TESTNET);
+    let sig = ctx.sign(&kp, &full_commitment);
+    assert!(ctx.verify(&sig, &kp.public, &full_commitment));
+    assert!(!ctx.verify(&sig, &kp.public, &command.commitment(NetworkId::TESTNET)));
+}
+
+// A payment from the fee payer to a new zkApp account, which calls an account update of its own token
+const ZKAPP_COMMAND_JSON: &str = r#"{
+  "feePayer": {
+    "body": {
+      "publicKey": "B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV",
+      "fee": "100000000",
+      "validUntil": null,
+      "nonce": "1"
+    },
+    "authorization": ""
+  },
+  "accountUpdates": [
+    {
+      "body": {
+        "publicKey": "B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV",
+        "tokenId": "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf",
+        "update": {
+          "appState": [null, null, null, null, null, null, null, null],
+          "delegate": null,
+          "verificationKey": null,
+          "permissions": null,
+          "zkappUri": null,
+          "tokenSymbol": null,
+          "timing": null,
+          "votingFor": null
+        },
+        "balanceChange": { "magnitude": "1000000000", "sgn": "Negative" },
+        "incrementNonce": false,
+        "events": [],
+        "actions": [],
+        "callData": "0",
+        "callDepth": 0,
+        "preconditions": {
+          "network": {
+            "snarkedLedgerHash": null,
+            "blockchainLength": null,
+            "minWindowDensity": null,
+            "totalCurrency": null,
+            "globalSlotSinceGenesis": null,
+            "stakingEpochData": {
+              "ledger": { "hash": null, "totalCurrency": null },
+              "seed": null,
+              "startCheckpoint": null,
+              "lockCheckpoint": null,
+              "epochLength": null
+            },
+            "nextEpochData": {
+              "ledger": { "hash": null, "totalCurrency": null },
+              "seed": null,
+              "startCheckpoint": null,
+              "lockCheckpoint": null,
+              "epochLength": null
+            }
+          },
+          "account": {
+            "balance": null,
+            "nonce": null,
+            "receiptChainHash": null,
+            "delegate": null,
+            "state": [null, null, null, null, null, null, null, null],
+            "actionState": null,
+            "provedState": null,
+            "isNew": null
+          },
+          "validWhile": null
+        },
+        "useFullCommitment": true,
+        "implicitAccountCreationFee": false,
+        "mayUseToken": { "parentsOwnToken": false, "inheritFromParent": false },
+        "authorizationKind": {
+          "isSigned": true,
+          "isProved": false,
+          "verificationKeyHash": "3392518251768960475377392625298437850623664973002200885669375116181514017494"
+        }
+      },
+      "authorization": { "proof": null, "signature": null }
+    },
+    {
+      "body": {
+        "publicKey": "B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt",
+        "tokenId": "wSHV2S4qX9jFsLjQo8r1BsMLH2ZRKsZx6EJd1sbozGPieEC4Jf",
+        "update": {
+          "appState": [null, "7", null, null, null, null, null, null],
+          "delegate": "B62qnzbXmRNo9q32n4SNu2mpB8e7FYYLH8NmaX6oFCBYjjQ8SbD7uzV",
+          "verificationKey": { "data": "", "hash": "8" },
+          "permissions": {
+            "editState": "Proof",
+            "access": "None",
+            "send": "Signature",
+            "receive": "None",
+            "setDelegate": "Either",
+            "setPermissions": "Impossible",
+            "setVerificationKey": { "auth": "Signature", "txnVersion": "3" },
+            "setZkappUri": "Signature",
+            "editActionState": "Proof",
+            "setTokenSymbol": "Signature",
+            "incrementNonce": "Signature",
+            "setVotingFor": "Signature",
+            "setTiming": "Signature"
+          },
+          "zkappUri": "https://minaprotocol.com",
+          "tokenSymbol": "MINA",
+          "timing": {
+            "initialMinimumBalance": "10",
+            "cliffTime": "11",
+            "cliffAmount": "12",
+            "vestingPeriod": "13",
+            "vestingIncrement": "14"
+          },
+          "votingFor": "3NK4ew8JXDWjNLd9VE9wCvfiRdfBtDUagXP9DfyekZGi2PiJoSpu"
+        },
+        "balanceChange": { "magnitude": "1000000000", "sgn": "Positive" },
+        "incrementNonce": false,
+        "events": [["1", "2"]],
+        "actions": [["3"]],
+        "callData": "11",
+        "callDepth": 0,
+        "preconditions": {
+          "network": {
+            "snarkedLedgerHash": "jw7V56gFaDnsggZSLgbiRDCMUHSoBH2nq9DV6XPMLhirx53dYoB",
+            "blockchainLength": { "lower": "100", "upper": "200" },
+            "minWindowDensity": null,
+            "totalCurrency": null,
+            "globalSlotSinceGenesis": null,
+            "stakingEpochData": {
+              "ledger": { "hash": null, "totalCurrency": null },
+              "seed": "2vaAVuWjpnA74JgmqyQVx7BB1dySvk489HKWFX7kn9NVeVvhgUVw",
+              "startCheckpoint": null,
+              "lockCheckpoint": null,
+              "epochLength": null
+            },
+            "nextEpochData": {
+              "ledger": { "hash": null, "totalCurrency": { "lower": "0", "upper": "1000" } },
+              "seed": null,
+              "startCheckpoint": "3NK4ew8JXDWjNLd9VE9wCvfiRdfBtDUagXP9DfyekZGi2PiJoSpu",
+              "lockCheckpoint": null,
+              "epochLength": null
+            }
+          },
+          "account": {
+            "balance": null,
+            "nonce": null,
+            "receiptChainHash": "2mzYoqDZzaTPrkU9Fx97tXVr6awMzkzx5g5sGeQtHctwRyCeGxcA",
+            "delegate": null,
+            "state": ["1", null, null, null, null, null, null, null],
+            "actionState": null,
+            "provedState": null,
+            "isNew": true
+          },
+          "validWhile": null
+        },
+        "useFullCommitment": false,
+        "implicitAccountCreationFee": true,
+        "mayUseToken": { "parentsOwnToken": false, "inheritFromParent": false },
+        "authorizationKind": {
+          "isSigned": false,
+          "isProved": false,
+          "verificationKeyHash": "3392518251768960475377392625298437850623664973002200885669375116181514017494"
+        }
+      },
+      "authorization": { "proof": null, "signature": null }
+    },
+    {
+      "body": {
+        "publicKey": "B62qicipYxyEHu7QjUqS7QvBipTs5CzgkYZZZkPoKVYBu6tnDUcE9Zt",
+        "tokenId": "wUVCgkviZDffMgeYcvBVEGgngwpWNFpY4Tka7w6hBf4DrQN5cW",
+        "update": {
+          "appState": [null, null, null, null, null, null, null, null],
+          "delegate": null,
+          "verificationKey": null,
+          "permissions": null,
+          "zkappUri": null,
+          "tokenSymbol": null,
+          "timing": null,
+          "votingFor": null
+        },
+        "balanceChange": { "magnitude": "0", "sgn": "Positive" },
+        "incrementNonce": false,
+        "events": [],
+        "actions": [],
+        "callData": "0",
+        "callDepth": 1,
+        "preconditions": {
+          "network": {
+            "snarkedLedgerHash": null,
+            "blockchainLength": null,
+            "minWindowDensity": null,
+            "totalCurrency": null,
+            "globalSlotSinceGenesis": null,
+            "stakingEpochData": {
+              "ledger": { "hash": null, "totalCurrency": null },
+              "seed": null,
+              "startCheckpoint": null,
+              "lockCheckpoint": null,
+              "epochLength": null
+            },
+            "nextEpochData": {
+              "ledger": { "hash": null, "totalCurrency": null },
+              "seed": null,
+              "startCheckpoint": null,
+              "lockCheckpoint": null,
+              "epochLength": null
+            }
+          },
+          "account": {
+            "balance": null,
+            "nonce": null,
+            "receiptChainHash": null,
+            "delegate": null,
+            "state": [null, null, null, null, null, null, null, null],
+            "actionState": null,
+            "provedState": null,
+            "isNew": null
+          },
+          "validWhile": { "lower": "5", "upper": "50" }
+        },
+        "useFullCommitment": false,
+        "implicitAccountCreationFee": false,
+        "mayUseToken": { "parentsOwnToken": true, "inheritFromParent": false },
+        "authorizationKind": {
+          "isSigned": false,
+          "isProved": true,
+          "verificationKeyHash": "8"
+        }
+      },
+      "authorization": { "proof": null, "signature": null }
+    }
+  ],
+  "memo": "E4YM2vTHhWEg66xpj52JErHUBU4pZ1yageL4TVDDpTTSsv8mK6YaH"
+}"#;
+
+#[test]
+fn zkapp_json() {
+    let kp = Keypair::from_hex("164244176fddb5d769b7de2027469d027ad428fadcc0c02396e6280142efb718")
+        .expect("failed to create keypair");
+    let zkapp =
+        Keypair::from_hex("3ca187a58f09da346844964310c7e0dd948a9105702b716f4d732e042e0c172e")
+            .expect("failed to create keypair")
+            .public
+            .into_compressed();
+    let state_hash = BaseField::from(4u64);
+
+    let payment = AccountUpdateBody {
+        balance_change: BalanceChange {
+            magnitude: 1000000000,
+            sgn: Sgn::Neg,
+        },
+        use_full_commitment: true,
+        authorization_kind: AuthorizationKind::Signature,
+        ..AccountUpdateBody::new(kp.public.into_compressed())
+    };
+    let mut app_state = [None; ZKAPP_STATE_LENGTH];
+    app_state[1] = Some(BaseField::from(7u64));
+    let mut state = [None; ZKAPP_STATE_LENGTH];
+    state[0] = Some(BaseField::from(1u64));
+    let deployment = AccountUpdateBody {
+        update: Update {
+            app_state,
+            delegate: Some(kp.public.into_compressed()),
+            verification_key_hash: Some(BaseField::from(8u64)),
+            permissions: Some(Permissions {
+                edit_state: AuthRequired::Proof,
+                send: AuthRequired::Signature,
+                set_delegate: AuthRequired::Either,
+                set_permissions: AuthRequired::Impossible,
+                set_verification_key: (AuthRequired::Signature, 3),
+                set_zkapp_uri: AuthRequired::Signature,
+                edit_action_state: AuthRequired::Proof,
+                set_token_symbol: AuthRequired::Signature,
+                increment_nonce: AuthRequired::Signature,
+                set_voting_for: AuthRequired::Signature,
+                set_timing: AuthRequired::Signature,
+                ..Permissions::empty()
+            }),
+            zkapp_uri: Some("https://minaprotocol.com".to_string()),
+            token_symbol: Some("MINA".to_string()),
+            timing: Some(Timing {
+                initial_minimum_balance: 10,
+                cliff_time: 11,
+                cliff_amount: 12,
+                vesting_period: 13,
+                vesting_increment: 14,
+            }),
+            voting_for: Some(state_hash),
+        },
+        balance_change: BalanceChange {
+            magnitude: 1000000000,
+            sgn: Sgn::Pos,
+        },
+        events: Events(vec![vec![BaseField::from(1u64), BaseField::from(2u64)]]),
+        actions: Events(vec![vec![BaseField::from(3u64)]]),
+        call_data: BaseField::from(11u64),
+        preconditions: Preconditions {
+            network: NetworkPrecondition {
+                snarked_ledger_hash: Some(BaseField::from(2u64)),
+                blockchain_length: Some(ClosedInterval {
+                    lower: 100,
+                    upper: 200,
+                }),
+                staking_epoch_data: EpochDataPrecondition {
+                    seed: Some(BaseField::from(3u64)),
+                    ..Default::default()
+                },
+                next_epoch_data: EpochDataPrecondition {
+                    ledger: EpochLedgerPrecondition {
+                        hash: None,
+                        total_currency: Some(ClosedInterval {
+                            lower: 0,
+                            upper: 1000,
+                        }),
+                    },
+                    start_checkpoint: Some(state_hash),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            account: AccountPrecondition {
+                receipt_chain_hash: Some(BaseField::from(5u64)),
+                state,
+                is_new: Some(true),
+                ..Default::default()
+            },
+            valid_while: None,
+        },
+        implicit_account_creation_fee: true,
+        ..AccountUpdateBody::new(zkapp.clone())
+    };
+    let token_update = AccountUpdateBody {
+        token_id: BaseField::from(6u64),
+        preconditions: Preconditions {
+            valid_while: Some(ClosedInterval {
+                lower: 5,
+                upper: 50,
+            }),
+            ..Default::default()
+        },
+        may_use_token: MayUseToken::ParentsOwnToken,
+        authorization_kind: AuthorizationKind::Proof(BaseField::from(8u64)),
+        ..AccountUpdateBody::new(zkapp)
+    };
+    let command = zkapp_command(
+        &kp,
+        vec![
+            AccountUpdate::new(payment),
+            AccountUpdate {
+                body: deployment,
+                calls: vec![AccountUpdate::new(token_update)],
+            },
+        ],
+    );
+    assert_eq!(
+        ZkappCommand::from_json(ZKAPP_COMMAND_JSON),
+        Ok(command.clone())
+    );
+
+    // N.B. Regression values of this implementation, not produced by o1js
+    let network_id = NetworkId::TESTNET;
+    let commitment = command.commitment(network_id.clone());
+    let full_commitment = command.full_commitment(network_id.clone());
+    assert_eq!(
+        commitment.0.to_biguint(),
+        "15478536716758494374525562557524261319081831839824621438170943921780070115072"
+            .parse()
+            .unwrap()
+    );
+    assert_eq!(
+        full_commitment.0.to_biguint(),
+        "18119958822433455755803502083193960476777418362095645357869245598148073907568"
+            .parse()
+            .unwrap()
+    );
+
+    let mut ctx = mina_signer::create_kimchi::<TransactionCommitment>(network_id);
+    let sig = ctx.sign(&kp, &full_commitment);
+    assert_eq!(
+        sig.to_string(),
+        "03efb7acebf04f9319474ad1c3ff86a86dcf14b251b5a18cd4385c64cb12913f\
+         265e33d21bf55a16a05e187188853ad5d3d9b9deefcf776beabc2ee41f55ebd9"
+    );
+    assert!(ctx.verify(&sig, &kp.public, &full_commitment));
+}
@@ -1,7 +1,10 @@
 //! This adds a few utility functions for serializing and deserializing
 //! [arkworks](http://arkworks.rs/) types that implement [CanonicalSerialize] and [CanonicalDeserialize].
 
+use ark_ec::{short_weierstrass_jacobian::GroupAffine, SWModelParameters};
+use ark_ff::PrimeField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use mina_curves::serialization::{from_compressed_bytes, to_compressed_bytes};
 use serde_with::Bytes;
 
 //
@@ -87,3 +90,50 @@ where
         T::deserialize(&mut &bytes[..]).map_err(serde::de::Error::custom)
     }
 }
+
+//
+// Serialization of curve points with the encoding of [mina_curves::serialization]
+//
+
+/// You can use [SerdeAsCompressedPoint] with [serde_with] to serialize and deserialize curve points
+/// with the canonical compressed encoding of [mina_curves::serialization], instead of the one of arkworks.
+/// Deserialization checks that the points are on the curve and in the prime-order subgroup.
+/// Simply add annotations like `#[serde_as(as = "o1_utils::serialization::SerdeAsCompressedPoint")]`
+pub struct SerdeAsCompressedPoint;
+
+impl<P> serde_with::SerializeAs<GroupAffine<P>> for SerdeAsCompressedPoint
+where
+    P: SWModelParameters,
+    P::BaseField: PrimeField,
+{
+    fn serialize_as<S>(val: &GroupAffine<P>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let bytes = to_compressed_bytes(val);
+
+        if serializer.is_human_readable() {
+            hex::serde::serialize(bytes, serializer)
+        } else {
+            Bytes::serialize_as(&bytes, serializer)
+        }
+    }
+}
+
+impl<'de, P> serde_with::DeserializeAs<'de, GroupAffine<P>> for SerdeAsCompressedPoint
+where
+    P: SWModelParameters,
+    P::BaseField: PrimeField,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<GroupAffine<P>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes: Vec<u8> = if deserializer.is_human_readable() {
+            hex::serde::deserialize(deserializer)?
+        } else {
+            Bytes::deserialize_as(deserializer)?
+        };
+        from_compressed_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}